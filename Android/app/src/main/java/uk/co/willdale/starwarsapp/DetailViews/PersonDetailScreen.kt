@@ -143,6 +143,10 @@ private fun PersonDetailContent(
                 is uniffi.starwars.ListItems.Homeworld -> {
                     SelectableRow(title = "Homeworld") { onHomeworldClick(item.v1) }
                 }
+                // People are never related to other people
+                is uniffi.starwars.ListItems.Characters,
+                is uniffi.starwars.ListItems.Residents,
+                is uniffi.starwars.ListItems.Pilots -> Unit
             }
         }
     }
@@ -5,9 +5,12 @@ import androidx.activity.ComponentActivity
 import androidx.activity.compose.setContent
 import androidx.activity.enableEdgeToEdge
 import androidx.lifecycle.ViewModelProvider
+import java.io.File
 import uk.co.willdale.starwarsapp.Networking.HttpClientAndroid
 import uniffi.starwars.ApiClient
 import uniffi.starwars.DataRepository
+import uniffi.starwars.FileStore
+import uniffi.starwars.StoreException
 
 class MainActivity : ComponentActivity() {
 
@@ -28,9 +31,17 @@ class MainActivity : ComponentActivity() {
         val factory = MainViewModelFactory(
             apiClient = ApiClient(
                 httpClient = HttpClientAndroid(),
-                repository = DataRepository()
+                repository = makeRepository()
             )
         )
         viewModel = ViewModelProvider(this, factory)[MainViewModel::class.java]
     }
+
+    // Cached records survive relaunches; if the cache directory can't be used, fall back to memory
+    private fun makeRepository(): DataRepository =
+        try {
+            DataRepository.withStore(FileStore(File(cacheDir, "repository").path))
+        } catch (e: StoreException) {
+            DataRepository()
+        }
 }
@@ -16,30 +16,50 @@ class MainViewModel(private val apiClient: ApiClient) : ViewModel() {
 
     private val fetchedCategories = mutableSetOf<Fetchable>()
 
+    // Every load replaces the UI state, so starting one cancels whatever the previous load left running
+    private var cancellation = CancellationToken()
+
+    private fun restartCancellation(): CancellationToken {
+        cancellation.cancel()
+        return CancellationToken().also { cancellation = it }
+    }
+
+    override fun onCleared() {
+        cancellation.cancel()
+        super.onCleared()
+    }
+
     fun loadData(fetchable: Fetchable, urls: List<String>? = null) {
+        val cancellation = restartCancellation()
         viewModelScope.launch {
             _uiState.value = UiState.Loading
             try {
                 val data: List<Any> = if (urls != null) {
                     urls.map { url ->
                         async {
-                            fetchSingleItem(fetchable, url)
+                            fetchSingleItem(fetchable, url, cancellation)
                         }
                     }.awaitAll()
                 } else {
-                    val ignoreCache = !fetchedCategories.contains(fetchable)
+                    val policy = if (fetchedCategories.contains(fetchable)) {
+                        CachePolicy.CACHE_FIRST
+                    } else {
+                        CachePolicy.NETWORK_FIRST
+                    }
                     val result = when (fetchable) {
-                        Fetchable.PLANETS -> apiClient.fetchPlanets(ignoreCache)
-                        Fetchable.PEOPLE -> apiClient.fetchPeople(ignoreCache)
-                        Fetchable.FILMS -> apiClient.fetchFilms(ignoreCache)
-                        Fetchable.SPECIES -> apiClient.fetchSpeciesList(ignoreCache)
-                        Fetchable.STARSHIPS -> apiClient.fetchStarships(ignoreCache)
-                        Fetchable.VEHICLES -> apiClient.fetchVehicles(ignoreCache)
+                        Fetchable.PLANETS -> apiClient.fetchPlanets(policy, cancellation)
+                        Fetchable.PEOPLE -> apiClient.fetchPeople(policy, cancellation)
+                        Fetchable.FILMS -> apiClient.fetchFilms(policy, cancellation)
+                        Fetchable.SPECIES -> apiClient.fetchSpeciesList(policy, cancellation)
+                        Fetchable.STARSHIPS -> apiClient.fetchStarships(policy, cancellation)
+                        Fetchable.VEHICLES -> apiClient.fetchVehicles(policy, cancellation)
                     }
                     fetchedCategories.add(fetchable)
                     result
                 }
                 _uiState.value = UiState.Success(data)
+            } catch (e: ApiException.Cancelled) {
+                // Superseded by a newer load, which owns the UI state now
             } catch (e: Exception) {
                 _uiState.value = UiState.Error(e.message ?: "An unexpected error occurred")
             }
@@ -47,25 +67,28 @@ class MainViewModel(private val apiClient: ApiClient) : ViewModel() {
     }
 
     fun loadItem(fetchable: Fetchable, url: String) {
+        val cancellation = restartCancellation()
         viewModelScope.launch {
             _uiState.value = UiState.Loading
             try {
-                val item = fetchSingleItem(fetchable, url)
+                val item = fetchSingleItem(fetchable, url, cancellation)
                 _uiState.value = UiState.Success(listOf(item))
+            } catch (e: ApiException.Cancelled) {
+                // Superseded by a newer load, which owns the UI state now
             } catch (e: Exception) {
                 _uiState.value = UiState.Error(e.message ?: "An unexpected error occurred")
             }
         }
     }
 
-    private suspend fun fetchSingleItem(fetchable: Fetchable, url: String): Any {
+    private suspend fun fetchSingleItem(fetchable: Fetchable, url: String, cancellation: CancellationToken): Any {
         return when (fetchable) {
-            Fetchable.PLANETS -> apiClient.fetchPlanet(url)
-            Fetchable.PEOPLE -> apiClient.fetchPerson(url)
-            Fetchable.FILMS -> apiClient.fetchFilm(url)
-            Fetchable.SPECIES -> apiClient.fetchSpecies(url)
-            Fetchable.STARSHIPS -> apiClient.fetchStarship(url)
-            Fetchable.VEHICLES -> apiClient.fetchVehicle(url)
+            Fetchable.PLANETS -> apiClient.fetchPlanet(url, CachePolicy.CACHE_FIRST, cancellation)
+            Fetchable.PEOPLE -> apiClient.fetchPerson(url, CachePolicy.CACHE_FIRST, cancellation)
+            Fetchable.FILMS -> apiClient.fetchFilm(url, CachePolicy.CACHE_FIRST, cancellation)
+            Fetchable.SPECIES -> apiClient.fetchSpecies(url, CachePolicy.CACHE_FIRST, cancellation)
+            Fetchable.STARSHIPS -> apiClient.fetchStarship(url, CachePolicy.CACHE_FIRST, cancellation)
+            Fetchable.VEHICLES -> apiClient.fetchVehicle(url, CachePolicy.CACHE_FIRST, cancellation)
         }
     }
 }
@@ -2,32 +2,43 @@ package uk.co.willdale.starwarsapp.Networking
 
 import kotlinx.coroutines.Dispatchers
 import kotlinx.coroutines.withContext
+import uniffi.starwars.CancellationListener
 import uniffi.starwars.HttpClient
+import uniffi.starwars.HttpRequest
+import uniffi.starwars.HttpResponse
 import uniffi.starwars.NetworkException
 import java.net.HttpURLConnection
 import java.net.SocketTimeoutException
 import java.net.URL
 
 class HttpClientAndroid : HttpClient {
-    override suspend fun fetch(url: String): ByteArray = withContext(Dispatchers.IO) {
-        val connection = URL(url).openConnection() as HttpURLConnection
+    override suspend fun fetch(request: HttpRequest): HttpResponse = withContext(Dispatchers.IO) {
+        val connection = URL(request.url).openConnection() as HttpURLConnection
+        // Disconnecting unblocks a connect or read in progress, so a cancelled request stops at once
+        request.cancellation.onCancel(object : CancellationListener {
+            override fun onCancel() = connection.disconnect()
+        })
         try {
             connection.requestMethod = "GET"
             connection.connectTimeout = 5000
             connection.readTimeout = 5000
+            request.headers.forEach { (name, value) -> connection.setRequestProperty(name, value) }
 
             val responseCode = connection.responseCode
-            if (responseCode in 200..299) {
-                connection.inputStream.use { it.readBytes() }
-            } else {
-                throw NetworkException.RequestFailed(reason = "HTTP error: $responseCode")
-            }
+            val stream = if (responseCode in 200..299) connection.inputStream else connection.errorStream
+            val body = stream?.use { it.readBytes() } ?: ByteArray(0)
+            val headers = connection.headerFields
+                .mapNotNull { (key, values) -> key?.let { it to values.joinToString(", ") } }
+                .toMap()
+            HttpResponse(status = responseCode.toUShort(), headers = headers, body = body)
         } catch (e: NetworkException) {
             throw e  // let UniFFI-mapped exceptions pass through untouched
-        } catch (e: SocketTimeoutException) {
-            throw NetworkException.Timeout()
         } catch (e: Exception) {
-            throw NetworkException.RequestFailed(reason = e.message ?: "Unknown error")
+            throw when {
+                request.cancellation.isCancelled() -> NetworkException.Cancelled()
+                e is SocketTimeoutException -> NetworkException.Timeout()
+                else -> NetworkException.RequestFailed(reason = e.message ?: "Unknown error")
+            }
         } finally {
             connection.disconnect()
         }
@@ -620,8 +620,45 @@ internal open class UniffiForeignFutureResultVoid(
 internal interface UniffiForeignFutureCompleteVoid : com.sun.jna.Callback {
     fun callback(`callbackData`: Long,`result`: UniffiForeignFutureResultVoid.UniffiByValue,)
 }
+internal interface UniffiCallbackInterfaceCancellationListenerMethod0 : com.sun.jna.Callback {
+    fun callback(`uniffiHandle`: Long,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,)
+}
 internal interface UniffiCallbackInterfaceHttpClientMethod0 : com.sun.jna.Callback {
-    fun callback(`uniffiHandle`: Long,`url`: RustBuffer.ByValue,`uniffiFutureCallback`: UniffiForeignFutureCompleteRustBuffer,`uniffiCallbackData`: Long,`uniffiOutDroppedCallback`: UniffiForeignFutureDroppedCallbackStruct,)
+    fun callback(`uniffiHandle`: Long,`request`: RustBuffer.ByValue,`uniffiFutureCallback`: UniffiForeignFutureCompleteRustBuffer,`uniffiCallbackData`: Long,`uniffiOutDroppedCallback`: UniffiForeignFutureDroppedCallbackStruct,)
+}
+internal interface UniffiCallbackInterfaceRepositoryObserverMethod0 : com.sun.jna.Callback {
+    fun callback(`uniffiHandle`: Long,`change`: RustBuffer.ByValue,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,)
+}
+internal interface UniffiCallbackInterfaceRepositoryStoreMethod0 : com.sun.jna.Callback {
+    fun callback(`uniffiHandle`: Long,`key`: RustBuffer.ByValue,`uniffiOutReturn`: RustBuffer,uniffiCallStatus: UniffiRustCallStatus,)
+}
+internal interface UniffiCallbackInterfaceRepositoryStoreMethod1 : com.sun.jna.Callback {
+    fun callback(`uniffiHandle`: Long,`key`: RustBuffer.ByValue,`contents`: RustBuffer.ByValue,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,)
+}
+internal interface UniffiCallbackInterfaceRepositoryStoreMethod2 : com.sun.jna.Callback {
+    fun callback(`uniffiHandle`: Long,`key`: RustBuffer.ByValue,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,)
+}
+internal interface UniffiCallbackInterfaceRepositoryStoreMethod3 : com.sun.jna.Callback {
+    fun callback(`uniffiHandle`: Long,`uniffiOutReturn`: RustBuffer,uniffiCallStatus: UniffiRustCallStatus,)
+}
+@Structure.FieldOrder("uniffiFree", "uniffiClone", "onCancel")
+internal open class UniffiVTableCallbackInterfaceCancellationListener(
+    @JvmField internal var `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+    @JvmField internal var `uniffiClone`: UniffiCallbackInterfaceClone? = null,
+    @JvmField internal var `onCancel`: UniffiCallbackInterfaceCancellationListenerMethod0? = null,
+) : Structure() {
+    class UniffiByValue(
+        `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+        `uniffiClone`: UniffiCallbackInterfaceClone? = null,
+        `onCancel`: UniffiCallbackInterfaceCancellationListenerMethod0? = null,
+    ): UniffiVTableCallbackInterfaceCancellationListener(`uniffiFree`,`uniffiClone`,`onCancel`,), Structure.ByValue
+
+   internal fun uniffiSetValue(other: UniffiVTableCallbackInterfaceCancellationListener) {
+        `uniffiFree` = other.`uniffiFree`
+        `uniffiClone` = other.`uniffiClone`
+        `onCancel` = other.`onCancel`
+    }
+
 }
 @Structure.FieldOrder("uniffiFree", "uniffiClone", "fetch")
 internal open class UniffiVTableCallbackInterfaceHttpClient(
@@ -641,6 +678,53 @@ internal open class UniffiVTableCallbackInterfaceHttpClient(
         `fetch` = other.`fetch`
     }
 
+}
+@Structure.FieldOrder("uniffiFree", "uniffiClone", "onChange")
+internal open class UniffiVTableCallbackInterfaceRepositoryObserver(
+    @JvmField internal var `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+    @JvmField internal var `uniffiClone`: UniffiCallbackInterfaceClone? = null,
+    @JvmField internal var `onChange`: UniffiCallbackInterfaceRepositoryObserverMethod0? = null,
+) : Structure() {
+    class UniffiByValue(
+        `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+        `uniffiClone`: UniffiCallbackInterfaceClone? = null,
+        `onChange`: UniffiCallbackInterfaceRepositoryObserverMethod0? = null,
+    ): UniffiVTableCallbackInterfaceRepositoryObserver(`uniffiFree`,`uniffiClone`,`onChange`,), Structure.ByValue
+
+   internal fun uniffiSetValue(other: UniffiVTableCallbackInterfaceRepositoryObserver) {
+        `uniffiFree` = other.`uniffiFree`
+        `uniffiClone` = other.`uniffiClone`
+        `onChange` = other.`onChange`
+    }
+
+}
+@Structure.FieldOrder("uniffiFree", "uniffiClone", "read", "write", "remove", "keys")
+internal open class UniffiVTableCallbackInterfaceRepositoryStore(
+    @JvmField internal var `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+    @JvmField internal var `uniffiClone`: UniffiCallbackInterfaceClone? = null,
+    @JvmField internal var `read`: UniffiCallbackInterfaceRepositoryStoreMethod0? = null,
+    @JvmField internal var `write`: UniffiCallbackInterfaceRepositoryStoreMethod1? = null,
+    @JvmField internal var `remove`: UniffiCallbackInterfaceRepositoryStoreMethod2? = null,
+    @JvmField internal var `keys`: UniffiCallbackInterfaceRepositoryStoreMethod3? = null,
+) : Structure() {
+    class UniffiByValue(
+        `uniffiFree`: UniffiCallbackInterfaceFree? = null,
+        `uniffiClone`: UniffiCallbackInterfaceClone? = null,
+        `read`: UniffiCallbackInterfaceRepositoryStoreMethod0? = null,
+        `write`: UniffiCallbackInterfaceRepositoryStoreMethod1? = null,
+        `remove`: UniffiCallbackInterfaceRepositoryStoreMethod2? = null,
+        `keys`: UniffiCallbackInterfaceRepositoryStoreMethod3? = null,
+    ): UniffiVTableCallbackInterfaceRepositoryStore(`uniffiFree`,`uniffiClone`,`read`,`write`,`remove`,`keys`,), Structure.ByValue
+
+   internal fun uniffiSetValue(other: UniffiVTableCallbackInterfaceRepositoryStore) {
+        `uniffiFree` = other.`uniffiFree`
+        `uniffiClone` = other.`uniffiClone`
+        `read` = other.`read`
+        `write` = other.`write`
+        `remove` = other.`remove`
+        `keys` = other.`keys`
+    }
+
 }
 
 // A JNA Library to expose the extern-C FFI definitions.
@@ -666,45 +750,221 @@ internal object IntegrityCheckingUniffiLib {
         uniffiCheckApiChecksums(this)
     }
     external fun uniffi_starwars_checksum_func_all_fetchable(
-    ): Short
+    ): Int
+    external fun uniffi_starwars_checksum_func_resource_id(
+    ): Int
     external fun uniffi_starwars_checksum_func_person_related_items(
-    ): Short
+    ): Int
+    external fun uniffi_starwars_checksum_func_related_items(
+    ): Int
+    external fun uniffi_starwars_checksum_func_split_attributes(
+    ): Int
+    external fun uniffi_starwars_checksum_func_format_consumables(
+    ): Int
+    external fun uniffi_starwars_checksum_func_format_galactic_year(
+    ): Int
+    external fun uniffi_starwars_checksum_func_parse_consumables(
+    ): Int
+    external fun uniffi_starwars_checksum_func_parse_galactic_year(
+    ): Int
+    external fun uniffi_starwars_checksum_func_parse_count(
+    ): Int
+    external fun uniffi_starwars_checksum_func_parse_measurement(
+    ): Int
+    external fun uniffi_starwars_checksum_func_parse_measurement_range(
+    ): Int
+    external fun uniffi_starwars_checksum_func_parse_timestamp(
+    ): Int
+    external fun uniffi_starwars_checksum_method_apiclient_search_index(
+    ): Int
     external fun uniffi_starwars_checksum_method_apiclient_fetch_film(
-    ): Short
+    ): Int
     external fun uniffi_starwars_checksum_method_apiclient_fetch_films(
-    ): Short
+    ): Int
     external fun uniffi_starwars_checksum_method_apiclient_fetch_people(
-    ): Short
+    ): Int
     external fun uniffi_starwars_checksum_method_apiclient_fetch_person(
-    ): Short
+    ): Int
     external fun uniffi_starwars_checksum_method_apiclient_fetch_planet(
-    ): Short
+    ): Int
     external fun uniffi_starwars_checksum_method_apiclient_fetch_planets(
-    ): Short
+    ): Int
     external fun uniffi_starwars_checksum_method_apiclient_fetch_species(
-    ): Short
+    ): Int
     external fun uniffi_starwars_checksum_method_apiclient_fetch_species_list(
-    ): Short
+    ): Int
     external fun uniffi_starwars_checksum_method_apiclient_fetch_starship(
-    ): Short
+    ): Int
     external fun uniffi_starwars_checksum_method_apiclient_fetch_starships(
-    ): Short
+    ): Int
     external fun uniffi_starwars_checksum_method_apiclient_fetch_vehicle(
-    ): Short
+    ): Int
     external fun uniffi_starwars_checksum_method_apiclient_fetch_vehicles(
-    ): Short
+    ): Int
+    external fun uniffi_starwars_checksum_method_apiclient_page_cursor(
+    ): Int
+    external fun uniffi_starwars_checksum_method_apiclient_resolve_related(
+    ): Int
+    external fun uniffi_starwars_checksum_method_apiclient_search(
+    ): Int
+    external fun uniffi_starwars_checksum_method_cancellationlistener_on_cancel(
+    ): Int
+    external fun uniffi_starwars_checksum_method_cancellationtoken_cancel(
+    ): Int
+    external fun uniffi_starwars_checksum_method_cancellationtoken_is_cancelled(
+    ): Int
+    external fun uniffi_starwars_checksum_method_cancellationtoken_on_cancel(
+    ): Int
     external fun uniffi_starwars_checksum_method_httpclient_fetch(
-    ): Short
+    ): Int
+    external fun uniffi_starwars_checksum_method_repositoryobserver_on_change(
+    ): Int
+    external fun uniffi_starwars_checksum_method_pagecursor_fetchable(
+    ): Int
+    external fun uniffi_starwars_checksum_method_pagecursor_has_more(
+    ): Int
+    external fun uniffi_starwars_checksum_method_pagecursor_is_loading(
+    ): Int
+    external fun uniffi_starwars_checksum_method_pagecursor_items(
+    ): Int
+    external fun uniffi_starwars_checksum_method_pagecursor_load_next(
+    ): Int
+    external fun uniffi_starwars_checksum_method_pagecursor_next_url(
+    ): Int
+    external fun uniffi_starwars_checksum_method_pagecursor_previous_url(
+    ): Int
+    external fun uniffi_starwars_checksum_method_pagecursor_total_count(
+    ): Int
+    external fun uniffi_starwars_checksum_method_query_filter(
+    ): Int
+    external fun uniffi_starwars_checksum_method_query_limit(
+    ): Int
+    external fun uniffi_starwars_checksum_method_query_offset(
+    ): Int
+    external fun uniffi_starwars_checksum_method_query_sort_by(
+    ): Int
+    external fun uniffi_starwars_checksum_method_datarepository_query_films(
+    ): Int
+    external fun uniffi_starwars_checksum_method_datarepository_query_people(
+    ): Int
+    external fun uniffi_starwars_checksum_method_datarepository_query_planets(
+    ): Int
+    external fun uniffi_starwars_checksum_method_datarepository_query_species(
+    ): Int
+    external fun uniffi_starwars_checksum_method_datarepository_query_starships(
+    ): Int
+    external fun uniffi_starwars_checksum_method_datarepository_query_vehicles(
+    ): Int
+    external fun uniffi_starwars_checksum_method_datarepository_add_observer(
+    ): Int
+    external fun uniffi_starwars_checksum_method_datarepository_clear(
+    ): Int
+    external fun uniffi_starwars_checksum_method_datarepository_fetched_at(
+    ): Int
+    external fun uniffi_starwars_checksum_method_datarepository_get_film(
+    ): Int
+    external fun uniffi_starwars_checksum_method_datarepository_get_film_by_id(
+    ): Int
+    external fun uniffi_starwars_checksum_method_datarepository_get_films(
+    ): Int
+    external fun uniffi_starwars_checksum_method_datarepository_get_people(
+    ): Int
+    external fun uniffi_starwars_checksum_method_datarepository_get_person(
+    ): Int
+    external fun uniffi_starwars_checksum_method_datarepository_get_person_by_id(
+    ): Int
     external fun uniffi_starwars_checksum_method_datarepository_get_planet(
-    ): Short
+    ): Int
+    external fun uniffi_starwars_checksum_method_datarepository_get_planet_by_id(
+    ): Int
     external fun uniffi_starwars_checksum_method_datarepository_get_planets(
-    ): Short
+    ): Int
+    external fun uniffi_starwars_checksum_method_datarepository_get_species(
+    ): Int
+    external fun uniffi_starwars_checksum_method_datarepository_get_species_by_id(
+    ): Int
+    external fun uniffi_starwars_checksum_method_datarepository_get_species_list(
+    ): Int
+    external fun uniffi_starwars_checksum_method_datarepository_get_starship(
+    ): Int
+    external fun uniffi_starwars_checksum_method_datarepository_get_starship_by_id(
+    ): Int
+    external fun uniffi_starwars_checksum_method_datarepository_get_starships(
+    ): Int
+    external fun uniffi_starwars_checksum_method_datarepository_get_vehicle(
+    ): Int
+    external fun uniffi_starwars_checksum_method_datarepository_get_vehicle_by_id(
+    ): Int
+    external fun uniffi_starwars_checksum_method_datarepository_get_vehicles(
+    ): Int
+    external fun uniffi_starwars_checksum_method_datarepository_insert_film(
+    ): Int
+    external fun uniffi_starwars_checksum_method_datarepository_insert_person(
+    ): Int
     external fun uniffi_starwars_checksum_method_datarepository_insert_planet(
-    ): Short
+    ): Int
+    external fun uniffi_starwars_checksum_method_datarepository_insert_species(
+    ): Int
+    external fun uniffi_starwars_checksum_method_datarepository_insert_starship(
+    ): Int
+    external fun uniffi_starwars_checksum_method_datarepository_insert_vehicle(
+    ): Int
+    external fun uniffi_starwars_checksum_method_datarepository_remove(
+    ): Int
+    external fun uniffi_starwars_checksum_method_datarepository_remove_observer(
+    ): Int
+    external fun uniffi_starwars_checksum_method_datarepository_set_ttl(
+    ): Int
+    external fun uniffi_starwars_checksum_method_datarepository_ttl(
+    ): Int
+    external fun uniffi_starwars_checksum_method_searchindex_rebuild(
+    ): Int
+    external fun uniffi_starwars_checksum_method_searchindex_search(
+    ): Int
+    external fun uniffi_starwars_checksum_method_searchindex_search_in(
+    ): Int
+    external fun uniffi_starwars_checksum_method_filestore_keys(
+    ): Int
+    external fun uniffi_starwars_checksum_method_filestore_read(
+    ): Int
+    external fun uniffi_starwars_checksum_method_filestore_remove(
+    ): Int
+    external fun uniffi_starwars_checksum_method_filestore_write(
+    ): Int
+    external fun uniffi_starwars_checksum_method_inmemorystore_keys(
+    ): Int
+    external fun uniffi_starwars_checksum_method_inmemorystore_read(
+    ): Int
+    external fun uniffi_starwars_checksum_method_inmemorystore_remove(
+    ): Int
+    external fun uniffi_starwars_checksum_method_inmemorystore_write(
+    ): Int
+    external fun uniffi_starwars_checksum_method_repositorystore_read(
+    ): Int
+    external fun uniffi_starwars_checksum_method_repositorystore_write(
+    ): Int
+    external fun uniffi_starwars_checksum_method_repositorystore_remove(
+    ): Int
+    external fun uniffi_starwars_checksum_method_repositorystore_keys(
+    ): Int
     external fun uniffi_starwars_checksum_constructor_apiclient_new(
-    ): Short
+    ): Int
+    external fun uniffi_starwars_checksum_constructor_apiclient_with_config(
+    ): Int
+    external fun uniffi_starwars_checksum_constructor_cancellationtoken_new(
+    ): Int
+    external fun uniffi_starwars_checksum_constructor_query_new(
+    ): Int
     external fun uniffi_starwars_checksum_constructor_datarepository_new(
-    ): Short
+    ): Int
+    external fun uniffi_starwars_checksum_constructor_datarepository_with_store(
+    ): Int
+    external fun uniffi_starwars_checksum_constructor_searchindex_new(
+    ): Int
+    external fun uniffi_starwars_checksum_constructor_filestore_new(
+    ): Int
+    external fun uniffi_starwars_checksum_constructor_inmemorystore_new(
+    ): Int
     external fun ffi_starwars_uniffi_contract_version(
     ): Int
 
@@ -721,171 +981,486 @@ internal object UniffiLib {
 
     init {
         Native.register(UniffiLib::class.java, findLibraryName(componentName = "starwars"))
+        uniffiCallbackInterfaceCancellationListener.register(this)
         uniffiCallbackInterfaceHttpClient.register(this)
+        uniffiCallbackInterfaceRepositoryObserver.register(this)
+        uniffiCallbackInterfaceRepositoryStore.register(this)
         
     }
     external fun uniffi_starwars_fn_clone_apiclient(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
-    ): Long
-    external fun uniffi_starwars_fn_free_apiclient(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
-    ): Unit
-    external fun uniffi_starwars_fn_constructor_apiclient_new(`httpClient`: Long,`repository`: Long,uniffi_out_err: UniffiRustCallStatus, 
-    ): Long
-    external fun uniffi_starwars_fn_method_apiclient_fetch_film(`ptr`: Long,`url`: RustBuffer.ByValue,
-    ): Long
-    external fun uniffi_starwars_fn_method_apiclient_fetch_films(`ptr`: Long,`ignoreCache`: Byte,
-    ): Long
-    external fun uniffi_starwars_fn_method_apiclient_fetch_people(`ptr`: Long,`ignoreCache`: Byte,
-    ): Long
-    external fun uniffi_starwars_fn_method_apiclient_fetch_person(`ptr`: Long,`url`: RustBuffer.ByValue,
-    ): Long
-    external fun uniffi_starwars_fn_method_apiclient_fetch_planet(`ptr`: Long,`url`: RustBuffer.ByValue,
-    ): Long
-    external fun uniffi_starwars_fn_method_apiclient_fetch_planets(`ptr`: Long,`ignoreCache`: Byte,
-    ): Long
-    external fun uniffi_starwars_fn_method_apiclient_fetch_species(`ptr`: Long,`url`: RustBuffer.ByValue,
-    ): Long
-    external fun uniffi_starwars_fn_method_apiclient_fetch_species_list(`ptr`: Long,`ignoreCache`: Byte,
-    ): Long
-    external fun uniffi_starwars_fn_method_apiclient_fetch_starship(`ptr`: Long,`url`: RustBuffer.ByValue,
-    ): Long
-    external fun uniffi_starwars_fn_method_apiclient_fetch_starships(`ptr`: Long,`ignoreCache`: Byte,
-    ): Long
-    external fun uniffi_starwars_fn_method_apiclient_fetch_vehicle(`ptr`: Long,`url`: RustBuffer.ByValue,
-    ): Long
-    external fun uniffi_starwars_fn_method_apiclient_fetch_vehicles(`ptr`: Long,`ignoreCache`: Byte,
-    ): Long
-    external fun uniffi_starwars_fn_clone_httpclient(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
-    ): Long
-    external fun uniffi_starwars_fn_free_httpclient(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
-    ): Unit
-    external fun uniffi_starwars_fn_init_callback_vtable_httpclient(`vtable`: UniffiVTableCallbackInterfaceHttpClient,
-    ): Unit
-    external fun uniffi_starwars_fn_method_httpclient_fetch(`ptr`: Long,`url`: RustBuffer.ByValue,
-    ): Long
-    external fun uniffi_starwars_fn_clone_datarepository(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
-    ): Long
-    external fun uniffi_starwars_fn_free_datarepository(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
-    ): Unit
-    external fun uniffi_starwars_fn_constructor_datarepository_new(uniffi_out_err: UniffiRustCallStatus, 
-    ): Long
-    external fun uniffi_starwars_fn_method_datarepository_get_planet(`ptr`: Long,`url`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
-    ): RustBuffer.ByValue
-    external fun uniffi_starwars_fn_method_datarepository_get_planets(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
-    ): RustBuffer.ByValue
-    external fun uniffi_starwars_fn_method_datarepository_insert_planet(`ptr`: Long,`planet`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
-    ): Unit
-    external fun uniffi_starwars_fn_method_fetchable_display_name(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
-    ): RustBuffer.ByValue
-    external fun uniffi_starwars_fn_func_all_fetchable(uniffi_out_err: UniffiRustCallStatus, 
-    ): RustBuffer.ByValue
-    external fun uniffi_starwars_fn_func_person_related_items(`person`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
-    ): RustBuffer.ByValue
-    external fun ffi_starwars_rustbuffer_alloc(`size`: Long,uniffi_out_err: UniffiRustCallStatus, 
-    ): RustBuffer.ByValue
-    external fun ffi_starwars_rustbuffer_from_bytes(`bytes`: ForeignBytes.ByValue,uniffi_out_err: UniffiRustCallStatus, 
-    ): RustBuffer.ByValue
-    external fun ffi_starwars_rustbuffer_free(`buf`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
-    ): Unit
-    external fun ffi_starwars_rustbuffer_reserve(`buf`: RustBuffer.ByValue,`additional`: Long,uniffi_out_err: UniffiRustCallStatus, 
-    ): RustBuffer.ByValue
-    external fun ffi_starwars_rust_future_poll_u8(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
-    ): Unit
-    external fun ffi_starwars_rust_future_cancel_u8(`handle`: Long,
-    ): Unit
-    external fun ffi_starwars_rust_future_free_u8(`handle`: Long,
-    ): Unit
-    external fun ffi_starwars_rust_future_complete_u8(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
-    ): Byte
-    external fun ffi_starwars_rust_future_poll_i8(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
-    ): Unit
-    external fun ffi_starwars_rust_future_cancel_i8(`handle`: Long,
-    ): Unit
-    external fun ffi_starwars_rust_future_free_i8(`handle`: Long,
-    ): Unit
-    external fun ffi_starwars_rust_future_complete_i8(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
-    ): Byte
-    external fun ffi_starwars_rust_future_poll_u16(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
-    ): Unit
-    external fun ffi_starwars_rust_future_cancel_u16(`handle`: Long,
-    ): Unit
-    external fun ffi_starwars_rust_future_free_u16(`handle`: Long,
-    ): Unit
-    external fun ffi_starwars_rust_future_complete_u16(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
-    ): Short
-    external fun ffi_starwars_rust_future_poll_i16(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
-    ): Unit
-    external fun ffi_starwars_rust_future_cancel_i16(`handle`: Long,
-    ): Unit
-    external fun ffi_starwars_rust_future_free_i16(`handle`: Long,
-    ): Unit
-    external fun ffi_starwars_rust_future_complete_i16(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
-    ): Short
-    external fun ffi_starwars_rust_future_poll_u32(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
-    ): Unit
-    external fun ffi_starwars_rust_future_cancel_u32(`handle`: Long,
-    ): Unit
-    external fun ffi_starwars_rust_future_free_u32(`handle`: Long,
-    ): Unit
-    external fun ffi_starwars_rust_future_complete_u32(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
-    ): Int
-    external fun ffi_starwars_rust_future_poll_i32(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
-    ): Unit
-    external fun ffi_starwars_rust_future_cancel_i32(`handle`: Long,
-    ): Unit
-    external fun ffi_starwars_rust_future_free_i32(`handle`: Long,
-    ): Unit
-    external fun ffi_starwars_rust_future_complete_i32(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
-    ): Int
-    external fun ffi_starwars_rust_future_poll_u64(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
-    ): Unit
-    external fun ffi_starwars_rust_future_cancel_u64(`handle`: Long,
-    ): Unit
-    external fun ffi_starwars_rust_future_free_u64(`handle`: Long,
-    ): Unit
-    external fun ffi_starwars_rust_future_complete_u64(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
-    ): Long
-    external fun ffi_starwars_rust_future_poll_i64(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
-    ): Unit
-    external fun ffi_starwars_rust_future_cancel_i64(`handle`: Long,
-    ): Unit
-    external fun ffi_starwars_rust_future_free_i64(`handle`: Long,
-    ): Unit
-    external fun ffi_starwars_rust_future_complete_i64(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
-    ): Long
-    external fun ffi_starwars_rust_future_poll_f32(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
-    ): Unit
-    external fun ffi_starwars_rust_future_cancel_f32(`handle`: Long,
-    ): Unit
-    external fun ffi_starwars_rust_future_free_f32(`handle`: Long,
-    ): Unit
-    external fun ffi_starwars_rust_future_complete_f32(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
-    ): Float
-    external fun ffi_starwars_rust_future_poll_f64(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
-    ): Unit
-    external fun ffi_starwars_rust_future_cancel_f64(`handle`: Long,
-    ): Unit
-    external fun ffi_starwars_rust_future_free_f64(`handle`: Long,
-    ): Unit
-    external fun ffi_starwars_rust_future_complete_f64(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
-    ): Double
-    external fun ffi_starwars_rust_future_poll_rust_buffer(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
-    ): Unit
-    external fun ffi_starwars_rust_future_cancel_rust_buffer(`handle`: Long,
-    ): Unit
-    external fun ffi_starwars_rust_future_free_rust_buffer(`handle`: Long,
-    ): Unit
-    external fun ffi_starwars_rust_future_complete_rust_buffer(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
-    ): RustBuffer.ByValue
-    external fun ffi_starwars_rust_future_poll_void(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
-    ): Unit
-    external fun ffi_starwars_rust_future_cancel_void(`handle`: Long,
-    ): Unit
-    external fun ffi_starwars_rust_future_free_void(`handle`: Long,
-    ): Unit
-    external fun ffi_starwars_rust_future_complete_void(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
-    ): Unit
+): Long
+external fun uniffi_starwars_fn_free_apiclient(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_starwars_fn_constructor_apiclient_new(`httpClient`: Long,`repository`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_starwars_fn_constructor_apiclient_with_config(`httpClient`: Long,`repository`: Long,`config`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_starwars_fn_method_apiclient_search_index(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_starwars_fn_method_apiclient_fetch_film(`ptr`: Long,`url`: RustBuffer.ByValue,`policy`: RustBuffer.ByValue,`cancellation`: RustBuffer.ByValue,
+): Long
+external fun uniffi_starwars_fn_method_apiclient_fetch_films(`ptr`: Long,`policy`: RustBuffer.ByValue,`cancellation`: RustBuffer.ByValue,
+): Long
+external fun uniffi_starwars_fn_method_apiclient_fetch_people(`ptr`: Long,`policy`: RustBuffer.ByValue,`cancellation`: RustBuffer.ByValue,
+): Long
+external fun uniffi_starwars_fn_method_apiclient_fetch_person(`ptr`: Long,`url`: RustBuffer.ByValue,`policy`: RustBuffer.ByValue,`cancellation`: RustBuffer.ByValue,
+): Long
+external fun uniffi_starwars_fn_method_apiclient_fetch_planet(`ptr`: Long,`url`: RustBuffer.ByValue,`policy`: RustBuffer.ByValue,`cancellation`: RustBuffer.ByValue,
+): Long
+external fun uniffi_starwars_fn_method_apiclient_fetch_planets(`ptr`: Long,`policy`: RustBuffer.ByValue,`cancellation`: RustBuffer.ByValue,
+): Long
+external fun uniffi_starwars_fn_method_apiclient_fetch_species(`ptr`: Long,`url`: RustBuffer.ByValue,`policy`: RustBuffer.ByValue,`cancellation`: RustBuffer.ByValue,
+): Long
+external fun uniffi_starwars_fn_method_apiclient_fetch_species_list(`ptr`: Long,`policy`: RustBuffer.ByValue,`cancellation`: RustBuffer.ByValue,
+): Long
+external fun uniffi_starwars_fn_method_apiclient_fetch_starship(`ptr`: Long,`url`: RustBuffer.ByValue,`policy`: RustBuffer.ByValue,`cancellation`: RustBuffer.ByValue,
+): Long
+external fun uniffi_starwars_fn_method_apiclient_fetch_starships(`ptr`: Long,`policy`: RustBuffer.ByValue,`cancellation`: RustBuffer.ByValue,
+): Long
+external fun uniffi_starwars_fn_method_apiclient_fetch_vehicle(`ptr`: Long,`url`: RustBuffer.ByValue,`policy`: RustBuffer.ByValue,`cancellation`: RustBuffer.ByValue,
+): Long
+external fun uniffi_starwars_fn_method_apiclient_fetch_vehicles(`ptr`: Long,`policy`: RustBuffer.ByValue,`cancellation`: RustBuffer.ByValue,
+): Long
+external fun uniffi_starwars_fn_method_apiclient_page_cursor(`ptr`: Long,`fetchable`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_starwars_fn_method_apiclient_resolve_related(`ptr`: Long,`items`: RustBuffer.ByValue,`cancellation`: RustBuffer.ByValue,
+): Long
+external fun uniffi_starwars_fn_method_apiclient_search(`ptr`: Long,`fetchable`: RustBuffer.ByValue,`query`: RustBuffer.ByValue,`cancellation`: RustBuffer.ByValue,
+): Long
+external fun uniffi_starwars_fn_clone_cancellationlistener(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_starwars_fn_free_cancellationlistener(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_starwars_fn_init_callback_vtable_cancellationlistener(`vtable`: UniffiVTableCallbackInterfaceCancellationListener,
+): Unit
+external fun uniffi_starwars_fn_method_cancellationlistener_on_cancel(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_starwars_fn_clone_cancellationtoken(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_starwars_fn_free_cancellationtoken(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_starwars_fn_constructor_cancellationtoken_new(uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_starwars_fn_method_cancellationtoken_cancel(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_starwars_fn_method_cancellationtoken_is_cancelled(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Byte
+external fun uniffi_starwars_fn_method_cancellationtoken_on_cancel(`ptr`: Long,`listener`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_starwars_fn_clone_httpclient(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_starwars_fn_free_httpclient(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_starwars_fn_init_callback_vtable_httpclient(`vtable`: UniffiVTableCallbackInterfaceHttpClient,
+): Unit
+external fun uniffi_starwars_fn_method_httpclient_fetch(`ptr`: Long,`request`: RustBuffer.ByValue,
+): Long
+external fun uniffi_starwars_fn_clone_repositoryobserver(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_starwars_fn_free_repositoryobserver(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_starwars_fn_init_callback_vtable_repositoryobserver(`vtable`: UniffiVTableCallbackInterfaceRepositoryObserver,
+): Unit
+external fun uniffi_starwars_fn_method_repositoryobserver_on_change(`ptr`: Long,`change`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_starwars_fn_clone_pagecursor(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_starwars_fn_free_pagecursor(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_starwars_fn_method_pagecursor_fetchable(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_pagecursor_has_more(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Byte
+external fun uniffi_starwars_fn_method_pagecursor_is_loading(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Byte
+external fun uniffi_starwars_fn_method_pagecursor_items(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_pagecursor_load_next(`ptr`: Long,`cancellation`: RustBuffer.ByValue,
+): Long
+external fun uniffi_starwars_fn_method_pagecursor_next_url(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_pagecursor_previous_url(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_pagecursor_total_count(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_clone_query(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_starwars_fn_free_query(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_starwars_fn_constructor_query_new(uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_starwars_fn_method_query_filter(`ptr`: Long,`field`: RustBuffer.ByValue,`operator`: RustBuffer.ByValue,`value`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_starwars_fn_method_query_limit(`ptr`: Long,`limit`: Int,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_starwars_fn_method_query_offset(`ptr`: Long,`offset`: Int,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_starwars_fn_method_query_sort_by(`ptr`: Long,`field`: RustBuffer.ByValue,`direction`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_starwars_fn_clone_datarepository(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_starwars_fn_free_datarepository(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_starwars_fn_constructor_datarepository_new(uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_starwars_fn_constructor_datarepository_with_store(`store`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_starwars_fn_method_datarepository_query_films(`ptr`: Long,`query`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_datarepository_query_people(`ptr`: Long,`query`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_datarepository_query_planets(`ptr`: Long,`query`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_datarepository_query_species(`ptr`: Long,`query`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_datarepository_query_starships(`ptr`: Long,`query`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_datarepository_query_vehicles(`ptr`: Long,`query`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_datarepository_add_observer(`ptr`: Long,`observer`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_starwars_fn_method_datarepository_clear(`ptr`: Long,`fetchable`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_starwars_fn_method_datarepository_fetched_at(`ptr`: Long,`fetchable`: RustBuffer.ByValue,`url`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_datarepository_get_film(`ptr`: Long,`url`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_datarepository_get_film_by_id(`ptr`: Long,`id`: Int,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_datarepository_get_films(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_datarepository_get_people(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_datarepository_get_person(`ptr`: Long,`url`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_datarepository_get_person_by_id(`ptr`: Long,`id`: Int,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_datarepository_get_planet(`ptr`: Long,`url`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_datarepository_get_planet_by_id(`ptr`: Long,`id`: Int,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_datarepository_get_planets(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_datarepository_get_species(`ptr`: Long,`url`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_datarepository_get_species_by_id(`ptr`: Long,`id`: Int,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_datarepository_get_species_list(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_datarepository_get_starship(`ptr`: Long,`url`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_datarepository_get_starship_by_id(`ptr`: Long,`id`: Int,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_datarepository_get_starships(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_datarepository_get_vehicle(`ptr`: Long,`url`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_datarepository_get_vehicle_by_id(`ptr`: Long,`id`: Int,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_datarepository_get_vehicles(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_datarepository_insert_film(`ptr`: Long,`film`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_starwars_fn_method_datarepository_insert_person(`ptr`: Long,`person`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_starwars_fn_method_datarepository_insert_planet(`ptr`: Long,`planet`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_starwars_fn_method_datarepository_insert_species(`ptr`: Long,`species`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_starwars_fn_method_datarepository_insert_starship(`ptr`: Long,`starship`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_starwars_fn_method_datarepository_insert_vehicle(`ptr`: Long,`vehicle`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_starwars_fn_method_datarepository_remove(`ptr`: Long,`fetchable`: RustBuffer.ByValue,`id`: Int,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_starwars_fn_method_datarepository_remove_observer(`ptr`: Long,`token`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_starwars_fn_method_datarepository_set_ttl(`ptr`: Long,`fetchable`: RustBuffer.ByValue,`ttlSeconds`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_starwars_fn_method_datarepository_ttl(`ptr`: Long,`fetchable`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_starwars_fn_clone_searchindex(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_starwars_fn_free_searchindex(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_starwars_fn_constructor_searchindex_new(`repository`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_starwars_fn_method_searchindex_rebuild(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_starwars_fn_method_searchindex_search(`ptr`: Long,`query`: RustBuffer.ByValue,`limit`: Int,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_searchindex_search_in(`ptr`: Long,`fetchable`: RustBuffer.ByValue,`query`: RustBuffer.ByValue,`limit`: Int,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_clone_filestore(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_starwars_fn_free_filestore(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_starwars_fn_constructor_filestore_new(`directory`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_starwars_fn_method_filestore_keys(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_filestore_read(`ptr`: Long,`key`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_filestore_remove(`ptr`: Long,`key`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_starwars_fn_method_filestore_write(`ptr`: Long,`key`: RustBuffer.ByValue,`contents`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_starwars_fn_clone_inmemorystore(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_starwars_fn_free_inmemorystore(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_starwars_fn_constructor_inmemorystore_new(uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_starwars_fn_method_inmemorystore_keys(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_inmemorystore_read(`ptr`: Long,`key`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_inmemorystore_remove(`ptr`: Long,`key`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_starwars_fn_method_inmemorystore_write(`ptr`: Long,`key`: RustBuffer.ByValue,`contents`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_starwars_fn_clone_repositorystore(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun uniffi_starwars_fn_free_repositorystore(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_starwars_fn_init_callback_vtable_repositorystore(`vtable`: UniffiVTableCallbackInterfaceRepositoryStore,
+): Unit
+external fun uniffi_starwars_fn_method_repositorystore_read(`ptr`: Long,`key`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_repositorystore_write(`ptr`: Long,`key`: RustBuffer.ByValue,`contents`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_starwars_fn_method_repositorystore_remove(`ptr`: Long,`key`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun uniffi_starwars_fn_method_repositorystore_keys(`ptr`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_fetchable_display_name(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_film_created_at(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_film_edited_at(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_film_released_at(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_consumables_days(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Double
+external fun uniffi_starwars_fn_method_person_birth_year_value(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_person_created_at(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_person_edited_at(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_person_height_value(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_person_mass_value(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_planet_climate_tags(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_planet_created_at(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_planet_diameter_value(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_planet_edited_at(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_planet_orbital_period_value(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_planet_population_value(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_planet_rotation_period_value(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_planet_surface_water_value(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_planet_terrain_tags(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_species_average_height_value(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_species_average_lifespan_value(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_species_created_at(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_species_edited_at(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_species_eye_color_tags(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_species_hair_color_tags(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_species_skin_color_tags(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_starship_cargo_capacity_value(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_starship_consumables_value(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_starship_cost_in_credits_value(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_starship_created_at(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_starship_crew_range(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_starship_edited_at(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_starship_hyperdrive_rating_value(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_starship_length_value(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_starship_manufacturer_tags(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_starship_max_atmosphering_speed_value(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_starship_mglt_value(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_starship_passengers_range(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_vehicle_cargo_capacity_value(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_vehicle_consumables_value(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_vehicle_cost_in_credits_value(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_vehicle_created_at(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_vehicle_crew_range(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_vehicle_edited_at(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_vehicle_length_value(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_vehicle_manufacturer_tags(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_vehicle_max_atmosphering_speed_value(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_method_vehicle_passengers_range(`ptr`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_func_all_fetchable(uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_func_resource_id(`url`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_func_person_related_items(`person`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_func_related_items(`selected`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_func_split_attributes(`value`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_func_format_consumables(`consumables`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_func_format_galactic_year(`year`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_func_parse_consumables(`value`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_func_parse_galactic_year(`value`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_func_parse_count(`value`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_func_parse_measurement(`value`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_func_parse_measurement_range(`value`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun uniffi_starwars_fn_func_parse_timestamp(`value`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun ffi_starwars_rustbuffer_alloc(`size`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun ffi_starwars_rustbuffer_from_bytes(`bytes`: ForeignBytes.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun ffi_starwars_rustbuffer_free(`buf`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+external fun ffi_starwars_rustbuffer_reserve(`buf`: RustBuffer.ByValue,`additional`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun ffi_starwars_rust_future_poll_u8(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+external fun ffi_starwars_rust_future_cancel_u8(`handle`: Long,
+): Unit
+external fun ffi_starwars_rust_future_free_u8(`handle`: Long,
+): Unit
+external fun ffi_starwars_rust_future_complete_u8(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Int
+external fun ffi_starwars_rust_future_poll_i8(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+external fun ffi_starwars_rust_future_cancel_i8(`handle`: Long,
+): Unit
+external fun ffi_starwars_rust_future_free_i8(`handle`: Long,
+): Unit
+external fun ffi_starwars_rust_future_complete_i8(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Byte
+external fun ffi_starwars_rust_future_poll_u16(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+external fun ffi_starwars_rust_future_cancel_u16(`handle`: Long,
+): Unit
+external fun ffi_starwars_rust_future_free_u16(`handle`: Long,
+): Unit
+external fun ffi_starwars_rust_future_complete_u16(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Int
+external fun ffi_starwars_rust_future_poll_i16(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+external fun ffi_starwars_rust_future_cancel_i16(`handle`: Long,
+): Unit
+external fun ffi_starwars_rust_future_free_i16(`handle`: Long,
+): Unit
+external fun ffi_starwars_rust_future_complete_i16(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Short
+external fun ffi_starwars_rust_future_poll_u32(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+external fun ffi_starwars_rust_future_cancel_u32(`handle`: Long,
+): Unit
+external fun ffi_starwars_rust_future_free_u32(`handle`: Long,
+): Unit
+external fun ffi_starwars_rust_future_complete_u32(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Int
+external fun ffi_starwars_rust_future_poll_i32(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+external fun ffi_starwars_rust_future_cancel_i32(`handle`: Long,
+): Unit
+external fun ffi_starwars_rust_future_free_i32(`handle`: Long,
+): Unit
+external fun ffi_starwars_rust_future_complete_i32(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Int
+external fun ffi_starwars_rust_future_poll_u64(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+external fun ffi_starwars_rust_future_cancel_u64(`handle`: Long,
+): Unit
+external fun ffi_starwars_rust_future_free_u64(`handle`: Long,
+): Unit
+external fun ffi_starwars_rust_future_complete_u64(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun ffi_starwars_rust_future_poll_i64(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+external fun ffi_starwars_rust_future_cancel_i64(`handle`: Long,
+): Unit
+external fun ffi_starwars_rust_future_free_i64(`handle`: Long,
+): Unit
+external fun ffi_starwars_rust_future_complete_i64(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+external fun ffi_starwars_rust_future_poll_f32(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+external fun ffi_starwars_rust_future_cancel_f32(`handle`: Long,
+): Unit
+external fun ffi_starwars_rust_future_free_f32(`handle`: Long,
+): Unit
+external fun ffi_starwars_rust_future_complete_f32(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Float
+external fun ffi_starwars_rust_future_poll_f64(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+external fun ffi_starwars_rust_future_cancel_f64(`handle`: Long,
+): Unit
+external fun ffi_starwars_rust_future_free_f64(`handle`: Long,
+): Unit
+external fun ffi_starwars_rust_future_complete_f64(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Double
+external fun ffi_starwars_rust_future_poll_rust_buffer(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+external fun ffi_starwars_rust_future_cancel_rust_buffer(`handle`: Long,
+): Unit
+external fun ffi_starwars_rust_future_free_rust_buffer(`handle`: Long,
+): Unit
+external fun ffi_starwars_rust_future_complete_rust_buffer(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+external fun ffi_starwars_rust_future_poll_void(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+external fun ffi_starwars_rust_future_cancel_void(`handle`: Long,
+): Unit
+external fun ffi_starwars_rust_future_free_void(`handle`: Long,
+): Unit
+external fun ffi_starwars_rust_future_complete_void(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
 
-        
+    
 }
 
 private fun uniffiCheckContractApiVersion(lib: IntegrityCheckingUniffiLib) {
@@ -899,180 +1474,444 @@ private fun uniffiCheckContractApiVersion(lib: IntegrityCheckingUniffiLib) {
 }
 @Suppress("UNUSED_PARAMETER")
 private fun uniffiCheckApiChecksums(lib: IntegrityCheckingUniffiLib) {
-    if (lib.uniffi_starwars_checksum_func_all_fetchable() != 46703.toShort()) {
+    if (lib.uniffi_starwars_checksum_func_all_fetchable() != 46703) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_starwars_checksum_func_person_related_items() != 10831.toShort()) {
+    if (lib.uniffi_starwars_checksum_func_resource_id() != 25126) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_starwars_checksum_method_apiclient_fetch_film() != 29515.toShort()) {
+    if (lib.uniffi_starwars_checksum_func_person_related_items() != 10831) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_starwars_checksum_method_apiclient_fetch_films() != 32635.toShort()) {
+    if (lib.uniffi_starwars_checksum_func_related_items() != 31240) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_starwars_checksum_method_apiclient_fetch_people() != 51794.toShort()) {
+    if (lib.uniffi_starwars_checksum_func_split_attributes() != 15022) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_starwars_checksum_method_apiclient_fetch_person() != 2190.toShort()) {
+    if (lib.uniffi_starwars_checksum_func_format_consumables() != 33977) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_starwars_checksum_method_apiclient_fetch_planet() != 64974.toShort()) {
+    if (lib.uniffi_starwars_checksum_func_format_galactic_year() != 63486) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_starwars_checksum_method_apiclient_fetch_planets() != 51131.toShort()) {
+    if (lib.uniffi_starwars_checksum_func_parse_consumables() != 35451) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_starwars_checksum_method_apiclient_fetch_species() != 60394.toShort()) {
+    if (lib.uniffi_starwars_checksum_func_parse_galactic_year() != 26746) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_starwars_checksum_method_apiclient_fetch_species_list() != 53838.toShort()) {
+    if (lib.uniffi_starwars_checksum_func_parse_count() != 29497) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_starwars_checksum_method_apiclient_fetch_starship() != 23891.toShort()) {
+    if (lib.uniffi_starwars_checksum_func_parse_measurement() != 42099) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_starwars_checksum_method_apiclient_fetch_starships() != 20326.toShort()) {
+    if (lib.uniffi_starwars_checksum_func_parse_measurement_range() != 27124) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_starwars_checksum_method_apiclient_fetch_vehicle() != 14057.toShort()) {
+    if (lib.uniffi_starwars_checksum_func_parse_timestamp() != 55381) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_starwars_checksum_method_apiclient_fetch_vehicles() != 18633.toShort()) {
+    if (lib.uniffi_starwars_checksum_method_apiclient_search_index() != 4199) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_starwars_checksum_method_httpclient_fetch() != 26818.toShort()) {
+    if (lib.uniffi_starwars_checksum_method_apiclient_fetch_film() != 60658) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_starwars_checksum_method_datarepository_get_planet() != 13100.toShort()) {
+    if (lib.uniffi_starwars_checksum_method_apiclient_fetch_films() != 11553) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_starwars_checksum_method_datarepository_get_planets() != 37315.toShort()) {
+    if (lib.uniffi_starwars_checksum_method_apiclient_fetch_people() != 5299) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_starwars_checksum_method_datarepository_insert_planet() != 38824.toShort()) {
+    if (lib.uniffi_starwars_checksum_method_apiclient_fetch_person() != 49080) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_starwars_checksum_constructor_apiclient_new() != 50275.toShort()) {
+    if (lib.uniffi_starwars_checksum_method_apiclient_fetch_planet() != 44856) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_starwars_checksum_constructor_datarepository_new() != 26238.toShort()) {
+    if (lib.uniffi_starwars_checksum_method_apiclient_fetch_planets() != 48194) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-}
-
-/**
- * @suppress
- */
-public fun uniffiEnsureInitialized() {
-    IntegrityCheckingUniffiLib
-    // UniffiLib() initialized as objects are used, but we still need to explicitly
-    // reference it so initialization across crates works as expected.
-    UniffiLib
-}
-
-// Async support
-// Async return type handlers
-
-internal const val UNIFFI_RUST_FUTURE_POLL_READY = 0.toByte()
-internal const val UNIFFI_RUST_FUTURE_POLL_WAKE = 1.toByte()
-
-internal val uniffiContinuationHandleMap = UniffiHandleMap<CancellableContinuation<Byte>>()
-
-// FFI type for Rust future continuations
-internal object uniffiRustFutureContinuationCallbackImpl: UniffiRustFutureContinuationCallback {
-    override fun callback(data: Long, pollResult: Byte) {
-        uniffiContinuationHandleMap.remove(data).resume(pollResult)
+    if (lib.uniffi_starwars_checksum_method_apiclient_fetch_species() != 23546) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-}
-
-internal suspend fun<T, F, E: kotlin.Exception> uniffiRustCallAsync(
-    rustFuture: Long,
-    pollFunc: (Long, UniffiRustFutureContinuationCallback, Long) -> Unit,
-    completeFunc: (Long, UniffiRustCallStatus) -> F,
-    freeFunc: (Long) -> Unit,
-    liftFunc: (F) -> T,
-    errorHandler: UniffiRustCallStatusErrorHandler<E>
-): T {
-    try {
-        do {
-            val pollResult = suspendCancellableCoroutine<Byte> { continuation ->
-                pollFunc(
-                    rustFuture,
-                    uniffiRustFutureContinuationCallbackImpl,
-                    uniffiContinuationHandleMap.insert(continuation)
-                )
-            }
-        } while (pollResult != UNIFFI_RUST_FUTURE_POLL_READY);
-
-        return liftFunc(
-            uniffiRustCallWithError(errorHandler, { status -> completeFunc(rustFuture, status) })
-        )
-    } finally {
-        freeFunc(rustFuture)
+    if (lib.uniffi_starwars_checksum_method_apiclient_fetch_species_list() != 45700) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-}
-internal inline fun<T> uniffiTraitInterfaceCallAsync(
-    crossinline makeCall: suspend () -> T,
-    crossinline handleSuccess: (T) -> Unit,
-    crossinline handleError: (UniffiRustCallStatus.ByValue) -> Unit,
-    uniffiOutDroppedCallback: UniffiForeignFutureDroppedCallbackStruct,
-) {
-    // Using `GlobalScope` is labeled as a "delicate API" and generally discouraged in Kotlin programs, since it breaks structured concurrency.
-    // However, our parent task is a Rust future, so we're going to need to break structure concurrency in any case.
-    //
-    // Uniffi does its best to support structured concurrency across the FFI.
-    // If the Rust future is dropped, `uniffiForeignFutureDroppedCallbackImpl` is called, which will cancel the Kotlin coroutine if it's still running.
-    @OptIn(DelicateCoroutinesApi::class)
-    val job = GlobalScope.launch coroutineBlock@ {
-        // Note: it's important we call either `handleSuccess` or `handleError` exactly once.  Each
-        // call consumes an Arc reference, which means there should be no possibility of a double
-        // call.  The following code is structured so that will will never call both `handleSuccess`
-        // and `handleError`, even in the face of weird exceptions.
-        //
-        // In extreme circumstances we may not call either, for example if we fail to make the JNA
-        // call to `handleSuccess`.  This means we will leak the Arc reference, which is better than
-        // double-freeing it.
-        val callResult = try {
-            makeCall()
-        } catch(e: kotlin.Exception) {
-            handleError(
-                UniffiRustCallStatus.create(
-                    UNIFFI_CALL_UNEXPECTED_ERROR,
-                    FfiConverterString.lower(e.toString()),
-                )
-            )
-            return@coroutineBlock
-        }
-        handleSuccess(callResult)
+    if (lib.uniffi_starwars_checksum_method_apiclient_fetch_starship() != 42129) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    val handle = uniffiForeignFutureHandleMap.insert(job)
-    uniffiOutDroppedCallback.uniffiSetValue(UniffiForeignFutureDroppedCallbackStruct(handle, uniffiForeignFutureDroppedCallbackImpl))
-}
-
-internal inline fun<T, reified E: Throwable> uniffiTraitInterfaceCallAsyncWithError(
-    crossinline makeCall: suspend () -> T,
-    crossinline handleSuccess: (T) -> Unit,
-    crossinline handleError: (UniffiRustCallStatus.ByValue) -> Unit,
-    crossinline lowerError: (E) -> RustBuffer.ByValue,
-    uniffiOutDroppedCallback: UniffiForeignFutureDroppedCallbackStruct,
-) {
-    // See uniffiTraitInterfaceCallAsync for details on `DelicateCoroutinesApi`
-    @OptIn(DelicateCoroutinesApi::class)
-    val job = GlobalScope.launch coroutineBlock@ {
-        // See the note in uniffiTraitInterfaceCallAsync for details on `handleSuccess` and
-        // `handleError`.
+    if (lib.uniffi_starwars_checksum_method_apiclient_fetch_starships() != 55260) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_apiclient_fetch_vehicle() != 4249) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_apiclient_fetch_vehicles() != 54121) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_apiclient_page_cursor() != 60269) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_apiclient_resolve_related() != 40878) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_apiclient_search() != 46047) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_cancellationlistener_on_cancel() != 59954) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_cancellationtoken_cancel() != 56873) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_cancellationtoken_is_cancelled() != 19164) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_cancellationtoken_on_cancel() != 51218) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_httpclient_fetch() != 11135) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_repositoryobserver_on_change() != 51212) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_pagecursor_fetchable() != 3833) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_pagecursor_has_more() != 20221) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_pagecursor_is_loading() != 54729) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_pagecursor_items() != 40040) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_pagecursor_load_next() != 22944) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_pagecursor_next_url() != 14462) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_pagecursor_previous_url() != 31028) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_pagecursor_total_count() != 20696) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_query_filter() != 47283) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_query_limit() != 61702) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_query_offset() != 56352) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_query_sort_by() != 6917) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_datarepository_query_films() != 65360) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_datarepository_query_people() != 60333) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_datarepository_query_planets() != 19636) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_datarepository_query_species() != 14230) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_datarepository_query_starships() != 57653) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_datarepository_query_vehicles() != 60892) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_datarepository_add_observer() != 25770) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_datarepository_clear() != 39968) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_datarepository_fetched_at() != 50862) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_datarepository_get_film() != 36261) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_datarepository_get_film_by_id() != 50942) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_datarepository_get_films() != 25713) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_datarepository_get_people() != 9680) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_datarepository_get_person() != 3133) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_datarepository_get_person_by_id() != 19029) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_datarepository_get_planet() != 13100) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_datarepository_get_planet_by_id() != 21640) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_datarepository_get_planets() != 37315) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_datarepository_get_species() != 35573) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_datarepository_get_species_by_id() != 13785) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_datarepository_get_species_list() != 36863) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_datarepository_get_starship() != 44656) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_datarepository_get_starship_by_id() != 11129) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_datarepository_get_starships() != 62721) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_datarepository_get_vehicle() != 62509) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_datarepository_get_vehicle_by_id() != 30592) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_datarepository_get_vehicles() != 18919) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_datarepository_insert_film() != 57109) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_datarepository_insert_person() != 58312) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_datarepository_insert_planet() != 27787) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_datarepository_insert_species() != 60131) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_datarepository_insert_starship() != 15090) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_datarepository_insert_vehicle() != 36848) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_datarepository_remove() != 28792) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_datarepository_remove_observer() != 16819) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_datarepository_set_ttl() != 61573) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_datarepository_ttl() != 51912) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_searchindex_rebuild() != 26833) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_searchindex_search() != 36139) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_searchindex_search_in() != 32709) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_filestore_keys() != 15743) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_filestore_read() != 48328) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_filestore_remove() != 24318) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_filestore_write() != 48490) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_inmemorystore_keys() != 63189) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_inmemorystore_read() != 39733) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_inmemorystore_remove() != 36573) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_inmemorystore_write() != 65383) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_repositorystore_read() != 13966) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_repositorystore_write() != 55409) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_repositorystore_remove() != 25108) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_method_repositorystore_keys() != 34149) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_constructor_apiclient_new() != 50275) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_constructor_apiclient_with_config() != 58498) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_constructor_cancellationtoken_new() != 19703) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_constructor_query_new() != 3164) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_constructor_datarepository_new() != 26238) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_constructor_datarepository_with_store() != 29957) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_constructor_searchindex_new() != 26330) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_constructor_filestore_new() != 61920) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_starwars_checksum_constructor_inmemorystore_new() != 12457) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+}
+
+/**
+ * @suppress
+ */
+public fun uniffiEnsureInitialized() {
+    IntegrityCheckingUniffiLib
+    // UniffiLib() initialized as objects are used, but we still need to explicitly
+    // reference it so initialization across crates works as expected.
+    UniffiLib
+}
+
+// Async support
+// Async return type handlers
+
+internal const val UNIFFI_RUST_FUTURE_POLL_READY = 0.toByte()
+internal const val UNIFFI_RUST_FUTURE_POLL_WAKE = 1.toByte()
+
+internal val uniffiContinuationHandleMap = UniffiHandleMap<CancellableContinuation<Byte>>()
+
+// FFI type for Rust future continuations
+internal object uniffiRustFutureContinuationCallbackImpl: UniffiRustFutureContinuationCallback {
+    override fun callback(data: Long, pollResult: Byte) {
+        uniffiContinuationHandleMap.remove(data).resume(pollResult)
+    }
+}
+
+internal suspend fun<T, F, E: kotlin.Exception> uniffiRustCallAsync(
+    rustFuture: Long,
+    pollFunc: (Long, UniffiRustFutureContinuationCallback, Long) -> Unit,
+    completeFunc: (Long, UniffiRustCallStatus) -> F,
+    freeFunc: (Long) -> Unit,
+    liftFunc: (F) -> T,
+    errorHandler: UniffiRustCallStatusErrorHandler<E>
+): T {
+    try {
+        do {
+            val pollResult = suspendCancellableCoroutine<Byte> { continuation ->
+                pollFunc(
+                    rustFuture,
+                    uniffiRustFutureContinuationCallbackImpl,
+                    uniffiContinuationHandleMap.insert(continuation)
+                )
+            }
+        } while (pollResult != UNIFFI_RUST_FUTURE_POLL_READY);
+
+        return liftFunc(
+            uniffiRustCallWithError(errorHandler, { status -> completeFunc(rustFuture, status) })
+        )
+    } finally {
+        freeFunc(rustFuture)
+    }
+}
+internal inline fun<T> uniffiTraitInterfaceCallAsync(
+    crossinline makeCall: suspend () -> T,
+    crossinline handleSuccess: (T) -> Unit,
+    crossinline handleError: (UniffiRustCallStatus.ByValue) -> Unit,
+    uniffiOutDroppedCallback: UniffiForeignFutureDroppedCallbackStruct,
+) {
+    // Using `GlobalScope` is labeled as a "delicate API" and generally discouraged in Kotlin programs, since it breaks structured concurrency.
+    // However, our parent task is a Rust future, so we're going to need to break structure concurrency in any case.
+    //
+    // Uniffi does its best to support structured concurrency across the FFI.
+    // If the Rust future is dropped, `uniffiForeignFutureDroppedCallbackImpl` is called, which will cancel the Kotlin coroutine if it's still running.
+    @OptIn(DelicateCoroutinesApi::class)
+    val job = GlobalScope.launch coroutineBlock@ {
+        // Note: it's important we call either `handleSuccess` or `handleError` exactly once.  Each
+        // call consumes an Arc reference, which means there should be no possibility of a double
+        // call.  The following code is structured so that will will never call both `handleSuccess`
+        // and `handleError`, even in the face of weird exceptions.
+        //
+        // In extreme circumstances we may not call either, for example if we fail to make the JNA
+        // call to `handleSuccess`.  This means we will leak the Arc reference, which is better than
+        // double-freeing it.
         val callResult = try {
             makeCall()
         } catch(e: kotlin.Exception) {
-            if (e is E) {
-                handleError(
-                    UniffiRustCallStatus.create(
-                        UNIFFI_CALL_ERROR,
-                        lowerError(e),
-                    )
+            handleError(
+                UniffiRustCallStatus.create(
+                    UNIFFI_CALL_UNEXPECTED_ERROR,
+                    FfiConverterString.lower(e.toString()),
                 )
-            } else {
+            )
+            return@coroutineBlock
+        }
+        handleSuccess(callResult)
+    }
+    val handle = uniffiForeignFutureHandleMap.insert(job)
+    uniffiOutDroppedCallback.uniffiSetValue(UniffiForeignFutureDroppedCallbackStruct(handle, uniffiForeignFutureDroppedCallbackImpl))
+}
+
+internal inline fun<T, reified E: Throwable> uniffiTraitInterfaceCallAsyncWithError(
+    crossinline makeCall: suspend () -> T,
+    crossinline handleSuccess: (T) -> Unit,
+    crossinline handleError: (UniffiRustCallStatus.ByValue) -> Unit,
+    crossinline lowerError: (E) -> RustBuffer.ByValue,
+    uniffiOutDroppedCallback: UniffiForeignFutureDroppedCallbackStruct,
+) {
+    // See uniffiTraitInterfaceCallAsync for details on `DelicateCoroutinesApi`
+    @OptIn(DelicateCoroutinesApi::class)
+    val job = GlobalScope.launch coroutineBlock@ {
+        // See the note in uniffiTraitInterfaceCallAsync for details on `handleSuccess` and
+        // `handleError`.
+        val callResult = try {
+            makeCall()
+        } catch(e: kotlin.Exception) {
+            if (e is E) {
+                handleError(
+                    UniffiRustCallStatus.create(
+                        UNIFFI_CALL_ERROR,
+                        lowerError(e),
+                    )
+                )
+            } else {
                 handleError(
                     UniffiRustCallStatus.create(
                         UNIFFI_CALL_UNEXPECTED_ERROR,
@@ -1084,2699 +1923,8434 @@ internal inline fun<T, reified E: Throwable> uniffiTraitInterfaceCallAsyncWithEr
         }
         handleSuccess(callResult)
     }
-    val handle = uniffiForeignFutureHandleMap.insert(job)
-    uniffiOutDroppedCallback.uniffiSetValue(UniffiForeignFutureDroppedCallbackStruct(handle, uniffiForeignFutureDroppedCallbackImpl))
+    val handle = uniffiForeignFutureHandleMap.insert(job)
+    uniffiOutDroppedCallback.uniffiSetValue(UniffiForeignFutureDroppedCallbackStruct(handle, uniffiForeignFutureDroppedCallbackImpl))
+}
+
+internal val uniffiForeignFutureHandleMap = UniffiHandleMap<Job>()
+
+internal object uniffiForeignFutureDroppedCallbackImpl: UniffiForeignFutureDroppedCallback {
+    override fun callback(handle: Long) {
+        val job = uniffiForeignFutureHandleMap.remove(handle)
+        if (!job.isCompleted) {
+            job.cancel()
+        }
+    }
+}
+
+// For testing
+public fun uniffiForeignFutureHandleCount() = uniffiForeignFutureHandleMap.size
+
+// Public interface members begin here.
+
+
+// Interface implemented by anything that can contain an object reference.
+//
+// Such types expose a `destroy()` method that must be called to cleanly
+// dispose of the contained objects. Failure to call this method may result
+// in memory leaks.
+//
+// The easiest way to ensure this method is called is to use the `.use`
+// helper method to execute a block and destroy the object at the end.
+interface Disposable {
+    fun destroy()
+    companion object {
+        fun destroy(vararg args: Any?) {
+            for (arg in args) {
+                when (arg) {
+                    is Disposable -> arg.destroy()
+                    is ArrayList<*> -> {
+                        for (idx in arg.indices) {
+                            val element = arg[idx]
+                            if (element is Disposable) {
+                                element.destroy()
+                            }
+                        }
+                    }
+                    is Map<*, *> -> {
+                        for (element in arg.values) {
+                            if (element is Disposable) {
+                                element.destroy()
+                            }
+                        }
+                    }
+                    is Iterable<*> -> {
+                        for (element in arg) {
+                            if (element is Disposable) {
+                                element.destroy()
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/**
+ * @suppress
+ */
+inline fun <T : Disposable?, R> T.use(block: (T) -> R) =
+    try {
+        block(this)
+    } finally {
+        try {
+            // N.B. our implementation is on the nullable type `Disposable?`.
+            this?.destroy()
+        } catch (e: Throwable) {
+            // swallow
+        }
+    }
+
+/** 
+ * Placeholder object used to signal that we're constructing an interface with a FFI handle.
+ *
+ * This is the first argument for interface constructors that input a raw handle. It exists is that
+ * so we can avoid signature conflicts when an interface has a regular constructor than inputs a
+ * Long.
+ *
+ * @suppress
+ * */
+object UniffiWithHandle
+
+/** 
+ * Used to instantiate an interface without an actual pointer, for fakes in tests, mostly.
+ *
+ * @suppress
+ * */
+object NoHandle// Magic number for the Rust proxy to call using the same mechanism as every other method,
+// to free the callback once it's dropped by Rust.
+internal const val IDX_CALLBACK_FREE = 0
+// Callback return codes
+internal const val UNIFFI_CALLBACK_SUCCESS = 0
+internal const val UNIFFI_CALLBACK_ERROR = 1
+internal const val UNIFFI_CALLBACK_UNEXPECTED_ERROR = 2
+
+/**
+ * @suppress
+ */
+public abstract class FfiConverterCallbackInterface<CallbackInterface: Any>: FfiConverter<CallbackInterface, Long> {
+    internal val handleMap = UniffiHandleMap<CallbackInterface>()
+
+    internal fun drop(handle: Long) {
+        handleMap.remove(handle)
+    }
+
+    override fun lift(value: Long): CallbackInterface {
+        return handleMap.get(value)
+    }
+
+    override fun read(buf: ByteBuffer) = lift(buf.getLong())
+
+    override fun lower(value: CallbackInterface) = handleMap.insert(value)
+
+    override fun allocationSize(value: CallbackInterface) = 8UL
+
+    override fun write(value: CallbackInterface, buf: ByteBuffer) {
+        buf.putLong(lower(value))
+    }
+}
+/**
+ * The cleaner interface for Object finalization code to run.
+ * This is the entry point to any implementation that we're using.
+ *
+ * The cleaner registers objects and returns cleanables, so now we are
+ * defining a `UniffiCleaner` with a `UniffiClenaer.Cleanable` to abstract the
+ * different implmentations available at compile time.
+ *
+ * @suppress
+ */
+interface UniffiCleaner {
+    interface Cleanable {
+        fun clean()
+    }
+
+    fun register(value: Any, cleanUpTask: Runnable): UniffiCleaner.Cleanable
+
+    companion object
+}
+
+// The fallback Jna cleaner, which is available for both Android, and the JVM.
+private class UniffiJnaCleaner : UniffiCleaner {
+    private val cleaner = com.sun.jna.internal.Cleaner.getCleaner()
+
+    override fun register(value: Any, cleanUpTask: Runnable): UniffiCleaner.Cleanable =
+        UniffiJnaCleanable(cleaner.register(value, cleanUpTask))
+}
+
+private class UniffiJnaCleanable(
+    private val cleanable: com.sun.jna.internal.Cleaner.Cleanable,
+) : UniffiCleaner.Cleanable {
+    override fun clean() = cleanable.clean()
+}
+
+
+// We decide at uniffi binding generation time whether we were
+// using Android or not.
+// There are further runtime checks to chose the correct implementation
+// of the cleaner.
+private fun UniffiCleaner.Companion.create(): UniffiCleaner =
+    try {
+        // For safety's sake: if the library hasn't been run in android_cleaner = true
+        // mode, but is being run on Android, then we still need to think about
+        // Android API versions.
+        // So we check if java.lang.ref.Cleaner is there, and use that…
+        java.lang.Class.forName("java.lang.ref.Cleaner")
+        JavaLangRefCleaner()
+    } catch (e: ClassNotFoundException) {
+        // … otherwise, fallback to the JNA cleaner.
+        UniffiJnaCleaner()
+    }
+
+private class JavaLangRefCleaner : UniffiCleaner {
+    val cleaner = java.lang.ref.Cleaner.create()
+
+    override fun register(value: Any, cleanUpTask: Runnable): UniffiCleaner.Cleanable =
+        JavaLangRefCleanable(cleaner.register(value, cleanUpTask))
+}
+
+private class JavaLangRefCleanable(
+    val cleanable: java.lang.ref.Cleaner.Cleanable
+) : UniffiCleaner.Cleanable {
+    override fun clean() = cleanable.clean()
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterUShort: FfiConverter<UShort, Short> {
+    override fun lift(value: Short): UShort {
+        return value.toUShort()
+    }
+
+    fun lift(value: Int): UShort {
+        return value.toUShort()
+    }
+
+    override fun read(buf: ByteBuffer): UShort {
+        return lift(buf.getShort())
+    }
+
+    override fun lower(value: UShort): Short {
+        return value.toShort()
+    }
+
+    override fun allocationSize(value: UShort) = 2UL
+
+    override fun write(value: UShort, buf: ByteBuffer) {
+        buf.putShort(value.toShort())
+    }
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterUInt: FfiConverter<UInt, Int> {
+    override fun lift(value: Int): UInt {
+        return value.toUInt()
+    }
+
+    override fun read(buf: ByteBuffer): UInt {
+        return lift(buf.getInt())
+    }
+
+    override fun lower(value: UInt): Int {
+        return value.toInt()
+    }
+
+    override fun allocationSize(value: UInt) = 4UL
+
+    override fun write(value: UInt, buf: ByteBuffer) {
+        buf.putInt(value.toInt())
+    }
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterInt: FfiConverter<Int, Int> {
+    override fun lift(value: Int): Int {
+        return value
+    }
+
+    override fun read(buf: ByteBuffer): Int {
+        return buf.getInt()
+    }
+
+    override fun lower(value: Int): Int {
+        return value
+    }
+
+    override fun allocationSize(value: Int) = 4UL
+
+    override fun write(value: Int, buf: ByteBuffer) {
+        buf.putInt(value)
+    }
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterULong: FfiConverter<ULong, Long> {
+    override fun lift(value: Long): ULong {
+        return value.toULong()
+    }
+
+    override fun read(buf: ByteBuffer): ULong {
+        return lift(buf.getLong())
+    }
+
+    override fun lower(value: ULong): Long {
+        return value.toLong()
+    }
+
+    override fun allocationSize(value: ULong) = 8UL
+
+    override fun write(value: ULong, buf: ByteBuffer) {
+        buf.putLong(value.toLong())
+    }
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterDouble: FfiConverter<Double, Double> {
+    override fun lift(value: Double): Double {
+        return value
+    }
+
+    override fun read(buf: ByteBuffer): Double {
+        return buf.getDouble()
+    }
+
+    override fun lower(value: Double): Double {
+        return value
+    }
+
+    override fun allocationSize(value: Double) = 8UL
+
+    override fun write(value: Double, buf: ByteBuffer) {
+        buf.putDouble(value)
+    }
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterBoolean: FfiConverter<Boolean, Byte> {
+    override fun lift(value: Byte): Boolean {
+        return value.toInt() != 0
+    }
+
+    override fun read(buf: ByteBuffer): Boolean {
+        return lift(buf.get())
+    }
+
+    override fun lower(value: Boolean): Byte {
+        return if (value) 1.toByte() else 0.toByte()
+    }
+
+    override fun allocationSize(value: Boolean) = 1UL
+
+    override fun write(value: Boolean, buf: ByteBuffer) {
+        buf.put(lower(value))
+    }
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterString: FfiConverter<String, RustBuffer.ByValue> {
+    // Note: we don't inherit from FfiConverterRustBuffer, because we use a
+    // special encoding when lowering/lifting.  We can use `RustBuffer.len` to
+    // store our length and avoid writing it out to the buffer.
+    override fun lift(value: RustBuffer.ByValue): String {
+        try {
+            val byteArr = ByteArray(value.len.toInt())
+            value.asByteBuffer()!!.get(byteArr)
+            return byteArr.toString(Charsets.UTF_8)
+        } finally {
+            RustBuffer.free(value)
+        }
+    }
+
+    override fun read(buf: ByteBuffer): String {
+        val len = buf.getInt()
+        val byteArr = ByteArray(len)
+        buf.get(byteArr)
+        return byteArr.toString(Charsets.UTF_8)
+    }
+
+    fun toUtf8(value: String): ByteBuffer {
+        // Make sure we don't have invalid UTF-16, check for lone surrogates.
+        return Charsets.UTF_8.newEncoder().run {
+            onMalformedInput(CodingErrorAction.REPORT)
+            encode(CharBuffer.wrap(value))
+        }
+    }
+
+    override fun lower(value: String): RustBuffer.ByValue {
+        val byteBuf = toUtf8(value)
+        // Ideally we'd pass these bytes to `ffi_bytebuffer_from_bytes`, but doing so would require us
+        // to copy them into a JNA `Memory`. So we might as well directly copy them into a `RustBuffer`.
+        val rbuf = RustBuffer.alloc(byteBuf.limit().toULong())
+        rbuf.asByteBuffer()!!.put(byteBuf)
+        return rbuf
+    }
+
+    // We aren't sure exactly how many bytes our string will be once it's UTF-8
+    // encoded.  Allocate 3 bytes per UTF-16 code unit which will always be
+    // enough.
+    override fun allocationSize(value: String): ULong {
+        val sizeForLength = 4UL
+        val sizeForString = value.length.toULong() * 3UL
+        return sizeForLength + sizeForString
+    }
+
+    override fun write(value: String, buf: ByteBuffer) {
+        val byteBuf = toUtf8(value)
+        buf.putInt(byteBuf.limit())
+        buf.put(byteBuf)
+    }
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterByteArray: FfiConverterRustBuffer<ByteArray> {
+    override fun read(buf: ByteBuffer): ByteArray {
+        val len = buf.getInt()
+        val byteArr = ByteArray(len)
+        buf.get(byteArr)
+        return byteArr
+    }
+    override fun allocationSize(value: ByteArray): ULong {
+        return 4UL + value.size.toULong()
+    }
+    override fun write(value: ByteArray, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        buf.put(value)
+    }
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTimestamp: FfiConverterRustBuffer<java.time.Instant> {
+    override fun read(buf: ByteBuffer): java.time.Instant {
+        val seconds = buf.getLong()
+        // Type mismatch (should be u32) but we check for overflow/underflow below
+        val nanoseconds = buf.getInt().toLong()
+        if (nanoseconds < 0) {
+            throw java.time.DateTimeException("Instant nanoseconds exceed minimum or maximum supported by uniffi")
+        }
+        if (seconds >= 0) {
+            return java.time.Instant.EPOCH.plus(java.time.Duration.ofSeconds(seconds, nanoseconds))
+        } else {
+            return java.time.Instant.EPOCH.minus(java.time.Duration.ofSeconds(-seconds, nanoseconds))
+        }
+    }
+
+    // 8 bytes for seconds, 4 bytes for nanoseconds
+    override fun allocationSize(value: java.time.Instant) = 12UL
+
+    override fun write(value: java.time.Instant, buf: ByteBuffer) {
+        var epochOffset = java.time.Duration.between(java.time.Instant.EPOCH, value)
+
+        var sign = 1
+        if (epochOffset.isNegative()) {
+            sign = -1
+            epochOffset = epochOffset.negated()
+        }
+
+        if (epochOffset.nano < 0) {
+            // Java docs provide guarantee that nano will always be positive, so this should be impossible
+            // See: https://docs.oracle.com/javase/8/docs/api/java/time/Instant.html
+            throw IllegalArgumentException("Invalid timestamp, nano value must be non-negative")
+        }
+
+        buf.putLong(sign * epochOffset.seconds)
+        // Type mismatch (should be u32) but since values will always be between 0 and 999,999,999 it should be OK
+        buf.putInt(epochOffset.nano)
+    }
+}
+
+
+// This template implements a class for working with a Rust struct via a handle
+// to the live Rust struct on the other side of the FFI.
+//
+// There's some subtlety here, because we have to be careful not to operate on a Rust
+// struct after it has been dropped, and because we must expose a public API for freeing
+// theq Kotlin wrapper object in lieu of reliable finalizers. The core requirements are:
+//
+//   * Each instance holds an opaque handle to the underlying Rust struct.
+//     Method calls need to read this handle from the object's state and pass it in to
+//     the Rust FFI.
+//
+//   * When an instance is no longer needed, its handle should be passed to a
+//     special destructor function provided by the Rust FFI, which will drop the
+//     underlying Rust struct.
+//
+//   * Given an instance, calling code is expected to call the special
+//     `destroy` method in order to free it after use, either by calling it explicitly
+//     or by using a higher-level helper like the `use` method. Failing to do so risks
+//     leaking the underlying Rust struct.
+//
+//   * We can't assume that calling code will do the right thing, and must be prepared
+//     to handle Kotlin method calls executing concurrently with or even after a call to
+//     `destroy`, and to handle multiple (possibly concurrent!) calls to `destroy`.
+//
+//   * We must never allow Rust code to operate on the underlying Rust struct after
+//     the destructor has been called, and must never call the destructor more than once.
+//     Doing so may trigger memory unsafety.
+//
+//   * To mitigate many of the risks of leaking memory and use-after-free unsafety, a `Cleaner`
+//     is implemented to call the destructor when the Kotlin object becomes unreachable.
+//     This is done in a background thread. This is not a panacea, and client code should be aware that
+//      1. the thread may starve if some there are objects that have poorly performing
+//     `drop` methods or do significant work in their `drop` methods.
+//      2. the thread is shared across the whole library. This can be tuned by using `android_cleaner = true`,
+//         or `android = true` in the [`kotlin` section of the `uniffi.toml` file](https://mozilla.github.io/uniffi-rs/kotlin/configuration.html).
+//
+// If we try to implement this with mutual exclusion on access to the handle, there is the
+// possibility of a race between a method call and a concurrent call to `destroy`:
+//
+//    * Thread A starts a method call, reads the value of the handle, but is interrupted
+//      before it can pass the handle over the FFI to Rust.
+//    * Thread B calls `destroy` and frees the underlying Rust struct.
+//    * Thread A resumes, passing the already-read handle value to Rust and triggering
+//      a use-after-free.
+//
+// One possible solution would be to use a `ReadWriteLock`, with each method call taking
+// a read lock (and thus allowed to run concurrently) and the special `destroy` method
+// taking a write lock (and thus blocking on live method calls). However, we aim not to
+// generate methods with any hidden blocking semantics, and a `destroy` method that might
+// block if called incorrectly seems to meet that bar.
+//
+// So, we achieve our goals by giving each instance an associated `AtomicLong` counter to track
+// the number of in-flight method calls, and an `AtomicBoolean` flag to indicate whether `destroy`
+// has been called. These are updated according to the following rules:
+//
+//    * The initial value of the counter is 1, indicating a live object with no in-flight calls.
+//      The initial value for the flag is false.
+//
+//    * At the start of each method call, we atomically check the counter.
+//      If it is 0 then the underlying Rust struct has already been destroyed and the call is aborted.
+//      If it is nonzero them we atomically increment it by 1 and proceed with the method call.
+//
+//    * At the end of each method call, we atomically decrement and check the counter.
+//      If it has reached zero then we destroy the underlying Rust struct.
+//
+//    * When `destroy` is called, we atomically flip the flag from false to true.
+//      If the flag was already true we silently fail.
+//      Otherwise we atomically decrement and check the counter.
+//      If it has reached zero then we destroy the underlying Rust struct.
+//
+// Astute readers may observe that this all sounds very similar to the way that Rust's `Arc<T>` works,
+// and indeed it is, with the addition of a flag to guard against multiple calls to `destroy`.
+//
+// The overall effect is that the underlying Rust struct is destroyed only when `destroy` has been
+// called *and* all in-flight method calls have completed, avoiding violating any of the expectations
+// of the underlying Rust code.
+//
+// This makes a cleaner a better alternative to _not_ calling `destroy()` as
+// and when the object is finished with, but the abstraction is not perfect: if the Rust object's `drop`
+// method is slow, and/or there are many objects to cleanup, and it's on a low end Android device, then the cleaner
+// thread may be starved, and the app will leak memory.
+//
+// In this case, `destroy`ing manually may be a better solution.
+//
+// The cleaner can live side by side with the manual calling of `destroy`. In the order of responsiveness, uniffi objects
+// with Rust peers are reclaimed:
+//
+// 1. By calling the `destroy` method of the object, which calls `rustObject.free()`. If that doesn't happen:
+// 2. When the object becomes unreachable, AND the Cleaner thread gets to call `rustObject.free()`. If the thread is starved then:
+// 3. The memory is reclaimed when the process terminates.
+//
+// [1] https://stackoverflow.com/questions/24376768/can-java-finalize-an-object-when-it-is-still-in-scope/24380219
+//
+
+
+public interface ApiClientInterface {
+    
+    fun `searchIndex`(): SearchIndex
+    
+    suspend fun `fetchFilm`(`url`: kotlin.String, `policy`: CachePolicy, `cancellation`: CancellationToken?): Film
+    
+    suspend fun `fetchFilms`(`policy`: CachePolicy, `cancellation`: CancellationToken?): List<Film>
+    
+    suspend fun `fetchPeople`(`policy`: CachePolicy, `cancellation`: CancellationToken?): List<Person>
+    
+    suspend fun `fetchPerson`(`url`: kotlin.String, `policy`: CachePolicy, `cancellation`: CancellationToken?): Person
+    
+    suspend fun `fetchPlanet`(`url`: kotlin.String, `policy`: CachePolicy, `cancellation`: CancellationToken?): Planet
+    
+    suspend fun `fetchPlanets`(`policy`: CachePolicy, `cancellation`: CancellationToken?): List<Planet>
+    
+    suspend fun `fetchSpecies`(`url`: kotlin.String, `policy`: CachePolicy, `cancellation`: CancellationToken?): Species
+    
+    suspend fun `fetchSpeciesList`(`policy`: CachePolicy, `cancellation`: CancellationToken?): List<Species>
+    
+    suspend fun `fetchStarship`(`url`: kotlin.String, `policy`: CachePolicy, `cancellation`: CancellationToken?): Starship
+    
+    suspend fun `fetchStarships`(`policy`: CachePolicy, `cancellation`: CancellationToken?): List<Starship>
+    
+    suspend fun `fetchVehicle`(`url`: kotlin.String, `policy`: CachePolicy, `cancellation`: CancellationToken?): Vehicle
+    
+    suspend fun `fetchVehicles`(`policy`: CachePolicy, `cancellation`: CancellationToken?): List<Vehicle>
+    
+    fun `pageCursor`(`fetchable`: Fetchable): PageCursor
+    
+    suspend fun `resolveRelated`(`items`: List<ListItems>, `cancellation`: CancellationToken?): List<RelatedGroup>
+    
+    suspend fun `search`(`fetchable`: Fetchable, `query`: kotlin.String, `cancellation`: CancellationToken?): List<Selected>
+    
+    companion object
+}
+
+open class ApiClient: Disposable, AutoCloseable, ApiClientInterface
+{
+
+    @Suppress("UNUSED_PARAMETER")
+    /**
+     * @suppress
+     */
+    constructor(withHandle: UniffiWithHandle, handle: Long) {
+        this.handle = handle
+        this.cleanable = UniffiLib.CLEANER.register(this, UniffiCleanAction(handle))
+    }
+
+    /**
+     * @suppress
+     *
+     * This constructor can be used to instantiate a fake object. Only used for tests. Any
+     * attempt to actually use an object constructed this way will fail as there is no
+     * connected Rust object.
+     */
+    @Suppress("UNUSED_PARAMETER")
+    constructor(noHandle: NoHandle) {
+        this.handle = 0
+        this.cleanable = null
+    }
+    constructor(`httpClient`: HttpClient, `repository`: DataRepository) :
+        this(UniffiWithHandle, 
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_constructor_apiclient_new(
+    
+        FfiConverterTypeHttpClient.lower(`httpClient`),FfiConverterTypeDataRepository.lower(`repository`),_status)
+}
+    )
+
+    protected val handle: Long
+    protected val cleanable: UniffiCleaner.Cleanable?
+
+    private val wasDestroyed = AtomicBoolean(false)
+    private val callCounter = AtomicLong(1)
+
+    override fun destroy() {
+        // Only allow a single call to this method.
+        // TODO: maybe we should log a warning if called more than once?
+        if (this.wasDestroyed.compareAndSet(false, true)) {
+            // This decrement always matches the initial count of 1 given at creation time.
+            if (this.callCounter.decrementAndGet() == 0L) {
+                cleanable?.clean()
+            }
+        }
+    }
+
+    @Synchronized
+    override fun close() {
+        this.destroy()
+    }
+
+    internal inline fun <R> callWithHandle(block: (handle: Long) -> R): R {
+        // Check and increment the call counter, to keep the object alive.
+        // This needs a compare-and-set retry loop in case of concurrent updates.
+        do {
+            val c = this.callCounter.get()
+            if (c == 0L) {
+                throw IllegalStateException("${this.javaClass.simpleName} object has already been destroyed")
+            }
+            if (c == Long.MAX_VALUE) {
+                throw IllegalStateException("${this.javaClass.simpleName} call counter would overflow")
+            }
+        } while (! this.callCounter.compareAndSet(c, c + 1L))
+        // Now we can safely do the method call without the handle being freed concurrently.
+        try {
+            return block(this.uniffiCloneHandle())
+        } finally {
+            // This decrement always matches the increment we performed above.
+            if (this.callCounter.decrementAndGet() == 0L) {
+                cleanable?.clean()
+            }
+        }
+    }
+
+    // Use a static inner class instead of a closure so as not to accidentally
+    // capture `this` as part of the cleanable's action.
+    private class UniffiCleanAction(private val handle: Long) : Runnable {
+        override fun run() {
+            if (handle == 0.toLong()) {
+                // Fake object created with `NoHandle`, don't try to free.
+                return;
+            }
+            uniffiRustCall { status ->
+                UniffiLib.uniffi_starwars_fn_free_apiclient(handle, status)
+            }
+        }
+    }
+
+    /**
+     * @suppress
+     */
+    fun uniffiCloneHandle(): Long {
+        if (handle == 0.toLong()) {
+            throw InternalException("uniffiCloneHandle() called on NoHandle object");
+        }
+        return uniffiRustCall() { status ->
+            UniffiLib.uniffi_starwars_fn_clone_apiclient(handle, status)
+        }
+    }
+
+    override fun `searchIndex`(): SearchIndex {
+            return FfiConverterTypeSearchIndex.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_apiclient_search_index(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    
+    @Throws(ApiException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `fetchFilm`(`url`: kotlin.String, `policy`: CachePolicy, `cancellation`: CancellationToken?) : Film {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_starwars_fn_method_apiclient_fetch_film(
+                uniffiHandle,
+                FfiConverterString.lower(`url`),FfiConverterTypeCachePolicy.lower(`policy`),FfiConverterOptionalTypeCancellationToken.lower(`cancellation`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_starwars_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_starwars_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.ffi_starwars_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterTypeFilm.lift(it) },
+        // Error FFI converter
+        ApiException.ErrorHandler,
+    )
+    }
+
+    
+    @Throws(ApiException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `fetchFilms`(`policy`: CachePolicy, `cancellation`: CancellationToken?) : List<Film> {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_starwars_fn_method_apiclient_fetch_films(
+                uniffiHandle,
+                FfiConverterTypeCachePolicy.lower(`policy`),FfiConverterOptionalTypeCancellationToken.lower(`cancellation`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_starwars_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_starwars_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.ffi_starwars_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterSequenceTypeFilm.lift(it) },
+        // Error FFI converter
+        ApiException.ErrorHandler,
+    )
+    }
+
+    
+    @Throws(ApiException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `fetchPeople`(`policy`: CachePolicy, `cancellation`: CancellationToken?) : List<Person> {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_starwars_fn_method_apiclient_fetch_people(
+                uniffiHandle,
+                FfiConverterTypeCachePolicy.lower(`policy`),FfiConverterOptionalTypeCancellationToken.lower(`cancellation`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_starwars_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_starwars_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.ffi_starwars_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterSequenceTypePerson.lift(it) },
+        // Error FFI converter
+        ApiException.ErrorHandler,
+    )
+    }
+
+    
+    @Throws(ApiException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `fetchPerson`(`url`: kotlin.String, `policy`: CachePolicy, `cancellation`: CancellationToken?) : Person {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_starwars_fn_method_apiclient_fetch_person(
+                uniffiHandle,
+                FfiConverterString.lower(`url`),FfiConverterTypeCachePolicy.lower(`policy`),FfiConverterOptionalTypeCancellationToken.lower(`cancellation`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_starwars_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_starwars_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.ffi_starwars_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterTypePerson.lift(it) },
+        // Error FFI converter
+        ApiException.ErrorHandler,
+    )
+    }
+
+    
+    @Throws(ApiException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `fetchPlanet`(`url`: kotlin.String, `policy`: CachePolicy, `cancellation`: CancellationToken?) : Planet {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_starwars_fn_method_apiclient_fetch_planet(
+                uniffiHandle,
+                FfiConverterString.lower(`url`),FfiConverterTypeCachePolicy.lower(`policy`),FfiConverterOptionalTypeCancellationToken.lower(`cancellation`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_starwars_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_starwars_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.ffi_starwars_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterTypePlanet.lift(it) },
+        // Error FFI converter
+        ApiException.ErrorHandler,
+    )
+    }
+
+    
+    @Throws(ApiException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `fetchPlanets`(`policy`: CachePolicy, `cancellation`: CancellationToken?) : List<Planet> {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_starwars_fn_method_apiclient_fetch_planets(
+                uniffiHandle,
+                FfiConverterTypeCachePolicy.lower(`policy`),FfiConverterOptionalTypeCancellationToken.lower(`cancellation`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_starwars_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_starwars_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.ffi_starwars_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterSequenceTypePlanet.lift(it) },
+        // Error FFI converter
+        ApiException.ErrorHandler,
+    )
+    }
+
+    
+    @Throws(ApiException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `fetchSpecies`(`url`: kotlin.String, `policy`: CachePolicy, `cancellation`: CancellationToken?) : Species {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_starwars_fn_method_apiclient_fetch_species(
+                uniffiHandle,
+                FfiConverterString.lower(`url`),FfiConverterTypeCachePolicy.lower(`policy`),FfiConverterOptionalTypeCancellationToken.lower(`cancellation`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_starwars_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_starwars_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.ffi_starwars_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterTypeSpecies.lift(it) },
+        // Error FFI converter
+        ApiException.ErrorHandler,
+    )
+    }
+
+    
+    @Throws(ApiException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `fetchSpeciesList`(`policy`: CachePolicy, `cancellation`: CancellationToken?) : List<Species> {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_starwars_fn_method_apiclient_fetch_species_list(
+                uniffiHandle,
+                FfiConverterTypeCachePolicy.lower(`policy`),FfiConverterOptionalTypeCancellationToken.lower(`cancellation`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_starwars_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_starwars_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.ffi_starwars_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterSequenceTypeSpecies.lift(it) },
+        // Error FFI converter
+        ApiException.ErrorHandler,
+    )
+    }
+
+    
+    @Throws(ApiException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `fetchStarship`(`url`: kotlin.String, `policy`: CachePolicy, `cancellation`: CancellationToken?) : Starship {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_starwars_fn_method_apiclient_fetch_starship(
+                uniffiHandle,
+                FfiConverterString.lower(`url`),FfiConverterTypeCachePolicy.lower(`policy`),FfiConverterOptionalTypeCancellationToken.lower(`cancellation`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_starwars_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_starwars_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.ffi_starwars_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterTypeStarship.lift(it) },
+        // Error FFI converter
+        ApiException.ErrorHandler,
+    )
+    }
+
+    
+    @Throws(ApiException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `fetchStarships`(`policy`: CachePolicy, `cancellation`: CancellationToken?) : List<Starship> {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_starwars_fn_method_apiclient_fetch_starships(
+                uniffiHandle,
+                FfiConverterTypeCachePolicy.lower(`policy`),FfiConverterOptionalTypeCancellationToken.lower(`cancellation`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_starwars_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_starwars_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.ffi_starwars_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterSequenceTypeStarship.lift(it) },
+        // Error FFI converter
+        ApiException.ErrorHandler,
+    )
+    }
+
+    
+    @Throws(ApiException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `fetchVehicle`(`url`: kotlin.String, `policy`: CachePolicy, `cancellation`: CancellationToken?) : Vehicle {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_starwars_fn_method_apiclient_fetch_vehicle(
+                uniffiHandle,
+                FfiConverterString.lower(`url`),FfiConverterTypeCachePolicy.lower(`policy`),FfiConverterOptionalTypeCancellationToken.lower(`cancellation`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_starwars_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_starwars_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.ffi_starwars_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterTypeVehicle.lift(it) },
+        // Error FFI converter
+        ApiException.ErrorHandler,
+    )
+    }
+
+    
+    @Throws(ApiException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `fetchVehicles`(`policy`: CachePolicy, `cancellation`: CancellationToken?) : List<Vehicle> {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_starwars_fn_method_apiclient_fetch_vehicles(
+                uniffiHandle,
+                FfiConverterTypeCachePolicy.lower(`policy`),FfiConverterOptionalTypeCancellationToken.lower(`cancellation`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_starwars_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_starwars_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.ffi_starwars_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterSequenceTypeVehicle.lift(it) },
+        // Error FFI converter
+        ApiException.ErrorHandler,
+    )
+    }
+
+    override fun `pageCursor`(`fetchable`: Fetchable): PageCursor {
+            return FfiConverterTypePageCursor.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_apiclient_page_cursor(
+        it,
+        FfiConverterTypeFetchable.lower(`fetchable`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+    @Throws(ApiException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `resolveRelated`(`items`: List<ListItems>, `cancellation`: CancellationToken?) : List<RelatedGroup> {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_starwars_fn_method_apiclient_resolve_related(
+                uniffiHandle,
+                FfiConverterSequenceTypeListItems.lower(`items`),FfiConverterOptionalTypeCancellationToken.lower(`cancellation`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_starwars_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_starwars_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.ffi_starwars_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterSequenceTypeRelatedGroup.lift(it) },
+        // Error FFI converter
+        ApiException.ErrorHandler,
+    )
+    }
+
+    
+    @Throws(ApiException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `search`(`fetchable`: Fetchable, `query`: kotlin.String, `cancellation`: CancellationToken?) : List<Selected> {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_starwars_fn_method_apiclient_search(
+                uniffiHandle,
+                FfiConverterTypeFetchable.lower(`fetchable`),FfiConverterString.lower(`query`),FfiConverterOptionalTypeCancellationToken.lower(`cancellation`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_starwars_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_starwars_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.ffi_starwars_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterSequenceTypeSelected.lift(it) },
+        // Error FFI converter
+        ApiException.ErrorHandler,
+    )
+    }
+
+    
+
+    
+
+
+    
+    companion object {
+         fun `withConfig`(`httpClient`: HttpClient, `repository`: DataRepository, `config`: ClientConfig): ApiClient {
+            return FfiConverterTypeApiClient.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_constructor_apiclient_with_config(
+    
+        FfiConverterTypeHttpClient.lower(`httpClient`),FfiConverterTypeDataRepository.lower(`repository`),FfiConverterTypeClientConfig.lower(`config`),_status)
+}
+    )
+    }
+    
+
+        
+    }
+    
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeApiClient: FfiConverter<ApiClient, Long> {
+    override fun lower(value: ApiClient): Long {
+        return value.uniffiCloneHandle()
+    }
+
+    override fun lift(value: Long): ApiClient {
+        return ApiClient(UniffiWithHandle, value)
+    }
+
+    override fun read(buf: ByteBuffer): ApiClient {
+        return lift(buf.getLong())
+    }
+
+    override fun allocationSize(value: ApiClient) = 8UL
+
+    override fun write(value: ApiClient, buf: ByteBuffer) {
+        buf.putLong(lower(value))
+    }
+}
+
+
+// This template implements a class for working with a Rust struct via a handle
+// to the live Rust struct on the other side of the FFI.
+//
+// There's some subtlety here, because we have to be careful not to operate on a Rust
+// struct after it has been dropped, and because we must expose a public API for freeing
+// theq Kotlin wrapper object in lieu of reliable finalizers. The core requirements are:
+//
+//   * Each instance holds an opaque handle to the underlying Rust struct.
+//     Method calls need to read this handle from the object's state and pass it in to
+//     the Rust FFI.
+//
+//   * When an instance is no longer needed, its handle should be passed to a
+//     special destructor function provided by the Rust FFI, which will drop the
+//     underlying Rust struct.
+//
+//   * Given an instance, calling code is expected to call the special
+//     `destroy` method in order to free it after use, either by calling it explicitly
+//     or by using a higher-level helper like the `use` method. Failing to do so risks
+//     leaking the underlying Rust struct.
+//
+//   * We can't assume that calling code will do the right thing, and must be prepared
+//     to handle Kotlin method calls executing concurrently with or even after a call to
+//     `destroy`, and to handle multiple (possibly concurrent!) calls to `destroy`.
+//
+//   * We must never allow Rust code to operate on the underlying Rust struct after
+//     the destructor has been called, and must never call the destructor more than once.
+//     Doing so may trigger memory unsafety.
+//
+//   * To mitigate many of the risks of leaking memory and use-after-free unsafety, a `Cleaner`
+//     is implemented to call the destructor when the Kotlin object becomes unreachable.
+//     This is done in a background thread. This is not a panacea, and client code should be aware that
+//      1. the thread may starve if some there are objects that have poorly performing
+//     `drop` methods or do significant work in their `drop` methods.
+//      2. the thread is shared across the whole library. This can be tuned by using `android_cleaner = true`,
+//         or `android = true` in the [`kotlin` section of the `uniffi.toml` file](https://mozilla.github.io/uniffi-rs/kotlin/configuration.html).
+//
+// If we try to implement this with mutual exclusion on access to the handle, there is the
+// possibility of a race between a method call and a concurrent call to `destroy`:
+//
+//    * Thread A starts a method call, reads the value of the handle, but is interrupted
+//      before it can pass the handle over the FFI to Rust.
+//    * Thread B calls `destroy` and frees the underlying Rust struct.
+//    * Thread A resumes, passing the already-read handle value to Rust and triggering
+//      a use-after-free.
+//
+// One possible solution would be to use a `ReadWriteLock`, with each method call taking
+// a read lock (and thus allowed to run concurrently) and the special `destroy` method
+// taking a write lock (and thus blocking on live method calls). However, we aim not to
+// generate methods with any hidden blocking semantics, and a `destroy` method that might
+// block if called incorrectly seems to meet that bar.
+//
+// So, we achieve our goals by giving each instance an associated `AtomicLong` counter to track
+// the number of in-flight method calls, and an `AtomicBoolean` flag to indicate whether `destroy`
+// has been called. These are updated according to the following rules:
+//
+//    * The initial value of the counter is 1, indicating a live object with no in-flight calls.
+//      The initial value for the flag is false.
+//
+//    * At the start of each method call, we atomically check the counter.
+//      If it is 0 then the underlying Rust struct has already been destroyed and the call is aborted.
+//      If it is nonzero them we atomically increment it by 1 and proceed with the method call.
+//
+//    * At the end of each method call, we atomically decrement and check the counter.
+//      If it has reached zero then we destroy the underlying Rust struct.
+//
+//    * When `destroy` is called, we atomically flip the flag from false to true.
+//      If the flag was already true we silently fail.
+//      Otherwise we atomically decrement and check the counter.
+//      If it has reached zero then we destroy the underlying Rust struct.
+//
+// Astute readers may observe that this all sounds very similar to the way that Rust's `Arc<T>` works,
+// and indeed it is, with the addition of a flag to guard against multiple calls to `destroy`.
+//
+// The overall effect is that the underlying Rust struct is destroyed only when `destroy` has been
+// called *and* all in-flight method calls have completed, avoiding violating any of the expectations
+// of the underlying Rust code.
+//
+// This makes a cleaner a better alternative to _not_ calling `destroy()` as
+// and when the object is finished with, but the abstraction is not perfect: if the Rust object's `drop`
+// method is slow, and/or there are many objects to cleanup, and it's on a low end Android device, then the cleaner
+// thread may be starved, and the app will leak memory.
+//
+// In this case, `destroy`ing manually may be a better solution.
+//
+// The cleaner can live side by side with the manual calling of `destroy`. In the order of responsiveness, uniffi objects
+// with Rust peers are reclaimed:
+//
+// 1. By calling the `destroy` method of the object, which calls `rustObject.free()`. If that doesn't happen:
+// 2. When the object becomes unreachable, AND the Cleaner thread gets to call `rustObject.free()`. If the thread is starved then:
+// 3. The memory is reclaimed when the process terminates.
+//
+// [1] https://stackoverflow.com/questions/24376768/can-java-finalize-an-object-when-it-is-still-in-scope/24380219
+//
+
+
+public interface CancellationListener {
+    
+    fun `onCancel`()
+    
+    companion object
+}
+
+open class CancellationListenerImpl: Disposable, AutoCloseable, CancellationListener
+{
+
+    @Suppress("UNUSED_PARAMETER")
+    /**
+     * @suppress
+     */
+    constructor(withHandle: UniffiWithHandle, handle: Long) {
+        this.handle = handle
+        this.cleanable = UniffiLib.CLEANER.register(this, UniffiCleanAction(handle))
+    }
+
+    /**
+     * @suppress
+     *
+     * This constructor can be used to instantiate a fake object. Only used for tests. Any
+     * attempt to actually use an object constructed this way will fail as there is no
+     * connected Rust object.
+     */
+    @Suppress("UNUSED_PARAMETER")
+    constructor(noHandle: NoHandle) {
+        this.handle = 0
+        this.cleanable = null
+    }
+
+    protected val handle: Long
+    protected val cleanable: UniffiCleaner.Cleanable?
+
+    private val wasDestroyed = AtomicBoolean(false)
+    private val callCounter = AtomicLong(1)
+
+    override fun destroy() {
+        // Only allow a single call to this method.
+        // TODO: maybe we should log a warning if called more than once?
+        if (this.wasDestroyed.compareAndSet(false, true)) {
+            // This decrement always matches the initial count of 1 given at creation time.
+            if (this.callCounter.decrementAndGet() == 0L) {
+                cleanable?.clean()
+            }
+        }
+    }
+
+    @Synchronized
+    override fun close() {
+        this.destroy()
+    }
+
+    internal inline fun <R> callWithHandle(block: (handle: Long) -> R): R {
+        // Check and increment the call counter, to keep the object alive.
+        // This needs a compare-and-set retry loop in case of concurrent updates.
+        do {
+            val c = this.callCounter.get()
+            if (c == 0L) {
+                throw IllegalStateException("${this.javaClass.simpleName} object has already been destroyed")
+            }
+            if (c == Long.MAX_VALUE) {
+                throw IllegalStateException("${this.javaClass.simpleName} call counter would overflow")
+            }
+        } while (! this.callCounter.compareAndSet(c, c + 1L))
+        // Now we can safely do the method call without the handle being freed concurrently.
+        try {
+            return block(this.uniffiCloneHandle())
+        } finally {
+            // This decrement always matches the increment we performed above.
+            if (this.callCounter.decrementAndGet() == 0L) {
+                cleanable?.clean()
+            }
+        }
+    }
+
+    // Use a static inner class instead of a closure so as not to accidentally
+    // capture `this` as part of the cleanable's action.
+    private class UniffiCleanAction(private val handle: Long) : Runnable {
+        override fun run() {
+            if (handle == 0.toLong()) {
+                // Fake object created with `NoHandle`, don't try to free.
+                return;
+            }
+            uniffiRustCall { status ->
+                UniffiLib.uniffi_starwars_fn_free_cancellationlistener(handle, status)
+            }
+        }
+    }
+
+    /**
+     * @suppress
+     */
+    fun uniffiCloneHandle(): Long {
+        if (handle == 0.toLong()) {
+            throw InternalException("uniffiCloneHandle() called on NoHandle object");
+        }
+        return uniffiRustCall() { status ->
+            UniffiLib.uniffi_starwars_fn_clone_cancellationlistener(handle, status)
+        }
+    }
+
+    override fun `onCancel`()
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_cancellationlistener_on_cancel(
+        it,
+        _status)
+}
+    }
+    
+    
+
+    
+
+    
+
+
+    
+    
+    /**
+     * @suppress
+     */
+    companion object
+    
+}
+
+
+
+// Put the implementation in an object so we don't pollute the top-level namespace
+internal object uniffiCallbackInterfaceCancellationListener {
+    internal object `onCancel`: UniffiCallbackInterfaceCancellationListenerMethod0 {
+        override fun callback(`uniffiHandle`: Long,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,) {
+            val uniffiObj = FfiConverterTypeCancellationListener.handleMap.get(uniffiHandle)
+            val makeCall = { ->
+                uniffiObj.`onCancel`(
+                )
+            }
+            val writeReturn = { _: Unit -> Unit }
+            uniffiTraitInterfaceCall(uniffiCallStatus, makeCall, writeReturn)
+        }
+    }
+
+    internal object uniffiFree: UniffiCallbackInterfaceFree {
+        override fun callback(handle: Long) {
+            FfiConverterTypeCancellationListener.handleMap.remove(handle)
+        }
+    }
+
+    internal object uniffiClone: UniffiCallbackInterfaceClone {
+        override fun callback(handle: Long): Long {
+            return FfiConverterTypeCancellationListener.handleMap.clone(handle)
+        }
+    }
+
+    internal var vtable = UniffiVTableCallbackInterfaceCancellationListener.UniffiByValue(
+        uniffiFree,
+        uniffiClone,
+        `onCancel`,
+    )
+
+    // Registers the foreign callback with the Rust side.
+    // This method is generated for each callback interface.
+    internal fun register(lib: UniffiLib) {
+        lib.uniffi_starwars_fn_init_callback_vtable_cancellationlistener(vtable)
+    }
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeCancellationListener: FfiConverter<CancellationListener, Long> {
+    internal val handleMap = UniffiHandleMap<CancellationListener>()
+
+    override fun lower(value: CancellationListener): Long {
+        if (value is CancellationListenerImpl) {
+             // Rust-implemented object.  Clone the handle and return it
+            return value.uniffiCloneHandle()
+         } else {
+            // Kotlin object, generate a new vtable handle and return that.
+            return handleMap.insert(value)
+         }
+    }
+
+    override fun lift(value: Long): CancellationListener {
+        if ((value and 1.toLong()) == 0.toLong()) {
+            // Rust-generated handle, construct a new class that uses the handle to implement the
+            // interface
+            return CancellationListenerImpl(UniffiWithHandle, value)
+        } else {
+            // Kotlin-generated handle, get the object from the handle map
+            return handleMap.remove(value)
+        }
+    }
+
+    override fun read(buf: ByteBuffer): CancellationListener {
+        return lift(buf.getLong())
+    }
+
+    override fun allocationSize(value: CancellationListener) = 8UL
+
+    override fun write(value: CancellationListener, buf: ByteBuffer) {
+        buf.putLong(lower(value))
+    }
+}
+
+
+// This template implements a class for working with a Rust struct via a handle
+// to the live Rust struct on the other side of the FFI.
+//
+// There's some subtlety here, because we have to be careful not to operate on a Rust
+// struct after it has been dropped, and because we must expose a public API for freeing
+// theq Kotlin wrapper object in lieu of reliable finalizers. The core requirements are:
+//
+//   * Each instance holds an opaque handle to the underlying Rust struct.
+//     Method calls need to read this handle from the object's state and pass it in to
+//     the Rust FFI.
+//
+//   * When an instance is no longer needed, its handle should be passed to a
+//     special destructor function provided by the Rust FFI, which will drop the
+//     underlying Rust struct.
+//
+//   * Given an instance, calling code is expected to call the special
+//     `destroy` method in order to free it after use, either by calling it explicitly
+//     or by using a higher-level helper like the `use` method. Failing to do so risks
+//     leaking the underlying Rust struct.
+//
+//   * We can't assume that calling code will do the right thing, and must be prepared
+//     to handle Kotlin method calls executing concurrently with or even after a call to
+//     `destroy`, and to handle multiple (possibly concurrent!) calls to `destroy`.
+//
+//   * We must never allow Rust code to operate on the underlying Rust struct after
+//     the destructor has been called, and must never call the destructor more than once.
+//     Doing so may trigger memory unsafety.
+//
+//   * To mitigate many of the risks of leaking memory and use-after-free unsafety, a `Cleaner`
+//     is implemented to call the destructor when the Kotlin object becomes unreachable.
+//     This is done in a background thread. This is not a panacea, and client code should be aware that
+//      1. the thread may starve if some there are objects that have poorly performing
+//     `drop` methods or do significant work in their `drop` methods.
+//      2. the thread is shared across the whole library. This can be tuned by using `android_cleaner = true`,
+//         or `android = true` in the [`kotlin` section of the `uniffi.toml` file](https://mozilla.github.io/uniffi-rs/kotlin/configuration.html).
+//
+// If we try to implement this with mutual exclusion on access to the handle, there is the
+// possibility of a race between a method call and a concurrent call to `destroy`:
+//
+//    * Thread A starts a method call, reads the value of the handle, but is interrupted
+//      before it can pass the handle over the FFI to Rust.
+//    * Thread B calls `destroy` and frees the underlying Rust struct.
+//    * Thread A resumes, passing the already-read handle value to Rust and triggering
+//      a use-after-free.
+//
+// One possible solution would be to use a `ReadWriteLock`, with each method call taking
+// a read lock (and thus allowed to run concurrently) and the special `destroy` method
+// taking a write lock (and thus blocking on live method calls). However, we aim not to
+// generate methods with any hidden blocking semantics, and a `destroy` method that might
+// block if called incorrectly seems to meet that bar.
+//
+// So, we achieve our goals by giving each instance an associated `AtomicLong` counter to track
+// the number of in-flight method calls, and an `AtomicBoolean` flag to indicate whether `destroy`
+// has been called. These are updated according to the following rules:
+//
+//    * The initial value of the counter is 1, indicating a live object with no in-flight calls.
+//      The initial value for the flag is false.
+//
+//    * At the start of each method call, we atomically check the counter.
+//      If it is 0 then the underlying Rust struct has already been destroyed and the call is aborted.
+//      If it is nonzero them we atomically increment it by 1 and proceed with the method call.
+//
+//    * At the end of each method call, we atomically decrement and check the counter.
+//      If it has reached zero then we destroy the underlying Rust struct.
+//
+//    * When `destroy` is called, we atomically flip the flag from false to true.
+//      If the flag was already true we silently fail.
+//      Otherwise we atomically decrement and check the counter.
+//      If it has reached zero then we destroy the underlying Rust struct.
+//
+// Astute readers may observe that this all sounds very similar to the way that Rust's `Arc<T>` works,
+// and indeed it is, with the addition of a flag to guard against multiple calls to `destroy`.
+//
+// The overall effect is that the underlying Rust struct is destroyed only when `destroy` has been
+// called *and* all in-flight method calls have completed, avoiding violating any of the expectations
+// of the underlying Rust code.
+//
+// This makes a cleaner a better alternative to _not_ calling `destroy()` as
+// and when the object is finished with, but the abstraction is not perfect: if the Rust object's `drop`
+// method is slow, and/or there are many objects to cleanup, and it's on a low end Android device, then the cleaner
+// thread may be starved, and the app will leak memory.
+//
+// In this case, `destroy`ing manually may be a better solution.
+//
+// The cleaner can live side by side with the manual calling of `destroy`. In the order of responsiveness, uniffi objects
+// with Rust peers are reclaimed:
+//
+// 1. By calling the `destroy` method of the object, which calls `rustObject.free()`. If that doesn't happen:
+// 2. When the object becomes unreachable, AND the Cleaner thread gets to call `rustObject.free()`. If the thread is starved then:
+// 3. The memory is reclaimed when the process terminates.
+//
+// [1] https://stackoverflow.com/questions/24376768/can-java-finalize-an-object-when-it-is-still-in-scope/24380219
+//
+
+
+public interface CancellationTokenInterface {
+    
+    fun `cancel`()
+    
+    fun `isCancelled`(): kotlin.Boolean
+    
+    fun `onCancel`(`listener`: CancellationListener)
+    
+    companion object
+}
+
+open class CancellationToken: Disposable, AutoCloseable, CancellationTokenInterface
+{
+
+    @Suppress("UNUSED_PARAMETER")
+    /**
+     * @suppress
+     */
+    constructor(withHandle: UniffiWithHandle, handle: Long) {
+        this.handle = handle
+        this.cleanable = UniffiLib.CLEANER.register(this, UniffiCleanAction(handle))
+    }
+
+    /**
+     * @suppress
+     *
+     * This constructor can be used to instantiate a fake object. Only used for tests. Any
+     * attempt to actually use an object constructed this way will fail as there is no
+     * connected Rust object.
+     */
+    @Suppress("UNUSED_PARAMETER")
+    constructor(noHandle: NoHandle) {
+        this.handle = 0
+        this.cleanable = null
+    }
+    constructor() :
+        this(UniffiWithHandle, 
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_constructor_cancellationtoken_new(
+    
+        _status)
+}
+    )
+
+    protected val handle: Long
+    protected val cleanable: UniffiCleaner.Cleanable?
+
+    private val wasDestroyed = AtomicBoolean(false)
+    private val callCounter = AtomicLong(1)
+
+    override fun destroy() {
+        // Only allow a single call to this method.
+        // TODO: maybe we should log a warning if called more than once?
+        if (this.wasDestroyed.compareAndSet(false, true)) {
+            // This decrement always matches the initial count of 1 given at creation time.
+            if (this.callCounter.decrementAndGet() == 0L) {
+                cleanable?.clean()
+            }
+        }
+    }
+
+    @Synchronized
+    override fun close() {
+        this.destroy()
+    }
+
+    internal inline fun <R> callWithHandle(block: (handle: Long) -> R): R {
+        // Check and increment the call counter, to keep the object alive.
+        // This needs a compare-and-set retry loop in case of concurrent updates.
+        do {
+            val c = this.callCounter.get()
+            if (c == 0L) {
+                throw IllegalStateException("${this.javaClass.simpleName} object has already been destroyed")
+            }
+            if (c == Long.MAX_VALUE) {
+                throw IllegalStateException("${this.javaClass.simpleName} call counter would overflow")
+            }
+        } while (! this.callCounter.compareAndSet(c, c + 1L))
+        // Now we can safely do the method call without the handle being freed concurrently.
+        try {
+            return block(this.uniffiCloneHandle())
+        } finally {
+            // This decrement always matches the increment we performed above.
+            if (this.callCounter.decrementAndGet() == 0L) {
+                cleanable?.clean()
+            }
+        }
+    }
+
+    // Use a static inner class instead of a closure so as not to accidentally
+    // capture `this` as part of the cleanable's action.
+    private class UniffiCleanAction(private val handle: Long) : Runnable {
+        override fun run() {
+            if (handle == 0.toLong()) {
+                // Fake object created with `NoHandle`, don't try to free.
+                return;
+            }
+            uniffiRustCall { status ->
+                UniffiLib.uniffi_starwars_fn_free_cancellationtoken(handle, status)
+            }
+        }
+    }
+
+    /**
+     * @suppress
+     */
+    fun uniffiCloneHandle(): Long {
+        if (handle == 0.toLong()) {
+            throw InternalException("uniffiCloneHandle() called on NoHandle object");
+        }
+        return uniffiRustCall() { status ->
+            UniffiLib.uniffi_starwars_fn_clone_cancellationtoken(handle, status)
+        }
+    }
+
+    override fun `cancel`()
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_cancellationtoken_cancel(
+        it,
+        _status)
+}
+    }
+    
+    
+
+    override fun `isCancelled`(): kotlin.Boolean {
+            return FfiConverterBoolean.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_cancellationtoken_is_cancelled(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    override fun `onCancel`(`listener`: CancellationListener)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_cancellationtoken_on_cancel(
+        it,
+        FfiConverterTypeCancellationListener.lower(`listener`),_status)
+}
+    }
+    
+    
+
+    
+
+    
+
+
+    
+    
+    /**
+     * @suppress
+     */
+    companion object
+    
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeCancellationToken: FfiConverter<CancellationToken, Long> {
+    override fun lower(value: CancellationToken): Long {
+        return value.uniffiCloneHandle()
+    }
+
+    override fun lift(value: Long): CancellationToken {
+        return CancellationToken(UniffiWithHandle, value)
+    }
+
+    override fun read(buf: ByteBuffer): CancellationToken {
+        return lift(buf.getLong())
+    }
+
+    override fun allocationSize(value: CancellationToken) = 8UL
+
+    override fun write(value: CancellationToken, buf: ByteBuffer) {
+        buf.putLong(lower(value))
+    }
+}
+
+
+// This template implements a class for working with a Rust struct via a handle
+// to the live Rust struct on the other side of the FFI.
+//
+// There's some subtlety here, because we have to be careful not to operate on a Rust
+// struct after it has been dropped, and because we must expose a public API for freeing
+// theq Kotlin wrapper object in lieu of reliable finalizers. The core requirements are:
+//
+//   * Each instance holds an opaque handle to the underlying Rust struct.
+//     Method calls need to read this handle from the object's state and pass it in to
+//     the Rust FFI.
+//
+//   * When an instance is no longer needed, its handle should be passed to a
+//     special destructor function provided by the Rust FFI, which will drop the
+//     underlying Rust struct.
+//
+//   * Given an instance, calling code is expected to call the special
+//     `destroy` method in order to free it after use, either by calling it explicitly
+//     or by using a higher-level helper like the `use` method. Failing to do so risks
+//     leaking the underlying Rust struct.
+//
+//   * We can't assume that calling code will do the right thing, and must be prepared
+//     to handle Kotlin method calls executing concurrently with or even after a call to
+//     `destroy`, and to handle multiple (possibly concurrent!) calls to `destroy`.
+//
+//   * We must never allow Rust code to operate on the underlying Rust struct after
+//     the destructor has been called, and must never call the destructor more than once.
+//     Doing so may trigger memory unsafety.
+//
+//   * To mitigate many of the risks of leaking memory and use-after-free unsafety, a `Cleaner`
+//     is implemented to call the destructor when the Kotlin object becomes unreachable.
+//     This is done in a background thread. This is not a panacea, and client code should be aware that
+//      1. the thread may starve if some there are objects that have poorly performing
+//     `drop` methods or do significant work in their `drop` methods.
+//      2. the thread is shared across the whole library. This can be tuned by using `android_cleaner = true`,
+//         or `android = true` in the [`kotlin` section of the `uniffi.toml` file](https://mozilla.github.io/uniffi-rs/kotlin/configuration.html).
+//
+// If we try to implement this with mutual exclusion on access to the handle, there is the
+// possibility of a race between a method call and a concurrent call to `destroy`:
+//
+//    * Thread A starts a method call, reads the value of the handle, but is interrupted
+//      before it can pass the handle over the FFI to Rust.
+//    * Thread B calls `destroy` and frees the underlying Rust struct.
+//    * Thread A resumes, passing the already-read handle value to Rust and triggering
+//      a use-after-free.
+//
+// One possible solution would be to use a `ReadWriteLock`, with each method call taking
+// a read lock (and thus allowed to run concurrently) and the special `destroy` method
+// taking a write lock (and thus blocking on live method calls). However, we aim not to
+// generate methods with any hidden blocking semantics, and a `destroy` method that might
+// block if called incorrectly seems to meet that bar.
+//
+// So, we achieve our goals by giving each instance an associated `AtomicLong` counter to track
+// the number of in-flight method calls, and an `AtomicBoolean` flag to indicate whether `destroy`
+// has been called. These are updated according to the following rules:
+//
+//    * The initial value of the counter is 1, indicating a live object with no in-flight calls.
+//      The initial value for the flag is false.
+//
+//    * At the start of each method call, we atomically check the counter.
+//      If it is 0 then the underlying Rust struct has already been destroyed and the call is aborted.
+//      If it is nonzero them we atomically increment it by 1 and proceed with the method call.
+//
+//    * At the end of each method call, we atomically decrement and check the counter.
+//      If it has reached zero then we destroy the underlying Rust struct.
+//
+//    * When `destroy` is called, we atomically flip the flag from false to true.
+//      If the flag was already true we silently fail.
+//      Otherwise we atomically decrement and check the counter.
+//      If it has reached zero then we destroy the underlying Rust struct.
+//
+// Astute readers may observe that this all sounds very similar to the way that Rust's `Arc<T>` works,
+// and indeed it is, with the addition of a flag to guard against multiple calls to `destroy`.
+//
+// The overall effect is that the underlying Rust struct is destroyed only when `destroy` has been
+// called *and* all in-flight method calls have completed, avoiding violating any of the expectations
+// of the underlying Rust code.
+//
+// This makes a cleaner a better alternative to _not_ calling `destroy()` as
+// and when the object is finished with, but the abstraction is not perfect: if the Rust object's `drop`
+// method is slow, and/or there are many objects to cleanup, and it's on a low end Android device, then the cleaner
+// thread may be starved, and the app will leak memory.
+//
+// In this case, `destroy`ing manually may be a better solution.
+//
+// The cleaner can live side by side with the manual calling of `destroy`. In the order of responsiveness, uniffi objects
+// with Rust peers are reclaimed:
+//
+// 1. By calling the `destroy` method of the object, which calls `rustObject.free()`. If that doesn't happen:
+// 2. When the object becomes unreachable, AND the Cleaner thread gets to call `rustObject.free()`. If the thread is starved then:
+// 3. The memory is reclaimed when the process terminates.
+//
+// [1] https://stackoverflow.com/questions/24376768/can-java-finalize-an-object-when-it-is-still-in-scope/24380219
+//
+
+
+public interface DataRepositoryInterface {
+    
+    fun `queryFilms`(`query`: Query): List<Film>
+    
+    fun `queryPeople`(`query`: Query): List<Person>
+    
+    fun `queryPlanets`(`query`: Query): List<Planet>
+    
+    fun `querySpecies`(`query`: Query): List<Species>
+    
+    fun `queryStarships`(`query`: Query): List<Starship>
+    
+    fun `queryVehicles`(`query`: Query): List<Vehicle>
+    
+    fun `addObserver`(`observer`: RepositoryObserver): kotlin.ULong
+    
+    fun `clear`(`fetchable`: Fetchable)
+    
+    fun `fetchedAt`(`fetchable`: Fetchable, `url`: kotlin.String): kotlin.ULong?
+    
+    fun `getFilm`(`url`: kotlin.String): Film?
+    
+    fun `getFilmById`(`id`: kotlin.UInt): Film?
+    
+    fun `getFilms`(): List<Film>?
+    
+    fun `getPeople`(): List<Person>?
+    
+    fun `getPerson`(`url`: kotlin.String): Person?
+    
+    fun `getPersonById`(`id`: kotlin.UInt): Person?
+    
+    fun `getPlanet`(`url`: kotlin.String): Planet?
+    
+    fun `getPlanetById`(`id`: kotlin.UInt): Planet?
+    
+    fun `getPlanets`(): List<Planet>?
+    
+    fun `getSpecies`(`url`: kotlin.String): Species?
+    
+    fun `getSpeciesById`(`id`: kotlin.UInt): Species?
+    
+    fun `getSpeciesList`(): List<Species>?
+    
+    fun `getStarship`(`url`: kotlin.String): Starship?
+    
+    fun `getStarshipById`(`id`: kotlin.UInt): Starship?
+    
+    fun `getStarships`(): List<Starship>?
+    
+    fun `getVehicle`(`url`: kotlin.String): Vehicle?
+    
+    fun `getVehicleById`(`id`: kotlin.UInt): Vehicle?
+    
+    fun `getVehicles`(): List<Vehicle>?
+    
+    fun `insertFilm`(`film`: Film)
+    
+    fun `insertPerson`(`person`: Person)
+    
+    fun `insertPlanet`(`planet`: Planet)
+    
+    fun `insertSpecies`(`species`: Species)
+    
+    fun `insertStarship`(`starship`: Starship)
+    
+    fun `insertVehicle`(`vehicle`: Vehicle)
+    
+    fun `remove`(`fetchable`: Fetchable, `id`: kotlin.UInt)
+    
+    fun `removeObserver`(`token`: kotlin.ULong)
+    
+    fun `setTtl`(`fetchable`: Fetchable, `ttlSeconds`: kotlin.ULong)
+    
+    fun `ttl`(`fetchable`: Fetchable): kotlin.ULong
+    
+    companion object
+}
+
+open class DataRepository: Disposable, AutoCloseable, DataRepositoryInterface
+{
+
+    @Suppress("UNUSED_PARAMETER")
+    /**
+     * @suppress
+     */
+    constructor(withHandle: UniffiWithHandle, handle: Long) {
+        this.handle = handle
+        this.cleanable = UniffiLib.CLEANER.register(this, UniffiCleanAction(handle))
+    }
+
+    /**
+     * @suppress
+     *
+     * This constructor can be used to instantiate a fake object. Only used for tests. Any
+     * attempt to actually use an object constructed this way will fail as there is no
+     * connected Rust object.
+     */
+    @Suppress("UNUSED_PARAMETER")
+    constructor(noHandle: NoHandle) {
+        this.handle = 0
+        this.cleanable = null
+    }
+    constructor() :
+        this(UniffiWithHandle, 
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_constructor_datarepository_new(
+    
+        _status)
+}
+    )
+
+    protected val handle: Long
+    protected val cleanable: UniffiCleaner.Cleanable?
+
+    private val wasDestroyed = AtomicBoolean(false)
+    private val callCounter = AtomicLong(1)
+
+    override fun destroy() {
+        // Only allow a single call to this method.
+        // TODO: maybe we should log a warning if called more than once?
+        if (this.wasDestroyed.compareAndSet(false, true)) {
+            // This decrement always matches the initial count of 1 given at creation time.
+            if (this.callCounter.decrementAndGet() == 0L) {
+                cleanable?.clean()
+            }
+        }
+    }
+
+    @Synchronized
+    override fun close() {
+        this.destroy()
+    }
+
+    internal inline fun <R> callWithHandle(block: (handle: Long) -> R): R {
+        // Check and increment the call counter, to keep the object alive.
+        // This needs a compare-and-set retry loop in case of concurrent updates.
+        do {
+            val c = this.callCounter.get()
+            if (c == 0L) {
+                throw IllegalStateException("${this.javaClass.simpleName} object has already been destroyed")
+            }
+            if (c == Long.MAX_VALUE) {
+                throw IllegalStateException("${this.javaClass.simpleName} call counter would overflow")
+            }
+        } while (! this.callCounter.compareAndSet(c, c + 1L))
+        // Now we can safely do the method call without the handle being freed concurrently.
+        try {
+            return block(this.uniffiCloneHandle())
+        } finally {
+            // This decrement always matches the increment we performed above.
+            if (this.callCounter.decrementAndGet() == 0L) {
+                cleanable?.clean()
+            }
+        }
+    }
+
+    // Use a static inner class instead of a closure so as not to accidentally
+    // capture `this` as part of the cleanable's action.
+    private class UniffiCleanAction(private val handle: Long) : Runnable {
+        override fun run() {
+            if (handle == 0.toLong()) {
+                // Fake object created with `NoHandle`, don't try to free.
+                return;
+            }
+            uniffiRustCall { status ->
+                UniffiLib.uniffi_starwars_fn_free_datarepository(handle, status)
+            }
+        }
+    }
+
+    /**
+     * @suppress
+     */
+    fun uniffiCloneHandle(): Long {
+        if (handle == 0.toLong()) {
+            throw InternalException("uniffiCloneHandle() called on NoHandle object");
+        }
+        return uniffiRustCall() { status ->
+            UniffiLib.uniffi_starwars_fn_clone_datarepository(handle, status)
+        }
+    }
+
+    
+    @Throws(QueryException::class)override fun `queryFilms`(`query`: Query): List<Film> {
+            return FfiConverterSequenceTypeFilm.lift(
+    callWithHandle {
+    uniffiRustCallWithError(QueryException) { _status ->
+    UniffiLib.uniffi_starwars_fn_method_datarepository_query_films(
+        it,
+        FfiConverterTypeQuery.lower(`query`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+    @Throws(QueryException::class)override fun `queryPeople`(`query`: Query): List<Person> {
+            return FfiConverterSequenceTypePerson.lift(
+    callWithHandle {
+    uniffiRustCallWithError(QueryException) { _status ->
+    UniffiLib.uniffi_starwars_fn_method_datarepository_query_people(
+        it,
+        FfiConverterTypeQuery.lower(`query`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+    @Throws(QueryException::class)override fun `queryPlanets`(`query`: Query): List<Planet> {
+            return FfiConverterSequenceTypePlanet.lift(
+    callWithHandle {
+    uniffiRustCallWithError(QueryException) { _status ->
+    UniffiLib.uniffi_starwars_fn_method_datarepository_query_planets(
+        it,
+        FfiConverterTypeQuery.lower(`query`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+    @Throws(QueryException::class)override fun `querySpecies`(`query`: Query): List<Species> {
+            return FfiConverterSequenceTypeSpecies.lift(
+    callWithHandle {
+    uniffiRustCallWithError(QueryException) { _status ->
+    UniffiLib.uniffi_starwars_fn_method_datarepository_query_species(
+        it,
+        FfiConverterTypeQuery.lower(`query`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+    @Throws(QueryException::class)override fun `queryStarships`(`query`: Query): List<Starship> {
+            return FfiConverterSequenceTypeStarship.lift(
+    callWithHandle {
+    uniffiRustCallWithError(QueryException) { _status ->
+    UniffiLib.uniffi_starwars_fn_method_datarepository_query_starships(
+        it,
+        FfiConverterTypeQuery.lower(`query`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+    @Throws(QueryException::class)override fun `queryVehicles`(`query`: Query): List<Vehicle> {
+            return FfiConverterSequenceTypeVehicle.lift(
+    callWithHandle {
+    uniffiRustCallWithError(QueryException) { _status ->
+    UniffiLib.uniffi_starwars_fn_method_datarepository_query_vehicles(
+        it,
+        FfiConverterTypeQuery.lower(`query`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `addObserver`(`observer`: RepositoryObserver): kotlin.ULong {
+            return FfiConverterULong.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_datarepository_add_observer(
+        it,
+        FfiConverterTypeRepositoryObserver.lower(`observer`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+    @Throws(StoreException::class)override fun `clear`(`fetchable`: Fetchable)
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(StoreException) { _status ->
+    UniffiLib.uniffi_starwars_fn_method_datarepository_clear(
+        it,
+        FfiConverterTypeFetchable.lower(`fetchable`),_status)
+}
+    }
+    
+    
+
+    override fun `fetchedAt`(`fetchable`: Fetchable, `url`: kotlin.String): kotlin.ULong? {
+            return FfiConverterOptionalULong.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_datarepository_fetched_at(
+        it,
+        FfiConverterTypeFetchable.lower(`fetchable`),FfiConverterString.lower(`url`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `getFilm`(`url`: kotlin.String): Film? {
+            return FfiConverterOptionalTypeFilm.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_datarepository_get_film(
+        it,
+        FfiConverterString.lower(`url`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `getFilmById`(`id`: kotlin.UInt): Film? {
+            return FfiConverterOptionalTypeFilm.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_datarepository_get_film_by_id(
+        it,
+        FfiConverterUInt.lower(`id`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `getFilms`(): List<Film>? {
+            return FfiConverterOptionalSequenceTypeFilm.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_datarepository_get_films(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    override fun `getPeople`(): List<Person>? {
+            return FfiConverterOptionalSequenceTypePerson.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_datarepository_get_people(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    override fun `getPerson`(`url`: kotlin.String): Person? {
+            return FfiConverterOptionalTypePerson.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_datarepository_get_person(
+        it,
+        FfiConverterString.lower(`url`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `getPersonById`(`id`: kotlin.UInt): Person? {
+            return FfiConverterOptionalTypePerson.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_datarepository_get_person_by_id(
+        it,
+        FfiConverterUInt.lower(`id`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `getPlanet`(`url`: kotlin.String): Planet? {
+            return FfiConverterOptionalTypePlanet.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_datarepository_get_planet(
+        it,
+        FfiConverterString.lower(`url`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `getPlanetById`(`id`: kotlin.UInt): Planet? {
+            return FfiConverterOptionalTypePlanet.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_datarepository_get_planet_by_id(
+        it,
+        FfiConverterUInt.lower(`id`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `getPlanets`(): List<Planet>? {
+            return FfiConverterOptionalSequenceTypePlanet.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_datarepository_get_planets(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    override fun `getSpecies`(`url`: kotlin.String): Species? {
+            return FfiConverterOptionalTypeSpecies.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_datarepository_get_species(
+        it,
+        FfiConverterString.lower(`url`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `getSpeciesById`(`id`: kotlin.UInt): Species? {
+            return FfiConverterOptionalTypeSpecies.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_datarepository_get_species_by_id(
+        it,
+        FfiConverterUInt.lower(`id`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `getSpeciesList`(): List<Species>? {
+            return FfiConverterOptionalSequenceTypeSpecies.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_datarepository_get_species_list(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    override fun `getStarship`(`url`: kotlin.String): Starship? {
+            return FfiConverterOptionalTypeStarship.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_datarepository_get_starship(
+        it,
+        FfiConverterString.lower(`url`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `getStarshipById`(`id`: kotlin.UInt): Starship? {
+            return FfiConverterOptionalTypeStarship.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_datarepository_get_starship_by_id(
+        it,
+        FfiConverterUInt.lower(`id`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `getStarships`(): List<Starship>? {
+            return FfiConverterOptionalSequenceTypeStarship.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_datarepository_get_starships(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    override fun `getVehicle`(`url`: kotlin.String): Vehicle? {
+            return FfiConverterOptionalTypeVehicle.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_datarepository_get_vehicle(
+        it,
+        FfiConverterString.lower(`url`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `getVehicleById`(`id`: kotlin.UInt): Vehicle? {
+            return FfiConverterOptionalTypeVehicle.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_datarepository_get_vehicle_by_id(
+        it,
+        FfiConverterUInt.lower(`id`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `getVehicles`(): List<Vehicle>? {
+            return FfiConverterOptionalSequenceTypeVehicle.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_datarepository_get_vehicles(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    
+    @Throws(StoreException::class)override fun `insertFilm`(`film`: Film)
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(StoreException) { _status ->
+    UniffiLib.uniffi_starwars_fn_method_datarepository_insert_film(
+        it,
+        FfiConverterTypeFilm.lower(`film`),_status)
+}
+    }
+    
+    
+
+    
+    @Throws(StoreException::class)override fun `insertPerson`(`person`: Person)
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(StoreException) { _status ->
+    UniffiLib.uniffi_starwars_fn_method_datarepository_insert_person(
+        it,
+        FfiConverterTypePerson.lower(`person`),_status)
+}
+    }
+    
+    
+
+    
+    @Throws(StoreException::class)override fun `insertPlanet`(`planet`: Planet)
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(StoreException) { _status ->
+    UniffiLib.uniffi_starwars_fn_method_datarepository_insert_planet(
+        it,
+        FfiConverterTypePlanet.lower(`planet`),_status)
+}
+    }
+    
+    
+
+    
+    @Throws(StoreException::class)override fun `insertSpecies`(`species`: Species)
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(StoreException) { _status ->
+    UniffiLib.uniffi_starwars_fn_method_datarepository_insert_species(
+        it,
+        FfiConverterTypeSpecies.lower(`species`),_status)
+}
+    }
+    
+    
+
+    
+    @Throws(StoreException::class)override fun `insertStarship`(`starship`: Starship)
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(StoreException) { _status ->
+    UniffiLib.uniffi_starwars_fn_method_datarepository_insert_starship(
+        it,
+        FfiConverterTypeStarship.lower(`starship`),_status)
+}
+    }
+    
+    
+
+    
+    @Throws(StoreException::class)override fun `insertVehicle`(`vehicle`: Vehicle)
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(StoreException) { _status ->
+    UniffiLib.uniffi_starwars_fn_method_datarepository_insert_vehicle(
+        it,
+        FfiConverterTypeVehicle.lower(`vehicle`),_status)
+}
+    }
+    
+    
+
+    
+    @Throws(StoreException::class)override fun `remove`(`fetchable`: Fetchable, `id`: kotlin.UInt)
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(StoreException) { _status ->
+    UniffiLib.uniffi_starwars_fn_method_datarepository_remove(
+        it,
+        FfiConverterTypeFetchable.lower(`fetchable`),FfiConverterUInt.lower(`id`),_status)
+}
+    }
+    
+    
+
+    override fun `removeObserver`(`token`: kotlin.ULong)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_datarepository_remove_observer(
+        it,
+        FfiConverterULong.lower(`token`),_status)
+}
+    }
+    
+    
+
+    override fun `setTtl`(`fetchable`: Fetchable, `ttlSeconds`: kotlin.ULong)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_datarepository_set_ttl(
+        it,
+        FfiConverterTypeFetchable.lower(`fetchable`),FfiConverterULong.lower(`ttlSeconds`),_status)
+}
+    }
+    
+    
+
+    override fun `ttl`(`fetchable`: Fetchable): kotlin.ULong {
+            return FfiConverterULong.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_datarepository_ttl(
+        it,
+        FfiConverterTypeFetchable.lower(`fetchable`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+
+    
+
+
+    
+    companion object {
+        
+    @Throws(StoreException::class) fun `withStore`(`store`: RepositoryStore): DataRepository {
+            return FfiConverterTypeDataRepository.lift(
+    uniffiRustCallWithError(StoreException) { _status ->
+    UniffiLib.uniffi_starwars_fn_constructor_datarepository_with_store(
+    
+        FfiConverterTypeRepositoryStore.lower(`store`),_status)
+}
+    )
+    }
+    
+
+        
+    }
+    
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeDataRepository: FfiConverter<DataRepository, Long> {
+    override fun lower(value: DataRepository): Long {
+        return value.uniffiCloneHandle()
+    }
+
+    override fun lift(value: Long): DataRepository {
+        return DataRepository(UniffiWithHandle, value)
+    }
+
+    override fun read(buf: ByteBuffer): DataRepository {
+        return lift(buf.getLong())
+    }
+
+    override fun allocationSize(value: DataRepository) = 8UL
+
+    override fun write(value: DataRepository, buf: ByteBuffer) {
+        buf.putLong(lower(value))
+    }
+}
+
+
+// This template implements a class for working with a Rust struct via a handle
+// to the live Rust struct on the other side of the FFI.
+//
+// There's some subtlety here, because we have to be careful not to operate on a Rust
+// struct after it has been dropped, and because we must expose a public API for freeing
+// theq Kotlin wrapper object in lieu of reliable finalizers. The core requirements are:
+//
+//   * Each instance holds an opaque handle to the underlying Rust struct.
+//     Method calls need to read this handle from the object's state and pass it in to
+//     the Rust FFI.
+//
+//   * When an instance is no longer needed, its handle should be passed to a
+//     special destructor function provided by the Rust FFI, which will drop the
+//     underlying Rust struct.
+//
+//   * Given an instance, calling code is expected to call the special
+//     `destroy` method in order to free it after use, either by calling it explicitly
+//     or by using a higher-level helper like the `use` method. Failing to do so risks
+//     leaking the underlying Rust struct.
+//
+//   * We can't assume that calling code will do the right thing, and must be prepared
+//     to handle Kotlin method calls executing concurrently with or even after a call to
+//     `destroy`, and to handle multiple (possibly concurrent!) calls to `destroy`.
+//
+//   * We must never allow Rust code to operate on the underlying Rust struct after
+//     the destructor has been called, and must never call the destructor more than once.
+//     Doing so may trigger memory unsafety.
+//
+//   * To mitigate many of the risks of leaking memory and use-after-free unsafety, a `Cleaner`
+//     is implemented to call the destructor when the Kotlin object becomes unreachable.
+//     This is done in a background thread. This is not a panacea, and client code should be aware that
+//      1. the thread may starve if some there are objects that have poorly performing
+//     `drop` methods or do significant work in their `drop` methods.
+//      2. the thread is shared across the whole library. This can be tuned by using `android_cleaner = true`,
+//         or `android = true` in the [`kotlin` section of the `uniffi.toml` file](https://mozilla.github.io/uniffi-rs/kotlin/configuration.html).
+//
+// If we try to implement this with mutual exclusion on access to the handle, there is the
+// possibility of a race between a method call and a concurrent call to `destroy`:
+//
+//    * Thread A starts a method call, reads the value of the handle, but is interrupted
+//      before it can pass the handle over the FFI to Rust.
+//    * Thread B calls `destroy` and frees the underlying Rust struct.
+//    * Thread A resumes, passing the already-read handle value to Rust and triggering
+//      a use-after-free.
+//
+// One possible solution would be to use a `ReadWriteLock`, with each method call taking
+// a read lock (and thus allowed to run concurrently) and the special `destroy` method
+// taking a write lock (and thus blocking on live method calls). However, we aim not to
+// generate methods with any hidden blocking semantics, and a `destroy` method that might
+// block if called incorrectly seems to meet that bar.
+//
+// So, we achieve our goals by giving each instance an associated `AtomicLong` counter to track
+// the number of in-flight method calls, and an `AtomicBoolean` flag to indicate whether `destroy`
+// has been called. These are updated according to the following rules:
+//
+//    * The initial value of the counter is 1, indicating a live object with no in-flight calls.
+//      The initial value for the flag is false.
+//
+//    * At the start of each method call, we atomically check the counter.
+//      If it is 0 then the underlying Rust struct has already been destroyed and the call is aborted.
+//      If it is nonzero them we atomically increment it by 1 and proceed with the method call.
+//
+//    * At the end of each method call, we atomically decrement and check the counter.
+//      If it has reached zero then we destroy the underlying Rust struct.
+//
+//    * When `destroy` is called, we atomically flip the flag from false to true.
+//      If the flag was already true we silently fail.
+//      Otherwise we atomically decrement and check the counter.
+//      If it has reached zero then we destroy the underlying Rust struct.
+//
+// Astute readers may observe that this all sounds very similar to the way that Rust's `Arc<T>` works,
+// and indeed it is, with the addition of a flag to guard against multiple calls to `destroy`.
+//
+// The overall effect is that the underlying Rust struct is destroyed only when `destroy` has been
+// called *and* all in-flight method calls have completed, avoiding violating any of the expectations
+// of the underlying Rust code.
+//
+// This makes a cleaner a better alternative to _not_ calling `destroy()` as
+// and when the object is finished with, but the abstraction is not perfect: if the Rust object's `drop`
+// method is slow, and/or there are many objects to cleanup, and it's on a low end Android device, then the cleaner
+// thread may be starved, and the app will leak memory.
+//
+// In this case, `destroy`ing manually may be a better solution.
+//
+// The cleaner can live side by side with the manual calling of `destroy`. In the order of responsiveness, uniffi objects
+// with Rust peers are reclaimed:
+//
+// 1. By calling the `destroy` method of the object, which calls `rustObject.free()`. If that doesn't happen:
+// 2. When the object becomes unreachable, AND the Cleaner thread gets to call `rustObject.free()`. If the thread is starved then:
+// 3. The memory is reclaimed when the process terminates.
+//
+// [1] https://stackoverflow.com/questions/24376768/can-java-finalize-an-object-when-it-is-still-in-scope/24380219
+//
+
+
+public interface FileStoreInterface {
+    
+    fun `keys`(): List<kotlin.String>
+    
+    fun `read`(`key`: kotlin.String): kotlin.ByteArray?
+    
+    fun `remove`(`key`: kotlin.String)
+    
+    fun `write`(`key`: kotlin.String, `contents`: kotlin.ByteArray)
+    
+    companion object
+}
+
+open class FileStore: Disposable, AutoCloseable, FileStoreInterface
+, RepositoryStore
+
+{
+
+    @Suppress("UNUSED_PARAMETER")
+    /**
+     * @suppress
+     */
+    constructor(withHandle: UniffiWithHandle, handle: Long) {
+        this.handle = handle
+        this.cleanable = UniffiLib.CLEANER.register(this, UniffiCleanAction(handle))
+    }
+
+    /**
+     * @suppress
+     *
+     * This constructor can be used to instantiate a fake object. Only used for tests. Any
+     * attempt to actually use an object constructed this way will fail as there is no
+     * connected Rust object.
+     */
+    @Suppress("UNUSED_PARAMETER")
+    constructor(noHandle: NoHandle) {
+        this.handle = 0
+        this.cleanable = null
+    }
+    constructor(`directory`: kotlin.String) :
+        this(UniffiWithHandle, 
+    uniffiRustCallWithError(StoreException) { _status ->
+    UniffiLib.uniffi_starwars_fn_constructor_filestore_new(
+    
+        FfiConverterString.lower(`directory`),_status)
+}
+    )
+
+    protected val handle: Long
+    protected val cleanable: UniffiCleaner.Cleanable?
+
+    private val wasDestroyed = AtomicBoolean(false)
+    private val callCounter = AtomicLong(1)
+
+    override fun destroy() {
+        // Only allow a single call to this method.
+        // TODO: maybe we should log a warning if called more than once?
+        if (this.wasDestroyed.compareAndSet(false, true)) {
+            // This decrement always matches the initial count of 1 given at creation time.
+            if (this.callCounter.decrementAndGet() == 0L) {
+                cleanable?.clean()
+            }
+        }
+    }
+
+    @Synchronized
+    override fun close() {
+        this.destroy()
+    }
+
+    internal inline fun <R> callWithHandle(block: (handle: Long) -> R): R {
+        // Check and increment the call counter, to keep the object alive.
+        // This needs a compare-and-set retry loop in case of concurrent updates.
+        do {
+            val c = this.callCounter.get()
+            if (c == 0L) {
+                throw IllegalStateException("${this.javaClass.simpleName} object has already been destroyed")
+            }
+            if (c == Long.MAX_VALUE) {
+                throw IllegalStateException("${this.javaClass.simpleName} call counter would overflow")
+            }
+        } while (! this.callCounter.compareAndSet(c, c + 1L))
+        // Now we can safely do the method call without the handle being freed concurrently.
+        try {
+            return block(this.uniffiCloneHandle())
+        } finally {
+            // This decrement always matches the increment we performed above.
+            if (this.callCounter.decrementAndGet() == 0L) {
+                cleanable?.clean()
+            }
+        }
+    }
+
+    // Use a static inner class instead of a closure so as not to accidentally
+    // capture `this` as part of the cleanable's action.
+    private class UniffiCleanAction(private val handle: Long) : Runnable {
+        override fun run() {
+            if (handle == 0.toLong()) {
+                // Fake object created with `NoHandle`, don't try to free.
+                return;
+            }
+            uniffiRustCall { status ->
+                UniffiLib.uniffi_starwars_fn_free_filestore(handle, status)
+            }
+        }
+    }
+
+    /**
+     * @suppress
+     */
+    fun uniffiCloneHandle(): Long {
+        if (handle == 0.toLong()) {
+            throw InternalException("uniffiCloneHandle() called on NoHandle object");
+        }
+        return uniffiRustCall() { status ->
+            UniffiLib.uniffi_starwars_fn_clone_filestore(handle, status)
+        }
+    }
+
+    
+    @Throws(StoreException::class)override fun `keys`(): List<kotlin.String> {
+            return FfiConverterSequenceString.lift(
+    callWithHandle {
+    uniffiRustCallWithError(StoreException) { _status ->
+    UniffiLib.uniffi_starwars_fn_method_filestore_keys(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    
+    @Throws(StoreException::class)override fun `read`(`key`: kotlin.String): kotlin.ByteArray? {
+            return FfiConverterOptionalByteArray.lift(
+    callWithHandle {
+    uniffiRustCallWithError(StoreException) { _status ->
+    UniffiLib.uniffi_starwars_fn_method_filestore_read(
+        it,
+        FfiConverterString.lower(`key`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+    @Throws(StoreException::class)override fun `remove`(`key`: kotlin.String)
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(StoreException) { _status ->
+    UniffiLib.uniffi_starwars_fn_method_filestore_remove(
+        it,
+        FfiConverterString.lower(`key`),_status)
+}
+    }
+    
+    
+
+    
+    @Throws(StoreException::class)override fun `write`(`key`: kotlin.String, `contents`: kotlin.ByteArray)
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(StoreException) { _status ->
+    UniffiLib.uniffi_starwars_fn_method_filestore_write(
+        it,
+        FfiConverterString.lower(`key`),FfiConverterByteArray.lower(`contents`),_status)
+}
+    }
+    
+    
+
+    
+
+    
+
+
+    
+    
+    /**
+     * @suppress
+     */
+    companion object
+    
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeFileStore: FfiConverter<FileStore, Long> {
+    override fun lower(value: FileStore): Long {
+        return value.uniffiCloneHandle()
+    }
+
+    override fun lift(value: Long): FileStore {
+        return FileStore(UniffiWithHandle, value)
+    }
+
+    override fun read(buf: ByteBuffer): FileStore {
+        return lift(buf.getLong())
+    }
+
+    override fun allocationSize(value: FileStore) = 8UL
+
+    override fun write(value: FileStore, buf: ByteBuffer) {
+        buf.putLong(lower(value))
+    }
+}
+
+
+// This template implements a class for working with a Rust struct via a handle
+// to the live Rust struct on the other side of the FFI.
+//
+// There's some subtlety here, because we have to be careful not to operate on a Rust
+// struct after it has been dropped, and because we must expose a public API for freeing
+// theq Kotlin wrapper object in lieu of reliable finalizers. The core requirements are:
+//
+//   * Each instance holds an opaque handle to the underlying Rust struct.
+//     Method calls need to read this handle from the object's state and pass it in to
+//     the Rust FFI.
+//
+//   * When an instance is no longer needed, its handle should be passed to a
+//     special destructor function provided by the Rust FFI, which will drop the
+//     underlying Rust struct.
+//
+//   * Given an instance, calling code is expected to call the special
+//     `destroy` method in order to free it after use, either by calling it explicitly
+//     or by using a higher-level helper like the `use` method. Failing to do so risks
+//     leaking the underlying Rust struct.
+//
+//   * We can't assume that calling code will do the right thing, and must be prepared
+//     to handle Kotlin method calls executing concurrently with or even after a call to
+//     `destroy`, and to handle multiple (possibly concurrent!) calls to `destroy`.
+//
+//   * We must never allow Rust code to operate on the underlying Rust struct after
+//     the destructor has been called, and must never call the destructor more than once.
+//     Doing so may trigger memory unsafety.
+//
+//   * To mitigate many of the risks of leaking memory and use-after-free unsafety, a `Cleaner`
+//     is implemented to call the destructor when the Kotlin object becomes unreachable.
+//     This is done in a background thread. This is not a panacea, and client code should be aware that
+//      1. the thread may starve if some there are objects that have poorly performing
+//     `drop` methods or do significant work in their `drop` methods.
+//      2. the thread is shared across the whole library. This can be tuned by using `android_cleaner = true`,
+//         or `android = true` in the [`kotlin` section of the `uniffi.toml` file](https://mozilla.github.io/uniffi-rs/kotlin/configuration.html).
+//
+// If we try to implement this with mutual exclusion on access to the handle, there is the
+// possibility of a race between a method call and a concurrent call to `destroy`:
+//
+//    * Thread A starts a method call, reads the value of the handle, but is interrupted
+//      before it can pass the handle over the FFI to Rust.
+//    * Thread B calls `destroy` and frees the underlying Rust struct.
+//    * Thread A resumes, passing the already-read handle value to Rust and triggering
+//      a use-after-free.
+//
+// One possible solution would be to use a `ReadWriteLock`, with each method call taking
+// a read lock (and thus allowed to run concurrently) and the special `destroy` method
+// taking a write lock (and thus blocking on live method calls). However, we aim not to
+// generate methods with any hidden blocking semantics, and a `destroy` method that might
+// block if called incorrectly seems to meet that bar.
+//
+// So, we achieve our goals by giving each instance an associated `AtomicLong` counter to track
+// the number of in-flight method calls, and an `AtomicBoolean` flag to indicate whether `destroy`
+// has been called. These are updated according to the following rules:
+//
+//    * The initial value of the counter is 1, indicating a live object with no in-flight calls.
+//      The initial value for the flag is false.
+//
+//    * At the start of each method call, we atomically check the counter.
+//      If it is 0 then the underlying Rust struct has already been destroyed and the call is aborted.
+//      If it is nonzero them we atomically increment it by 1 and proceed with the method call.
+//
+//    * At the end of each method call, we atomically decrement and check the counter.
+//      If it has reached zero then we destroy the underlying Rust struct.
+//
+//    * When `destroy` is called, we atomically flip the flag from false to true.
+//      If the flag was already true we silently fail.
+//      Otherwise we atomically decrement and check the counter.
+//      If it has reached zero then we destroy the underlying Rust struct.
+//
+// Astute readers may observe that this all sounds very similar to the way that Rust's `Arc<T>` works,
+// and indeed it is, with the addition of a flag to guard against multiple calls to `destroy`.
+//
+// The overall effect is that the underlying Rust struct is destroyed only when `destroy` has been
+// called *and* all in-flight method calls have completed, avoiding violating any of the expectations
+// of the underlying Rust code.
+//
+// This makes a cleaner a better alternative to _not_ calling `destroy()` as
+// and when the object is finished with, but the abstraction is not perfect: if the Rust object's `drop`
+// method is slow, and/or there are many objects to cleanup, and it's on a low end Android device, then the cleaner
+// thread may be starved, and the app will leak memory.
+//
+// In this case, `destroy`ing manually may be a better solution.
+//
+// The cleaner can live side by side with the manual calling of `destroy`. In the order of responsiveness, uniffi objects
+// with Rust peers are reclaimed:
+//
+// 1. By calling the `destroy` method of the object, which calls `rustObject.free()`. If that doesn't happen:
+// 2. When the object becomes unreachable, AND the Cleaner thread gets to call `rustObject.free()`. If the thread is starved then:
+// 3. The memory is reclaimed when the process terminates.
+//
+// [1] https://stackoverflow.com/questions/24376768/can-java-finalize-an-object-when-it-is-still-in-scope/24380219
+//
+
+
+public interface HttpClient {
+    
+    suspend fun `fetch`(`request`: HttpRequest): HttpResponse
+    
+    companion object
+}
+
+open class HttpClientImpl: Disposable, AutoCloseable, HttpClient
+{
+
+    @Suppress("UNUSED_PARAMETER")
+    /**
+     * @suppress
+     */
+    constructor(withHandle: UniffiWithHandle, handle: Long) {
+        this.handle = handle
+        this.cleanable = UniffiLib.CLEANER.register(this, UniffiCleanAction(handle))
+    }
+
+    /**
+     * @suppress
+     *
+     * This constructor can be used to instantiate a fake object. Only used for tests. Any
+     * attempt to actually use an object constructed this way will fail as there is no
+     * connected Rust object.
+     */
+    @Suppress("UNUSED_PARAMETER")
+    constructor(noHandle: NoHandle) {
+        this.handle = 0
+        this.cleanable = null
+    }
+
+    protected val handle: Long
+    protected val cleanable: UniffiCleaner.Cleanable?
+
+    private val wasDestroyed = AtomicBoolean(false)
+    private val callCounter = AtomicLong(1)
+
+    override fun destroy() {
+        // Only allow a single call to this method.
+        // TODO: maybe we should log a warning if called more than once?
+        if (this.wasDestroyed.compareAndSet(false, true)) {
+            // This decrement always matches the initial count of 1 given at creation time.
+            if (this.callCounter.decrementAndGet() == 0L) {
+                cleanable?.clean()
+            }
+        }
+    }
+
+    @Synchronized
+    override fun close() {
+        this.destroy()
+    }
+
+    internal inline fun <R> callWithHandle(block: (handle: Long) -> R): R {
+        // Check and increment the call counter, to keep the object alive.
+        // This needs a compare-and-set retry loop in case of concurrent updates.
+        do {
+            val c = this.callCounter.get()
+            if (c == 0L) {
+                throw IllegalStateException("${this.javaClass.simpleName} object has already been destroyed")
+            }
+            if (c == Long.MAX_VALUE) {
+                throw IllegalStateException("${this.javaClass.simpleName} call counter would overflow")
+            }
+        } while (! this.callCounter.compareAndSet(c, c + 1L))
+        // Now we can safely do the method call without the handle being freed concurrently.
+        try {
+            return block(this.uniffiCloneHandle())
+        } finally {
+            // This decrement always matches the increment we performed above.
+            if (this.callCounter.decrementAndGet() == 0L) {
+                cleanable?.clean()
+            }
+        }
+    }
+
+    // Use a static inner class instead of a closure so as not to accidentally
+    // capture `this` as part of the cleanable's action.
+    private class UniffiCleanAction(private val handle: Long) : Runnable {
+        override fun run() {
+            if (handle == 0.toLong()) {
+                // Fake object created with `NoHandle`, don't try to free.
+                return;
+            }
+            uniffiRustCall { status ->
+                UniffiLib.uniffi_starwars_fn_free_httpclient(handle, status)
+            }
+        }
+    }
+
+    /**
+     * @suppress
+     */
+    fun uniffiCloneHandle(): Long {
+        if (handle == 0.toLong()) {
+            throw InternalException("uniffiCloneHandle() called on NoHandle object");
+        }
+        return uniffiRustCall() { status ->
+            UniffiLib.uniffi_starwars_fn_clone_httpclient(handle, status)
+        }
+    }
+
+    
+    @Throws(NetworkException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `fetch`(`request`: HttpRequest) : HttpResponse {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_starwars_fn_method_httpclient_fetch(
+                uniffiHandle,
+                FfiConverterTypeHttpRequest.lower(`request`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_starwars_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_starwars_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.ffi_starwars_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterTypeHttpResponse.lift(it) },
+        // Error FFI converter
+        NetworkException.ErrorHandler,
+    )
+    }
+
+    
+
+    
+
+
+    
+    
+    /**
+     * @suppress
+     */
+    companion object
+    
+}
+
+
+
+// Put the implementation in an object so we don't pollute the top-level namespace
+internal object uniffiCallbackInterfaceHttpClient {
+    internal object `fetch`: UniffiCallbackInterfaceHttpClientMethod0 {
+        override fun callback(`uniffiHandle`: Long,`request`: RustBuffer.ByValue,`uniffiFutureCallback`: UniffiForeignFutureCompleteRustBuffer,`uniffiCallbackData`: Long,`uniffiOutDroppedCallback`: UniffiForeignFutureDroppedCallbackStruct,) {
+            val uniffiObj = FfiConverterTypeHttpClient.handleMap.get(uniffiHandle)
+            val makeCall = suspend { ->
+                uniffiObj.`fetch`(
+                    FfiConverterTypeHttpRequest.lift(`request`),
+                )
+            }
+            val uniffiHandleSuccess = { returnValue: HttpResponse ->
+                val uniffiResult = UniffiForeignFutureResultRustBuffer.UniffiByValue(
+                    FfiConverterTypeHttpResponse.lower(returnValue),
+                    UniffiRustCallStatus.ByValue()
+                )
+                uniffiResult.write()
+                uniffiFutureCallback.callback(uniffiCallbackData, uniffiResult)
+            }
+            val uniffiHandleError = { callStatus: UniffiRustCallStatus.ByValue ->
+                uniffiFutureCallback.callback(
+                    uniffiCallbackData,
+                    UniffiForeignFutureResultRustBuffer.UniffiByValue(
+                        RustBuffer.ByValue(),
+                        callStatus,
+                    ),
+                )
+            }
+            uniffiTraitInterfaceCallAsyncWithError(
+                makeCall,
+                uniffiHandleSuccess,
+                uniffiHandleError,
+                { e: NetworkException -> FfiConverterTypeNetworkError.lower(e) },
+                uniffiOutDroppedCallback
+            )
+        }
+    }
+
+    internal object uniffiFree: UniffiCallbackInterfaceFree {
+        override fun callback(handle: Long) {
+            FfiConverterTypeHttpClient.handleMap.remove(handle)
+        }
+    }
+
+    internal object uniffiClone: UniffiCallbackInterfaceClone {
+        override fun callback(handle: Long): Long {
+            return FfiConverterTypeHttpClient.handleMap.clone(handle)
+        }
+    }
+
+    internal var vtable = UniffiVTableCallbackInterfaceHttpClient.UniffiByValue(
+        uniffiFree,
+        uniffiClone,
+        `fetch`,
+    )
+
+    // Registers the foreign callback with the Rust side.
+    // This method is generated for each callback interface.
+    internal fun register(lib: UniffiLib) {
+        lib.uniffi_starwars_fn_init_callback_vtable_httpclient(vtable)
+    }
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeHttpClient: FfiConverter<HttpClient, Long> {
+    internal val handleMap = UniffiHandleMap<HttpClient>()
+
+    override fun lower(value: HttpClient): Long {
+        if (value is HttpClientImpl) {
+             // Rust-implemented object.  Clone the handle and return it
+            return value.uniffiCloneHandle()
+         } else {
+            // Kotlin object, generate a new vtable handle and return that.
+            return handleMap.insert(value)
+         }
+    }
+
+    override fun lift(value: Long): HttpClient {
+        if ((value and 1.toLong()) == 0.toLong()) {
+            // Rust-generated handle, construct a new class that uses the handle to implement the
+            // interface
+            return HttpClientImpl(UniffiWithHandle, value)
+        } else {
+            // Kotlin-generated handle, get the object from the handle map
+            return handleMap.remove(value)
+        }
+    }
+
+    override fun read(buf: ByteBuffer): HttpClient {
+        return lift(buf.getLong())
+    }
+
+    override fun allocationSize(value: HttpClient) = 8UL
+
+    override fun write(value: HttpClient, buf: ByteBuffer) {
+        buf.putLong(lower(value))
+    }
+}
+
+
+// This template implements a class for working with a Rust struct via a handle
+// to the live Rust struct on the other side of the FFI.
+//
+// There's some subtlety here, because we have to be careful not to operate on a Rust
+// struct after it has been dropped, and because we must expose a public API for freeing
+// theq Kotlin wrapper object in lieu of reliable finalizers. The core requirements are:
+//
+//   * Each instance holds an opaque handle to the underlying Rust struct.
+//     Method calls need to read this handle from the object's state and pass it in to
+//     the Rust FFI.
+//
+//   * When an instance is no longer needed, its handle should be passed to a
+//     special destructor function provided by the Rust FFI, which will drop the
+//     underlying Rust struct.
+//
+//   * Given an instance, calling code is expected to call the special
+//     `destroy` method in order to free it after use, either by calling it explicitly
+//     or by using a higher-level helper like the `use` method. Failing to do so risks
+//     leaking the underlying Rust struct.
+//
+//   * We can't assume that calling code will do the right thing, and must be prepared
+//     to handle Kotlin method calls executing concurrently with or even after a call to
+//     `destroy`, and to handle multiple (possibly concurrent!) calls to `destroy`.
+//
+//   * We must never allow Rust code to operate on the underlying Rust struct after
+//     the destructor has been called, and must never call the destructor more than once.
+//     Doing so may trigger memory unsafety.
+//
+//   * To mitigate many of the risks of leaking memory and use-after-free unsafety, a `Cleaner`
+//     is implemented to call the destructor when the Kotlin object becomes unreachable.
+//     This is done in a background thread. This is not a panacea, and client code should be aware that
+//      1. the thread may starve if some there are objects that have poorly performing
+//     `drop` methods or do significant work in their `drop` methods.
+//      2. the thread is shared across the whole library. This can be tuned by using `android_cleaner = true`,
+//         or `android = true` in the [`kotlin` section of the `uniffi.toml` file](https://mozilla.github.io/uniffi-rs/kotlin/configuration.html).
+//
+// If we try to implement this with mutual exclusion on access to the handle, there is the
+// possibility of a race between a method call and a concurrent call to `destroy`:
+//
+//    * Thread A starts a method call, reads the value of the handle, but is interrupted
+//      before it can pass the handle over the FFI to Rust.
+//    * Thread B calls `destroy` and frees the underlying Rust struct.
+//    * Thread A resumes, passing the already-read handle value to Rust and triggering
+//      a use-after-free.
+//
+// One possible solution would be to use a `ReadWriteLock`, with each method call taking
+// a read lock (and thus allowed to run concurrently) and the special `destroy` method
+// taking a write lock (and thus blocking on live method calls). However, we aim not to
+// generate methods with any hidden blocking semantics, and a `destroy` method that might
+// block if called incorrectly seems to meet that bar.
+//
+// So, we achieve our goals by giving each instance an associated `AtomicLong` counter to track
+// the number of in-flight method calls, and an `AtomicBoolean` flag to indicate whether `destroy`
+// has been called. These are updated according to the following rules:
+//
+//    * The initial value of the counter is 1, indicating a live object with no in-flight calls.
+//      The initial value for the flag is false.
+//
+//    * At the start of each method call, we atomically check the counter.
+//      If it is 0 then the underlying Rust struct has already been destroyed and the call is aborted.
+//      If it is nonzero them we atomically increment it by 1 and proceed with the method call.
+//
+//    * At the end of each method call, we atomically decrement and check the counter.
+//      If it has reached zero then we destroy the underlying Rust struct.
+//
+//    * When `destroy` is called, we atomically flip the flag from false to true.
+//      If the flag was already true we silently fail.
+//      Otherwise we atomically decrement and check the counter.
+//      If it has reached zero then we destroy the underlying Rust struct.
+//
+// Astute readers may observe that this all sounds very similar to the way that Rust's `Arc<T>` works,
+// and indeed it is, with the addition of a flag to guard against multiple calls to `destroy`.
+//
+// The overall effect is that the underlying Rust struct is destroyed only when `destroy` has been
+// called *and* all in-flight method calls have completed, avoiding violating any of the expectations
+// of the underlying Rust code.
+//
+// This makes a cleaner a better alternative to _not_ calling `destroy()` as
+// and when the object is finished with, but the abstraction is not perfect: if the Rust object's `drop`
+// method is slow, and/or there are many objects to cleanup, and it's on a low end Android device, then the cleaner
+// thread may be starved, and the app will leak memory.
+//
+// In this case, `destroy`ing manually may be a better solution.
+//
+// The cleaner can live side by side with the manual calling of `destroy`. In the order of responsiveness, uniffi objects
+// with Rust peers are reclaimed:
+//
+// 1. By calling the `destroy` method of the object, which calls `rustObject.free()`. If that doesn't happen:
+// 2. When the object becomes unreachable, AND the Cleaner thread gets to call `rustObject.free()`. If the thread is starved then:
+// 3. The memory is reclaimed when the process terminates.
+//
+// [1] https://stackoverflow.com/questions/24376768/can-java-finalize-an-object-when-it-is-still-in-scope/24380219
+//
+
+
+public interface InMemoryStoreInterface {
+    
+    fun `keys`(): List<kotlin.String>
+    
+    fun `read`(`key`: kotlin.String): kotlin.ByteArray?
+    
+    fun `remove`(`key`: kotlin.String)
+    
+    fun `write`(`key`: kotlin.String, `contents`: kotlin.ByteArray)
+    
+    companion object
+}
+
+open class InMemoryStore: Disposable, AutoCloseable, InMemoryStoreInterface
+, RepositoryStore
+
+{
+
+    @Suppress("UNUSED_PARAMETER")
+    /**
+     * @suppress
+     */
+    constructor(withHandle: UniffiWithHandle, handle: Long) {
+        this.handle = handle
+        this.cleanable = UniffiLib.CLEANER.register(this, UniffiCleanAction(handle))
+    }
+
+    /**
+     * @suppress
+     *
+     * This constructor can be used to instantiate a fake object. Only used for tests. Any
+     * attempt to actually use an object constructed this way will fail as there is no
+     * connected Rust object.
+     */
+    @Suppress("UNUSED_PARAMETER")
+    constructor(noHandle: NoHandle) {
+        this.handle = 0
+        this.cleanable = null
+    }
+    constructor() :
+        this(UniffiWithHandle, 
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_constructor_inmemorystore_new(
+    
+        _status)
+}
+    )
+
+    protected val handle: Long
+    protected val cleanable: UniffiCleaner.Cleanable?
+
+    private val wasDestroyed = AtomicBoolean(false)
+    private val callCounter = AtomicLong(1)
+
+    override fun destroy() {
+        // Only allow a single call to this method.
+        // TODO: maybe we should log a warning if called more than once?
+        if (this.wasDestroyed.compareAndSet(false, true)) {
+            // This decrement always matches the initial count of 1 given at creation time.
+            if (this.callCounter.decrementAndGet() == 0L) {
+                cleanable?.clean()
+            }
+        }
+    }
+
+    @Synchronized
+    override fun close() {
+        this.destroy()
+    }
+
+    internal inline fun <R> callWithHandle(block: (handle: Long) -> R): R {
+        // Check and increment the call counter, to keep the object alive.
+        // This needs a compare-and-set retry loop in case of concurrent updates.
+        do {
+            val c = this.callCounter.get()
+            if (c == 0L) {
+                throw IllegalStateException("${this.javaClass.simpleName} object has already been destroyed")
+            }
+            if (c == Long.MAX_VALUE) {
+                throw IllegalStateException("${this.javaClass.simpleName} call counter would overflow")
+            }
+        } while (! this.callCounter.compareAndSet(c, c + 1L))
+        // Now we can safely do the method call without the handle being freed concurrently.
+        try {
+            return block(this.uniffiCloneHandle())
+        } finally {
+            // This decrement always matches the increment we performed above.
+            if (this.callCounter.decrementAndGet() == 0L) {
+                cleanable?.clean()
+            }
+        }
+    }
+
+    // Use a static inner class instead of a closure so as not to accidentally
+    // capture `this` as part of the cleanable's action.
+    private class UniffiCleanAction(private val handle: Long) : Runnable {
+        override fun run() {
+            if (handle == 0.toLong()) {
+                // Fake object created with `NoHandle`, don't try to free.
+                return;
+            }
+            uniffiRustCall { status ->
+                UniffiLib.uniffi_starwars_fn_free_inmemorystore(handle, status)
+            }
+        }
+    }
+
+    /**
+     * @suppress
+     */
+    fun uniffiCloneHandle(): Long {
+        if (handle == 0.toLong()) {
+            throw InternalException("uniffiCloneHandle() called on NoHandle object");
+        }
+        return uniffiRustCall() { status ->
+            UniffiLib.uniffi_starwars_fn_clone_inmemorystore(handle, status)
+        }
+    }
+
+    
+    @Throws(StoreException::class)override fun `keys`(): List<kotlin.String> {
+            return FfiConverterSequenceString.lift(
+    callWithHandle {
+    uniffiRustCallWithError(StoreException) { _status ->
+    UniffiLib.uniffi_starwars_fn_method_inmemorystore_keys(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    
+    @Throws(StoreException::class)override fun `read`(`key`: kotlin.String): kotlin.ByteArray? {
+            return FfiConverterOptionalByteArray.lift(
+    callWithHandle {
+    uniffiRustCallWithError(StoreException) { _status ->
+    UniffiLib.uniffi_starwars_fn_method_inmemorystore_read(
+        it,
+        FfiConverterString.lower(`key`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+    @Throws(StoreException::class)override fun `remove`(`key`: kotlin.String)
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(StoreException) { _status ->
+    UniffiLib.uniffi_starwars_fn_method_inmemorystore_remove(
+        it,
+        FfiConverterString.lower(`key`),_status)
+}
+    }
+    
+    
+
+    
+    @Throws(StoreException::class)override fun `write`(`key`: kotlin.String, `contents`: kotlin.ByteArray)
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(StoreException) { _status ->
+    UniffiLib.uniffi_starwars_fn_method_inmemorystore_write(
+        it,
+        FfiConverterString.lower(`key`),FfiConverterByteArray.lower(`contents`),_status)
+}
+    }
+    
+    
+
+    
+
+    
+
+
+    
+    
+    /**
+     * @suppress
+     */
+    companion object
+    
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeInMemoryStore: FfiConverter<InMemoryStore, Long> {
+    override fun lower(value: InMemoryStore): Long {
+        return value.uniffiCloneHandle()
+    }
+
+    override fun lift(value: Long): InMemoryStore {
+        return InMemoryStore(UniffiWithHandle, value)
+    }
+
+    override fun read(buf: ByteBuffer): InMemoryStore {
+        return lift(buf.getLong())
+    }
+
+    override fun allocationSize(value: InMemoryStore) = 8UL
+
+    override fun write(value: InMemoryStore, buf: ByteBuffer) {
+        buf.putLong(lower(value))
+    }
+}
+
+
+// This template implements a class for working with a Rust struct via a handle
+// to the live Rust struct on the other side of the FFI.
+//
+// There's some subtlety here, because we have to be careful not to operate on a Rust
+// struct after it has been dropped, and because we must expose a public API for freeing
+// theq Kotlin wrapper object in lieu of reliable finalizers. The core requirements are:
+//
+//   * Each instance holds an opaque handle to the underlying Rust struct.
+//     Method calls need to read this handle from the object's state and pass it in to
+//     the Rust FFI.
+//
+//   * When an instance is no longer needed, its handle should be passed to a
+//     special destructor function provided by the Rust FFI, which will drop the
+//     underlying Rust struct.
+//
+//   * Given an instance, calling code is expected to call the special
+//     `destroy` method in order to free it after use, either by calling it explicitly
+//     or by using a higher-level helper like the `use` method. Failing to do so risks
+//     leaking the underlying Rust struct.
+//
+//   * We can't assume that calling code will do the right thing, and must be prepared
+//     to handle Kotlin method calls executing concurrently with or even after a call to
+//     `destroy`, and to handle multiple (possibly concurrent!) calls to `destroy`.
+//
+//   * We must never allow Rust code to operate on the underlying Rust struct after
+//     the destructor has been called, and must never call the destructor more than once.
+//     Doing so may trigger memory unsafety.
+//
+//   * To mitigate many of the risks of leaking memory and use-after-free unsafety, a `Cleaner`
+//     is implemented to call the destructor when the Kotlin object becomes unreachable.
+//     This is done in a background thread. This is not a panacea, and client code should be aware that
+//      1. the thread may starve if some there are objects that have poorly performing
+//     `drop` methods or do significant work in their `drop` methods.
+//      2. the thread is shared across the whole library. This can be tuned by using `android_cleaner = true`,
+//         or `android = true` in the [`kotlin` section of the `uniffi.toml` file](https://mozilla.github.io/uniffi-rs/kotlin/configuration.html).
+//
+// If we try to implement this with mutual exclusion on access to the handle, there is the
+// possibility of a race between a method call and a concurrent call to `destroy`:
+//
+//    * Thread A starts a method call, reads the value of the handle, but is interrupted
+//      before it can pass the handle over the FFI to Rust.
+//    * Thread B calls `destroy` and frees the underlying Rust struct.
+//    * Thread A resumes, passing the already-read handle value to Rust and triggering
+//      a use-after-free.
+//
+// One possible solution would be to use a `ReadWriteLock`, with each method call taking
+// a read lock (and thus allowed to run concurrently) and the special `destroy` method
+// taking a write lock (and thus blocking on live method calls). However, we aim not to
+// generate methods with any hidden blocking semantics, and a `destroy` method that might
+// block if called incorrectly seems to meet that bar.
+//
+// So, we achieve our goals by giving each instance an associated `AtomicLong` counter to track
+// the number of in-flight method calls, and an `AtomicBoolean` flag to indicate whether `destroy`
+// has been called. These are updated according to the following rules:
+//
+//    * The initial value of the counter is 1, indicating a live object with no in-flight calls.
+//      The initial value for the flag is false.
+//
+//    * At the start of each method call, we atomically check the counter.
+//      If it is 0 then the underlying Rust struct has already been destroyed and the call is aborted.
+//      If it is nonzero them we atomically increment it by 1 and proceed with the method call.
+//
+//    * At the end of each method call, we atomically decrement and check the counter.
+//      If it has reached zero then we destroy the underlying Rust struct.
+//
+//    * When `destroy` is called, we atomically flip the flag from false to true.
+//      If the flag was already true we silently fail.
+//      Otherwise we atomically decrement and check the counter.
+//      If it has reached zero then we destroy the underlying Rust struct.
+//
+// Astute readers may observe that this all sounds very similar to the way that Rust's `Arc<T>` works,
+// and indeed it is, with the addition of a flag to guard against multiple calls to `destroy`.
+//
+// The overall effect is that the underlying Rust struct is destroyed only when `destroy` has been
+// called *and* all in-flight method calls have completed, avoiding violating any of the expectations
+// of the underlying Rust code.
+//
+// This makes a cleaner a better alternative to _not_ calling `destroy()` as
+// and when the object is finished with, but the abstraction is not perfect: if the Rust object's `drop`
+// method is slow, and/or there are many objects to cleanup, and it's on a low end Android device, then the cleaner
+// thread may be starved, and the app will leak memory.
+//
+// In this case, `destroy`ing manually may be a better solution.
+//
+// The cleaner can live side by side with the manual calling of `destroy`. In the order of responsiveness, uniffi objects
+// with Rust peers are reclaimed:
+//
+// 1. By calling the `destroy` method of the object, which calls `rustObject.free()`. If that doesn't happen:
+// 2. When the object becomes unreachable, AND the Cleaner thread gets to call `rustObject.free()`. If the thread is starved then:
+// 3. The memory is reclaimed when the process terminates.
+//
+// [1] https://stackoverflow.com/questions/24376768/can-java-finalize-an-object-when-it-is-still-in-scope/24380219
+//
+
+
+public interface PageCursorInterface {
+    
+    fun `fetchable`(): Fetchable
+    
+    fun `hasMore`(): kotlin.Boolean
+    
+    fun `isLoading`(): kotlin.Boolean
+    
+    fun `items`(): List<Selected>
+    
+    suspend fun `loadNext`(`cancellation`: CancellationToken?): List<Selected>
+    
+    fun `nextUrl`(): kotlin.String?
+    
+    fun `previousUrl`(): kotlin.String?
+    
+    fun `totalCount`(): kotlin.UInt?
+    
+    companion object
+}
+
+open class PageCursor: Disposable, AutoCloseable, PageCursorInterface
+{
+
+    @Suppress("UNUSED_PARAMETER")
+    /**
+     * @suppress
+     */
+    constructor(withHandle: UniffiWithHandle, handle: Long) {
+        this.handle = handle
+        this.cleanable = UniffiLib.CLEANER.register(this, UniffiCleanAction(handle))
+    }
+
+    /**
+     * @suppress
+     *
+     * This constructor can be used to instantiate a fake object. Only used for tests. Any
+     * attempt to actually use an object constructed this way will fail as there is no
+     * connected Rust object.
+     */
+    @Suppress("UNUSED_PARAMETER")
+    constructor(noHandle: NoHandle) {
+        this.handle = 0
+        this.cleanable = null
+    }
+
+    protected val handle: Long
+    protected val cleanable: UniffiCleaner.Cleanable?
+
+    private val wasDestroyed = AtomicBoolean(false)
+    private val callCounter = AtomicLong(1)
+
+    override fun destroy() {
+        // Only allow a single call to this method.
+        // TODO: maybe we should log a warning if called more than once?
+        if (this.wasDestroyed.compareAndSet(false, true)) {
+            // This decrement always matches the initial count of 1 given at creation time.
+            if (this.callCounter.decrementAndGet() == 0L) {
+                cleanable?.clean()
+            }
+        }
+    }
+
+    @Synchronized
+    override fun close() {
+        this.destroy()
+    }
+
+    internal inline fun <R> callWithHandle(block: (handle: Long) -> R): R {
+        // Check and increment the call counter, to keep the object alive.
+        // This needs a compare-and-set retry loop in case of concurrent updates.
+        do {
+            val c = this.callCounter.get()
+            if (c == 0L) {
+                throw IllegalStateException("${this.javaClass.simpleName} object has already been destroyed")
+            }
+            if (c == Long.MAX_VALUE) {
+                throw IllegalStateException("${this.javaClass.simpleName} call counter would overflow")
+            }
+        } while (! this.callCounter.compareAndSet(c, c + 1L))
+        // Now we can safely do the method call without the handle being freed concurrently.
+        try {
+            return block(this.uniffiCloneHandle())
+        } finally {
+            // This decrement always matches the increment we performed above.
+            if (this.callCounter.decrementAndGet() == 0L) {
+                cleanable?.clean()
+            }
+        }
+    }
+
+    // Use a static inner class instead of a closure so as not to accidentally
+    // capture `this` as part of the cleanable's action.
+    private class UniffiCleanAction(private val handle: Long) : Runnable {
+        override fun run() {
+            if (handle == 0.toLong()) {
+                // Fake object created with `NoHandle`, don't try to free.
+                return;
+            }
+            uniffiRustCall { status ->
+                UniffiLib.uniffi_starwars_fn_free_pagecursor(handle, status)
+            }
+        }
+    }
+
+    /**
+     * @suppress
+     */
+    fun uniffiCloneHandle(): Long {
+        if (handle == 0.toLong()) {
+            throw InternalException("uniffiCloneHandle() called on NoHandle object");
+        }
+        return uniffiRustCall() { status ->
+            UniffiLib.uniffi_starwars_fn_clone_pagecursor(handle, status)
+        }
+    }
+
+    override fun `fetchable`(): Fetchable {
+            return FfiConverterTypeFetchable.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_pagecursor_fetchable(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    override fun `hasMore`(): kotlin.Boolean {
+            return FfiConverterBoolean.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_pagecursor_has_more(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    override fun `isLoading`(): kotlin.Boolean {
+            return FfiConverterBoolean.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_pagecursor_is_loading(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    override fun `items`(): List<Selected> {
+            return FfiConverterSequenceTypeSelected.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_pagecursor_items(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    
+    @Throws(ApiException::class)
+    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
+    override suspend fun `loadNext`(`cancellation`: CancellationToken?) : List<Selected> {
+        return uniffiRustCallAsync(
+        callWithHandle { uniffiHandle ->
+            UniffiLib.uniffi_starwars_fn_method_pagecursor_load_next(
+                uniffiHandle,
+                FfiConverterOptionalTypeCancellationToken.lower(`cancellation`),
+            )
+        },
+        { future, callback, continuation -> UniffiLib.ffi_starwars_rust_future_poll_rust_buffer(future, callback, continuation) },
+        { future, continuation -> UniffiLib.ffi_starwars_rust_future_complete_rust_buffer(future, continuation) },
+        { future -> UniffiLib.ffi_starwars_rust_future_free_rust_buffer(future) },
+        // lift function
+        { FfiConverterSequenceTypeSelected.lift(it) },
+        // Error FFI converter
+        ApiException.ErrorHandler,
+    )
+    }
+
+    override fun `nextUrl`(): kotlin.String? {
+            return FfiConverterOptionalString.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_pagecursor_next_url(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    override fun `previousUrl`(): kotlin.String? {
+            return FfiConverterOptionalString.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_pagecursor_previous_url(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    override fun `totalCount`(): kotlin.UInt? {
+            return FfiConverterOptionalUInt.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_pagecursor_total_count(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    
+
+    
+
+
+    
+    
+    /**
+     * @suppress
+     */
+    companion object
+    
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypePageCursor: FfiConverter<PageCursor, Long> {
+    override fun lower(value: PageCursor): Long {
+        return value.uniffiCloneHandle()
+    }
+
+    override fun lift(value: Long): PageCursor {
+        return PageCursor(UniffiWithHandle, value)
+    }
+
+    override fun read(buf: ByteBuffer): PageCursor {
+        return lift(buf.getLong())
+    }
+
+    override fun allocationSize(value: PageCursor) = 8UL
+
+    override fun write(value: PageCursor, buf: ByteBuffer) {
+        buf.putLong(lower(value))
+    }
+}
+
+
+// This template implements a class for working with a Rust struct via a handle
+// to the live Rust struct on the other side of the FFI.
+//
+// There's some subtlety here, because we have to be careful not to operate on a Rust
+// struct after it has been dropped, and because we must expose a public API for freeing
+// theq Kotlin wrapper object in lieu of reliable finalizers. The core requirements are:
+//
+//   * Each instance holds an opaque handle to the underlying Rust struct.
+//     Method calls need to read this handle from the object's state and pass it in to
+//     the Rust FFI.
+//
+//   * When an instance is no longer needed, its handle should be passed to a
+//     special destructor function provided by the Rust FFI, which will drop the
+//     underlying Rust struct.
+//
+//   * Given an instance, calling code is expected to call the special
+//     `destroy` method in order to free it after use, either by calling it explicitly
+//     or by using a higher-level helper like the `use` method. Failing to do so risks
+//     leaking the underlying Rust struct.
+//
+//   * We can't assume that calling code will do the right thing, and must be prepared
+//     to handle Kotlin method calls executing concurrently with or even after a call to
+//     `destroy`, and to handle multiple (possibly concurrent!) calls to `destroy`.
+//
+//   * We must never allow Rust code to operate on the underlying Rust struct after
+//     the destructor has been called, and must never call the destructor more than once.
+//     Doing so may trigger memory unsafety.
+//
+//   * To mitigate many of the risks of leaking memory and use-after-free unsafety, a `Cleaner`
+//     is implemented to call the destructor when the Kotlin object becomes unreachable.
+//     This is done in a background thread. This is not a panacea, and client code should be aware that
+//      1. the thread may starve if some there are objects that have poorly performing
+//     `drop` methods or do significant work in their `drop` methods.
+//      2. the thread is shared across the whole library. This can be tuned by using `android_cleaner = true`,
+//         or `android = true` in the [`kotlin` section of the `uniffi.toml` file](https://mozilla.github.io/uniffi-rs/kotlin/configuration.html).
+//
+// If we try to implement this with mutual exclusion on access to the handle, there is the
+// possibility of a race between a method call and a concurrent call to `destroy`:
+//
+//    * Thread A starts a method call, reads the value of the handle, but is interrupted
+//      before it can pass the handle over the FFI to Rust.
+//    * Thread B calls `destroy` and frees the underlying Rust struct.
+//    * Thread A resumes, passing the already-read handle value to Rust and triggering
+//      a use-after-free.
+//
+// One possible solution would be to use a `ReadWriteLock`, with each method call taking
+// a read lock (and thus allowed to run concurrently) and the special `destroy` method
+// taking a write lock (and thus blocking on live method calls). However, we aim not to
+// generate methods with any hidden blocking semantics, and a `destroy` method that might
+// block if called incorrectly seems to meet that bar.
+//
+// So, we achieve our goals by giving each instance an associated `AtomicLong` counter to track
+// the number of in-flight method calls, and an `AtomicBoolean` flag to indicate whether `destroy`
+// has been called. These are updated according to the following rules:
+//
+//    * The initial value of the counter is 1, indicating a live object with no in-flight calls.
+//      The initial value for the flag is false.
+//
+//    * At the start of each method call, we atomically check the counter.
+//      If it is 0 then the underlying Rust struct has already been destroyed and the call is aborted.
+//      If it is nonzero them we atomically increment it by 1 and proceed with the method call.
+//
+//    * At the end of each method call, we atomically decrement and check the counter.
+//      If it has reached zero then we destroy the underlying Rust struct.
+//
+//    * When `destroy` is called, we atomically flip the flag from false to true.
+//      If the flag was already true we silently fail.
+//      Otherwise we atomically decrement and check the counter.
+//      If it has reached zero then we destroy the underlying Rust struct.
+//
+// Astute readers may observe that this all sounds very similar to the way that Rust's `Arc<T>` works,
+// and indeed it is, with the addition of a flag to guard against multiple calls to `destroy`.
+//
+// The overall effect is that the underlying Rust struct is destroyed only when `destroy` has been
+// called *and* all in-flight method calls have completed, avoiding violating any of the expectations
+// of the underlying Rust code.
+//
+// This makes a cleaner a better alternative to _not_ calling `destroy()` as
+// and when the object is finished with, but the abstraction is not perfect: if the Rust object's `drop`
+// method is slow, and/or there are many objects to cleanup, and it's on a low end Android device, then the cleaner
+// thread may be starved, and the app will leak memory.
+//
+// In this case, `destroy`ing manually may be a better solution.
+//
+// The cleaner can live side by side with the manual calling of `destroy`. In the order of responsiveness, uniffi objects
+// with Rust peers are reclaimed:
+//
+// 1. By calling the `destroy` method of the object, which calls `rustObject.free()`. If that doesn't happen:
+// 2. When the object becomes unreachable, AND the Cleaner thread gets to call `rustObject.free()`. If the thread is starved then:
+// 3. The memory is reclaimed when the process terminates.
+//
+// [1] https://stackoverflow.com/questions/24376768/can-java-finalize-an-object-when-it-is-still-in-scope/24380219
+//
+
+
+public interface QueryInterface {
+    
+    fun `filter`(`field`: kotlin.String, `operator`: FilterOperator, `value`: kotlin.String): Query
+    
+    fun `limit`(`limit`: kotlin.UInt): Query
+    
+    fun `offset`(`offset`: kotlin.UInt): Query
+    
+    fun `sortBy`(`field`: kotlin.String, `direction`: SortDirection): Query
+    
+    companion object
+}
+
+open class Query: Disposable, AutoCloseable, QueryInterface
+{
+
+    @Suppress("UNUSED_PARAMETER")
+    /**
+     * @suppress
+     */
+    constructor(withHandle: UniffiWithHandle, handle: Long) {
+        this.handle = handle
+        this.cleanable = UniffiLib.CLEANER.register(this, UniffiCleanAction(handle))
+    }
+
+    /**
+     * @suppress
+     *
+     * This constructor can be used to instantiate a fake object. Only used for tests. Any
+     * attempt to actually use an object constructed this way will fail as there is no
+     * connected Rust object.
+     */
+    @Suppress("UNUSED_PARAMETER")
+    constructor(noHandle: NoHandle) {
+        this.handle = 0
+        this.cleanable = null
+    }
+    constructor() :
+        this(UniffiWithHandle, 
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_constructor_query_new(
+    
+        _status)
+}
+    )
+
+    protected val handle: Long
+    protected val cleanable: UniffiCleaner.Cleanable?
+
+    private val wasDestroyed = AtomicBoolean(false)
+    private val callCounter = AtomicLong(1)
+
+    override fun destroy() {
+        // Only allow a single call to this method.
+        // TODO: maybe we should log a warning if called more than once?
+        if (this.wasDestroyed.compareAndSet(false, true)) {
+            // This decrement always matches the initial count of 1 given at creation time.
+            if (this.callCounter.decrementAndGet() == 0L) {
+                cleanable?.clean()
+            }
+        }
+    }
+
+    @Synchronized
+    override fun close() {
+        this.destroy()
+    }
+
+    internal inline fun <R> callWithHandle(block: (handle: Long) -> R): R {
+        // Check and increment the call counter, to keep the object alive.
+        // This needs a compare-and-set retry loop in case of concurrent updates.
+        do {
+            val c = this.callCounter.get()
+            if (c == 0L) {
+                throw IllegalStateException("${this.javaClass.simpleName} object has already been destroyed")
+            }
+            if (c == Long.MAX_VALUE) {
+                throw IllegalStateException("${this.javaClass.simpleName} call counter would overflow")
+            }
+        } while (! this.callCounter.compareAndSet(c, c + 1L))
+        // Now we can safely do the method call without the handle being freed concurrently.
+        try {
+            return block(this.uniffiCloneHandle())
+        } finally {
+            // This decrement always matches the increment we performed above.
+            if (this.callCounter.decrementAndGet() == 0L) {
+                cleanable?.clean()
+            }
+        }
+    }
+
+    // Use a static inner class instead of a closure so as not to accidentally
+    // capture `this` as part of the cleanable's action.
+    private class UniffiCleanAction(private val handle: Long) : Runnable {
+        override fun run() {
+            if (handle == 0.toLong()) {
+                // Fake object created with `NoHandle`, don't try to free.
+                return;
+            }
+            uniffiRustCall { status ->
+                UniffiLib.uniffi_starwars_fn_free_query(handle, status)
+            }
+        }
+    }
+
+    /**
+     * @suppress
+     */
+    fun uniffiCloneHandle(): Long {
+        if (handle == 0.toLong()) {
+            throw InternalException("uniffiCloneHandle() called on NoHandle object");
+        }
+        return uniffiRustCall() { status ->
+            UniffiLib.uniffi_starwars_fn_clone_query(handle, status)
+        }
+    }
+
+    override fun `filter`(`field`: kotlin.String, `operator`: FilterOperator, `value`: kotlin.String): Query {
+            return FfiConverterTypeQuery.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_query_filter(
+        it,
+        FfiConverterString.lower(`field`),FfiConverterTypeFilterOperator.lower(`operator`),FfiConverterString.lower(`value`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `limit`(`limit`: kotlin.UInt): Query {
+            return FfiConverterTypeQuery.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_query_limit(
+        it,
+        FfiConverterUInt.lower(`limit`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `offset`(`offset`: kotlin.UInt): Query {
+            return FfiConverterTypeQuery.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_query_offset(
+        it,
+        FfiConverterUInt.lower(`offset`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `sortBy`(`field`: kotlin.String, `direction`: SortDirection): Query {
+            return FfiConverterTypeQuery.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_query_sort_by(
+        it,
+        FfiConverterString.lower(`field`),FfiConverterTypeSortDirection.lower(`direction`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+
+    
+
+
+    
+    
+    /**
+     * @suppress
+     */
+    companion object
+    
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeQuery: FfiConverter<Query, Long> {
+    override fun lower(value: Query): Long {
+        return value.uniffiCloneHandle()
+    }
+
+    override fun lift(value: Long): Query {
+        return Query(UniffiWithHandle, value)
+    }
+
+    override fun read(buf: ByteBuffer): Query {
+        return lift(buf.getLong())
+    }
+
+    override fun allocationSize(value: Query) = 8UL
+
+    override fun write(value: Query, buf: ByteBuffer) {
+        buf.putLong(lower(value))
+    }
+}
+
+
+// This template implements a class for working with a Rust struct via a handle
+// to the live Rust struct on the other side of the FFI.
+//
+// There's some subtlety here, because we have to be careful not to operate on a Rust
+// struct after it has been dropped, and because we must expose a public API for freeing
+// theq Kotlin wrapper object in lieu of reliable finalizers. The core requirements are:
+//
+//   * Each instance holds an opaque handle to the underlying Rust struct.
+//     Method calls need to read this handle from the object's state and pass it in to
+//     the Rust FFI.
+//
+//   * When an instance is no longer needed, its handle should be passed to a
+//     special destructor function provided by the Rust FFI, which will drop the
+//     underlying Rust struct.
+//
+//   * Given an instance, calling code is expected to call the special
+//     `destroy` method in order to free it after use, either by calling it explicitly
+//     or by using a higher-level helper like the `use` method. Failing to do so risks
+//     leaking the underlying Rust struct.
+//
+//   * We can't assume that calling code will do the right thing, and must be prepared
+//     to handle Kotlin method calls executing concurrently with or even after a call to
+//     `destroy`, and to handle multiple (possibly concurrent!) calls to `destroy`.
+//
+//   * We must never allow Rust code to operate on the underlying Rust struct after
+//     the destructor has been called, and must never call the destructor more than once.
+//     Doing so may trigger memory unsafety.
+//
+//   * To mitigate many of the risks of leaking memory and use-after-free unsafety, a `Cleaner`
+//     is implemented to call the destructor when the Kotlin object becomes unreachable.
+//     This is done in a background thread. This is not a panacea, and client code should be aware that
+//      1. the thread may starve if some there are objects that have poorly performing
+//     `drop` methods or do significant work in their `drop` methods.
+//      2. the thread is shared across the whole library. This can be tuned by using `android_cleaner = true`,
+//         or `android = true` in the [`kotlin` section of the `uniffi.toml` file](https://mozilla.github.io/uniffi-rs/kotlin/configuration.html).
+//
+// If we try to implement this with mutual exclusion on access to the handle, there is the
+// possibility of a race between a method call and a concurrent call to `destroy`:
+//
+//    * Thread A starts a method call, reads the value of the handle, but is interrupted
+//      before it can pass the handle over the FFI to Rust.
+//    * Thread B calls `destroy` and frees the underlying Rust struct.
+//    * Thread A resumes, passing the already-read handle value to Rust and triggering
+//      a use-after-free.
+//
+// One possible solution would be to use a `ReadWriteLock`, with each method call taking
+// a read lock (and thus allowed to run concurrently) and the special `destroy` method
+// taking a write lock (and thus blocking on live method calls). However, we aim not to
+// generate methods with any hidden blocking semantics, and a `destroy` method that might
+// block if called incorrectly seems to meet that bar.
+//
+// So, we achieve our goals by giving each instance an associated `AtomicLong` counter to track
+// the number of in-flight method calls, and an `AtomicBoolean` flag to indicate whether `destroy`
+// has been called. These are updated according to the following rules:
+//
+//    * The initial value of the counter is 1, indicating a live object with no in-flight calls.
+//      The initial value for the flag is false.
+//
+//    * At the start of each method call, we atomically check the counter.
+//      If it is 0 then the underlying Rust struct has already been destroyed and the call is aborted.
+//      If it is nonzero them we atomically increment it by 1 and proceed with the method call.
+//
+//    * At the end of each method call, we atomically decrement and check the counter.
+//      If it has reached zero then we destroy the underlying Rust struct.
+//
+//    * When `destroy` is called, we atomically flip the flag from false to true.
+//      If the flag was already true we silently fail.
+//      Otherwise we atomically decrement and check the counter.
+//      If it has reached zero then we destroy the underlying Rust struct.
+//
+// Astute readers may observe that this all sounds very similar to the way that Rust's `Arc<T>` works,
+// and indeed it is, with the addition of a flag to guard against multiple calls to `destroy`.
+//
+// The overall effect is that the underlying Rust struct is destroyed only when `destroy` has been
+// called *and* all in-flight method calls have completed, avoiding violating any of the expectations
+// of the underlying Rust code.
+//
+// This makes a cleaner a better alternative to _not_ calling `destroy()` as
+// and when the object is finished with, but the abstraction is not perfect: if the Rust object's `drop`
+// method is slow, and/or there are many objects to cleanup, and it's on a low end Android device, then the cleaner
+// thread may be starved, and the app will leak memory.
+//
+// In this case, `destroy`ing manually may be a better solution.
+//
+// The cleaner can live side by side with the manual calling of `destroy`. In the order of responsiveness, uniffi objects
+// with Rust peers are reclaimed:
+//
+// 1. By calling the `destroy` method of the object, which calls `rustObject.free()`. If that doesn't happen:
+// 2. When the object becomes unreachable, AND the Cleaner thread gets to call `rustObject.free()`. If the thread is starved then:
+// 3. The memory is reclaimed when the process terminates.
+//
+// [1] https://stackoverflow.com/questions/24376768/can-java-finalize-an-object-when-it-is-still-in-scope/24380219
+//
+
+
+public interface RepositoryObserver {
+    
+    fun `onChange`(`change`: RepositoryChange)
+    
+    companion object
+}
+
+open class RepositoryObserverImpl: Disposable, AutoCloseable, RepositoryObserver
+{
+
+    @Suppress("UNUSED_PARAMETER")
+    /**
+     * @suppress
+     */
+    constructor(withHandle: UniffiWithHandle, handle: Long) {
+        this.handle = handle
+        this.cleanable = UniffiLib.CLEANER.register(this, UniffiCleanAction(handle))
+    }
+
+    /**
+     * @suppress
+     *
+     * This constructor can be used to instantiate a fake object. Only used for tests. Any
+     * attempt to actually use an object constructed this way will fail as there is no
+     * connected Rust object.
+     */
+    @Suppress("UNUSED_PARAMETER")
+    constructor(noHandle: NoHandle) {
+        this.handle = 0
+        this.cleanable = null
+    }
+
+    protected val handle: Long
+    protected val cleanable: UniffiCleaner.Cleanable?
+
+    private val wasDestroyed = AtomicBoolean(false)
+    private val callCounter = AtomicLong(1)
+
+    override fun destroy() {
+        // Only allow a single call to this method.
+        // TODO: maybe we should log a warning if called more than once?
+        if (this.wasDestroyed.compareAndSet(false, true)) {
+            // This decrement always matches the initial count of 1 given at creation time.
+            if (this.callCounter.decrementAndGet() == 0L) {
+                cleanable?.clean()
+            }
+        }
+    }
+
+    @Synchronized
+    override fun close() {
+        this.destroy()
+    }
+
+    internal inline fun <R> callWithHandle(block: (handle: Long) -> R): R {
+        // Check and increment the call counter, to keep the object alive.
+        // This needs a compare-and-set retry loop in case of concurrent updates.
+        do {
+            val c = this.callCounter.get()
+            if (c == 0L) {
+                throw IllegalStateException("${this.javaClass.simpleName} object has already been destroyed")
+            }
+            if (c == Long.MAX_VALUE) {
+                throw IllegalStateException("${this.javaClass.simpleName} call counter would overflow")
+            }
+        } while (! this.callCounter.compareAndSet(c, c + 1L))
+        // Now we can safely do the method call without the handle being freed concurrently.
+        try {
+            return block(this.uniffiCloneHandle())
+        } finally {
+            // This decrement always matches the increment we performed above.
+            if (this.callCounter.decrementAndGet() == 0L) {
+                cleanable?.clean()
+            }
+        }
+    }
+
+    // Use a static inner class instead of a closure so as not to accidentally
+    // capture `this` as part of the cleanable's action.
+    private class UniffiCleanAction(private val handle: Long) : Runnable {
+        override fun run() {
+            if (handle == 0.toLong()) {
+                // Fake object created with `NoHandle`, don't try to free.
+                return;
+            }
+            uniffiRustCall { status ->
+                UniffiLib.uniffi_starwars_fn_free_repositoryobserver(handle, status)
+            }
+        }
+    }
+
+    /**
+     * @suppress
+     */
+    fun uniffiCloneHandle(): Long {
+        if (handle == 0.toLong()) {
+            throw InternalException("uniffiCloneHandle() called on NoHandle object");
+        }
+        return uniffiRustCall() { status ->
+            UniffiLib.uniffi_starwars_fn_clone_repositoryobserver(handle, status)
+        }
+    }
+
+    override fun `onChange`(`change`: RepositoryChange)
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_repositoryobserver_on_change(
+        it,
+        FfiConverterTypeRepositoryChange.lower(`change`),_status)
+}
+    }
+    
+    
+
+    
+
+    
+
+
+    
+    
+    /**
+     * @suppress
+     */
+    companion object
+    
+}
+
+
+
+// Put the implementation in an object so we don't pollute the top-level namespace
+internal object uniffiCallbackInterfaceRepositoryObserver {
+    internal object `onChange`: UniffiCallbackInterfaceRepositoryObserverMethod0 {
+        override fun callback(`uniffiHandle`: Long,`change`: RustBuffer.ByValue,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,) {
+            val uniffiObj = FfiConverterTypeRepositoryObserver.handleMap.get(uniffiHandle)
+            val makeCall = { ->
+                uniffiObj.`onChange`(
+                    FfiConverterTypeRepositoryChange.lift(`change`),
+                )
+            }
+            val writeReturn = { _: Unit -> Unit }
+            uniffiTraitInterfaceCall(uniffiCallStatus, makeCall, writeReturn)
+        }
+    }
+
+    internal object uniffiFree: UniffiCallbackInterfaceFree {
+        override fun callback(handle: Long) {
+            FfiConverterTypeRepositoryObserver.handleMap.remove(handle)
+        }
+    }
+
+    internal object uniffiClone: UniffiCallbackInterfaceClone {
+        override fun callback(handle: Long): Long {
+            return FfiConverterTypeRepositoryObserver.handleMap.clone(handle)
+        }
+    }
+
+    internal var vtable = UniffiVTableCallbackInterfaceRepositoryObserver.UniffiByValue(
+        uniffiFree,
+        uniffiClone,
+        `onChange`,
+    )
+
+    // Registers the foreign callback with the Rust side.
+    // This method is generated for each callback interface.
+    internal fun register(lib: UniffiLib) {
+        lib.uniffi_starwars_fn_init_callback_vtable_repositoryobserver(vtable)
+    }
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeRepositoryObserver: FfiConverter<RepositoryObserver, Long> {
+    internal val handleMap = UniffiHandleMap<RepositoryObserver>()
+
+    override fun lower(value: RepositoryObserver): Long {
+        if (value is RepositoryObserverImpl) {
+             // Rust-implemented object.  Clone the handle and return it
+            return value.uniffiCloneHandle()
+         } else {
+            // Kotlin object, generate a new vtable handle and return that.
+            return handleMap.insert(value)
+         }
+    }
+
+    override fun lift(value: Long): RepositoryObserver {
+        if ((value and 1.toLong()) == 0.toLong()) {
+            // Rust-generated handle, construct a new class that uses the handle to implement the
+            // interface
+            return RepositoryObserverImpl(UniffiWithHandle, value)
+        } else {
+            // Kotlin-generated handle, get the object from the handle map
+            return handleMap.remove(value)
+        }
+    }
+
+    override fun read(buf: ByteBuffer): RepositoryObserver {
+        return lift(buf.getLong())
+    }
+
+    override fun allocationSize(value: RepositoryObserver) = 8UL
+
+    override fun write(value: RepositoryObserver, buf: ByteBuffer) {
+        buf.putLong(lower(value))
+    }
+}
+
+
+// This template implements a class for working with a Rust struct via a handle
+// to the live Rust struct on the other side of the FFI.
+//
+// There's some subtlety here, because we have to be careful not to operate on a Rust
+// struct after it has been dropped, and because we must expose a public API for freeing
+// theq Kotlin wrapper object in lieu of reliable finalizers. The core requirements are:
+//
+//   * Each instance holds an opaque handle to the underlying Rust struct.
+//     Method calls need to read this handle from the object's state and pass it in to
+//     the Rust FFI.
+//
+//   * When an instance is no longer needed, its handle should be passed to a
+//     special destructor function provided by the Rust FFI, which will drop the
+//     underlying Rust struct.
+//
+//   * Given an instance, calling code is expected to call the special
+//     `destroy` method in order to free it after use, either by calling it explicitly
+//     or by using a higher-level helper like the `use` method. Failing to do so risks
+//     leaking the underlying Rust struct.
+//
+//   * We can't assume that calling code will do the right thing, and must be prepared
+//     to handle Kotlin method calls executing concurrently with or even after a call to
+//     `destroy`, and to handle multiple (possibly concurrent!) calls to `destroy`.
+//
+//   * We must never allow Rust code to operate on the underlying Rust struct after
+//     the destructor has been called, and must never call the destructor more than once.
+//     Doing so may trigger memory unsafety.
+//
+//   * To mitigate many of the risks of leaking memory and use-after-free unsafety, a `Cleaner`
+//     is implemented to call the destructor when the Kotlin object becomes unreachable.
+//     This is done in a background thread. This is not a panacea, and client code should be aware that
+//      1. the thread may starve if some there are objects that have poorly performing
+//     `drop` methods or do significant work in their `drop` methods.
+//      2. the thread is shared across the whole library. This can be tuned by using `android_cleaner = true`,
+//         or `android = true` in the [`kotlin` section of the `uniffi.toml` file](https://mozilla.github.io/uniffi-rs/kotlin/configuration.html).
+//
+// If we try to implement this with mutual exclusion on access to the handle, there is the
+// possibility of a race between a method call and a concurrent call to `destroy`:
+//
+//    * Thread A starts a method call, reads the value of the handle, but is interrupted
+//      before it can pass the handle over the FFI to Rust.
+//    * Thread B calls `destroy` and frees the underlying Rust struct.
+//    * Thread A resumes, passing the already-read handle value to Rust and triggering
+//      a use-after-free.
+//
+// One possible solution would be to use a `ReadWriteLock`, with each method call taking
+// a read lock (and thus allowed to run concurrently) and the special `destroy` method
+// taking a write lock (and thus blocking on live method calls). However, we aim not to
+// generate methods with any hidden blocking semantics, and a `destroy` method that might
+// block if called incorrectly seems to meet that bar.
+//
+// So, we achieve our goals by giving each instance an associated `AtomicLong` counter to track
+// the number of in-flight method calls, and an `AtomicBoolean` flag to indicate whether `destroy`
+// has been called. These are updated according to the following rules:
+//
+//    * The initial value of the counter is 1, indicating a live object with no in-flight calls.
+//      The initial value for the flag is false.
+//
+//    * At the start of each method call, we atomically check the counter.
+//      If it is 0 then the underlying Rust struct has already been destroyed and the call is aborted.
+//      If it is nonzero them we atomically increment it by 1 and proceed with the method call.
+//
+//    * At the end of each method call, we atomically decrement and check the counter.
+//      If it has reached zero then we destroy the underlying Rust struct.
+//
+//    * When `destroy` is called, we atomically flip the flag from false to true.
+//      If the flag was already true we silently fail.
+//      Otherwise we atomically decrement and check the counter.
+//      If it has reached zero then we destroy the underlying Rust struct.
+//
+// Astute readers may observe that this all sounds very similar to the way that Rust's `Arc<T>` works,
+// and indeed it is, with the addition of a flag to guard against multiple calls to `destroy`.
+//
+// The overall effect is that the underlying Rust struct is destroyed only when `destroy` has been
+// called *and* all in-flight method calls have completed, avoiding violating any of the expectations
+// of the underlying Rust code.
+//
+// This makes a cleaner a better alternative to _not_ calling `destroy()` as
+// and when the object is finished with, but the abstraction is not perfect: if the Rust object's `drop`
+// method is slow, and/or there are many objects to cleanup, and it's on a low end Android device, then the cleaner
+// thread may be starved, and the app will leak memory.
+//
+// In this case, `destroy`ing manually may be a better solution.
+//
+// The cleaner can live side by side with the manual calling of `destroy`. In the order of responsiveness, uniffi objects
+// with Rust peers are reclaimed:
+//
+// 1. By calling the `destroy` method of the object, which calls `rustObject.free()`. If that doesn't happen:
+// 2. When the object becomes unreachable, AND the Cleaner thread gets to call `rustObject.free()`. If the thread is starved then:
+// 3. The memory is reclaimed when the process terminates.
+//
+// [1] https://stackoverflow.com/questions/24376768/can-java-finalize-an-object-when-it-is-still-in-scope/24380219
+//
+
+
+public interface RepositoryStore {
+    
+    fun `read`(`key`: kotlin.String): kotlin.ByteArray?
+    
+    fun `write`(`key`: kotlin.String, `contents`: kotlin.ByteArray)
+    
+    fun `remove`(`key`: kotlin.String)
+    
+    fun `keys`(): List<kotlin.String>
+    
+    companion object
+}
+
+open class RepositoryStoreImpl: Disposable, AutoCloseable, RepositoryStore
+{
+
+    @Suppress("UNUSED_PARAMETER")
+    /**
+     * @suppress
+     */
+    constructor(withHandle: UniffiWithHandle, handle: Long) {
+        this.handle = handle
+        this.cleanable = UniffiLib.CLEANER.register(this, UniffiCleanAction(handle))
+    }
+
+    /**
+     * @suppress
+     *
+     * This constructor can be used to instantiate a fake object. Only used for tests. Any
+     * attempt to actually use an object constructed this way will fail as there is no
+     * connected Rust object.
+     */
+    @Suppress("UNUSED_PARAMETER")
+    constructor(noHandle: NoHandle) {
+        this.handle = 0
+        this.cleanable = null
+    }
+
+    protected val handle: Long
+    protected val cleanable: UniffiCleaner.Cleanable?
+
+    private val wasDestroyed = AtomicBoolean(false)
+    private val callCounter = AtomicLong(1)
+
+    override fun destroy() {
+        // Only allow a single call to this method.
+        // TODO: maybe we should log a warning if called more than once?
+        if (this.wasDestroyed.compareAndSet(false, true)) {
+            // This decrement always matches the initial count of 1 given at creation time.
+            if (this.callCounter.decrementAndGet() == 0L) {
+                cleanable?.clean()
+            }
+        }
+    }
+
+    @Synchronized
+    override fun close() {
+        this.destroy()
+    }
+
+    internal inline fun <R> callWithHandle(block: (handle: Long) -> R): R {
+        // Check and increment the call counter, to keep the object alive.
+        // This needs a compare-and-set retry loop in case of concurrent updates.
+        do {
+            val c = this.callCounter.get()
+            if (c == 0L) {
+                throw IllegalStateException("${this.javaClass.simpleName} object has already been destroyed")
+            }
+            if (c == Long.MAX_VALUE) {
+                throw IllegalStateException("${this.javaClass.simpleName} call counter would overflow")
+            }
+        } while (! this.callCounter.compareAndSet(c, c + 1L))
+        // Now we can safely do the method call without the handle being freed concurrently.
+        try {
+            return block(this.uniffiCloneHandle())
+        } finally {
+            // This decrement always matches the increment we performed above.
+            if (this.callCounter.decrementAndGet() == 0L) {
+                cleanable?.clean()
+            }
+        }
+    }
+
+    // Use a static inner class instead of a closure so as not to accidentally
+    // capture `this` as part of the cleanable's action.
+    private class UniffiCleanAction(private val handle: Long) : Runnable {
+        override fun run() {
+            if (handle == 0.toLong()) {
+                // Fake object created with `NoHandle`, don't try to free.
+                return;
+            }
+            uniffiRustCall { status ->
+                UniffiLib.uniffi_starwars_fn_free_repositorystore(handle, status)
+            }
+        }
+    }
+
+    /**
+     * @suppress
+     */
+    fun uniffiCloneHandle(): Long {
+        if (handle == 0.toLong()) {
+            throw InternalException("uniffiCloneHandle() called on NoHandle object");
+        }
+        return uniffiRustCall() { status ->
+            UniffiLib.uniffi_starwars_fn_clone_repositorystore(handle, status)
+        }
+    }
+
+    
+    @Throws(StoreException::class)override fun `read`(`key`: kotlin.String): kotlin.ByteArray? {
+            return FfiConverterOptionalByteArray.lift(
+    callWithHandle {
+    uniffiRustCallWithError(StoreException) { _status ->
+    UniffiLib.uniffi_starwars_fn_method_repositorystore_read(
+        it,
+        FfiConverterString.lower(`key`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+    @Throws(StoreException::class)override fun `write`(`key`: kotlin.String, `contents`: kotlin.ByteArray)
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(StoreException) { _status ->
+    UniffiLib.uniffi_starwars_fn_method_repositorystore_write(
+        it,
+        FfiConverterString.lower(`key`),FfiConverterByteArray.lower(`contents`),_status)
+}
+    }
+    
+    
+
+    
+    @Throws(StoreException::class)override fun `remove`(`key`: kotlin.String)
+        = 
+    callWithHandle {
+    uniffiRustCallWithError(StoreException) { _status ->
+    UniffiLib.uniffi_starwars_fn_method_repositorystore_remove(
+        it,
+        FfiConverterString.lower(`key`),_status)
+}
+    }
+    
+    
+
+    
+    @Throws(StoreException::class)override fun `keys`(): List<kotlin.String> {
+            return FfiConverterSequenceString.lift(
+    callWithHandle {
+    uniffiRustCallWithError(StoreException) { _status ->
+    UniffiLib.uniffi_starwars_fn_method_repositorystore_keys(
+        it,
+        _status)
+}
+    }
+    )
+    }
+    
+
+    
+
+    
+
+
+    
+    
+    /**
+     * @suppress
+     */
+    companion object
+    
+}
+
+
+
+// Put the implementation in an object so we don't pollute the top-level namespace
+internal object uniffiCallbackInterfaceRepositoryStore {
+    internal object `read`: UniffiCallbackInterfaceRepositoryStoreMethod0 {
+        override fun callback(`uniffiHandle`: Long,`key`: RustBuffer.ByValue,`uniffiOutReturn`: RustBuffer,uniffiCallStatus: UniffiRustCallStatus,) {
+            val uniffiObj = FfiConverterTypeRepositoryStore.handleMap.get(uniffiHandle)
+            val makeCall = { ->
+                uniffiObj.`read`(
+                    FfiConverterString.lift(`key`),
+                )
+            }
+            val writeReturn = { value: kotlin.ByteArray? -> uniffiOutReturn.setValue(FfiConverterOptionalByteArray.lower(value)) }
+            uniffiTraitInterfaceCallWithError(
+                uniffiCallStatus,
+                makeCall,
+                writeReturn,
+                { e: StoreException -> FfiConverterTypeStoreError.lower(e) }
+            )
+        }
+    }
+    internal object `write`: UniffiCallbackInterfaceRepositoryStoreMethod1 {
+        override fun callback(`uniffiHandle`: Long,`key`: RustBuffer.ByValue,`contents`: RustBuffer.ByValue,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,) {
+            val uniffiObj = FfiConverterTypeRepositoryStore.handleMap.get(uniffiHandle)
+            val makeCall = { ->
+                uniffiObj.`write`(
+                    FfiConverterString.lift(`key`),
+                    FfiConverterByteArray.lift(`contents`),
+                )
+            }
+            val writeReturn = { _: Unit -> Unit }
+            uniffiTraitInterfaceCallWithError(
+                uniffiCallStatus,
+                makeCall,
+                writeReturn,
+                { e: StoreException -> FfiConverterTypeStoreError.lower(e) }
+            )
+        }
+    }
+    internal object `remove`: UniffiCallbackInterfaceRepositoryStoreMethod2 {
+        override fun callback(`uniffiHandle`: Long,`key`: RustBuffer.ByValue,`uniffiOutReturn`: Pointer,uniffiCallStatus: UniffiRustCallStatus,) {
+            val uniffiObj = FfiConverterTypeRepositoryStore.handleMap.get(uniffiHandle)
+            val makeCall = { ->
+                uniffiObj.`remove`(
+                    FfiConverterString.lift(`key`),
+                )
+            }
+            val writeReturn = { _: Unit -> Unit }
+            uniffiTraitInterfaceCallWithError(
+                uniffiCallStatus,
+                makeCall,
+                writeReturn,
+                { e: StoreException -> FfiConverterTypeStoreError.lower(e) }
+            )
+        }
+    }
+    internal object `keys`: UniffiCallbackInterfaceRepositoryStoreMethod3 {
+        override fun callback(`uniffiHandle`: Long,`uniffiOutReturn`: RustBuffer,uniffiCallStatus: UniffiRustCallStatus,) {
+            val uniffiObj = FfiConverterTypeRepositoryStore.handleMap.get(uniffiHandle)
+            val makeCall = { ->
+                uniffiObj.`keys`(
+                )
+            }
+            val writeReturn = { value: List<kotlin.String> -> uniffiOutReturn.setValue(FfiConverterSequenceString.lower(value)) }
+            uniffiTraitInterfaceCallWithError(
+                uniffiCallStatus,
+                makeCall,
+                writeReturn,
+                { e: StoreException -> FfiConverterTypeStoreError.lower(e) }
+            )
+        }
+    }
+
+    internal object uniffiFree: UniffiCallbackInterfaceFree {
+        override fun callback(handle: Long) {
+            FfiConverterTypeRepositoryStore.handleMap.remove(handle)
+        }
+    }
+
+    internal object uniffiClone: UniffiCallbackInterfaceClone {
+        override fun callback(handle: Long): Long {
+            return FfiConverterTypeRepositoryStore.handleMap.clone(handle)
+        }
+    }
+
+    internal var vtable = UniffiVTableCallbackInterfaceRepositoryStore.UniffiByValue(
+        uniffiFree,
+        uniffiClone,
+        `read`,
+        `write`,
+        `remove`,
+        `keys`,
+    )
+
+    // Registers the foreign callback with the Rust side.
+    // This method is generated for each callback interface.
+    internal fun register(lib: UniffiLib) {
+        lib.uniffi_starwars_fn_init_callback_vtable_repositorystore(vtable)
+    }
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeRepositoryStore: FfiConverter<RepositoryStore, Long> {
+    internal val handleMap = UniffiHandleMap<RepositoryStore>()
+
+    override fun lower(value: RepositoryStore): Long {
+        if (value is RepositoryStoreImpl) {
+             // Rust-implemented object.  Clone the handle and return it
+            return value.uniffiCloneHandle()
+         } else {
+            // Kotlin object, generate a new vtable handle and return that.
+            return handleMap.insert(value)
+         }
+    }
+
+    override fun lift(value: Long): RepositoryStore {
+        if ((value and 1.toLong()) == 0.toLong()) {
+            // Rust-generated handle, construct a new class that uses the handle to implement the
+            // interface
+            return RepositoryStoreImpl(UniffiWithHandle, value)
+        } else {
+            // Kotlin-generated handle, get the object from the handle map
+            return handleMap.remove(value)
+        }
+    }
+
+    override fun read(buf: ByteBuffer): RepositoryStore {
+        return lift(buf.getLong())
+    }
+
+    override fun allocationSize(value: RepositoryStore) = 8UL
+
+    override fun write(value: RepositoryStore, buf: ByteBuffer) {
+        buf.putLong(lower(value))
+    }
+}
+
+
+// This template implements a class for working with a Rust struct via a handle
+// to the live Rust struct on the other side of the FFI.
+//
+// There's some subtlety here, because we have to be careful not to operate on a Rust
+// struct after it has been dropped, and because we must expose a public API for freeing
+// theq Kotlin wrapper object in lieu of reliable finalizers. The core requirements are:
+//
+//   * Each instance holds an opaque handle to the underlying Rust struct.
+//     Method calls need to read this handle from the object's state and pass it in to
+//     the Rust FFI.
+//
+//   * When an instance is no longer needed, its handle should be passed to a
+//     special destructor function provided by the Rust FFI, which will drop the
+//     underlying Rust struct.
+//
+//   * Given an instance, calling code is expected to call the special
+//     `destroy` method in order to free it after use, either by calling it explicitly
+//     or by using a higher-level helper like the `use` method. Failing to do so risks
+//     leaking the underlying Rust struct.
+//
+//   * We can't assume that calling code will do the right thing, and must be prepared
+//     to handle Kotlin method calls executing concurrently with or even after a call to
+//     `destroy`, and to handle multiple (possibly concurrent!) calls to `destroy`.
+//
+//   * We must never allow Rust code to operate on the underlying Rust struct after
+//     the destructor has been called, and must never call the destructor more than once.
+//     Doing so may trigger memory unsafety.
+//
+//   * To mitigate many of the risks of leaking memory and use-after-free unsafety, a `Cleaner`
+//     is implemented to call the destructor when the Kotlin object becomes unreachable.
+//     This is done in a background thread. This is not a panacea, and client code should be aware that
+//      1. the thread may starve if some there are objects that have poorly performing
+//     `drop` methods or do significant work in their `drop` methods.
+//      2. the thread is shared across the whole library. This can be tuned by using `android_cleaner = true`,
+//         or `android = true` in the [`kotlin` section of the `uniffi.toml` file](https://mozilla.github.io/uniffi-rs/kotlin/configuration.html).
+//
+// If we try to implement this with mutual exclusion on access to the handle, there is the
+// possibility of a race between a method call and a concurrent call to `destroy`:
+//
+//    * Thread A starts a method call, reads the value of the handle, but is interrupted
+//      before it can pass the handle over the FFI to Rust.
+//    * Thread B calls `destroy` and frees the underlying Rust struct.
+//    * Thread A resumes, passing the already-read handle value to Rust and triggering
+//      a use-after-free.
+//
+// One possible solution would be to use a `ReadWriteLock`, with each method call taking
+// a read lock (and thus allowed to run concurrently) and the special `destroy` method
+// taking a write lock (and thus blocking on live method calls). However, we aim not to
+// generate methods with any hidden blocking semantics, and a `destroy` method that might
+// block if called incorrectly seems to meet that bar.
+//
+// So, we achieve our goals by giving each instance an associated `AtomicLong` counter to track
+// the number of in-flight method calls, and an `AtomicBoolean` flag to indicate whether `destroy`
+// has been called. These are updated according to the following rules:
+//
+//    * The initial value of the counter is 1, indicating a live object with no in-flight calls.
+//      The initial value for the flag is false.
+//
+//    * At the start of each method call, we atomically check the counter.
+//      If it is 0 then the underlying Rust struct has already been destroyed and the call is aborted.
+//      If it is nonzero them we atomically increment it by 1 and proceed with the method call.
+//
+//    * At the end of each method call, we atomically decrement and check the counter.
+//      If it has reached zero then we destroy the underlying Rust struct.
+//
+//    * When `destroy` is called, we atomically flip the flag from false to true.
+//      If the flag was already true we silently fail.
+//      Otherwise we atomically decrement and check the counter.
+//      If it has reached zero then we destroy the underlying Rust struct.
+//
+// Astute readers may observe that this all sounds very similar to the way that Rust's `Arc<T>` works,
+// and indeed it is, with the addition of a flag to guard against multiple calls to `destroy`.
+//
+// The overall effect is that the underlying Rust struct is destroyed only when `destroy` has been
+// called *and* all in-flight method calls have completed, avoiding violating any of the expectations
+// of the underlying Rust code.
+//
+// This makes a cleaner a better alternative to _not_ calling `destroy()` as
+// and when the object is finished with, but the abstraction is not perfect: if the Rust object's `drop`
+// method is slow, and/or there are many objects to cleanup, and it's on a low end Android device, then the cleaner
+// thread may be starved, and the app will leak memory.
+//
+// In this case, `destroy`ing manually may be a better solution.
+//
+// The cleaner can live side by side with the manual calling of `destroy`. In the order of responsiveness, uniffi objects
+// with Rust peers are reclaimed:
+//
+// 1. By calling the `destroy` method of the object, which calls `rustObject.free()`. If that doesn't happen:
+// 2. When the object becomes unreachable, AND the Cleaner thread gets to call `rustObject.free()`. If the thread is starved then:
+// 3. The memory is reclaimed when the process terminates.
+//
+// [1] https://stackoverflow.com/questions/24376768/can-java-finalize-an-object-when-it-is-still-in-scope/24380219
+//
+
+
+public interface SearchIndexInterface {
+    
+    fun `rebuild`()
+    
+    fun `search`(`query`: kotlin.String, `limit`: kotlin.UInt): List<Selected>
+    
+    fun `searchIn`(`fetchable`: Fetchable, `query`: kotlin.String, `limit`: kotlin.UInt): List<Selected>
+    
+    companion object
+}
+
+open class SearchIndex: Disposable, AutoCloseable, SearchIndexInterface
+{
+
+    @Suppress("UNUSED_PARAMETER")
+    /**
+     * @suppress
+     */
+    constructor(withHandle: UniffiWithHandle, handle: Long) {
+        this.handle = handle
+        this.cleanable = UniffiLib.CLEANER.register(this, UniffiCleanAction(handle))
+    }
+
+    /**
+     * @suppress
+     *
+     * This constructor can be used to instantiate a fake object. Only used for tests. Any
+     * attempt to actually use an object constructed this way will fail as there is no
+     * connected Rust object.
+     */
+    @Suppress("UNUSED_PARAMETER")
+    constructor(noHandle: NoHandle) {
+        this.handle = 0
+        this.cleanable = null
+    }
+    constructor(`repository`: DataRepository) :
+        this(UniffiWithHandle, 
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_constructor_searchindex_new(
+    
+        FfiConverterTypeDataRepository.lower(`repository`),_status)
+}
+    )
+
+    protected val handle: Long
+    protected val cleanable: UniffiCleaner.Cleanable?
+
+    private val wasDestroyed = AtomicBoolean(false)
+    private val callCounter = AtomicLong(1)
+
+    override fun destroy() {
+        // Only allow a single call to this method.
+        // TODO: maybe we should log a warning if called more than once?
+        if (this.wasDestroyed.compareAndSet(false, true)) {
+            // This decrement always matches the initial count of 1 given at creation time.
+            if (this.callCounter.decrementAndGet() == 0L) {
+                cleanable?.clean()
+            }
+        }
+    }
+
+    @Synchronized
+    override fun close() {
+        this.destroy()
+    }
+
+    internal inline fun <R> callWithHandle(block: (handle: Long) -> R): R {
+        // Check and increment the call counter, to keep the object alive.
+        // This needs a compare-and-set retry loop in case of concurrent updates.
+        do {
+            val c = this.callCounter.get()
+            if (c == 0L) {
+                throw IllegalStateException("${this.javaClass.simpleName} object has already been destroyed")
+            }
+            if (c == Long.MAX_VALUE) {
+                throw IllegalStateException("${this.javaClass.simpleName} call counter would overflow")
+            }
+        } while (! this.callCounter.compareAndSet(c, c + 1L))
+        // Now we can safely do the method call without the handle being freed concurrently.
+        try {
+            return block(this.uniffiCloneHandle())
+        } finally {
+            // This decrement always matches the increment we performed above.
+            if (this.callCounter.decrementAndGet() == 0L) {
+                cleanable?.clean()
+            }
+        }
+    }
+
+    // Use a static inner class instead of a closure so as not to accidentally
+    // capture `this` as part of the cleanable's action.
+    private class UniffiCleanAction(private val handle: Long) : Runnable {
+        override fun run() {
+            if (handle == 0.toLong()) {
+                // Fake object created with `NoHandle`, don't try to free.
+                return;
+            }
+            uniffiRustCall { status ->
+                UniffiLib.uniffi_starwars_fn_free_searchindex(handle, status)
+            }
+        }
+    }
+
+    /**
+     * @suppress
+     */
+    fun uniffiCloneHandle(): Long {
+        if (handle == 0.toLong()) {
+            throw InternalException("uniffiCloneHandle() called on NoHandle object");
+        }
+        return uniffiRustCall() { status ->
+            UniffiLib.uniffi_starwars_fn_clone_searchindex(handle, status)
+        }
+    }
+
+    override fun `rebuild`()
+        = 
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_searchindex_rebuild(
+        it,
+        _status)
+}
+    }
+    
+    
+
+    override fun `search`(`query`: kotlin.String, `limit`: kotlin.UInt): List<Selected> {
+            return FfiConverterSequenceTypeSelected.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_searchindex_search(
+        it,
+        FfiConverterString.lower(`query`),FfiConverterUInt.lower(`limit`),_status)
+}
+    }
+    )
+    }
+    
+
+    override fun `searchIn`(`fetchable`: Fetchable, `query`: kotlin.String, `limit`: kotlin.UInt): List<Selected> {
+            return FfiConverterSequenceTypeSelected.lift(
+    callWithHandle {
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_searchindex_search_in(
+        it,
+        FfiConverterTypeFetchable.lower(`fetchable`),FfiConverterString.lower(`query`),FfiConverterUInt.lower(`limit`),_status)
+}
+    }
+    )
+    }
+    
+
+    
+
+    
+
+
+    
+    
+    /**
+     * @suppress
+     */
+    companion object
+    
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeSearchIndex: FfiConverter<SearchIndex, Long> {
+    override fun lower(value: SearchIndex): Long {
+        return value.uniffiCloneHandle()
+    }
+
+    override fun lift(value: Long): SearchIndex {
+        return SearchIndex(UniffiWithHandle, value)
+    }
+
+    override fun read(buf: ByteBuffer): SearchIndex {
+        return lift(buf.getLong())
+    }
+
+    override fun allocationSize(value: SearchIndex) = 8UL
+
+    override fun write(value: SearchIndex, buf: ByteBuffer) {
+        buf.putLong(lower(value))
+    }
+}
+
+
+
+data class AttributeTag (
+    val `key`: kotlin.String
+    , 
+    val `label`: kotlin.String
+    
+){
+    
+
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeAttributeTag: FfiConverterRustBuffer<AttributeTag> {
+    override fun read(buf: ByteBuffer): AttributeTag {
+        return AttributeTag(
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: AttributeTag) = (
+            FfiConverterString.allocationSize(value.`key`) +
+            FfiConverterString.allocationSize(value.`label`)
+    )
+
+    override fun write(value: AttributeTag, buf: ByteBuffer) {
+            FfiConverterString.write(value.`key`, buf)
+            FfiConverterString.write(value.`label`, buf)
+    }
+}
+
+
+
+data class ClientConfig (
+    val `baseUrl`: kotlin.String = "https://swapi.dev/api/" 
+    , 
+    val `maxPages`: kotlin.UInt = 50u 
+    , 
+    val `maxConcurrentRequests`: kotlin.UInt = 6u 
+    , 
+    val `retry`: RetryPolicy = RetryPolicy() 
+    , 
+    val `format`: ResponseFormat? = null 
+    
+){
+    
+
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeClientConfig: FfiConverterRustBuffer<ClientConfig> {
+    override fun read(buf: ByteBuffer): ClientConfig {
+        return ClientConfig(
+            FfiConverterString.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterTypeRetryPolicy.read(buf),
+            FfiConverterOptionalTypeResponseFormat.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: ClientConfig) = (
+            FfiConverterString.allocationSize(value.`baseUrl`) +
+            FfiConverterUInt.allocationSize(value.`maxPages`) +
+            FfiConverterUInt.allocationSize(value.`maxConcurrentRequests`) +
+            FfiConverterTypeRetryPolicy.allocationSize(value.`retry`) +
+            FfiConverterOptionalTypeResponseFormat.allocationSize(value.`format`)
+    )
+
+    override fun write(value: ClientConfig, buf: ByteBuffer) {
+            FfiConverterString.write(value.`baseUrl`, buf)
+            FfiConverterUInt.write(value.`maxPages`, buf)
+            FfiConverterUInt.write(value.`maxConcurrentRequests`, buf)
+            FfiConverterTypeRetryPolicy.write(value.`retry`, buf)
+            FfiConverterOptionalTypeResponseFormat.write(value.`format`, buf)
+    }
+}
+
+
+
+data class Consumables (
+    val `amount`: kotlin.Double
+    , 
+    val `unit`: TimeUnit
+    
+){
+     fun `days`(): kotlin.Double {
+            return FfiConverterDouble.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_consumables_days(FfiConverterTypeConsumables.lower(this),
+        _status)
+}
+    )
+    }
+    
+
+    
+
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeConsumables: FfiConverterRustBuffer<Consumables> {
+    override fun read(buf: ByteBuffer): Consumables {
+        return Consumables(
+            FfiConverterDouble.read(buf),
+            FfiConverterTypeTimeUnit.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: Consumables) = (
+            FfiConverterDouble.allocationSize(value.`amount`) +
+            FfiConverterTypeTimeUnit.allocationSize(value.`unit`)
+    )
+
+    override fun write(value: Consumables, buf: ByteBuffer) {
+            FfiConverterDouble.write(value.`amount`, buf)
+            FfiConverterTypeTimeUnit.write(value.`unit`, buf)
+    }
+}
+
+
+
+data class FieldFilter (
+    val `field`: kotlin.String
+    , 
+    val `operator`: FilterOperator
+    , 
+    val `value`: kotlin.String
+    
+){
+    
+
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeFieldFilter: FfiConverterRustBuffer<FieldFilter> {
+    override fun read(buf: ByteBuffer): FieldFilter {
+        return FieldFilter(
+            FfiConverterString.read(buf),
+            FfiConverterTypeFilterOperator.read(buf),
+            FfiConverterString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: FieldFilter) = (
+            FfiConverterString.allocationSize(value.`field`) +
+            FfiConverterTypeFilterOperator.allocationSize(value.`operator`) +
+            FfiConverterString.allocationSize(value.`value`)
+    )
+
+    override fun write(value: FieldFilter, buf: ByteBuffer) {
+            FfiConverterString.write(value.`field`, buf)
+            FfiConverterTypeFilterOperator.write(value.`operator`, buf)
+            FfiConverterString.write(value.`value`, buf)
+    }
+}
+
+
+
+data class Film (
+    val `title`: kotlin.String
+    , 
+    val `episodeId`: kotlin.Int
+    , 
+    val `openingCrawl`: kotlin.String
+    , 
+    val `director`: kotlin.String
+    , 
+    val `producer`: kotlin.String
+    , 
+    val `releaseDate`: kotlin.String
+    , 
+    val `characters`: List<kotlin.String>
+    , 
+    val `planets`: List<kotlin.String>
+    , 
+    val `starships`: List<kotlin.String>
+    , 
+    val `vehicles`: List<kotlin.String>
+    , 
+    val `species`: List<kotlin.String>
+    , 
+    val `created`: kotlin.String
+    , 
+    val `edited`: kotlin.String
+    , 
+    val `url`: kotlin.String
+    
+){
+     fun `createdAt`(): Timestamp? {
+            return FfiConverterOptionalTypeTimestamp.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_film_created_at(FfiConverterTypeFilm.lower(this),
+        _status)
+}
+    )
+    }
+    
+
+     fun `editedAt`(): Timestamp? {
+            return FfiConverterOptionalTypeTimestamp.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_film_edited_at(FfiConverterTypeFilm.lower(this),
+        _status)
+}
+    )
+    }
+    
+
+     fun `releasedAt`(): Timestamp? {
+            return FfiConverterOptionalTypeTimestamp.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_film_released_at(FfiConverterTypeFilm.lower(this),
+        _status)
+}
+    )
+    }
+    
+
+    
+
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeFilm: FfiConverterRustBuffer<Film> {
+    override fun read(buf: ByteBuffer): Film {
+        return Film(
+            FfiConverterString.read(buf),
+            FfiConverterInt.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterSequenceString.read(buf),
+            FfiConverterSequenceString.read(buf),
+            FfiConverterSequenceString.read(buf),
+            FfiConverterSequenceString.read(buf),
+            FfiConverterSequenceString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: Film) = (
+            FfiConverterString.allocationSize(value.`title`) +
+            FfiConverterInt.allocationSize(value.`episodeId`) +
+            FfiConverterString.allocationSize(value.`openingCrawl`) +
+            FfiConverterString.allocationSize(value.`director`) +
+            FfiConverterString.allocationSize(value.`producer`) +
+            FfiConverterString.allocationSize(value.`releaseDate`) +
+            FfiConverterSequenceString.allocationSize(value.`characters`) +
+            FfiConverterSequenceString.allocationSize(value.`planets`) +
+            FfiConverterSequenceString.allocationSize(value.`starships`) +
+            FfiConverterSequenceString.allocationSize(value.`vehicles`) +
+            FfiConverterSequenceString.allocationSize(value.`species`) +
+            FfiConverterString.allocationSize(value.`created`) +
+            FfiConverterString.allocationSize(value.`edited`) +
+            FfiConverterString.allocationSize(value.`url`)
+    )
+
+    override fun write(value: Film, buf: ByteBuffer) {
+            FfiConverterString.write(value.`title`, buf)
+            FfiConverterInt.write(value.`episodeId`, buf)
+            FfiConverterString.write(value.`openingCrawl`, buf)
+            FfiConverterString.write(value.`director`, buf)
+            FfiConverterString.write(value.`producer`, buf)
+            FfiConverterString.write(value.`releaseDate`, buf)
+            FfiConverterSequenceString.write(value.`characters`, buf)
+            FfiConverterSequenceString.write(value.`planets`, buf)
+            FfiConverterSequenceString.write(value.`starships`, buf)
+            FfiConverterSequenceString.write(value.`vehicles`, buf)
+            FfiConverterSequenceString.write(value.`species`, buf)
+            FfiConverterString.write(value.`created`, buf)
+            FfiConverterString.write(value.`edited`, buf)
+            FfiConverterString.write(value.`url`, buf)
+    }
+}
+
+
+
+data class GalacticYear (
+    val `years`: kotlin.Double
+    
+){
+    
+
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeGalacticYear: FfiConverterRustBuffer<GalacticYear> {
+    override fun read(buf: ByteBuffer): GalacticYear {
+        return GalacticYear(
+            FfiConverterDouble.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: GalacticYear) = (
+            FfiConverterDouble.allocationSize(value.`years`)
+    )
+
+    override fun write(value: GalacticYear, buf: ByteBuffer) {
+            FfiConverterDouble.write(value.`years`, buf)
+    }
+}
+
+
+
+data class HttpRequest (
+    val `url`: kotlin.String
+    , 
+    val `headers`: Map<kotlin.String, kotlin.String>
+    , 
+    val `cancellation`: CancellationToken
+    
+): Disposable{
+    
+
+    
+
+    
+    @Suppress("UNNECESSARY_SAFE_CALL") // codegen is much simpler if we unconditionally emit safe calls here
+    override fun destroy() {
+        
+    Disposable.destroy(
+        this.`url`,
+        this.`headers`,
+        this.`cancellation`
+    )
+    }
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeHttpRequest: FfiConverterRustBuffer<HttpRequest> {
+    override fun read(buf: ByteBuffer): HttpRequest {
+        return HttpRequest(
+            FfiConverterString.read(buf),
+            FfiConverterMapStringString.read(buf),
+            FfiConverterTypeCancellationToken.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: HttpRequest) = (
+            FfiConverterString.allocationSize(value.`url`) +
+            FfiConverterMapStringString.allocationSize(value.`headers`) +
+            FfiConverterTypeCancellationToken.allocationSize(value.`cancellation`)
+    )
+
+    override fun write(value: HttpRequest, buf: ByteBuffer) {
+            FfiConverterString.write(value.`url`, buf)
+            FfiConverterMapStringString.write(value.`headers`, buf)
+            FfiConverterTypeCancellationToken.write(value.`cancellation`, buf)
+    }
+}
+
+
+
+data class HttpResponse (
+    val `status`: kotlin.UShort
+    , 
+    val `headers`: Map<kotlin.String, kotlin.String>
+    , 
+    val `body`: kotlin.ByteArray
+    
+){
+    
+
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeHttpResponse: FfiConverterRustBuffer<HttpResponse> {
+    override fun read(buf: ByteBuffer): HttpResponse {
+        return HttpResponse(
+            FfiConverterUShort.read(buf),
+            FfiConverterMapStringString.read(buf),
+            FfiConverterByteArray.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: HttpResponse) = (
+            FfiConverterUShort.allocationSize(value.`status`) +
+            FfiConverterMapStringString.allocationSize(value.`headers`) +
+            FfiConverterByteArray.allocationSize(value.`body`)
+    )
+
+    override fun write(value: HttpResponse, buf: ByteBuffer) {
+            FfiConverterUShort.write(value.`status`, buf)
+            FfiConverterMapStringString.write(value.`headers`, buf)
+            FfiConverterByteArray.write(value.`body`, buf)
+    }
+}
+
+
+
+data class NumericRange (
+    val `min`: kotlin.Double
+    , 
+    val `max`: kotlin.Double
+    
+){
+    
+
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeNumericRange: FfiConverterRustBuffer<NumericRange> {
+    override fun read(buf: ByteBuffer): NumericRange {
+        return NumericRange(
+            FfiConverterDouble.read(buf),
+            FfiConverterDouble.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: NumericRange) = (
+            FfiConverterDouble.allocationSize(value.`min`) +
+            FfiConverterDouble.allocationSize(value.`max`)
+    )
+
+    override fun write(value: NumericRange, buf: ByteBuffer) {
+            FfiConverterDouble.write(value.`min`, buf)
+            FfiConverterDouble.write(value.`max`, buf)
+    }
+}
+
+
+
+data class Person (
+    val `birthYear`: kotlin.String
+    , 
+    val `eyeColor`: kotlin.String
+    , 
+    val `films`: List<kotlin.String>
+    , 
+    val `gender`: kotlin.String
+    , 
+    val `hairColor`: kotlin.String
+    , 
+    val `height`: kotlin.String
+    , 
+    val `homeworld`: kotlin.String?
+    , 
+    val `mass`: kotlin.String
+    , 
+    val `name`: kotlin.String
+    , 
+    val `skinColor`: kotlin.String
+    , 
+    val `created`: kotlin.String
+    , 
+    val `edited`: kotlin.String
+    , 
+    val `species`: List<kotlin.String>
+    , 
+    val `starships`: List<kotlin.String>
+    , 
+    val `url`: kotlin.String
+    , 
+    val `vehicles`: List<kotlin.String>
+    
+){
+     fun `birthYearValue`(): GalacticYear? {
+            return FfiConverterOptionalTypeGalacticYear.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_person_birth_year_value(FfiConverterTypePerson.lower(this),
+        _status)
+}
+    )
+    }
+    
+
+     fun `createdAt`(): Timestamp? {
+            return FfiConverterOptionalTypeTimestamp.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_person_created_at(FfiConverterTypePerson.lower(this),
+        _status)
+}
+    )
+    }
+    
+
+     fun `editedAt`(): Timestamp? {
+            return FfiConverterOptionalTypeTimestamp.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_person_edited_at(FfiConverterTypePerson.lower(this),
+        _status)
+}
+    )
+    }
+    
+
+     fun `heightValue`(): kotlin.Double? {
+            return FfiConverterOptionalDouble.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_person_height_value(FfiConverterTypePerson.lower(this),
+        _status)
+}
+    )
+    }
+    
+
+     fun `massValue`(): kotlin.Double? {
+            return FfiConverterOptionalDouble.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_person_mass_value(FfiConverterTypePerson.lower(this),
+        _status)
+}
+    )
+    }
+    
+
+    
+
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypePerson: FfiConverterRustBuffer<Person> {
+    override fun read(buf: ByteBuffer): Person {
+        return Person(
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterSequenceString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterSequenceString.read(buf),
+            FfiConverterSequenceString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterSequenceString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: Person) = (
+            FfiConverterString.allocationSize(value.`birthYear`) +
+            FfiConverterString.allocationSize(value.`eyeColor`) +
+            FfiConverterSequenceString.allocationSize(value.`films`) +
+            FfiConverterString.allocationSize(value.`gender`) +
+            FfiConverterString.allocationSize(value.`hairColor`) +
+            FfiConverterString.allocationSize(value.`height`) +
+            FfiConverterOptionalString.allocationSize(value.`homeworld`) +
+            FfiConverterString.allocationSize(value.`mass`) +
+            FfiConverterString.allocationSize(value.`name`) +
+            FfiConverterString.allocationSize(value.`skinColor`) +
+            FfiConverterString.allocationSize(value.`created`) +
+            FfiConverterString.allocationSize(value.`edited`) +
+            FfiConverterSequenceString.allocationSize(value.`species`) +
+            FfiConverterSequenceString.allocationSize(value.`starships`) +
+            FfiConverterString.allocationSize(value.`url`) +
+            FfiConverterSequenceString.allocationSize(value.`vehicles`)
+    )
+
+    override fun write(value: Person, buf: ByteBuffer) {
+            FfiConverterString.write(value.`birthYear`, buf)
+            FfiConverterString.write(value.`eyeColor`, buf)
+            FfiConverterSequenceString.write(value.`films`, buf)
+            FfiConverterString.write(value.`gender`, buf)
+            FfiConverterString.write(value.`hairColor`, buf)
+            FfiConverterString.write(value.`height`, buf)
+            FfiConverterOptionalString.write(value.`homeworld`, buf)
+            FfiConverterString.write(value.`mass`, buf)
+            FfiConverterString.write(value.`name`, buf)
+            FfiConverterString.write(value.`skinColor`, buf)
+            FfiConverterString.write(value.`created`, buf)
+            FfiConverterString.write(value.`edited`, buf)
+            FfiConverterSequenceString.write(value.`species`, buf)
+            FfiConverterSequenceString.write(value.`starships`, buf)
+            FfiConverterString.write(value.`url`, buf)
+            FfiConverterSequenceString.write(value.`vehicles`, buf)
+    }
+}
+
+
+
+data class Planet (
+    val `name`: kotlin.String
+    , 
+    val `rotationPeriod`: kotlin.String
+    , 
+    val `orbitalPeriod`: kotlin.String
+    , 
+    val `diameter`: kotlin.String
+    , 
+    val `climate`: kotlin.String
+    , 
+    val `gravity`: kotlin.String
+    , 
+    val `terrain`: kotlin.String
+    , 
+    val `surfaceWater`: kotlin.String
+    , 
+    val `population`: kotlin.String
+    , 
+    val `residents`: List<kotlin.String>
+    , 
+    val `films`: List<kotlin.String>
+    , 
+    val `created`: kotlin.String
+    , 
+    val `edited`: kotlin.String
+    , 
+    val `url`: kotlin.String
+    
+){
+     fun `climateTags`(): List<AttributeTag> {
+            return FfiConverterSequenceTypeAttributeTag.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_planet_climate_tags(FfiConverterTypePlanet.lower(this),
+        _status)
+}
+    )
+    }
+    
+
+     fun `createdAt`(): Timestamp? {
+            return FfiConverterOptionalTypeTimestamp.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_planet_created_at(FfiConverterTypePlanet.lower(this),
+        _status)
+}
+    )
+    }
+    
+
+     fun `diameterValue`(): kotlin.Double? {
+            return FfiConverterOptionalDouble.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_planet_diameter_value(FfiConverterTypePlanet.lower(this),
+        _status)
+}
+    )
+    }
+    
+
+     fun `editedAt`(): Timestamp? {
+            return FfiConverterOptionalTypeTimestamp.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_planet_edited_at(FfiConverterTypePlanet.lower(this),
+        _status)
 }
+    )
+    }
+    
 
-internal val uniffiForeignFutureHandleMap = UniffiHandleMap<Job>()
+     fun `orbitalPeriodValue`(): kotlin.Double? {
+            return FfiConverterOptionalDouble.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_planet_orbital_period_value(FfiConverterTypePlanet.lower(this),
+        _status)
+}
+    )
+    }
+    
 
-internal object uniffiForeignFutureDroppedCallbackImpl: UniffiForeignFutureDroppedCallback {
-    override fun callback(handle: Long) {
-        val job = uniffiForeignFutureHandleMap.remove(handle)
-        if (!job.isCompleted) {
-            job.cancel()
-        }
+     fun `populationValue`(): kotlin.ULong? {
+            return FfiConverterOptionalULong.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_planet_population_value(FfiConverterTypePlanet.lower(this),
+        _status)
+}
+    )
     }
+    
+
+     fun `rotationPeriodValue`(): kotlin.Double? {
+            return FfiConverterOptionalDouble.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_planet_rotation_period_value(FfiConverterTypePlanet.lower(this),
+        _status)
 }
+    )
+    }
+    
 
-// For testing
-public fun uniffiForeignFutureHandleCount() = uniffiForeignFutureHandleMap.size
+     fun `surfaceWaterValue`(): kotlin.Double? {
+            return FfiConverterOptionalDouble.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_planet_surface_water_value(FfiConverterTypePlanet.lower(this),
+        _status)
+}
+    )
+    }
+    
 
-// Public interface members begin here.
+     fun `terrainTags`(): List<AttributeTag> {
+            return FfiConverterSequenceTypeAttributeTag.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_planet_terrain_tags(FfiConverterTypePlanet.lower(this),
+        _status)
+}
+    )
+    }
+    
 
+    
 
-// Interface implemented by anything that can contain an object reference.
-//
-// Such types expose a `destroy()` method that must be called to cleanly
-// dispose of the contained objects. Failure to call this method may result
-// in memory leaks.
-//
-// The easiest way to ensure this method is called is to use the `.use`
-// helper method to execute a block and destroy the object at the end.
-interface Disposable {
-    fun destroy()
-    companion object {
-        fun destroy(vararg args: Any?) {
-            for (arg in args) {
-                when (arg) {
-                    is Disposable -> arg.destroy()
-                    is ArrayList<*> -> {
-                        for (idx in arg.indices) {
-                            val element = arg[idx]
-                            if (element is Disposable) {
-                                element.destroy()
-                            }
-                        }
-                    }
-                    is Map<*, *> -> {
-                        for (element in arg.values) {
-                            if (element is Disposable) {
-                                element.destroy()
-                            }
-                        }
-                    }
-                    is Iterable<*> -> {
-                        for (element in arg) {
-                            if (element is Disposable) {
-                                element.destroy()
-                            }
-                        }
-                    }
-                }
-            }
-        }
-    }
+    
+
+    
+    companion object
 }
 
 /**
  * @suppress
  */
-inline fun <T : Disposable?, R> T.use(block: (T) -> R) =
-    try {
-        block(this)
-    } finally {
-        try {
-            // N.B. our implementation is on the nullable type `Disposable?`.
-            this?.destroy()
-        } catch (e: Throwable) {
-            // swallow
-        }
+public object FfiConverterTypePlanet: FfiConverterRustBuffer<Planet> {
+    override fun read(buf: ByteBuffer): Planet {
+        return Planet(
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterSequenceString.read(buf),
+            FfiConverterSequenceString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+        )
     }
 
-/** 
- * Placeholder object used to signal that we're constructing an interface with a FFI handle.
- *
- * This is the first argument for interface constructors that input a raw handle. It exists is that
- * so we can avoid signature conflicts when an interface has a regular constructor than inputs a
- * Long.
- *
- * @suppress
- * */
-object UniffiWithHandle
+    override fun allocationSize(value: Planet) = (
+            FfiConverterString.allocationSize(value.`name`) +
+            FfiConverterString.allocationSize(value.`rotationPeriod`) +
+            FfiConverterString.allocationSize(value.`orbitalPeriod`) +
+            FfiConverterString.allocationSize(value.`diameter`) +
+            FfiConverterString.allocationSize(value.`climate`) +
+            FfiConverterString.allocationSize(value.`gravity`) +
+            FfiConverterString.allocationSize(value.`terrain`) +
+            FfiConverterString.allocationSize(value.`surfaceWater`) +
+            FfiConverterString.allocationSize(value.`population`) +
+            FfiConverterSequenceString.allocationSize(value.`residents`) +
+            FfiConverterSequenceString.allocationSize(value.`films`) +
+            FfiConverterString.allocationSize(value.`created`) +
+            FfiConverterString.allocationSize(value.`edited`) +
+            FfiConverterString.allocationSize(value.`url`)
+    )
 
-/** 
- * Used to instantiate an interface without an actual pointer, for fakes in tests, mostly.
- *
+    override fun write(value: Planet, buf: ByteBuffer) {
+            FfiConverterString.write(value.`name`, buf)
+            FfiConverterString.write(value.`rotationPeriod`, buf)
+            FfiConverterString.write(value.`orbitalPeriod`, buf)
+            FfiConverterString.write(value.`diameter`, buf)
+            FfiConverterString.write(value.`climate`, buf)
+            FfiConverterString.write(value.`gravity`, buf)
+            FfiConverterString.write(value.`terrain`, buf)
+            FfiConverterString.write(value.`surfaceWater`, buf)
+            FfiConverterString.write(value.`population`, buf)
+            FfiConverterSequenceString.write(value.`residents`, buf)
+            FfiConverterSequenceString.write(value.`films`, buf)
+            FfiConverterString.write(value.`created`, buf)
+            FfiConverterString.write(value.`edited`, buf)
+            FfiConverterString.write(value.`url`, buf)
+    }
+}
+
+
+
+data class RelatedGroup (
+    val `relationship`: Relationship
+    , 
+    val `items`: List<Selected>
+    , 
+    val `failedUrls`: List<kotlin.String>
+    
+){
+    
+
+    
+
+    
+    companion object
+}
+
+/**
  * @suppress
- * */
-object NoHandle// Magic number for the Rust proxy to call using the same mechanism as every other method,
-// to free the callback once it's dropped by Rust.
-internal const val IDX_CALLBACK_FREE = 0
-// Callback return codes
-internal const val UNIFFI_CALLBACK_SUCCESS = 0
-internal const val UNIFFI_CALLBACK_ERROR = 1
-internal const val UNIFFI_CALLBACK_UNEXPECTED_ERROR = 2
+ */
+public object FfiConverterTypeRelatedGroup: FfiConverterRustBuffer<RelatedGroup> {
+    override fun read(buf: ByteBuffer): RelatedGroup {
+        return RelatedGroup(
+            FfiConverterTypeRelationship.read(buf),
+            FfiConverterSequenceTypeSelected.read(buf),
+            FfiConverterSequenceString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: RelatedGroup) = (
+            FfiConverterTypeRelationship.allocationSize(value.`relationship`) +
+            FfiConverterSequenceTypeSelected.allocationSize(value.`items`) +
+            FfiConverterSequenceString.allocationSize(value.`failedUrls`)
+    )
+
+    override fun write(value: RelatedGroup, buf: ByteBuffer) {
+            FfiConverterTypeRelationship.write(value.`relationship`, buf)
+            FfiConverterSequenceTypeSelected.write(value.`items`, buf)
+            FfiConverterSequenceString.write(value.`failedUrls`, buf)
+    }
+}
+
+
+
+data class RepositoryChange (
+    val `kind`: ChangeKind
+    , 
+    val `fetchable`: Fetchable
+    , 
+    val `ids`: List<kotlin.UInt>
+    
+){
+    
+
+    
+
+    
+    companion object
+}
 
 /**
  * @suppress
  */
-public abstract class FfiConverterCallbackInterface<CallbackInterface: Any>: FfiConverter<CallbackInterface, Long> {
-    internal val handleMap = UniffiHandleMap<CallbackInterface>()
+public object FfiConverterTypeRepositoryChange: FfiConverterRustBuffer<RepositoryChange> {
+    override fun read(buf: ByteBuffer): RepositoryChange {
+        return RepositoryChange(
+            FfiConverterTypeChangeKind.read(buf),
+            FfiConverterTypeFetchable.read(buf),
+            FfiConverterSequenceUInt.read(buf),
+        )
+    }
 
-    internal fun drop(handle: Long) {
-        handleMap.remove(handle)
+    override fun allocationSize(value: RepositoryChange) = (
+            FfiConverterTypeChangeKind.allocationSize(value.`kind`) +
+            FfiConverterTypeFetchable.allocationSize(value.`fetchable`) +
+            FfiConverterSequenceUInt.allocationSize(value.`ids`)
+    )
+
+    override fun write(value: RepositoryChange, buf: ByteBuffer) {
+            FfiConverterTypeChangeKind.write(value.`kind`, buf)
+            FfiConverterTypeFetchable.write(value.`fetchable`, buf)
+            FfiConverterSequenceUInt.write(value.`ids`, buf)
     }
+}
 
-    override fun lift(value: Long): CallbackInterface {
-        return handleMap.get(value)
+
+
+data class RetryPolicy (
+    val `maxAttempts`: kotlin.UInt = 3u 
+    , 
+    val `baseDelayMs`: kotlin.ULong = 500uL 
+    , 
+    val `maxDelayMs`: kotlin.ULong = 10000uL 
+    , 
+    val `jitter`: kotlin.Double = 0.2 
+    
+){
+    
+
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeRetryPolicy: FfiConverterRustBuffer<RetryPolicy> {
+    override fun read(buf: ByteBuffer): RetryPolicy {
+        return RetryPolicy(
+            FfiConverterUInt.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterDouble.read(buf),
+        )
     }
 
-    override fun read(buf: ByteBuffer) = lift(buf.getLong())
+    override fun allocationSize(value: RetryPolicy) = (
+            FfiConverterUInt.allocationSize(value.`maxAttempts`) +
+            FfiConverterULong.allocationSize(value.`baseDelayMs`) +
+            FfiConverterULong.allocationSize(value.`maxDelayMs`) +
+            FfiConverterDouble.allocationSize(value.`jitter`)
+    )
+
+    override fun write(value: RetryPolicy, buf: ByteBuffer) {
+            FfiConverterUInt.write(value.`maxAttempts`, buf)
+            FfiConverterULong.write(value.`baseDelayMs`, buf)
+            FfiConverterULong.write(value.`maxDelayMs`, buf)
+            FfiConverterDouble.write(value.`jitter`, buf)
+    }
+}
+
+
 
-    override fun lower(value: CallbackInterface) = handleMap.insert(value)
+data class SortKey (
+    val `field`: kotlin.String
+    , 
+    val `direction`: SortDirection
+    
+){
+    
 
-    override fun allocationSize(value: CallbackInterface) = 8UL
+    
 
-    override fun write(value: CallbackInterface, buf: ByteBuffer) {
-        buf.putLong(lower(value))
-    }
+    
+    companion object
 }
+
 /**
- * The cleaner interface for Object finalization code to run.
- * This is the entry point to any implementation that we're using.
- *
- * The cleaner registers objects and returns cleanables, so now we are
- * defining a `UniffiCleaner` with a `UniffiClenaer.Cleanable` to abstract the
- * different implmentations available at compile time.
- *
  * @suppress
  */
-interface UniffiCleaner {
-    interface Cleanable {
-        fun clean()
+public object FfiConverterTypeSortKey: FfiConverterRustBuffer<SortKey> {
+    override fun read(buf: ByteBuffer): SortKey {
+        return SortKey(
+            FfiConverterString.read(buf),
+            FfiConverterTypeSortDirection.read(buf),
+        )
     }
 
-    fun register(value: Any, cleanUpTask: Runnable): UniffiCleaner.Cleanable
+    override fun allocationSize(value: SortKey) = (
+            FfiConverterString.allocationSize(value.`field`) +
+            FfiConverterTypeSortDirection.allocationSize(value.`direction`)
+    )
 
-    companion object
+    override fun write(value: SortKey, buf: ByteBuffer) {
+            FfiConverterString.write(value.`field`, buf)
+            FfiConverterTypeSortDirection.write(value.`direction`, buf)
+    }
 }
 
-// The fallback Jna cleaner, which is available for both Android, and the JVM.
-private class UniffiJnaCleaner : UniffiCleaner {
-    private val cleaner = com.sun.jna.internal.Cleaner.getCleaner()
 
-    override fun register(value: Any, cleanUpTask: Runnable): UniffiCleaner.Cleanable =
-        UniffiJnaCleanable(cleaner.register(value, cleanUpTask))
+
+data class Species (
+    val `name`: kotlin.String
+    , 
+    val `classification`: kotlin.String
+    , 
+    val `designation`: kotlin.String
+    , 
+    val `averageHeight`: kotlin.String
+    , 
+    val `skinColors`: kotlin.String
+    , 
+    val `hairColors`: kotlin.String
+    , 
+    val `eyeColors`: kotlin.String
+    , 
+    val `averageLifespan`: kotlin.String
+    , 
+    val `homeworld`: kotlin.String?
+    , 
+    val `language`: kotlin.String
+    , 
+    val `people`: List<kotlin.String>
+    , 
+    val `films`: List<kotlin.String>
+    , 
+    val `created`: kotlin.String
+    , 
+    val `edited`: kotlin.String
+    , 
+    val `url`: kotlin.String
+    
+){
+     fun `averageHeightValue`(): kotlin.Double? {
+            return FfiConverterOptionalDouble.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_species_average_height_value(FfiConverterTypeSpecies.lower(this),
+        _status)
 }
+    )
+    }
+    
 
-private class UniffiJnaCleanable(
-    private val cleanable: com.sun.jna.internal.Cleaner.Cleanable,
-) : UniffiCleaner.Cleanable {
-    override fun clean() = cleanable.clean()
+     fun `averageLifespanValue`(): kotlin.Double? {
+            return FfiConverterOptionalDouble.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_species_average_lifespan_value(FfiConverterTypeSpecies.lower(this),
+        _status)
 }
+    )
+    }
+    
 
+     fun `createdAt`(): Timestamp? {
+            return FfiConverterOptionalTypeTimestamp.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_species_created_at(FfiConverterTypeSpecies.lower(this),
+        _status)
+}
+    )
+    }
+    
 
-// We decide at uniffi binding generation time whether we were
-// using Android or not.
-// There are further runtime checks to chose the correct implementation
-// of the cleaner.
-private fun UniffiCleaner.Companion.create(): UniffiCleaner =
-    try {
-        // For safety's sake: if the library hasn't been run in android_cleaner = true
-        // mode, but is being run on Android, then we still need to think about
-        // Android API versions.
-        // So we check if java.lang.ref.Cleaner is there, and use that…
-        java.lang.Class.forName("java.lang.ref.Cleaner")
-        JavaLangRefCleaner()
-    } catch (e: ClassNotFoundException) {
-        // … otherwise, fallback to the JNA cleaner.
-        UniffiJnaCleaner()
+     fun `editedAt`(): Timestamp? {
+            return FfiConverterOptionalTypeTimestamp.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_species_edited_at(FfiConverterTypeSpecies.lower(this),
+        _status)
+}
+    )
     }
+    
 
-private class JavaLangRefCleaner : UniffiCleaner {
-    val cleaner = java.lang.ref.Cleaner.create()
+     fun `eyeColorTags`(): List<AttributeTag> {
+            return FfiConverterSequenceTypeAttributeTag.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_species_eye_color_tags(FfiConverterTypeSpecies.lower(this),
+        _status)
+}
+    )
+    }
+    
 
-    override fun register(value: Any, cleanUpTask: Runnable): UniffiCleaner.Cleanable =
-        JavaLangRefCleanable(cleaner.register(value, cleanUpTask))
+     fun `hairColorTags`(): List<AttributeTag> {
+            return FfiConverterSequenceTypeAttributeTag.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_species_hair_color_tags(FfiConverterTypeSpecies.lower(this),
+        _status)
 }
+    )
+    }
+    
 
-private class JavaLangRefCleanable(
-    val cleanable: java.lang.ref.Cleaner.Cleanable
-) : UniffiCleaner.Cleanable {
-    override fun clean() = cleanable.clean()
+     fun `skinColorTags`(): List<AttributeTag> {
+            return FfiConverterSequenceTypeAttributeTag.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_species_skin_color_tags(FfiConverterTypeSpecies.lower(this),
+        _status)
+}
+    )
+    }
+    
+
+    
+
+    
+
+    
+    companion object
 }
 
 /**
  * @suppress
  */
-public object FfiConverterInt: FfiConverter<Int, Int> {
-    override fun lift(value: Int): Int {
-        return value
+public object FfiConverterTypeSpecies: FfiConverterRustBuffer<Species> {
+    override fun read(buf: ByteBuffer): Species {
+        return Species(
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterSequenceString.read(buf),
+            FfiConverterSequenceString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+        )
     }
 
-    override fun read(buf: ByteBuffer): Int {
-        return buf.getInt()
-    }
+    override fun allocationSize(value: Species) = (
+            FfiConverterString.allocationSize(value.`name`) +
+            FfiConverterString.allocationSize(value.`classification`) +
+            FfiConverterString.allocationSize(value.`designation`) +
+            FfiConverterString.allocationSize(value.`averageHeight`) +
+            FfiConverterString.allocationSize(value.`skinColors`) +
+            FfiConverterString.allocationSize(value.`hairColors`) +
+            FfiConverterString.allocationSize(value.`eyeColors`) +
+            FfiConverterString.allocationSize(value.`averageLifespan`) +
+            FfiConverterOptionalString.allocationSize(value.`homeworld`) +
+            FfiConverterString.allocationSize(value.`language`) +
+            FfiConverterSequenceString.allocationSize(value.`people`) +
+            FfiConverterSequenceString.allocationSize(value.`films`) +
+            FfiConverterString.allocationSize(value.`created`) +
+            FfiConverterString.allocationSize(value.`edited`) +
+            FfiConverterString.allocationSize(value.`url`)
+    )
 
-    override fun lower(value: Int): Int {
-        return value
+    override fun write(value: Species, buf: ByteBuffer) {
+            FfiConverterString.write(value.`name`, buf)
+            FfiConverterString.write(value.`classification`, buf)
+            FfiConverterString.write(value.`designation`, buf)
+            FfiConverterString.write(value.`averageHeight`, buf)
+            FfiConverterString.write(value.`skinColors`, buf)
+            FfiConverterString.write(value.`hairColors`, buf)
+            FfiConverterString.write(value.`eyeColors`, buf)
+            FfiConverterString.write(value.`averageLifespan`, buf)
+            FfiConverterOptionalString.write(value.`homeworld`, buf)
+            FfiConverterString.write(value.`language`, buf)
+            FfiConverterSequenceString.write(value.`people`, buf)
+            FfiConverterSequenceString.write(value.`films`, buf)
+            FfiConverterString.write(value.`created`, buf)
+            FfiConverterString.write(value.`edited`, buf)
+            FfiConverterString.write(value.`url`, buf)
     }
+}
 
-    override fun allocationSize(value: Int) = 4UL
 
-    override fun write(value: Int, buf: ByteBuffer) {
-        buf.putInt(value)
-    }
+
+data class Starship (
+    val `name`: kotlin.String
+    , 
+    val `model`: kotlin.String
+    , 
+    val `manufacturer`: kotlin.String
+    , 
+    val `costInCredits`: kotlin.String
+    , 
+    val `length`: kotlin.String
+    , 
+    val `maxAtmospheringSpeed`: kotlin.String
+    , 
+    val `crew`: kotlin.String
+    , 
+    val `passengers`: kotlin.String
+    , 
+    val `cargoCapacity`: kotlin.String
+    , 
+    val `consumables`: kotlin.String
+    , 
+    val `hyperdriveRating`: kotlin.String
+    , 
+    val `mglt`: kotlin.String
+    , 
+    val `starshipClass`: kotlin.String
+    , 
+    val `pilots`: List<kotlin.String>
+    , 
+    val `films`: List<kotlin.String>
+    , 
+    val `created`: kotlin.String
+    , 
+    val `edited`: kotlin.String
+    , 
+    val `url`: kotlin.String
+    
+){
+     fun `cargoCapacityValue`(): kotlin.ULong? {
+            return FfiConverterOptionalULong.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_starship_cargo_capacity_value(FfiConverterTypeStarship.lower(this),
+        _status)
 }
+    )
+    }
+    
 
-/**
- * @suppress
- */
-public object FfiConverterBoolean: FfiConverter<Boolean, Byte> {
-    override fun lift(value: Byte): Boolean {
-        return value.toInt() != 0
+     fun `consumablesValue`(): Consumables? {
+            return FfiConverterOptionalTypeConsumables.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_starship_consumables_value(FfiConverterTypeStarship.lower(this),
+        _status)
+}
+    )
     }
+    
 
-    override fun read(buf: ByteBuffer): Boolean {
-        return lift(buf.get())
+     fun `costInCreditsValue`(): kotlin.ULong? {
+            return FfiConverterOptionalULong.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_starship_cost_in_credits_value(FfiConverterTypeStarship.lower(this),
+        _status)
+}
+    )
     }
+    
 
-    override fun lower(value: Boolean): Byte {
-        return if (value) 1.toByte() else 0.toByte()
+     fun `createdAt`(): Timestamp? {
+            return FfiConverterOptionalTypeTimestamp.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_starship_created_at(FfiConverterTypeStarship.lower(this),
+        _status)
+}
+    )
     }
+    
 
-    override fun allocationSize(value: Boolean) = 1UL
-
-    override fun write(value: Boolean, buf: ByteBuffer) {
-        buf.put(lower(value))
+     fun `crewRange`(): NumericRange? {
+            return FfiConverterOptionalTypeNumericRange.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_starship_crew_range(FfiConverterTypeStarship.lower(this),
+        _status)
+}
+    )
     }
+    
+
+     fun `editedAt`(): Timestamp? {
+            return FfiConverterOptionalTypeTimestamp.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_starship_edited_at(FfiConverterTypeStarship.lower(this),
+        _status)
 }
+    )
+    }
+    
 
-/**
- * @suppress
- */
-public object FfiConverterString: FfiConverter<String, RustBuffer.ByValue> {
-    // Note: we don't inherit from FfiConverterRustBuffer, because we use a
-    // special encoding when lowering/lifting.  We can use `RustBuffer.len` to
-    // store our length and avoid writing it out to the buffer.
-    override fun lift(value: RustBuffer.ByValue): String {
-        try {
-            val byteArr = ByteArray(value.len.toInt())
-            value.asByteBuffer()!!.get(byteArr)
-            return byteArr.toString(Charsets.UTF_8)
-        } finally {
-            RustBuffer.free(value)
-        }
+     fun `hyperdriveRatingValue`(): kotlin.Double? {
+            return FfiConverterOptionalDouble.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_starship_hyperdrive_rating_value(FfiConverterTypeStarship.lower(this),
+        _status)
+}
+    )
     }
+    
 
-    override fun read(buf: ByteBuffer): String {
-        val len = buf.getInt()
-        val byteArr = ByteArray(len)
-        buf.get(byteArr)
-        return byteArr.toString(Charsets.UTF_8)
+     fun `lengthValue`(): kotlin.Double? {
+            return FfiConverterOptionalDouble.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_starship_length_value(FfiConverterTypeStarship.lower(this),
+        _status)
+}
+    )
     }
+    
 
-    fun toUtf8(value: String): ByteBuffer {
-        // Make sure we don't have invalid UTF-16, check for lone surrogates.
-        return Charsets.UTF_8.newEncoder().run {
-            onMalformedInput(CodingErrorAction.REPORT)
-            encode(CharBuffer.wrap(value))
-        }
+     fun `manufacturerTags`(): List<AttributeTag> {
+            return FfiConverterSequenceTypeAttributeTag.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_starship_manufacturer_tags(FfiConverterTypeStarship.lower(this),
+        _status)
+}
+    )
     }
+    
 
-    override fun lower(value: String): RustBuffer.ByValue {
-        val byteBuf = toUtf8(value)
-        // Ideally we'd pass these bytes to `ffi_bytebuffer_from_bytes`, but doing so would require us
-        // to copy them into a JNA `Memory`. So we might as well directly copy them into a `RustBuffer`.
-        val rbuf = RustBuffer.alloc(byteBuf.limit().toULong())
-        rbuf.asByteBuffer()!!.put(byteBuf)
-        return rbuf
+     fun `maxAtmospheringSpeedValue`(): kotlin.Double? {
+            return FfiConverterOptionalDouble.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_starship_max_atmosphering_speed_value(FfiConverterTypeStarship.lower(this),
+        _status)
+}
+    )
     }
+    
 
-    // We aren't sure exactly how many bytes our string will be once it's UTF-8
-    // encoded.  Allocate 3 bytes per UTF-16 code unit which will always be
-    // enough.
-    override fun allocationSize(value: String): ULong {
-        val sizeForLength = 4UL
-        val sizeForString = value.length.toULong() * 3UL
-        return sizeForLength + sizeForString
+     fun `mgltValue`(): kotlin.Double? {
+            return FfiConverterOptionalDouble.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_starship_mglt_value(FfiConverterTypeStarship.lower(this),
+        _status)
+}
+    )
     }
+    
 
-    override fun write(value: String, buf: ByteBuffer) {
-        val byteBuf = toUtf8(value)
-        buf.putInt(byteBuf.limit())
-        buf.put(byteBuf)
+     fun `passengersRange`(): NumericRange? {
+            return FfiConverterOptionalTypeNumericRange.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_starship_passengers_range(FfiConverterTypeStarship.lower(this),
+        _status)
+}
+    )
     }
+    
+
+    
+
+    
+
+    
+    companion object
 }
 
 /**
  * @suppress
  */
-public object FfiConverterByteArray: FfiConverterRustBuffer<ByteArray> {
-    override fun read(buf: ByteBuffer): ByteArray {
-        val len = buf.getInt()
-        val byteArr = ByteArray(len)
-        buf.get(byteArr)
-        return byteArr
-    }
-    override fun allocationSize(value: ByteArray): ULong {
-        return 4UL + value.size.toULong()
-    }
-    override fun write(value: ByteArray, buf: ByteBuffer) {
-        buf.putInt(value.size)
-        buf.put(value)
+public object FfiConverterTypeStarship: FfiConverterRustBuffer<Starship> {
+    override fun read(buf: ByteBuffer): Starship {
+        return Starship(
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterSequenceString.read(buf),
+            FfiConverterSequenceString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+        )
     }
-}
-
 
-// This template implements a class for working with a Rust struct via a handle
-// to the live Rust struct on the other side of the FFI.
-//
-// There's some subtlety here, because we have to be careful not to operate on a Rust
-// struct after it has been dropped, and because we must expose a public API for freeing
-// theq Kotlin wrapper object in lieu of reliable finalizers. The core requirements are:
-//
-//   * Each instance holds an opaque handle to the underlying Rust struct.
-//     Method calls need to read this handle from the object's state and pass it in to
-//     the Rust FFI.
-//
-//   * When an instance is no longer needed, its handle should be passed to a
-//     special destructor function provided by the Rust FFI, which will drop the
-//     underlying Rust struct.
-//
-//   * Given an instance, calling code is expected to call the special
-//     `destroy` method in order to free it after use, either by calling it explicitly
-//     or by using a higher-level helper like the `use` method. Failing to do so risks
-//     leaking the underlying Rust struct.
-//
-//   * We can't assume that calling code will do the right thing, and must be prepared
-//     to handle Kotlin method calls executing concurrently with or even after a call to
-//     `destroy`, and to handle multiple (possibly concurrent!) calls to `destroy`.
-//
-//   * We must never allow Rust code to operate on the underlying Rust struct after
-//     the destructor has been called, and must never call the destructor more than once.
-//     Doing so may trigger memory unsafety.
-//
-//   * To mitigate many of the risks of leaking memory and use-after-free unsafety, a `Cleaner`
-//     is implemented to call the destructor when the Kotlin object becomes unreachable.
-//     This is done in a background thread. This is not a panacea, and client code should be aware that
-//      1. the thread may starve if some there are objects that have poorly performing
-//     `drop` methods or do significant work in their `drop` methods.
-//      2. the thread is shared across the whole library. This can be tuned by using `android_cleaner = true`,
-//         or `android = true` in the [`kotlin` section of the `uniffi.toml` file](https://mozilla.github.io/uniffi-rs/kotlin/configuration.html).
-//
-// If we try to implement this with mutual exclusion on access to the handle, there is the
-// possibility of a race between a method call and a concurrent call to `destroy`:
-//
-//    * Thread A starts a method call, reads the value of the handle, but is interrupted
-//      before it can pass the handle over the FFI to Rust.
-//    * Thread B calls `destroy` and frees the underlying Rust struct.
-//    * Thread A resumes, passing the already-read handle value to Rust and triggering
-//      a use-after-free.
-//
-// One possible solution would be to use a `ReadWriteLock`, with each method call taking
-// a read lock (and thus allowed to run concurrently) and the special `destroy` method
-// taking a write lock (and thus blocking on live method calls). However, we aim not to
-// generate methods with any hidden blocking semantics, and a `destroy` method that might
-// block if called incorrectly seems to meet that bar.
-//
-// So, we achieve our goals by giving each instance an associated `AtomicLong` counter to track
-// the number of in-flight method calls, and an `AtomicBoolean` flag to indicate whether `destroy`
-// has been called. These are updated according to the following rules:
-//
-//    * The initial value of the counter is 1, indicating a live object with no in-flight calls.
-//      The initial value for the flag is false.
-//
-//    * At the start of each method call, we atomically check the counter.
-//      If it is 0 then the underlying Rust struct has already been destroyed and the call is aborted.
-//      If it is nonzero them we atomically increment it by 1 and proceed with the method call.
-//
-//    * At the end of each method call, we atomically decrement and check the counter.
-//      If it has reached zero then we destroy the underlying Rust struct.
-//
-//    * When `destroy` is called, we atomically flip the flag from false to true.
-//      If the flag was already true we silently fail.
-//      Otherwise we atomically decrement and check the counter.
-//      If it has reached zero then we destroy the underlying Rust struct.
-//
-// Astute readers may observe that this all sounds very similar to the way that Rust's `Arc<T>` works,
-// and indeed it is, with the addition of a flag to guard against multiple calls to `destroy`.
-//
-// The overall effect is that the underlying Rust struct is destroyed only when `destroy` has been
-// called *and* all in-flight method calls have completed, avoiding violating any of the expectations
-// of the underlying Rust code.
-//
-// This makes a cleaner a better alternative to _not_ calling `destroy()` as
-// and when the object is finished with, but the abstraction is not perfect: if the Rust object's `drop`
-// method is slow, and/or there are many objects to cleanup, and it's on a low end Android device, then the cleaner
-// thread may be starved, and the app will leak memory.
-//
-// In this case, `destroy`ing manually may be a better solution.
-//
-// The cleaner can live side by side with the manual calling of `destroy`. In the order of responsiveness, uniffi objects
-// with Rust peers are reclaimed:
-//
-// 1. By calling the `destroy` method of the object, which calls `rustObject.free()`. If that doesn't happen:
-// 2. When the object becomes unreachable, AND the Cleaner thread gets to call `rustObject.free()`. If the thread is starved then:
-// 3. The memory is reclaimed when the process terminates.
-//
-// [1] https://stackoverflow.com/questions/24376768/can-java-finalize-an-object-when-it-is-still-in-scope/24380219
-//
+    override fun allocationSize(value: Starship) = (
+            FfiConverterString.allocationSize(value.`name`) +
+            FfiConverterString.allocationSize(value.`model`) +
+            FfiConverterString.allocationSize(value.`manufacturer`) +
+            FfiConverterString.allocationSize(value.`costInCredits`) +
+            FfiConverterString.allocationSize(value.`length`) +
+            FfiConverterString.allocationSize(value.`maxAtmospheringSpeed`) +
+            FfiConverterString.allocationSize(value.`crew`) +
+            FfiConverterString.allocationSize(value.`passengers`) +
+            FfiConverterString.allocationSize(value.`cargoCapacity`) +
+            FfiConverterString.allocationSize(value.`consumables`) +
+            FfiConverterString.allocationSize(value.`hyperdriveRating`) +
+            FfiConverterString.allocationSize(value.`mglt`) +
+            FfiConverterString.allocationSize(value.`starshipClass`) +
+            FfiConverterSequenceString.allocationSize(value.`pilots`) +
+            FfiConverterSequenceString.allocationSize(value.`films`) +
+            FfiConverterString.allocationSize(value.`created`) +
+            FfiConverterString.allocationSize(value.`edited`) +
+            FfiConverterString.allocationSize(value.`url`)
+    )
+
+    override fun write(value: Starship, buf: ByteBuffer) {
+            FfiConverterString.write(value.`name`, buf)
+            FfiConverterString.write(value.`model`, buf)
+            FfiConverterString.write(value.`manufacturer`, buf)
+            FfiConverterString.write(value.`costInCredits`, buf)
+            FfiConverterString.write(value.`length`, buf)
+            FfiConverterString.write(value.`maxAtmospheringSpeed`, buf)
+            FfiConverterString.write(value.`crew`, buf)
+            FfiConverterString.write(value.`passengers`, buf)
+            FfiConverterString.write(value.`cargoCapacity`, buf)
+            FfiConverterString.write(value.`consumables`, buf)
+            FfiConverterString.write(value.`hyperdriveRating`, buf)
+            FfiConverterString.write(value.`mglt`, buf)
+            FfiConverterString.write(value.`starshipClass`, buf)
+            FfiConverterSequenceString.write(value.`pilots`, buf)
+            FfiConverterSequenceString.write(value.`films`, buf)
+            FfiConverterString.write(value.`created`, buf)
+            FfiConverterString.write(value.`edited`, buf)
+            FfiConverterString.write(value.`url`, buf)
+    }
+}
 
 
-public interface ApiClientInterface {
+
+data class Vehicle (
+    val `name`: kotlin.String
+    , 
+    val `model`: kotlin.String
+    , 
+    val `manufacturer`: kotlin.String
+    , 
+    val `costInCredits`: kotlin.String
+    , 
+    val `length`: kotlin.String
+    , 
+    val `maxAtmospheringSpeed`: kotlin.String
+    , 
+    val `crew`: kotlin.String
+    , 
+    val `passengers`: kotlin.String
+    , 
+    val `cargoCapacity`: kotlin.String
+    , 
+    val `consumables`: kotlin.String
+    , 
+    val `vehicleClass`: kotlin.String
+    , 
+    val `pilots`: List<kotlin.String>
+    , 
+    val `films`: List<kotlin.String>
+    , 
+    val `created`: kotlin.String
+    , 
+    val `edited`: kotlin.String
+    , 
+    val `url`: kotlin.String
+    
+){
+     fun `cargoCapacityValue`(): kotlin.ULong? {
+            return FfiConverterOptionalULong.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_vehicle_cargo_capacity_value(FfiConverterTypeVehicle.lower(this),
+        _status)
+}
+    )
+    }
     
-    suspend fun `fetchFilm`(`url`: kotlin.String): Film
+
+     fun `consumablesValue`(): Consumables? {
+            return FfiConverterOptionalTypeConsumables.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_vehicle_consumables_value(FfiConverterTypeVehicle.lower(this),
+        _status)
+}
+    )
+    }
     
-    suspend fun `fetchFilms`(`ignoreCache`: kotlin.Boolean): List<Film>
+
+     fun `costInCreditsValue`(): kotlin.ULong? {
+            return FfiConverterOptionalULong.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_vehicle_cost_in_credits_value(FfiConverterTypeVehicle.lower(this),
+        _status)
+}
+    )
+    }
     
-    suspend fun `fetchPeople`(`ignoreCache`: kotlin.Boolean): List<Person>
+
+     fun `createdAt`(): Timestamp? {
+            return FfiConverterOptionalTypeTimestamp.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_vehicle_created_at(FfiConverterTypeVehicle.lower(this),
+        _status)
+}
+    )
+    }
     
-    suspend fun `fetchPerson`(`url`: kotlin.String): Person
+
+     fun `crewRange`(): NumericRange? {
+            return FfiConverterOptionalTypeNumericRange.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_vehicle_crew_range(FfiConverterTypeVehicle.lower(this),
+        _status)
+}
+    )
+    }
     
-    suspend fun `fetchPlanet`(`url`: kotlin.String): Planet
+
+     fun `editedAt`(): Timestamp? {
+            return FfiConverterOptionalTypeTimestamp.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_vehicle_edited_at(FfiConverterTypeVehicle.lower(this),
+        _status)
+}
+    )
+    }
     
-    suspend fun `fetchPlanets`(`ignoreCache`: kotlin.Boolean): List<Planet>
+
+     fun `lengthValue`(): kotlin.Double? {
+            return FfiConverterOptionalDouble.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_vehicle_length_value(FfiConverterTypeVehicle.lower(this),
+        _status)
+}
+    )
+    }
     
-    suspend fun `fetchSpecies`(`url`: kotlin.String): Species
+
+     fun `manufacturerTags`(): List<AttributeTag> {
+            return FfiConverterSequenceTypeAttributeTag.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_vehicle_manufacturer_tags(FfiConverterTypeVehicle.lower(this),
+        _status)
+}
+    )
+    }
     
-    suspend fun `fetchSpeciesList`(`ignoreCache`: kotlin.Boolean): List<Species>
+
+     fun `maxAtmospheringSpeedValue`(): kotlin.Double? {
+            return FfiConverterOptionalDouble.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_vehicle_max_atmosphering_speed_value(FfiConverterTypeVehicle.lower(this),
+        _status)
+}
+    )
+    }
     
-    suspend fun `fetchStarship`(`url`: kotlin.String): Starship
+
+     fun `passengersRange`(): NumericRange? {
+            return FfiConverterOptionalTypeNumericRange.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_vehicle_passengers_range(FfiConverterTypeVehicle.lower(this),
+        _status)
+}
+    )
+    }
     
-    suspend fun `fetchStarships`(`ignoreCache`: kotlin.Boolean): List<Starship>
+
     
-    suspend fun `fetchVehicle`(`url`: kotlin.String): Vehicle
+
     
-    suspend fun `fetchVehicles`(`ignoreCache`: kotlin.Boolean): List<Vehicle>
+
     
     companion object
 }
 
-open class ApiClient: Disposable, AutoCloseable, ApiClientInterface
-{
-
-    @Suppress("UNUSED_PARAMETER")
-    /**
-     * @suppress
-     */
-    constructor(withHandle: UniffiWithHandle, handle: Long) {
-        this.handle = handle
-        this.cleanable = UniffiLib.CLEANER.register(this, UniffiCleanAction(handle))
+/**
+ * @suppress
+ */
+public object FfiConverterTypeVehicle: FfiConverterRustBuffer<Vehicle> {
+    override fun read(buf: ByteBuffer): Vehicle {
+        return Vehicle(
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterSequenceString.read(buf),
+            FfiConverterSequenceString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+        )
     }
 
-    /**
-     * @suppress
-     *
-     * This constructor can be used to instantiate a fake object. Only used for tests. Any
-     * attempt to actually use an object constructed this way will fail as there is no
-     * connected Rust object.
-     */
-    @Suppress("UNUSED_PARAMETER")
-    constructor(noHandle: NoHandle) {
-        this.handle = 0
-        this.cleanable = null
+    override fun allocationSize(value: Vehicle) = (
+            FfiConverterString.allocationSize(value.`name`) +
+            FfiConverterString.allocationSize(value.`model`) +
+            FfiConverterString.allocationSize(value.`manufacturer`) +
+            FfiConverterString.allocationSize(value.`costInCredits`) +
+            FfiConverterString.allocationSize(value.`length`) +
+            FfiConverterString.allocationSize(value.`maxAtmospheringSpeed`) +
+            FfiConverterString.allocationSize(value.`crew`) +
+            FfiConverterString.allocationSize(value.`passengers`) +
+            FfiConverterString.allocationSize(value.`cargoCapacity`) +
+            FfiConverterString.allocationSize(value.`consumables`) +
+            FfiConverterString.allocationSize(value.`vehicleClass`) +
+            FfiConverterSequenceString.allocationSize(value.`pilots`) +
+            FfiConverterSequenceString.allocationSize(value.`films`) +
+            FfiConverterString.allocationSize(value.`created`) +
+            FfiConverterString.allocationSize(value.`edited`) +
+            FfiConverterString.allocationSize(value.`url`)
+    )
+
+    override fun write(value: Vehicle, buf: ByteBuffer) {
+            FfiConverterString.write(value.`name`, buf)
+            FfiConverterString.write(value.`model`, buf)
+            FfiConverterString.write(value.`manufacturer`, buf)
+            FfiConverterString.write(value.`costInCredits`, buf)
+            FfiConverterString.write(value.`length`, buf)
+            FfiConverterString.write(value.`maxAtmospheringSpeed`, buf)
+            FfiConverterString.write(value.`crew`, buf)
+            FfiConverterString.write(value.`passengers`, buf)
+            FfiConverterString.write(value.`cargoCapacity`, buf)
+            FfiConverterString.write(value.`consumables`, buf)
+            FfiConverterString.write(value.`vehicleClass`, buf)
+            FfiConverterSequenceString.write(value.`pilots`, buf)
+            FfiConverterSequenceString.write(value.`films`, buf)
+            FfiConverterString.write(value.`created`, buf)
+            FfiConverterString.write(value.`edited`, buf)
+            FfiConverterString.write(value.`url`, buf)
     }
-    constructor(`httpClient`: HttpClient, `repository`: DataRepository) :
-        this(UniffiWithHandle, 
-    uniffiRustCall() { _status ->
-    UniffiLib.uniffi_starwars_fn_constructor_apiclient_new(
-    
-        FfiConverterTypeHttpClient.lower(`httpClient`),FfiConverterTypeDataRepository.lower(`repository`),_status)
 }
-    )
 
-    protected val handle: Long
-    protected val cleanable: UniffiCleaner.Cleanable?
 
-    private val wasDestroyed = AtomicBoolean(false)
-    private val callCounter = AtomicLong(1)
 
-    override fun destroy() {
-        // Only allow a single call to this method.
-        // TODO: maybe we should log a warning if called more than once?
-        if (this.wasDestroyed.compareAndSet(false, true)) {
-            // This decrement always matches the initial count of 1 given at creation time.
-            if (this.callCounter.decrementAndGet() == 0L) {
-                cleanable?.clean()
-            }
+
+
+sealed class ApiException(message: String): kotlin.Exception(message) {
+        
+        class HttpException(message: String) : ApiException(message)
+        
+        class NetworkException(message: String) : ApiException(message)
+        
+        class SerializationException(message: String) : ApiException(message)
+        
+        class DeserializationException(message: String) : ApiException(message)
+        
+        class InvalidResponse(message: String) : ApiException(message)
+        
+        class NotFound(message: String) : ApiException(message)
+        
+        class Unauthorized(message: String) : ApiException(message)
+        
+        class RateLimited(message: String) : ApiException(message)
+        
+        class CacheMiss(message: String) : ApiException(message)
+        
+        class ServerException(message: String) : ApiException(message)
+        
+        class StorageException(message: String) : ApiException(message)
+        
+        class Cancelled(message: String) : ApiException(message)
+        
+
+    companion object ErrorHandler : UniffiRustCallStatusErrorHandler<ApiException> {
+        override fun lift(error_buf: RustBuffer.ByValue): ApiException = FfiConverterTypeApiError.lift(error_buf)
+    }
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeApiError : FfiConverterRustBuffer<ApiException> {
+    override fun read(buf: ByteBuffer): ApiException {
+        
+            return when(buf.getInt()) {
+            1 -> ApiException.HttpException(FfiConverterString.read(buf))
+            2 -> ApiException.NetworkException(FfiConverterString.read(buf))
+            3 -> ApiException.SerializationException(FfiConverterString.read(buf))
+            4 -> ApiException.DeserializationException(FfiConverterString.read(buf))
+            5 -> ApiException.InvalidResponse(FfiConverterString.read(buf))
+            6 -> ApiException.NotFound(FfiConverterString.read(buf))
+            7 -> ApiException.Unauthorized(FfiConverterString.read(buf))
+            8 -> ApiException.RateLimited(FfiConverterString.read(buf))
+            9 -> ApiException.CacheMiss(FfiConverterString.read(buf))
+            10 -> ApiException.ServerException(FfiConverterString.read(buf))
+            11 -> ApiException.StorageException(FfiConverterString.read(buf))
+            12 -> ApiException.Cancelled(FfiConverterString.read(buf))
+            else -> throw RuntimeException("invalid error enum value, something is very wrong!!")
         }
+        
     }
 
-    @Synchronized
-    override fun close() {
-        this.destroy()
+    override fun allocationSize(value: ApiException): ULong {
+        return 4UL
     }
 
-    internal inline fun <R> callWithHandle(block: (handle: Long) -> R): R {
-        // Check and increment the call counter, to keep the object alive.
-        // This needs a compare-and-set retry loop in case of concurrent updates.
-        do {
-            val c = this.callCounter.get()
-            if (c == 0L) {
-                throw IllegalStateException("${this.javaClass.simpleName} object has already been destroyed")
+    override fun write(value: ApiException, buf: ByteBuffer) {
+        when(value) {
+            is ApiException.HttpException -> {
+                buf.putInt(1)
+                Unit
             }
-            if (c == Long.MAX_VALUE) {
-                throw IllegalStateException("${this.javaClass.simpleName} call counter would overflow")
+            is ApiException.NetworkException -> {
+                buf.putInt(2)
+                Unit
             }
-        } while (! this.callCounter.compareAndSet(c, c + 1L))
-        // Now we can safely do the method call without the handle being freed concurrently.
-        try {
-            return block(this.uniffiCloneHandle())
-        } finally {
-            // This decrement always matches the increment we performed above.
-            if (this.callCounter.decrementAndGet() == 0L) {
-                cleanable?.clean()
+            is ApiException.SerializationException -> {
+                buf.putInt(3)
+                Unit
             }
-        }
-    }
-
-    // Use a static inner class instead of a closure so as not to accidentally
-    // capture `this` as part of the cleanable's action.
-    private class UniffiCleanAction(private val handle: Long) : Runnable {
-        override fun run() {
-            if (handle == 0.toLong()) {
-                // Fake object created with `NoHandle`, don't try to free.
-                return;
+            is ApiException.DeserializationException -> {
+                buf.putInt(4)
+                Unit
             }
-            uniffiRustCall { status ->
-                UniffiLib.uniffi_starwars_fn_free_apiclient(handle, status)
+            is ApiException.InvalidResponse -> {
+                buf.putInt(5)
+                Unit
             }
-        }
+            is ApiException.NotFound -> {
+                buf.putInt(6)
+                Unit
+            }
+            is ApiException.Unauthorized -> {
+                buf.putInt(7)
+                Unit
+            }
+            is ApiException.RateLimited -> {
+                buf.putInt(8)
+                Unit
+            }
+            is ApiException.CacheMiss -> {
+                buf.putInt(9)
+                Unit
+            }
+            is ApiException.ServerException -> {
+                buf.putInt(10)
+                Unit
+            }
+            is ApiException.StorageException -> {
+                buf.putInt(11)
+                Unit
+            }
+            is ApiException.Cancelled -> {
+                buf.putInt(12)
+                Unit
+            }
+        }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
     }
 
-    /**
-     * @suppress
-     */
-    fun uniffiCloneHandle(): Long {
-        if (handle == 0.toLong()) {
-            throw InternalException("uniffiCloneHandle() called on NoHandle object");
-        }
-        return uniffiRustCall() { status ->
-            UniffiLib.uniffi_starwars_fn_clone_apiclient(handle, status)
-        }
-    }
+}
 
-    
-    @Throws(ApiException::class)
-    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `fetchFilm`(`url`: kotlin.String) : Film {
-        return uniffiRustCallAsync(
-        callWithHandle { uniffiHandle ->
-            UniffiLib.uniffi_starwars_fn_method_apiclient_fetch_film(
-                uniffiHandle,
-                FfiConverterString.lower(`url`),
-            )
-        },
-        { future, callback, continuation -> UniffiLib.ffi_starwars_rust_future_poll_rust_buffer(future, callback, continuation) },
-        { future, continuation -> UniffiLib.ffi_starwars_rust_future_complete_rust_buffer(future, continuation) },
-        { future -> UniffiLib.ffi_starwars_rust_future_free_rust_buffer(future) },
-        // lift function
-        { FfiConverterTypeFilm.lift(it) },
-        // Error FFI converter
-        ApiException.ErrorHandler,
-    )
-    }
 
-    
-    @Throws(ApiException::class)
-    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `fetchFilms`(`ignoreCache`: kotlin.Boolean) : List<Film> {
-        return uniffiRustCallAsync(
-        callWithHandle { uniffiHandle ->
-            UniffiLib.uniffi_starwars_fn_method_apiclient_fetch_films(
-                uniffiHandle,
-                FfiConverterBoolean.lower(`ignoreCache`),
-            )
-        },
-        { future, callback, continuation -> UniffiLib.ffi_starwars_rust_future_poll_rust_buffer(future, callback, continuation) },
-        { future, continuation -> UniffiLib.ffi_starwars_rust_future_complete_rust_buffer(future, continuation) },
-        { future -> UniffiLib.ffi_starwars_rust_future_free_rust_buffer(future) },
-        // lift function
-        { FfiConverterSequenceTypeFilm.lift(it) },
-        // Error FFI converter
-        ApiException.ErrorHandler,
-    )
-    }
 
-    
-    @Throws(ApiException::class)
-    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `fetchPeople`(`ignoreCache`: kotlin.Boolean) : List<Person> {
-        return uniffiRustCallAsync(
-        callWithHandle { uniffiHandle ->
-            UniffiLib.uniffi_starwars_fn_method_apiclient_fetch_people(
-                uniffiHandle,
-                FfiConverterBoolean.lower(`ignoreCache`),
-            )
-        },
-        { future, callback, continuation -> UniffiLib.ffi_starwars_rust_future_poll_rust_buffer(future, callback, continuation) },
-        { future, continuation -> UniffiLib.ffi_starwars_rust_future_complete_rust_buffer(future, continuation) },
-        { future -> UniffiLib.ffi_starwars_rust_future_free_rust_buffer(future) },
-        // lift function
-        { FfiConverterSequenceTypePerson.lift(it) },
-        // Error FFI converter
-        ApiException.ErrorHandler,
-    )
-    }
 
+enum class CachePolicy {
     
-    @Throws(ApiException::class)
-    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `fetchPerson`(`url`: kotlin.String) : Person {
-        return uniffiRustCallAsync(
-        callWithHandle { uniffiHandle ->
-            UniffiLib.uniffi_starwars_fn_method_apiclient_fetch_person(
-                uniffiHandle,
-                FfiConverterString.lower(`url`),
-            )
-        },
-        { future, callback, continuation -> UniffiLib.ffi_starwars_rust_future_poll_rust_buffer(future, callback, continuation) },
-        { future, continuation -> UniffiLib.ffi_starwars_rust_future_complete_rust_buffer(future, continuation) },
-        { future -> UniffiLib.ffi_starwars_rust_future_free_rust_buffer(future) },
-        // lift function
-        { FfiConverterTypePerson.lift(it) },
-        // Error FFI converter
-        ApiException.ErrorHandler,
-    )
-    }
+    CACHE_FIRST,
+    NETWORK_FIRST,
+    CACHE_ONLY,
+    NETWORK_ONLY,
+    STALE_WHILE_REVALIDATE;
 
     
-    @Throws(ApiException::class)
-    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `fetchPlanet`(`url`: kotlin.String) : Planet {
-        return uniffiRustCallAsync(
-        callWithHandle { uniffiHandle ->
-            UniffiLib.uniffi_starwars_fn_method_apiclient_fetch_planet(
-                uniffiHandle,
-                FfiConverterString.lower(`url`),
-            )
-        },
-        { future, callback, continuation -> UniffiLib.ffi_starwars_rust_future_poll_rust_buffer(future, callback, continuation) },
-        { future, continuation -> UniffiLib.ffi_starwars_rust_future_complete_rust_buffer(future, continuation) },
-        { future -> UniffiLib.ffi_starwars_rust_future_free_rust_buffer(future) },
-        // lift function
-        { FfiConverterTypePlanet.lift(it) },
-        // Error FFI converter
-        ApiException.ErrorHandler,
-    )
-    }
 
-    
-    @Throws(ApiException::class)
-    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `fetchPlanets`(`ignoreCache`: kotlin.Boolean) : List<Planet> {
-        return uniffiRustCallAsync(
-        callWithHandle { uniffiHandle ->
-            UniffiLib.uniffi_starwars_fn_method_apiclient_fetch_planets(
-                uniffiHandle,
-                FfiConverterBoolean.lower(`ignoreCache`),
-            )
-        },
-        { future, callback, continuation -> UniffiLib.ffi_starwars_rust_future_poll_rust_buffer(future, callback, continuation) },
-        { future, continuation -> UniffiLib.ffi_starwars_rust_future_complete_rust_buffer(future, continuation) },
-        { future -> UniffiLib.ffi_starwars_rust_future_free_rust_buffer(future) },
-        // lift function
-        { FfiConverterSequenceTypePlanet.lift(it) },
-        // Error FFI converter
-        ApiException.ErrorHandler,
-    )
-    }
 
-    
-    @Throws(ApiException::class)
-    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `fetchSpecies`(`url`: kotlin.String) : Species {
-        return uniffiRustCallAsync(
-        callWithHandle { uniffiHandle ->
-            UniffiLib.uniffi_starwars_fn_method_apiclient_fetch_species(
-                uniffiHandle,
-                FfiConverterString.lower(`url`),
-            )
-        },
-        { future, callback, continuation -> UniffiLib.ffi_starwars_rust_future_poll_rust_buffer(future, callback, continuation) },
-        { future, continuation -> UniffiLib.ffi_starwars_rust_future_complete_rust_buffer(future, continuation) },
-        { future -> UniffiLib.ffi_starwars_rust_future_free_rust_buffer(future) },
-        // lift function
-        { FfiConverterTypeSpecies.lift(it) },
-        // Error FFI converter
-        ApiException.ErrorHandler,
-    )
+    companion object
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeCachePolicy: FfiConverterRustBuffer<CachePolicy> {
+    override fun read(buf: ByteBuffer) = try {
+        CachePolicy.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
     }
 
-    
-    @Throws(ApiException::class)
-    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `fetchSpeciesList`(`ignoreCache`: kotlin.Boolean) : List<Species> {
-        return uniffiRustCallAsync(
-        callWithHandle { uniffiHandle ->
-            UniffiLib.uniffi_starwars_fn_method_apiclient_fetch_species_list(
-                uniffiHandle,
-                FfiConverterBoolean.lower(`ignoreCache`),
-            )
-        },
-        { future, callback, continuation -> UniffiLib.ffi_starwars_rust_future_poll_rust_buffer(future, callback, continuation) },
-        { future, continuation -> UniffiLib.ffi_starwars_rust_future_complete_rust_buffer(future, continuation) },
-        { future -> UniffiLib.ffi_starwars_rust_future_free_rust_buffer(future) },
-        // lift function
-        { FfiConverterSequenceTypeSpecies.lift(it) },
-        // Error FFI converter
-        ApiException.ErrorHandler,
-    )
+    override fun allocationSize(value: CachePolicy) = 4UL
+
+    override fun write(value: CachePolicy, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
     }
+}
+
+
 
+
+
+
+enum class ChangeKind {
     
-    @Throws(ApiException::class)
-    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `fetchStarship`(`url`: kotlin.String) : Starship {
-        return uniffiRustCallAsync(
-        callWithHandle { uniffiHandle ->
-            UniffiLib.uniffi_starwars_fn_method_apiclient_fetch_starship(
-                uniffiHandle,
-                FfiConverterString.lower(`url`),
-            )
-        },
-        { future, callback, continuation -> UniffiLib.ffi_starwars_rust_future_poll_rust_buffer(future, callback, continuation) },
-        { future, continuation -> UniffiLib.ffi_starwars_rust_future_complete_rust_buffer(future, continuation) },
-        { future -> UniffiLib.ffi_starwars_rust_future_free_rust_buffer(future) },
-        // lift function
-        { FfiConverterTypeStarship.lift(it) },
-        // Error FFI converter
-        ApiException.ErrorHandler,
-    )
-    }
+    INSERTED,
+    UPDATED,
+    REMOVED;
 
     
-    @Throws(ApiException::class)
-    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `fetchStarships`(`ignoreCache`: kotlin.Boolean) : List<Starship> {
-        return uniffiRustCallAsync(
-        callWithHandle { uniffiHandle ->
-            UniffiLib.uniffi_starwars_fn_method_apiclient_fetch_starships(
-                uniffiHandle,
-                FfiConverterBoolean.lower(`ignoreCache`),
-            )
-        },
-        { future, callback, continuation -> UniffiLib.ffi_starwars_rust_future_poll_rust_buffer(future, callback, continuation) },
-        { future, continuation -> UniffiLib.ffi_starwars_rust_future_complete_rust_buffer(future, continuation) },
-        { future -> UniffiLib.ffi_starwars_rust_future_free_rust_buffer(future) },
-        // lift function
-        { FfiConverterSequenceTypeStarship.lift(it) },
-        // Error FFI converter
-        ApiException.ErrorHandler,
-    )
+
+
+    companion object
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeChangeKind: FfiConverterRustBuffer<ChangeKind> {
+    override fun read(buf: ByteBuffer) = try {
+        ChangeKind.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
     }
 
-    
-    @Throws(ApiException::class)
-    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `fetchVehicle`(`url`: kotlin.String) : Vehicle {
-        return uniffiRustCallAsync(
-        callWithHandle { uniffiHandle ->
-            UniffiLib.uniffi_starwars_fn_method_apiclient_fetch_vehicle(
-                uniffiHandle,
-                FfiConverterString.lower(`url`),
-            )
-        },
-        { future, callback, continuation -> UniffiLib.ffi_starwars_rust_future_poll_rust_buffer(future, callback, continuation) },
-        { future, continuation -> UniffiLib.ffi_starwars_rust_future_complete_rust_buffer(future, continuation) },
-        { future -> UniffiLib.ffi_starwars_rust_future_free_rust_buffer(future) },
-        // lift function
-        { FfiConverterTypeVehicle.lift(it) },
-        // Error FFI converter
-        ApiException.ErrorHandler,
-    )
+    override fun allocationSize(value: ChangeKind) = 4UL
+
+    override fun write(value: ChangeKind, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
     }
+}
 
+
+
+
+
+
+enum class Fetchable {
     
-    @Throws(ApiException::class)
-    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `fetchVehicles`(`ignoreCache`: kotlin.Boolean) : List<Vehicle> {
-        return uniffiRustCallAsync(
-        callWithHandle { uniffiHandle ->
-            UniffiLib.uniffi_starwars_fn_method_apiclient_fetch_vehicles(
-                uniffiHandle,
-                FfiConverterBoolean.lower(`ignoreCache`),
-            )
-        },
-        { future, callback, continuation -> UniffiLib.ffi_starwars_rust_future_poll_rust_buffer(future, callback, continuation) },
-        { future, continuation -> UniffiLib.ffi_starwars_rust_future_complete_rust_buffer(future, continuation) },
-        { future -> UniffiLib.ffi_starwars_rust_future_free_rust_buffer(future) },
-        // lift function
-        { FfiConverterSequenceTypeVehicle.lift(it) },
-        // Error FFI converter
-        ApiException.ErrorHandler,
+    PLANETS,
+    PEOPLE,
+    FILMS,
+    SPECIES,
+    STARSHIPS,
+    VEHICLES;
+
+     fun `displayName`(): kotlin.String {
+            return FfiConverterString.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_method_fetchable_display_name(FfiConverterTypeFetchable.lower(this),
+        _status)
+}
     )
     }
-
     
 
     
 
 
-    
-    
-    /**
-     * @suppress
-     */
     companion object
-    
 }
 
 
 /**
  * @suppress
  */
-public object FfiConverterTypeApiClient: FfiConverter<ApiClient, Long> {
-    override fun lower(value: ApiClient): Long {
-        return value.uniffiCloneHandle()
+public object FfiConverterTypeFetchable: FfiConverterRustBuffer<Fetchable> {
+    override fun read(buf: ByteBuffer) = try {
+        Fetchable.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
     }
 
-    override fun lift(value: Long): ApiClient {
-        return ApiClient(UniffiWithHandle, value)
+    override fun allocationSize(value: Fetchable) = 4UL
+
+    override fun write(value: Fetchable, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
     }
+}
 
-    override fun read(buf: ByteBuffer): ApiClient {
-        return lift(buf.getLong())
+
+
+
+
+
+enum class FilterOperator {
+    
+    EQUALS,
+    NOT_EQUALS,
+    CONTAINS,
+    STARTS_WITH,
+    GREATER_THAN,
+    GREATER_THAN_OR_EQUAL,
+    LESS_THAN,
+    LESS_THAN_OR_EQUAL;
+
+    
+
+
+    companion object
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeFilterOperator: FfiConverterRustBuffer<FilterOperator> {
+    override fun read(buf: ByteBuffer) = try {
+        FilterOperator.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
     }
 
-    override fun allocationSize(value: ApiClient) = 8UL
+    override fun allocationSize(value: FilterOperator) = 4UL
 
-    override fun write(value: ApiClient, buf: ByteBuffer) {
-        buf.putLong(lower(value))
+    override fun write(value: FilterOperator, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
     }
 }
 
 
-// This template implements a class for working with a Rust struct via a handle
-// to the live Rust struct on the other side of the FFI.
-//
-// There's some subtlety here, because we have to be careful not to operate on a Rust
-// struct after it has been dropped, and because we must expose a public API for freeing
-// theq Kotlin wrapper object in lieu of reliable finalizers. The core requirements are:
-//
-//   * Each instance holds an opaque handle to the underlying Rust struct.
-//     Method calls need to read this handle from the object's state and pass it in to
-//     the Rust FFI.
-//
-//   * When an instance is no longer needed, its handle should be passed to a
-//     special destructor function provided by the Rust FFI, which will drop the
-//     underlying Rust struct.
-//
-//   * Given an instance, calling code is expected to call the special
-//     `destroy` method in order to free it after use, either by calling it explicitly
-//     or by using a higher-level helper like the `use` method. Failing to do so risks
-//     leaking the underlying Rust struct.
-//
-//   * We can't assume that calling code will do the right thing, and must be prepared
-//     to handle Kotlin method calls executing concurrently with or even after a call to
-//     `destroy`, and to handle multiple (possibly concurrent!) calls to `destroy`.
-//
-//   * We must never allow Rust code to operate on the underlying Rust struct after
-//     the destructor has been called, and must never call the destructor more than once.
-//     Doing so may trigger memory unsafety.
-//
-//   * To mitigate many of the risks of leaking memory and use-after-free unsafety, a `Cleaner`
-//     is implemented to call the destructor when the Kotlin object becomes unreachable.
-//     This is done in a background thread. This is not a panacea, and client code should be aware that
-//      1. the thread may starve if some there are objects that have poorly performing
-//     `drop` methods or do significant work in their `drop` methods.
-//      2. the thread is shared across the whole library. This can be tuned by using `android_cleaner = true`,
-//         or `android = true` in the [`kotlin` section of the `uniffi.toml` file](https://mozilla.github.io/uniffi-rs/kotlin/configuration.html).
-//
-// If we try to implement this with mutual exclusion on access to the handle, there is the
-// possibility of a race between a method call and a concurrent call to `destroy`:
-//
-//    * Thread A starts a method call, reads the value of the handle, but is interrupted
-//      before it can pass the handle over the FFI to Rust.
-//    * Thread B calls `destroy` and frees the underlying Rust struct.
-//    * Thread A resumes, passing the already-read handle value to Rust and triggering
-//      a use-after-free.
-//
-// One possible solution would be to use a `ReadWriteLock`, with each method call taking
-// a read lock (and thus allowed to run concurrently) and the special `destroy` method
-// taking a write lock (and thus blocking on live method calls). However, we aim not to
-// generate methods with any hidden blocking semantics, and a `destroy` method that might
-// block if called incorrectly seems to meet that bar.
-//
-// So, we achieve our goals by giving each instance an associated `AtomicLong` counter to track
-// the number of in-flight method calls, and an `AtomicBoolean` flag to indicate whether `destroy`
-// has been called. These are updated according to the following rules:
-//
-//    * The initial value of the counter is 1, indicating a live object with no in-flight calls.
-//      The initial value for the flag is false.
-//
-//    * At the start of each method call, we atomically check the counter.
-//      If it is 0 then the underlying Rust struct has already been destroyed and the call is aborted.
-//      If it is nonzero them we atomically increment it by 1 and proceed with the method call.
-//
-//    * At the end of each method call, we atomically decrement and check the counter.
-//      If it has reached zero then we destroy the underlying Rust struct.
-//
-//    * When `destroy` is called, we atomically flip the flag from false to true.
-//      If the flag was already true we silently fail.
-//      Otherwise we atomically decrement and check the counter.
-//      If it has reached zero then we destroy the underlying Rust struct.
-//
-// Astute readers may observe that this all sounds very similar to the way that Rust's `Arc<T>` works,
-// and indeed it is, with the addition of a flag to guard against multiple calls to `destroy`.
-//
-// The overall effect is that the underlying Rust struct is destroyed only when `destroy` has been
-// called *and* all in-flight method calls have completed, avoiding violating any of the expectations
-// of the underlying Rust code.
-//
-// This makes a cleaner a better alternative to _not_ calling `destroy()` as
-// and when the object is finished with, but the abstraction is not perfect: if the Rust object's `drop`
-// method is slow, and/or there are many objects to cleanup, and it's on a low end Android device, then the cleaner
-// thread may be starved, and the app will leak memory.
-//
-// In this case, `destroy`ing manually may be a better solution.
-//
-// The cleaner can live side by side with the manual calling of `destroy`. In the order of responsiveness, uniffi objects
-// with Rust peers are reclaimed:
-//
-// 1. By calling the `destroy` method of the object, which calls `rustObject.free()`. If that doesn't happen:
-// 2. When the object becomes unreachable, AND the Cleaner thread gets to call `rustObject.free()`. If the thread is starved then:
-// 3. The memory is reclaimed when the process terminates.
-//
-// [1] https://stackoverflow.com/questions/24376768/can-java-finalize-an-object-when-it-is-still-in-scope/24380219
-//
 
 
-public interface DataRepositoryInterface {
+
+sealed class ListItems {
+    
+    data class Planets(
+        val v1: List<kotlin.String>) : ListItems()
+        
+    {
+        
+
+        companion object
+    }
+    
+    data class People(
+        val v1: List<kotlin.String>) : ListItems()
+        
+    {
+        
+
+        companion object
+    }
     
-    fun `getPlanet`(`url`: kotlin.String): Planet?
+    data class Films(
+        val v1: List<kotlin.String>) : ListItems()
+        
+    {
+        
+
+        companion object
+    }
     
-    fun `getPlanets`(): List<Planet>?
+    data class Species(
+        val v1: List<kotlin.String>) : ListItems()
+        
+    {
+        
+
+        companion object
+    }
     
-    fun `insertPlanet`(`planet`: Planet)
+    data class Starships(
+        val v1: List<kotlin.String>) : ListItems()
+        
+    {
+        
+
+        companion object
+    }
     
-    companion object
-}
+    data class Vehicles(
+        val v1: List<kotlin.String>) : ListItems()
+        
+    {
+        
 
-open class DataRepository: Disposable, AutoCloseable, DataRepositoryInterface
-{
+        companion object
+    }
+    
+    data class Homeworld(
+        val v1: kotlin.String) : ListItems()
+        
+    {
+        
 
-    @Suppress("UNUSED_PARAMETER")
-    /**
-     * @suppress
-     */
-    constructor(withHandle: UniffiWithHandle, handle: Long) {
-        this.handle = handle
-        this.cleanable = UniffiLib.CLEANER.register(this, UniffiCleanAction(handle))
+        companion object
     }
+    
+    data class Characters(
+        val v1: List<kotlin.String>) : ListItems()
+        
+    {
+        
 
-    /**
-     * @suppress
-     *
-     * This constructor can be used to instantiate a fake object. Only used for tests. Any
-     * attempt to actually use an object constructed this way will fail as there is no
-     * connected Rust object.
-     */
-    @Suppress("UNUSED_PARAMETER")
-    constructor(noHandle: NoHandle) {
-        this.handle = 0
-        this.cleanable = null
+        companion object
     }
-    constructor() :
-        this(UniffiWithHandle, 
-    uniffiRustCall() { _status ->
-    UniffiLib.uniffi_starwars_fn_constructor_datarepository_new(
     
-        _status)
-}
-    )
+    data class Residents(
+        val v1: List<kotlin.String>) : ListItems()
+        
+    {
+        
 
-    protected val handle: Long
-    protected val cleanable: UniffiCleaner.Cleanable?
+        companion object
+    }
+    
+    data class Pilots(
+        val v1: List<kotlin.String>) : ListItems()
+        
+    {
+        
 
-    private val wasDestroyed = AtomicBoolean(false)
-    private val callCounter = AtomicLong(1)
+        companion object
+    }
+    
 
-    override fun destroy() {
-        // Only allow a single call to this method.
-        // TODO: maybe we should log a warning if called more than once?
-        if (this.wasDestroyed.compareAndSet(false, true)) {
-            // This decrement always matches the initial count of 1 given at creation time.
-            if (this.callCounter.decrementAndGet() == 0L) {
-                cleanable?.clean()
-            }
+    
+
+    
+    
+
+
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeListItems : FfiConverterRustBuffer<ListItems>{
+    override fun read(buf: ByteBuffer): ListItems {
+        return when(buf.getInt()) {
+            1 -> ListItems.Planets(
+                FfiConverterSequenceString.read(buf),
+                )
+            2 -> ListItems.People(
+                FfiConverterSequenceString.read(buf),
+                )
+            3 -> ListItems.Films(
+                FfiConverterSequenceString.read(buf),
+                )
+            4 -> ListItems.Species(
+                FfiConverterSequenceString.read(buf),
+                )
+            5 -> ListItems.Starships(
+                FfiConverterSequenceString.read(buf),
+                )
+            6 -> ListItems.Vehicles(
+                FfiConverterSequenceString.read(buf),
+                )
+            7 -> ListItems.Homeworld(
+                FfiConverterString.read(buf),
+                )
+            8 -> ListItems.Characters(
+                FfiConverterSequenceString.read(buf),
+                )
+            9 -> ListItems.Residents(
+                FfiConverterSequenceString.read(buf),
+                )
+            10 -> ListItems.Pilots(
+                FfiConverterSequenceString.read(buf),
+                )
+            else -> throw RuntimeException("invalid enum value, something is very wrong!!")
         }
     }
 
-    @Synchronized
-    override fun close() {
-        this.destroy()
+    override fun allocationSize(value: ListItems) = when(value) {
+        is ListItems.Planets -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterSequenceString.allocationSize(value.v1)
+            )
+        }
+        is ListItems.People -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterSequenceString.allocationSize(value.v1)
+            )
+        }
+        is ListItems.Films -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterSequenceString.allocationSize(value.v1)
+            )
+        }
+        is ListItems.Species -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterSequenceString.allocationSize(value.v1)
+            )
+        }
+        is ListItems.Starships -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterSequenceString.allocationSize(value.v1)
+            )
+        }
+        is ListItems.Vehicles -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterSequenceString.allocationSize(value.v1)
+            )
+        }
+        is ListItems.Homeworld -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.v1)
+            )
+        }
+        is ListItems.Characters -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterSequenceString.allocationSize(value.v1)
+            )
+        }
+        is ListItems.Residents -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterSequenceString.allocationSize(value.v1)
+            )
+        }
+        is ListItems.Pilots -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterSequenceString.allocationSize(value.v1)
+            )
+        }
     }
 
-    internal inline fun <R> callWithHandle(block: (handle: Long) -> R): R {
-        // Check and increment the call counter, to keep the object alive.
-        // This needs a compare-and-set retry loop in case of concurrent updates.
-        do {
-            val c = this.callCounter.get()
-            if (c == 0L) {
-                throw IllegalStateException("${this.javaClass.simpleName} object has already been destroyed")
+    override fun write(value: ListItems, buf: ByteBuffer) {
+        when(value) {
+            is ListItems.Planets -> {
+                buf.putInt(1)
+                FfiConverterSequenceString.write(value.v1, buf)
+                Unit
+            }
+            is ListItems.People -> {
+                buf.putInt(2)
+                FfiConverterSequenceString.write(value.v1, buf)
+                Unit
+            }
+            is ListItems.Films -> {
+                buf.putInt(3)
+                FfiConverterSequenceString.write(value.v1, buf)
+                Unit
+            }
+            is ListItems.Species -> {
+                buf.putInt(4)
+                FfiConverterSequenceString.write(value.v1, buf)
+                Unit
             }
-            if (c == Long.MAX_VALUE) {
-                throw IllegalStateException("${this.javaClass.simpleName} call counter would overflow")
+            is ListItems.Starships -> {
+                buf.putInt(5)
+                FfiConverterSequenceString.write(value.v1, buf)
+                Unit
             }
-        } while (! this.callCounter.compareAndSet(c, c + 1L))
-        // Now we can safely do the method call without the handle being freed concurrently.
-        try {
-            return block(this.uniffiCloneHandle())
-        } finally {
-            // This decrement always matches the increment we performed above.
-            if (this.callCounter.decrementAndGet() == 0L) {
-                cleanable?.clean()
+            is ListItems.Vehicles -> {
+                buf.putInt(6)
+                FfiConverterSequenceString.write(value.v1, buf)
+                Unit
             }
-        }
-    }
-
-    // Use a static inner class instead of a closure so as not to accidentally
-    // capture `this` as part of the cleanable's action.
-    private class UniffiCleanAction(private val handle: Long) : Runnable {
-        override fun run() {
-            if (handle == 0.toLong()) {
-                // Fake object created with `NoHandle`, don't try to free.
-                return;
+            is ListItems.Homeworld -> {
+                buf.putInt(7)
+                FfiConverterString.write(value.v1, buf)
+                Unit
             }
-            uniffiRustCall { status ->
-                UniffiLib.uniffi_starwars_fn_free_datarepository(handle, status)
+            is ListItems.Characters -> {
+                buf.putInt(8)
+                FfiConverterSequenceString.write(value.v1, buf)
+                Unit
             }
-        }
+            is ListItems.Residents -> {
+                buf.putInt(9)
+                FfiConverterSequenceString.write(value.v1, buf)
+                Unit
+            }
+            is ListItems.Pilots -> {
+                buf.putInt(10)
+                FfiConverterSequenceString.write(value.v1, buf)
+                Unit
+            }
+        }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
     }
+}
 
-    /**
-     * @suppress
-     */
-    fun uniffiCloneHandle(): Long {
-        if (handle == 0.toLong()) {
-            throw InternalException("uniffiCloneHandle() called on NoHandle object");
-        }
-        return uniffiRustCall() { status ->
-            UniffiLib.uniffi_starwars_fn_clone_datarepository(handle, status)
-        }
-    }
 
-    override fun `getPlanet`(`url`: kotlin.String): Planet? {
-            return FfiConverterOptionalTypePlanet.lift(
-    callWithHandle {
-    uniffiRustCall() { _status ->
-    UniffiLib.uniffi_starwars_fn_method_datarepository_get_planet(
-        it,
-        FfiConverterString.lower(`url`),_status)
-}
-    }
-    )
-    }
-    
 
-    override fun `getPlanets`(): List<Planet>? {
-            return FfiConverterOptionalSequenceTypePlanet.lift(
-    callWithHandle {
-    uniffiRustCall() { _status ->
-    UniffiLib.uniffi_starwars_fn_method_datarepository_get_planets(
-        it,
-        _status)
-}
-    }
-    )
+
+
+
+
+sealed class NetworkException: kotlin.Exception() {
+    
+    class RequestFailed(
+        
+        val `reason`: kotlin.String
+        ) : NetworkException() {
+        override val message
+            get() = "reason=${ `reason` }"
     }
     
-
-    override fun `insertPlanet`(`planet`: Planet)
-        = 
-    callWithHandle {
-    uniffiRustCall() { _status ->
-    UniffiLib.uniffi_starwars_fn_method_datarepository_insert_planet(
-        it,
-        FfiConverterTypePlanet.lower(`planet`),_status)
-}
+    class Timeout(
+        ) : NetworkException() {
+        override val message
+            get() = ""
     }
     
+    class Cancelled(
+        ) : NetworkException() {
+        override val message
+            get() = ""
+    }
     
 
     
 
-    
 
+    companion object ErrorHandler : UniffiRustCallStatusErrorHandler<NetworkException> {
+        override fun lift(error_buf: RustBuffer.ByValue): NetworkException = FfiConverterTypeNetworkError.lift(error_buf)
+    }
 
     
-    
-    /**
-     * @suppress
-     */
-    companion object
-    
 }
 
-
 /**
  * @suppress
  */
-public object FfiConverterTypeDataRepository: FfiConverter<DataRepository, Long> {
-    override fun lower(value: DataRepository): Long {
-        return value.uniffiCloneHandle()
-    }
-
-    override fun lift(value: Long): DataRepository {
-        return DataRepository(UniffiWithHandle, value)
-    }
+public object FfiConverterTypeNetworkError : FfiConverterRustBuffer<NetworkException> {
+    override fun read(buf: ByteBuffer): NetworkException {
+        
 
-    override fun read(buf: ByteBuffer): DataRepository {
-        return lift(buf.getLong())
+        return when(buf.getInt()) {
+            1 -> NetworkException.RequestFailed(
+                FfiConverterString.read(buf),
+                )
+            2 -> NetworkException.Timeout()
+            3 -> NetworkException.Cancelled()
+            else -> throw RuntimeException("invalid error enum value, something is very wrong!!")
+        }
     }
 
-    override fun allocationSize(value: DataRepository) = 8UL
-
-    override fun write(value: DataRepository, buf: ByteBuffer) {
-        buf.putLong(lower(value))
+    override fun allocationSize(value: NetworkException): ULong {
+        return when(value) {
+            is NetworkException.RequestFailed -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterString.allocationSize(value.`reason`)
+            )
+            is NetworkException.Timeout -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+            is NetworkException.Cancelled -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+        }
     }
-}
-
 
-// This template implements a class for working with a Rust struct via a handle
-// to the live Rust struct on the other side of the FFI.
-//
-// There's some subtlety here, because we have to be careful not to operate on a Rust
-// struct after it has been dropped, and because we must expose a public API for freeing
-// theq Kotlin wrapper object in lieu of reliable finalizers. The core requirements are:
-//
-//   * Each instance holds an opaque handle to the underlying Rust struct.
-//     Method calls need to read this handle from the object's state and pass it in to
-//     the Rust FFI.
-//
-//   * When an instance is no longer needed, its handle should be passed to a
-//     special destructor function provided by the Rust FFI, which will drop the
-//     underlying Rust struct.
-//
-//   * Given an instance, calling code is expected to call the special
-//     `destroy` method in order to free it after use, either by calling it explicitly
-//     or by using a higher-level helper like the `use` method. Failing to do so risks
-//     leaking the underlying Rust struct.
-//
-//   * We can't assume that calling code will do the right thing, and must be prepared
-//     to handle Kotlin method calls executing concurrently with or even after a call to
-//     `destroy`, and to handle multiple (possibly concurrent!) calls to `destroy`.
-//
-//   * We must never allow Rust code to operate on the underlying Rust struct after
-//     the destructor has been called, and must never call the destructor more than once.
-//     Doing so may trigger memory unsafety.
-//
-//   * To mitigate many of the risks of leaking memory and use-after-free unsafety, a `Cleaner`
-//     is implemented to call the destructor when the Kotlin object becomes unreachable.
-//     This is done in a background thread. This is not a panacea, and client code should be aware that
-//      1. the thread may starve if some there are objects that have poorly performing
-//     `drop` methods or do significant work in their `drop` methods.
-//      2. the thread is shared across the whole library. This can be tuned by using `android_cleaner = true`,
-//         or `android = true` in the [`kotlin` section of the `uniffi.toml` file](https://mozilla.github.io/uniffi-rs/kotlin/configuration.html).
-//
-// If we try to implement this with mutual exclusion on access to the handle, there is the
-// possibility of a race between a method call and a concurrent call to `destroy`:
-//
-//    * Thread A starts a method call, reads the value of the handle, but is interrupted
-//      before it can pass the handle over the FFI to Rust.
-//    * Thread B calls `destroy` and frees the underlying Rust struct.
-//    * Thread A resumes, passing the already-read handle value to Rust and triggering
-//      a use-after-free.
-//
-// One possible solution would be to use a `ReadWriteLock`, with each method call taking
-// a read lock (and thus allowed to run concurrently) and the special `destroy` method
-// taking a write lock (and thus blocking on live method calls). However, we aim not to
-// generate methods with any hidden blocking semantics, and a `destroy` method that might
-// block if called incorrectly seems to meet that bar.
-//
-// So, we achieve our goals by giving each instance an associated `AtomicLong` counter to track
-// the number of in-flight method calls, and an `AtomicBoolean` flag to indicate whether `destroy`
-// has been called. These are updated according to the following rules:
-//
-//    * The initial value of the counter is 1, indicating a live object with no in-flight calls.
-//      The initial value for the flag is false.
-//
-//    * At the start of each method call, we atomically check the counter.
-//      If it is 0 then the underlying Rust struct has already been destroyed and the call is aborted.
-//      If it is nonzero them we atomically increment it by 1 and proceed with the method call.
-//
-//    * At the end of each method call, we atomically decrement and check the counter.
-//      If it has reached zero then we destroy the underlying Rust struct.
-//
-//    * When `destroy` is called, we atomically flip the flag from false to true.
-//      If the flag was already true we silently fail.
-//      Otherwise we atomically decrement and check the counter.
-//      If it has reached zero then we destroy the underlying Rust struct.
-//
-// Astute readers may observe that this all sounds very similar to the way that Rust's `Arc<T>` works,
-// and indeed it is, with the addition of a flag to guard against multiple calls to `destroy`.
-//
-// The overall effect is that the underlying Rust struct is destroyed only when `destroy` has been
-// called *and* all in-flight method calls have completed, avoiding violating any of the expectations
-// of the underlying Rust code.
-//
-// This makes a cleaner a better alternative to _not_ calling `destroy()` as
-// and when the object is finished with, but the abstraction is not perfect: if the Rust object's `drop`
-// method is slow, and/or there are many objects to cleanup, and it's on a low end Android device, then the cleaner
-// thread may be starved, and the app will leak memory.
-//
-// In this case, `destroy`ing manually may be a better solution.
-//
-// The cleaner can live side by side with the manual calling of `destroy`. In the order of responsiveness, uniffi objects
-// with Rust peers are reclaimed:
-//
-// 1. By calling the `destroy` method of the object, which calls `rustObject.free()`. If that doesn't happen:
-// 2. When the object becomes unreachable, AND the Cleaner thread gets to call `rustObject.free()`. If the thread is starved then:
-// 3. The memory is reclaimed when the process terminates.
-//
-// [1] https://stackoverflow.com/questions/24376768/can-java-finalize-an-object-when-it-is-still-in-scope/24380219
-//
+    override fun write(value: NetworkException, buf: ByteBuffer) {
+        when(value) {
+            is NetworkException.RequestFailed -> {
+                buf.putInt(1)
+                FfiConverterString.write(value.`reason`, buf)
+                Unit
+            }
+            is NetworkException.Timeout -> {
+                buf.putInt(2)
+                Unit
+            }
+            is NetworkException.Cancelled -> {
+                buf.putInt(3)
+                Unit
+            }
+        }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
+    }
+
+}
 
 
-public interface HttpClient {
+
+
+
+sealed class QueryException: kotlin.Exception() {
     
-    suspend fun `fetch`(`url`: kotlin.String): kotlin.ByteArray
+    class UnknownField(
+        
+        val `field`: kotlin.String
+        ) : QueryException() {
+        override val message
+            get() = "field=${ `field` }"
+    }
     
-    companion object
-}
 
-open class HttpClientImpl: Disposable, AutoCloseable, HttpClient
-{
+    
 
-    @Suppress("UNUSED_PARAMETER")
-    /**
-     * @suppress
-     */
-    constructor(withHandle: UniffiWithHandle, handle: Long) {
-        this.handle = handle
-        this.cleanable = UniffiLib.CLEANER.register(this, UniffiCleanAction(handle))
-    }
 
-    /**
-     * @suppress
-     *
-     * This constructor can be used to instantiate a fake object. Only used for tests. Any
-     * attempt to actually use an object constructed this way will fail as there is no
-     * connected Rust object.
-     */
-    @Suppress("UNUSED_PARAMETER")
-    constructor(noHandle: NoHandle) {
-        this.handle = 0
-        this.cleanable = null
+    companion object ErrorHandler : UniffiRustCallStatusErrorHandler<QueryException> {
+        override fun lift(error_buf: RustBuffer.ByValue): QueryException = FfiConverterTypeQueryError.lift(error_buf)
     }
 
-    protected val handle: Long
-    protected val cleanable: UniffiCleaner.Cleanable?
+    
+}
 
-    private val wasDestroyed = AtomicBoolean(false)
-    private val callCounter = AtomicLong(1)
+/**
+ * @suppress
+ */
+public object FfiConverterTypeQueryError : FfiConverterRustBuffer<QueryException> {
+    override fun read(buf: ByteBuffer): QueryException {
+        
 
-    override fun destroy() {
-        // Only allow a single call to this method.
-        // TODO: maybe we should log a warning if called more than once?
-        if (this.wasDestroyed.compareAndSet(false, true)) {
-            // This decrement always matches the initial count of 1 given at creation time.
-            if (this.callCounter.decrementAndGet() == 0L) {
-                cleanable?.clean()
-            }
+        return when(buf.getInt()) {
+            1 -> QueryException.UnknownField(
+                FfiConverterString.read(buf),
+                )
+            else -> throw RuntimeException("invalid error enum value, something is very wrong!!")
         }
     }
 
-    @Synchronized
-    override fun close() {
-        this.destroy()
-    }
-
-    internal inline fun <R> callWithHandle(block: (handle: Long) -> R): R {
-        // Check and increment the call counter, to keep the object alive.
-        // This needs a compare-and-set retry loop in case of concurrent updates.
-        do {
-            val c = this.callCounter.get()
-            if (c == 0L) {
-                throw IllegalStateException("${this.javaClass.simpleName} object has already been destroyed")
-            }
-            if (c == Long.MAX_VALUE) {
-                throw IllegalStateException("${this.javaClass.simpleName} call counter would overflow")
-            }
-        } while (! this.callCounter.compareAndSet(c, c + 1L))
-        // Now we can safely do the method call without the handle being freed concurrently.
-        try {
-            return block(this.uniffiCloneHandle())
-        } finally {
-            // This decrement always matches the increment we performed above.
-            if (this.callCounter.decrementAndGet() == 0L) {
-                cleanable?.clean()
-            }
+    override fun allocationSize(value: QueryException): ULong {
+        return when(value) {
+            is QueryException.UnknownField -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterString.allocationSize(value.`field`)
+            )
         }
     }
 
-    // Use a static inner class instead of a closure so as not to accidentally
-    // capture `this` as part of the cleanable's action.
-    private class UniffiCleanAction(private val handle: Long) : Runnable {
-        override fun run() {
-            if (handle == 0.toLong()) {
-                // Fake object created with `NoHandle`, don't try to free.
-                return;
-            }
-            uniffiRustCall { status ->
-                UniffiLib.uniffi_starwars_fn_free_httpclient(handle, status)
+    override fun write(value: QueryException, buf: ByteBuffer) {
+        when(value) {
+            is QueryException.UnknownField -> {
+                buf.putInt(1)
+                FfiConverterString.write(value.`field`, buf)
+                Unit
             }
-        }
+        }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
     }
 
-    /**
-     * @suppress
-     */
-    fun uniffiCloneHandle(): Long {
-        if (handle == 0.toLong()) {
-            throw InternalException("uniffiCloneHandle() called on NoHandle object");
-        }
-        return uniffiRustCall() { status ->
-            UniffiLib.uniffi_starwars_fn_clone_httpclient(handle, status)
-        }
-    }
+}
+
 
-    
-    @Throws(NetworkException::class)
-    @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    override suspend fun `fetch`(`url`: kotlin.String) : kotlin.ByteArray {
-        return uniffiRustCallAsync(
-        callWithHandle { uniffiHandle ->
-            UniffiLib.uniffi_starwars_fn_method_httpclient_fetch(
-                uniffiHandle,
-                FfiConverterString.lower(`url`),
-            )
-        },
-        { future, callback, continuation -> UniffiLib.ffi_starwars_rust_future_poll_rust_buffer(future, callback, continuation) },
-        { future, continuation -> UniffiLib.ffi_starwars_rust_future_complete_rust_buffer(future, continuation) },
-        { future -> UniffiLib.ffi_starwars_rust_future_free_rust_buffer(future) },
-        // lift function
-        { FfiConverterByteArray.lift(it) },
-        // Error FFI converter
-        NetworkException.ErrorHandler,
-    )
-    }
 
+
+enum class Relationship {
     
+    PLANETS,
+    PEOPLE,
+    FILMS,
+    SPECIES,
+    STARSHIPS,
+    VEHICLES,
+    HOMEWORLD,
+    CHARACTERS,
+    RESIDENTS,
+    PILOTS;
 
     
 
 
+    companion object
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeRelationship: FfiConverterRustBuffer<Relationship> {
+    override fun read(buf: ByteBuffer) = try {
+        Relationship.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: Relationship) = 4UL
+
+    override fun write(value: Relationship, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+
+enum class ResponseFormat {
     
+    JSON,
+    WOOKIEE;
+
     
-    /**
-     * @suppress
-     */
+
+
     companion object
-    
 }
 
 
+/**
+ * @suppress
+ */
+public object FfiConverterTypeResponseFormat: FfiConverterRustBuffer<ResponseFormat> {
+    override fun read(buf: ByteBuffer) = try {
+        ResponseFormat.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
 
-// Put the implementation in an object so we don't pollute the top-level namespace
-internal object uniffiCallbackInterfaceHttpClient {
-    internal object `fetch`: UniffiCallbackInterfaceHttpClientMethod0 {
-        override fun callback(`uniffiHandle`: Long,`url`: RustBuffer.ByValue,`uniffiFutureCallback`: UniffiForeignFutureCompleteRustBuffer,`uniffiCallbackData`: Long,`uniffiOutDroppedCallback`: UniffiForeignFutureDroppedCallbackStruct,) {
-            val uniffiObj = FfiConverterTypeHttpClient.handleMap.get(uniffiHandle)
-            val makeCall = suspend { ->
-                uniffiObj.`fetch`(
-                    FfiConverterString.lift(`url`),
-                )
-            }
-            val uniffiHandleSuccess = { returnValue: kotlin.ByteArray ->
-                val uniffiResult = UniffiForeignFutureResultRustBuffer.UniffiByValue(
-                    FfiConverterByteArray.lower(returnValue),
-                    UniffiRustCallStatus.ByValue()
-                )
-                uniffiResult.write()
-                uniffiFutureCallback.callback(uniffiCallbackData, uniffiResult)
-            }
-            val uniffiHandleError = { callStatus: UniffiRustCallStatus.ByValue ->
-                uniffiFutureCallback.callback(
-                    uniffiCallbackData,
-                    UniffiForeignFutureResultRustBuffer.UniffiByValue(
-                        RustBuffer.ByValue(),
-                        callStatus,
-                    ),
-                )
-            }
-            uniffiTraitInterfaceCallAsyncWithError(
-                makeCall,
-                uniffiHandleSuccess,
-                uniffiHandleError,
-                { e: NetworkException -> FfiConverterTypeNetworkError.lower(e) },
-                uniffiOutDroppedCallback
-            )
-        }
+    override fun allocationSize(value: ResponseFormat) = 4UL
+
+    override fun write(value: ResponseFormat, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+sealed class Selected {
+    
+    data class Film(
+        val v1: uniffi.starwars.Film) : Selected()
+        
+    {
+        
+
+        companion object
+    }
+    
+    data class Person(
+        val v1: uniffi.starwars.Person) : Selected()
+        
+    {
+        
+
+        companion object
+    }
+    
+    data class Planet(
+        val v1: uniffi.starwars.Planet) : Selected()
+        
+    {
+        
+
+        companion object
+    }
+    
+    data class Species(
+        val v1: uniffi.starwars.Species) : Selected()
+        
+    {
+        
+
+        companion object
     }
+    
+    data class Starship(
+        val v1: uniffi.starwars.Starship) : Selected()
+        
+    {
+        
 
-    internal object uniffiFree: UniffiCallbackInterfaceFree {
-        override fun callback(handle: Long) {
-            FfiConverterTypeHttpClient.handleMap.remove(handle)
-        }
+        companion object
     }
+    
+    data class Vehicle(
+        val v1: uniffi.starwars.Vehicle) : Selected()
+        
+    {
+        
 
-    internal object uniffiClone: UniffiCallbackInterfaceClone {
-        override fun callback(handle: Long): Long {
-            return FfiConverterTypeHttpClient.handleMap.clone(handle)
-        }
+        companion object
     }
+    
 
-    internal var vtable = UniffiVTableCallbackInterfaceHttpClient.UniffiByValue(
-        uniffiFree,
-        uniffiClone,
-        `fetch`,
-    )
+    
 
-    // Registers the foreign callback with the Rust side.
-    // This method is generated for each callback interface.
-    internal fun register(lib: UniffiLib) {
-        lib.uniffi_starwars_fn_init_callback_vtable_httpclient(vtable)
-    }
+    
+    
+
+
+    companion object
 }
 
 /**
  * @suppress
  */
-public object FfiConverterTypeHttpClient: FfiConverter<HttpClient, Long> {
-    internal val handleMap = UniffiHandleMap<HttpClient>()
-
-    override fun lower(value: HttpClient): Long {
-        if (value is HttpClientImpl) {
-             // Rust-implemented object.  Clone the handle and return it
-            return value.uniffiCloneHandle()
-         } else {
-            // Kotlin object, generate a new vtable handle and return that.
-            return handleMap.insert(value)
-         }
+public object FfiConverterTypeSelected : FfiConverterRustBuffer<Selected>{
+    override fun read(buf: ByteBuffer): Selected {
+        return when(buf.getInt()) {
+            1 -> Selected.Film(
+                FfiConverterTypeFilm.read(buf),
+                )
+            2 -> Selected.Person(
+                FfiConverterTypePerson.read(buf),
+                )
+            3 -> Selected.Planet(
+                FfiConverterTypePlanet.read(buf),
+                )
+            4 -> Selected.Species(
+                FfiConverterTypeSpecies.read(buf),
+                )
+            5 -> Selected.Starship(
+                FfiConverterTypeStarship.read(buf),
+                )
+            6 -> Selected.Vehicle(
+                FfiConverterTypeVehicle.read(buf),
+                )
+            else -> throw RuntimeException("invalid enum value, something is very wrong!!")
+        }
     }
 
-    override fun lift(value: Long): HttpClient {
-        if ((value and 1.toLong()) == 0.toLong()) {
-            // Rust-generated handle, construct a new class that uses the handle to implement the
-            // interface
-            return HttpClientImpl(UniffiWithHandle, value)
-        } else {
-            // Kotlin-generated handle, get the object from the handle map
-            return handleMap.remove(value)
+    override fun allocationSize(value: Selected) = when(value) {
+        is Selected.Film -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterTypeFilm.allocationSize(value.v1)
+            )
+        }
+        is Selected.Person -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterTypePerson.allocationSize(value.v1)
+            )
+        }
+        is Selected.Planet -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterTypePlanet.allocationSize(value.v1)
+            )
+        }
+        is Selected.Species -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterTypeSpecies.allocationSize(value.v1)
+            )
+        }
+        is Selected.Starship -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterTypeStarship.allocationSize(value.v1)
+            )
+        }
+        is Selected.Vehicle -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterTypeVehicle.allocationSize(value.v1)
+            )
         }
     }
 
-    override fun read(buf: ByteBuffer): HttpClient {
-        return lift(buf.getLong())
+    override fun write(value: Selected, buf: ByteBuffer) {
+        when(value) {
+            is Selected.Film -> {
+                buf.putInt(1)
+                FfiConverterTypeFilm.write(value.v1, buf)
+                Unit
+            }
+            is Selected.Person -> {
+                buf.putInt(2)
+                FfiConverterTypePerson.write(value.v1, buf)
+                Unit
+            }
+            is Selected.Planet -> {
+                buf.putInt(3)
+                FfiConverterTypePlanet.write(value.v1, buf)
+                Unit
+            }
+            is Selected.Species -> {
+                buf.putInt(4)
+                FfiConverterTypeSpecies.write(value.v1, buf)
+                Unit
+            }
+            is Selected.Starship -> {
+                buf.putInt(5)
+                FfiConverterTypeStarship.write(value.v1, buf)
+                Unit
+            }
+            is Selected.Vehicle -> {
+                buf.putInt(6)
+                FfiConverterTypeVehicle.write(value.v1, buf)
+                Unit
+            }
+        }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
     }
+}
 
-    override fun allocationSize(value: HttpClient) = 8UL
 
-    override fun write(value: HttpClient, buf: ByteBuffer) {
-        buf.putLong(lower(value))
-    }
-}
 
 
 
-data class Film (
-    val `title`: kotlin.String
-    , 
-    val `episodeId`: kotlin.Int
-    , 
-    val `openingCrawl`: kotlin.String
-    , 
-    val `director`: kotlin.String
-    , 
-    val `producer`: kotlin.String
-    , 
-    val `releaseDate`: kotlin.String
-    , 
-    val `characters`: List<kotlin.String>
-    , 
-    val `planets`: List<kotlin.String>
-    , 
-    val `starships`: List<kotlin.String>
-    , 
-    val `vehicles`: List<kotlin.String>
-    , 
-    val `species`: List<kotlin.String>
-    , 
-    val `created`: kotlin.String
-    , 
-    val `edited`: kotlin.String
-    , 
-    val `url`: kotlin.String
-    
-){
-    
 
+enum class SortDirection {
     
+    ASCENDING,
+    DESCENDING;
 
     
+
+
     companion object
 }
 
-/**
- * @suppress
- */
-public object FfiConverterTypeFilm: FfiConverterRustBuffer<Film> {
-    override fun read(buf: ByteBuffer): Film {
-        return Film(
-            FfiConverterString.read(buf),
-            FfiConverterInt.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterSequenceString.read(buf),
-            FfiConverterSequenceString.read(buf),
-            FfiConverterSequenceString.read(buf),
-            FfiConverterSequenceString.read(buf),
-            FfiConverterSequenceString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-        )
-    }
-
-    override fun allocationSize(value: Film) = (
-            FfiConverterString.allocationSize(value.`title`) +
-            FfiConverterInt.allocationSize(value.`episodeId`) +
-            FfiConverterString.allocationSize(value.`openingCrawl`) +
-            FfiConverterString.allocationSize(value.`director`) +
-            FfiConverterString.allocationSize(value.`producer`) +
-            FfiConverterString.allocationSize(value.`releaseDate`) +
-            FfiConverterSequenceString.allocationSize(value.`characters`) +
-            FfiConverterSequenceString.allocationSize(value.`planets`) +
-            FfiConverterSequenceString.allocationSize(value.`starships`) +
-            FfiConverterSequenceString.allocationSize(value.`vehicles`) +
-            FfiConverterSequenceString.allocationSize(value.`species`) +
-            FfiConverterString.allocationSize(value.`created`) +
-            FfiConverterString.allocationSize(value.`edited`) +
-            FfiConverterString.allocationSize(value.`url`)
-    )
 
-    override fun write(value: Film, buf: ByteBuffer) {
-            FfiConverterString.write(value.`title`, buf)
-            FfiConverterInt.write(value.`episodeId`, buf)
-            FfiConverterString.write(value.`openingCrawl`, buf)
-            FfiConverterString.write(value.`director`, buf)
-            FfiConverterString.write(value.`producer`, buf)
-            FfiConverterString.write(value.`releaseDate`, buf)
-            FfiConverterSequenceString.write(value.`characters`, buf)
-            FfiConverterSequenceString.write(value.`planets`, buf)
-            FfiConverterSequenceString.write(value.`starships`, buf)
-            FfiConverterSequenceString.write(value.`vehicles`, buf)
-            FfiConverterSequenceString.write(value.`species`, buf)
-            FfiConverterString.write(value.`created`, buf)
-            FfiConverterString.write(value.`edited`, buf)
-            FfiConverterString.write(value.`url`, buf)
+/**
+ * @suppress
+ */
+public object FfiConverterTypeSortDirection: FfiConverterRustBuffer<SortDirection> {
+    override fun read(buf: ByteBuffer) = try {
+        SortDirection.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: SortDirection) = 4UL
+
+    override fun write(value: SortDirection, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
     }
 }
 
 
 
-data class Person (
-    val `birthYear`: kotlin.String
-    , 
-    val `eyeColor`: kotlin.String
-    , 
-    val `films`: List<kotlin.String>
-    , 
-    val `gender`: kotlin.String
-    , 
-    val `hairColor`: kotlin.String
-    , 
-    val `height`: kotlin.String
-    , 
-    val `homeworld`: kotlin.String?
-    , 
-    val `mass`: kotlin.String
-    , 
-    val `name`: kotlin.String
-    , 
-    val `skinColor`: kotlin.String
-    , 
-    val `created`: kotlin.String
-    , 
-    val `edited`: kotlin.String
-    , 
-    val `species`: List<kotlin.String>
-    , 
-    val `starships`: List<kotlin.String>
-    , 
-    val `url`: kotlin.String
-    , 
-    val `vehicles`: List<kotlin.String>
+
+
+
+
+sealed class StoreException: kotlin.Exception() {
     
-){
+    class Io(
+        
+        val `reason`: kotlin.String
+        ) : StoreException() {
+        override val message
+            get() = "reason=${ `reason` }"
+    }
     
 
     
 
+
+    companion object ErrorHandler : UniffiRustCallStatusErrorHandler<StoreException> {
+        override fun lift(error_buf: RustBuffer.ByValue): StoreException = FfiConverterTypeStoreError.lift(error_buf)
+    }
+
     
-    companion object
 }
 
 /**
  * @suppress
  */
-public object FfiConverterTypePerson: FfiConverterRustBuffer<Person> {
-    override fun read(buf: ByteBuffer): Person {
-        return Person(
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterSequenceString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterOptionalString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterSequenceString.read(buf),
-            FfiConverterSequenceString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterSequenceString.read(buf),
-        )
+public object FfiConverterTypeStoreError : FfiConverterRustBuffer<StoreException> {
+    override fun read(buf: ByteBuffer): StoreException {
+        
+
+        return when(buf.getInt()) {
+            1 -> StoreException.Io(
+                FfiConverterString.read(buf),
+                )
+            else -> throw RuntimeException("invalid error enum value, something is very wrong!!")
+        }
     }
 
-    override fun allocationSize(value: Person) = (
-            FfiConverterString.allocationSize(value.`birthYear`) +
-            FfiConverterString.allocationSize(value.`eyeColor`) +
-            FfiConverterSequenceString.allocationSize(value.`films`) +
-            FfiConverterString.allocationSize(value.`gender`) +
-            FfiConverterString.allocationSize(value.`hairColor`) +
-            FfiConverterString.allocationSize(value.`height`) +
-            FfiConverterOptionalString.allocationSize(value.`homeworld`) +
-            FfiConverterString.allocationSize(value.`mass`) +
-            FfiConverterString.allocationSize(value.`name`) +
-            FfiConverterString.allocationSize(value.`skinColor`) +
-            FfiConverterString.allocationSize(value.`created`) +
-            FfiConverterString.allocationSize(value.`edited`) +
-            FfiConverterSequenceString.allocationSize(value.`species`) +
-            FfiConverterSequenceString.allocationSize(value.`starships`) +
-            FfiConverterString.allocationSize(value.`url`) +
-            FfiConverterSequenceString.allocationSize(value.`vehicles`)
-    )
+    override fun allocationSize(value: StoreException): ULong {
+        return when(value) {
+            is StoreException.Io -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterString.allocationSize(value.`reason`)
+            )
+        }
+    }
 
-    override fun write(value: Person, buf: ByteBuffer) {
-            FfiConverterString.write(value.`birthYear`, buf)
-            FfiConverterString.write(value.`eyeColor`, buf)
-            FfiConverterSequenceString.write(value.`films`, buf)
-            FfiConverterString.write(value.`gender`, buf)
-            FfiConverterString.write(value.`hairColor`, buf)
-            FfiConverterString.write(value.`height`, buf)
-            FfiConverterOptionalString.write(value.`homeworld`, buf)
-            FfiConverterString.write(value.`mass`, buf)
-            FfiConverterString.write(value.`name`, buf)
-            FfiConverterString.write(value.`skinColor`, buf)
-            FfiConverterString.write(value.`created`, buf)
-            FfiConverterString.write(value.`edited`, buf)
-            FfiConverterSequenceString.write(value.`species`, buf)
-            FfiConverterSequenceString.write(value.`starships`, buf)
-            FfiConverterString.write(value.`url`, buf)
-            FfiConverterSequenceString.write(value.`vehicles`, buf)
+    override fun write(value: StoreException, buf: ByteBuffer) {
+        when(value) {
+            is StoreException.Io -> {
+                buf.putInt(1)
+                FfiConverterString.write(value.`reason`, buf)
+                Unit
+            }
+        }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
     }
+
 }
 
 
 
-data class Planet (
-    val `name`: kotlin.String
-    , 
-    val `rotationPeriod`: kotlin.String
-    , 
-    val `orbitalPeriod`: kotlin.String
-    , 
-    val `diameter`: kotlin.String
-    , 
-    val `climate`: kotlin.String
-    , 
-    val `gravity`: kotlin.String
-    , 
-    val `terrain`: kotlin.String
-    , 
-    val `surfaceWater`: kotlin.String
-    , 
-    val `population`: kotlin.String
-    , 
-    val `residents`: List<kotlin.String>
-    , 
-    val `films`: List<kotlin.String>
-    , 
-    val `created`: kotlin.String
-    , 
-    val `edited`: kotlin.String
-    , 
-    val `url`: kotlin.String
-    
-){
-    
 
+enum class TimeUnit {
     
+    HOURS,
+    DAYS,
+    WEEKS,
+    MONTHS,
+    YEARS;
 
     
+
+
     companion object
 }
 
+
 /**
  * @suppress
  */
-public object FfiConverterTypePlanet: FfiConverterRustBuffer<Planet> {
-    override fun read(buf: ByteBuffer): Planet {
-        return Planet(
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterSequenceString.read(buf),
-            FfiConverterSequenceString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-        )
+public object FfiConverterTypeTimeUnit: FfiConverterRustBuffer<TimeUnit> {
+    override fun read(buf: ByteBuffer) = try {
+        TimeUnit.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
     }
 
-    override fun allocationSize(value: Planet) = (
-            FfiConverterString.allocationSize(value.`name`) +
-            FfiConverterString.allocationSize(value.`rotationPeriod`) +
-            FfiConverterString.allocationSize(value.`orbitalPeriod`) +
-            FfiConverterString.allocationSize(value.`diameter`) +
-            FfiConverterString.allocationSize(value.`climate`) +
-            FfiConverterString.allocationSize(value.`gravity`) +
-            FfiConverterString.allocationSize(value.`terrain`) +
-            FfiConverterString.allocationSize(value.`surfaceWater`) +
-            FfiConverterString.allocationSize(value.`population`) +
-            FfiConverterSequenceString.allocationSize(value.`residents`) +
-            FfiConverterSequenceString.allocationSize(value.`films`) +
-            FfiConverterString.allocationSize(value.`created`) +
-            FfiConverterString.allocationSize(value.`edited`) +
-            FfiConverterString.allocationSize(value.`url`)
-    )
+    override fun allocationSize(value: TimeUnit) = 4UL
 
-    override fun write(value: Planet, buf: ByteBuffer) {
-            FfiConverterString.write(value.`name`, buf)
-            FfiConverterString.write(value.`rotationPeriod`, buf)
-            FfiConverterString.write(value.`orbitalPeriod`, buf)
-            FfiConverterString.write(value.`diameter`, buf)
-            FfiConverterString.write(value.`climate`, buf)
-            FfiConverterString.write(value.`gravity`, buf)
-            FfiConverterString.write(value.`terrain`, buf)
-            FfiConverterString.write(value.`surfaceWater`, buf)
-            FfiConverterString.write(value.`population`, buf)
-            FfiConverterSequenceString.write(value.`residents`, buf)
-            FfiConverterSequenceString.write(value.`films`, buf)
-            FfiConverterString.write(value.`created`, buf)
-            FfiConverterString.write(value.`edited`, buf)
-            FfiConverterString.write(value.`url`, buf)
+    override fun write(value: TimeUnit, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalUInt: FfiConverterRustBuffer<kotlin.UInt?> {
+    override fun read(buf: ByteBuffer): kotlin.UInt? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterUInt.read(buf)
+    }
+
+    override fun allocationSize(value: kotlin.UInt?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterUInt.allocationSize(value)
+        }
+    }
+
+    override fun write(value: kotlin.UInt?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterUInt.write(value, buf)
+        }
     }
 }
 
 
 
-data class Species (
-    val `name`: kotlin.String
-    , 
-    val `classification`: kotlin.String
-    , 
-    val `designation`: kotlin.String
-    , 
-    val `averageHeight`: kotlin.String
-    , 
-    val `skinColors`: kotlin.String
-    , 
-    val `hairColors`: kotlin.String
-    , 
-    val `eyeColors`: kotlin.String
-    , 
-    val `averageLifespan`: kotlin.String
-    , 
-    val `homeworld`: kotlin.String?
-    , 
-    val `language`: kotlin.String
-    , 
-    val `people`: List<kotlin.String>
-    , 
-    val `films`: List<kotlin.String>
-    , 
-    val `created`: kotlin.String
-    , 
-    val `edited`: kotlin.String
-    , 
-    val `url`: kotlin.String
-    
-){
-    
 
-    
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalULong: FfiConverterRustBuffer<kotlin.ULong?> {
+    override fun read(buf: ByteBuffer): kotlin.ULong? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterULong.read(buf)
+    }
+
+    override fun allocationSize(value: kotlin.ULong?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterULong.allocationSize(value)
+        }
+    }
+
+    override fun write(value: kotlin.ULong?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterULong.write(value, buf)
+        }
+    }
+}
+
+
 
-    
-    companion object
-}
 
 /**
  * @suppress
  */
-public object FfiConverterTypeSpecies: FfiConverterRustBuffer<Species> {
-    override fun read(buf: ByteBuffer): Species {
-        return Species(
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterOptionalString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterSequenceString.read(buf),
-            FfiConverterSequenceString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-        )
+public object FfiConverterOptionalDouble: FfiConverterRustBuffer<kotlin.Double?> {
+    override fun read(buf: ByteBuffer): kotlin.Double? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterDouble.read(buf)
     }
 
-    override fun allocationSize(value: Species) = (
-            FfiConverterString.allocationSize(value.`name`) +
-            FfiConverterString.allocationSize(value.`classification`) +
-            FfiConverterString.allocationSize(value.`designation`) +
-            FfiConverterString.allocationSize(value.`averageHeight`) +
-            FfiConverterString.allocationSize(value.`skinColors`) +
-            FfiConverterString.allocationSize(value.`hairColors`) +
-            FfiConverterString.allocationSize(value.`eyeColors`) +
-            FfiConverterString.allocationSize(value.`averageLifespan`) +
-            FfiConverterOptionalString.allocationSize(value.`homeworld`) +
-            FfiConverterString.allocationSize(value.`language`) +
-            FfiConverterSequenceString.allocationSize(value.`people`) +
-            FfiConverterSequenceString.allocationSize(value.`films`) +
-            FfiConverterString.allocationSize(value.`created`) +
-            FfiConverterString.allocationSize(value.`edited`) +
-            FfiConverterString.allocationSize(value.`url`)
-    )
+    override fun allocationSize(value: kotlin.Double?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterDouble.allocationSize(value)
+        }
+    }
 
-    override fun write(value: Species, buf: ByteBuffer) {
-            FfiConverterString.write(value.`name`, buf)
-            FfiConverterString.write(value.`classification`, buf)
-            FfiConverterString.write(value.`designation`, buf)
-            FfiConverterString.write(value.`averageHeight`, buf)
-            FfiConverterString.write(value.`skinColors`, buf)
-            FfiConverterString.write(value.`hairColors`, buf)
-            FfiConverterString.write(value.`eyeColors`, buf)
-            FfiConverterString.write(value.`averageLifespan`, buf)
-            FfiConverterOptionalString.write(value.`homeworld`, buf)
-            FfiConverterString.write(value.`language`, buf)
-            FfiConverterSequenceString.write(value.`people`, buf)
-            FfiConverterSequenceString.write(value.`films`, buf)
-            FfiConverterString.write(value.`created`, buf)
-            FfiConverterString.write(value.`edited`, buf)
-            FfiConverterString.write(value.`url`, buf)
+    override fun write(value: kotlin.Double?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterDouble.write(value, buf)
+        }
     }
 }
 
 
 
-data class Starship (
-    val `name`: kotlin.String
-    , 
-    val `model`: kotlin.String
-    , 
-    val `manufacturer`: kotlin.String
-    , 
-    val `costInCredits`: kotlin.String
-    , 
-    val `length`: kotlin.String
-    , 
-    val `maxAtmospheringSpeed`: kotlin.String
-    , 
-    val `crew`: kotlin.String
-    , 
-    val `passengers`: kotlin.String
-    , 
-    val `cargoCapacity`: kotlin.String
-    , 
-    val `consumables`: kotlin.String
-    , 
-    val `hyperdriveRating`: kotlin.String
-    , 
-    val `mglt`: kotlin.String
-    , 
-    val `starshipClass`: kotlin.String
-    , 
-    val `pilots`: List<kotlin.String>
-    , 
-    val `films`: List<kotlin.String>
-    , 
-    val `created`: kotlin.String
-    , 
-    val `edited`: kotlin.String
-    , 
-    val `url`: kotlin.String
-    
-){
-    
 
-    
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalString: FfiConverterRustBuffer<kotlin.String?> {
+    override fun read(buf: ByteBuffer): kotlin.String? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterString.read(buf)
+    }
 
-    
-    companion object
+    override fun allocationSize(value: kotlin.String?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterString.allocationSize(value)
+        }
+    }
+
+    override fun write(value: kotlin.String?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterString.write(value, buf)
+        }
+    }
 }
 
+
+
+
 /**
  * @suppress
  */
-public object FfiConverterTypeStarship: FfiConverterRustBuffer<Starship> {
-    override fun read(buf: ByteBuffer): Starship {
-        return Starship(
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterSequenceString.read(buf),
-            FfiConverterSequenceString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-        )
+public object FfiConverterOptionalByteArray: FfiConverterRustBuffer<kotlin.ByteArray?> {
+    override fun read(buf: ByteBuffer): kotlin.ByteArray? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterByteArray.read(buf)
     }
 
-    override fun allocationSize(value: Starship) = (
-            FfiConverterString.allocationSize(value.`name`) +
-            FfiConverterString.allocationSize(value.`model`) +
-            FfiConverterString.allocationSize(value.`manufacturer`) +
-            FfiConverterString.allocationSize(value.`costInCredits`) +
-            FfiConverterString.allocationSize(value.`length`) +
-            FfiConverterString.allocationSize(value.`maxAtmospheringSpeed`) +
-            FfiConverterString.allocationSize(value.`crew`) +
-            FfiConverterString.allocationSize(value.`passengers`) +
-            FfiConverterString.allocationSize(value.`cargoCapacity`) +
-            FfiConverterString.allocationSize(value.`consumables`) +
-            FfiConverterString.allocationSize(value.`hyperdriveRating`) +
-            FfiConverterString.allocationSize(value.`mglt`) +
-            FfiConverterString.allocationSize(value.`starshipClass`) +
-            FfiConverterSequenceString.allocationSize(value.`pilots`) +
-            FfiConverterSequenceString.allocationSize(value.`films`) +
-            FfiConverterString.allocationSize(value.`created`) +
-            FfiConverterString.allocationSize(value.`edited`) +
-            FfiConverterString.allocationSize(value.`url`)
-    )
+    override fun allocationSize(value: kotlin.ByteArray?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterByteArray.allocationSize(value)
+        }
+    }
 
-    override fun write(value: Starship, buf: ByteBuffer) {
-            FfiConverterString.write(value.`name`, buf)
-            FfiConverterString.write(value.`model`, buf)
-            FfiConverterString.write(value.`manufacturer`, buf)
-            FfiConverterString.write(value.`costInCredits`, buf)
-            FfiConverterString.write(value.`length`, buf)
-            FfiConverterString.write(value.`maxAtmospheringSpeed`, buf)
-            FfiConverterString.write(value.`crew`, buf)
-            FfiConverterString.write(value.`passengers`, buf)
-            FfiConverterString.write(value.`cargoCapacity`, buf)
-            FfiConverterString.write(value.`consumables`, buf)
-            FfiConverterString.write(value.`hyperdriveRating`, buf)
-            FfiConverterString.write(value.`mglt`, buf)
-            FfiConverterString.write(value.`starshipClass`, buf)
-            FfiConverterSequenceString.write(value.`pilots`, buf)
-            FfiConverterSequenceString.write(value.`films`, buf)
-            FfiConverterString.write(value.`created`, buf)
-            FfiConverterString.write(value.`edited`, buf)
-            FfiConverterString.write(value.`url`, buf)
+    override fun write(value: kotlin.ByteArray?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterByteArray.write(value, buf)
+        }
     }
 }
 
 
 
-data class Vehicle (
-    val `name`: kotlin.String
-    , 
-    val `model`: kotlin.String
-    , 
-    val `manufacturer`: kotlin.String
-    , 
-    val `costInCredits`: kotlin.String
-    , 
-    val `length`: kotlin.String
-    , 
-    val `maxAtmospheringSpeed`: kotlin.String
-    , 
-    val `crew`: kotlin.String
-    , 
-    val `passengers`: kotlin.String
-    , 
-    val `cargoCapacity`: kotlin.String
-    , 
-    val `consumables`: kotlin.String
-    , 
-    val `vehicleClass`: kotlin.String
-    , 
-    val `pilots`: List<kotlin.String>
-    , 
-    val `films`: List<kotlin.String>
-    , 
-    val `created`: kotlin.String
-    , 
-    val `edited`: kotlin.String
-    , 
-    val `url`: kotlin.String
-    
-){
-    
-
-    
-
-    
-    companion object
-}
 
 /**
  * @suppress
  */
-public object FfiConverterTypeVehicle: FfiConverterRustBuffer<Vehicle> {
-    override fun read(buf: ByteBuffer): Vehicle {
-        return Vehicle(
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterSequenceString.read(buf),
-            FfiConverterSequenceString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-        )
+public object FfiConverterOptionalTypeCancellationToken: FfiConverterRustBuffer<CancellationToken?> {
+    override fun read(buf: ByteBuffer): CancellationToken? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterTypeCancellationToken.read(buf)
     }
 
-    override fun allocationSize(value: Vehicle) = (
-            FfiConverterString.allocationSize(value.`name`) +
-            FfiConverterString.allocationSize(value.`model`) +
-            FfiConverterString.allocationSize(value.`manufacturer`) +
-            FfiConverterString.allocationSize(value.`costInCredits`) +
-            FfiConverterString.allocationSize(value.`length`) +
-            FfiConverterString.allocationSize(value.`maxAtmospheringSpeed`) +
-            FfiConverterString.allocationSize(value.`crew`) +
-            FfiConverterString.allocationSize(value.`passengers`) +
-            FfiConverterString.allocationSize(value.`cargoCapacity`) +
-            FfiConverterString.allocationSize(value.`consumables`) +
-            FfiConverterString.allocationSize(value.`vehicleClass`) +
-            FfiConverterSequenceString.allocationSize(value.`pilots`) +
-            FfiConverterSequenceString.allocationSize(value.`films`) +
-            FfiConverterString.allocationSize(value.`created`) +
-            FfiConverterString.allocationSize(value.`edited`) +
-            FfiConverterString.allocationSize(value.`url`)
-    )
+    override fun allocationSize(value: CancellationToken?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypeCancellationToken.allocationSize(value)
+        }
+    }
 
-    override fun write(value: Vehicle, buf: ByteBuffer) {
-            FfiConverterString.write(value.`name`, buf)
-            FfiConverterString.write(value.`model`, buf)
-            FfiConverterString.write(value.`manufacturer`, buf)
-            FfiConverterString.write(value.`costInCredits`, buf)
-            FfiConverterString.write(value.`length`, buf)
-            FfiConverterString.write(value.`maxAtmospheringSpeed`, buf)
-            FfiConverterString.write(value.`crew`, buf)
-            FfiConverterString.write(value.`passengers`, buf)
-            FfiConverterString.write(value.`cargoCapacity`, buf)
-            FfiConverterString.write(value.`consumables`, buf)
-            FfiConverterString.write(value.`vehicleClass`, buf)
-            FfiConverterSequenceString.write(value.`pilots`, buf)
-            FfiConverterSequenceString.write(value.`films`, buf)
-            FfiConverterString.write(value.`created`, buf)
-            FfiConverterString.write(value.`edited`, buf)
-            FfiConverterString.write(value.`url`, buf)
+    override fun write(value: CancellationToken?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypeCancellationToken.write(value, buf)
+        }
     }
 }
 
 
 
 
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalTypeConsumables: FfiConverterRustBuffer<Consumables?> {
+    override fun read(buf: ByteBuffer): Consumables? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterTypeConsumables.read(buf)
+    }
 
-sealed class ApiException(message: String): kotlin.Exception(message) {
-        
-        class HttpException(message: String) : ApiException(message)
-        
-        class NetworkException(message: String) : ApiException(message)
-        
-        class SerializationException(message: String) : ApiException(message)
-        
-        class DeserializationException(message: String) : ApiException(message)
-        
-        class InvalidResponse(message: String) : ApiException(message)
-        
-        class NotFound(message: String) : ApiException(message)
-        
-        class Unauthorized(message: String) : ApiException(message)
-        
-        class RateLimited(message: String) : ApiException(message)
-        
-        class ServerException(message: String) : ApiException(message)
-        
+    override fun allocationSize(value: Consumables?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypeConsumables.allocationSize(value)
+        }
+    }
 
-    companion object ErrorHandler : UniffiRustCallStatusErrorHandler<ApiException> {
-        override fun lift(error_buf: RustBuffer.ByValue): ApiException = FfiConverterTypeApiError.lift(error_buf)
+    override fun write(value: Consumables?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypeConsumables.write(value, buf)
+        }
     }
 }
 
+
+
+
 /**
  * @suppress
  */
-public object FfiConverterTypeApiError : FfiConverterRustBuffer<ApiException> {
-    override fun read(buf: ByteBuffer): ApiException {
-        
-            return when(buf.getInt()) {
-            1 -> ApiException.HttpException(FfiConverterString.read(buf))
-            2 -> ApiException.NetworkException(FfiConverterString.read(buf))
-            3 -> ApiException.SerializationException(FfiConverterString.read(buf))
-            4 -> ApiException.DeserializationException(FfiConverterString.read(buf))
-            5 -> ApiException.InvalidResponse(FfiConverterString.read(buf))
-            6 -> ApiException.NotFound(FfiConverterString.read(buf))
-            7 -> ApiException.Unauthorized(FfiConverterString.read(buf))
-            8 -> ApiException.RateLimited(FfiConverterString.read(buf))
-            9 -> ApiException.ServerException(FfiConverterString.read(buf))
-            else -> throw RuntimeException("invalid error enum value, something is very wrong!!")
+public object FfiConverterOptionalTypeFilm: FfiConverterRustBuffer<Film?> {
+    override fun read(buf: ByteBuffer): Film? {
+        if (buf.get().toInt() == 0) {
+            return null
         }
-        
+        return FfiConverterTypeFilm.read(buf)
     }
 
-    override fun allocationSize(value: ApiException): ULong {
-        return 4UL
+    override fun allocationSize(value: Film?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypeFilm.allocationSize(value)
+        }
     }
 
-    override fun write(value: ApiException, buf: ByteBuffer) {
-        when(value) {
-            is ApiException.HttpException -> {
-                buf.putInt(1)
-                Unit
-            }
-            is ApiException.NetworkException -> {
-                buf.putInt(2)
-                Unit
-            }
-            is ApiException.SerializationException -> {
-                buf.putInt(3)
-                Unit
-            }
-            is ApiException.DeserializationException -> {
-                buf.putInt(4)
-                Unit
-            }
-            is ApiException.InvalidResponse -> {
-                buf.putInt(5)
-                Unit
-            }
-            is ApiException.NotFound -> {
-                buf.putInt(6)
-                Unit
-            }
-            is ApiException.Unauthorized -> {
-                buf.putInt(7)
-                Unit
-            }
-            is ApiException.RateLimited -> {
-                buf.putInt(8)
-                Unit
-            }
-            is ApiException.ServerException -> {
-                buf.putInt(9)
-                Unit
-            }
-        }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
+    override fun write(value: Film?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypeFilm.write(value, buf)
+        }
     }
-
 }
 
 
 
 
-enum class Fetchable {
-    
-    PLANETS,
-    PEOPLE,
-    FILMS,
-    SPECIES,
-    STARSHIPS,
-    VEHICLES;
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalTypeGalacticYear: FfiConverterRustBuffer<GalacticYear?> {
+    override fun read(buf: ByteBuffer): GalacticYear? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterTypeGalacticYear.read(buf)
+    }
 
-     fun `displayName`(): kotlin.String {
-            return FfiConverterString.lift(
-    uniffiRustCall() { _status ->
-    UniffiLib.uniffi_starwars_fn_method_fetchable_display_name(FfiConverterTypeFetchable.lower(this),
-        _status)
-}
-    )
+    override fun allocationSize(value: GalacticYear?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypeGalacticYear.allocationSize(value)
+        }
     }
-    
 
-    
+    override fun write(value: GalacticYear?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypeGalacticYear.write(value, buf)
+        }
+    }
+}
 
 
-    companion object
-}
 
 
 /**
  * @suppress
  */
-public object FfiConverterTypeFetchable: FfiConverterRustBuffer<Fetchable> {
-    override fun read(buf: ByteBuffer) = try {
-        Fetchable.values()[buf.getInt() - 1]
-    } catch (e: IndexOutOfBoundsException) {
-        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+public object FfiConverterOptionalTypeNumericRange: FfiConverterRustBuffer<NumericRange?> {
+    override fun read(buf: ByteBuffer): NumericRange? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterTypeNumericRange.read(buf)
     }
 
-    override fun allocationSize(value: Fetchable) = 4UL
+    override fun allocationSize(value: NumericRange?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypeNumericRange.allocationSize(value)
+        }
+    }
 
-    override fun write(value: Fetchable, buf: ByteBuffer) {
-        buf.putInt(value.ordinal + 1)
+    override fun write(value: NumericRange?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypeNumericRange.write(value, buf)
+        }
     }
 }
 
 
 
 
-
-sealed class ListItems {
-    
-    data class Planets(
-        val v1: List<kotlin.String>) : ListItems()
-        
-    {
-        
-
-        companion object
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalTypePerson: FfiConverterRustBuffer<Person?> {
+    override fun read(buf: ByteBuffer): Person? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterTypePerson.read(buf)
     }
-    
-    data class People(
-        val v1: List<kotlin.String>) : ListItems()
-        
-    {
-        
 
-        companion object
+    override fun allocationSize(value: Person?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypePerson.allocationSize(value)
+        }
     }
-    
-    data class Films(
-        val v1: List<kotlin.String>) : ListItems()
-        
-    {
-        
 
-        companion object
+    override fun write(value: Person?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypePerson.write(value, buf)
+        }
     }
-    
-    data class Species(
-        val v1: List<kotlin.String>) : ListItems()
-        
-    {
-        
+}
 
-        companion object
-    }
-    
-    data class Starships(
-        val v1: List<kotlin.String>) : ListItems()
-        
-    {
-        
 
-        companion object
-    }
-    
-    data class Vehicles(
-        val v1: List<kotlin.String>) : ListItems()
-        
-    {
-        
 
-        companion object
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalTypePlanet: FfiConverterRustBuffer<Planet?> {
+    override fun read(buf: ByteBuffer): Planet? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterTypePlanet.read(buf)
     }
-    
-    data class Homeworld(
-        val v1: kotlin.String) : ListItems()
-        
-    {
-        
 
-        companion object
+    override fun allocationSize(value: Planet?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypePlanet.allocationSize(value)
+        }
     }
-    
 
-    
+    override fun write(value: Planet?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypePlanet.write(value, buf)
+        }
+    }
+}
 
-    
-    
 
 
-    companion object
-}
 
 /**
  * @suppress
  */
-public object FfiConverterTypeListItems : FfiConverterRustBuffer<ListItems>{
-    override fun read(buf: ByteBuffer): ListItems {
-        return when(buf.getInt()) {
-            1 -> ListItems.Planets(
-                FfiConverterSequenceString.read(buf),
-                )
-            2 -> ListItems.People(
-                FfiConverterSequenceString.read(buf),
-                )
-            3 -> ListItems.Films(
-                FfiConverterSequenceString.read(buf),
-                )
-            4 -> ListItems.Species(
-                FfiConverterSequenceString.read(buf),
-                )
-            5 -> ListItems.Starships(
-                FfiConverterSequenceString.read(buf),
-                )
-            6 -> ListItems.Vehicles(
-                FfiConverterSequenceString.read(buf),
-                )
-            7 -> ListItems.Homeworld(
-                FfiConverterString.read(buf),
-                )
-            else -> throw RuntimeException("invalid enum value, something is very wrong!!")
+public object FfiConverterOptionalTypeSpecies: FfiConverterRustBuffer<Species?> {
+    override fun read(buf: ByteBuffer): Species? {
+        if (buf.get().toInt() == 0) {
+            return null
         }
+        return FfiConverterTypeSpecies.read(buf)
     }
 
-    override fun allocationSize(value: ListItems) = when(value) {
-        is ListItems.Planets -> {
-            // Add the size for the Int that specifies the variant plus the size needed for all fields
-            (
-                4UL
-                + FfiConverterSequenceString.allocationSize(value.v1)
-            )
-        }
-        is ListItems.People -> {
-            // Add the size for the Int that specifies the variant plus the size needed for all fields
-            (
-                4UL
-                + FfiConverterSequenceString.allocationSize(value.v1)
-            )
-        }
-        is ListItems.Films -> {
-            // Add the size for the Int that specifies the variant plus the size needed for all fields
-            (
-                4UL
-                + FfiConverterSequenceString.allocationSize(value.v1)
-            )
-        }
-        is ListItems.Species -> {
-            // Add the size for the Int that specifies the variant plus the size needed for all fields
-            (
-                4UL
-                + FfiConverterSequenceString.allocationSize(value.v1)
-            )
-        }
-        is ListItems.Starships -> {
-            // Add the size for the Int that specifies the variant plus the size needed for all fields
-            (
-                4UL
-                + FfiConverterSequenceString.allocationSize(value.v1)
-            )
-        }
-        is ListItems.Vehicles -> {
-            // Add the size for the Int that specifies the variant plus the size needed for all fields
-            (
-                4UL
-                + FfiConverterSequenceString.allocationSize(value.v1)
-            )
+    override fun allocationSize(value: Species?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypeSpecies.allocationSize(value)
         }
-        is ListItems.Homeworld -> {
-            // Add the size for the Int that specifies the variant plus the size needed for all fields
-            (
-                4UL
-                + FfiConverterString.allocationSize(value.v1)
-            )
+    }
+
+    override fun write(value: Species?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypeSpecies.write(value, buf)
         }
     }
+}
 
-    override fun write(value: ListItems, buf: ByteBuffer) {
-        when(value) {
-            is ListItems.Planets -> {
-                buf.putInt(1)
-                FfiConverterSequenceString.write(value.v1, buf)
-                Unit
-            }
-            is ListItems.People -> {
-                buf.putInt(2)
-                FfiConverterSequenceString.write(value.v1, buf)
-                Unit
-            }
-            is ListItems.Films -> {
-                buf.putInt(3)
-                FfiConverterSequenceString.write(value.v1, buf)
-                Unit
-            }
-            is ListItems.Species -> {
-                buf.putInt(4)
-                FfiConverterSequenceString.write(value.v1, buf)
-                Unit
-            }
-            is ListItems.Starships -> {
-                buf.putInt(5)
-                FfiConverterSequenceString.write(value.v1, buf)
-                Unit
-            }
-            is ListItems.Vehicles -> {
-                buf.putInt(6)
-                FfiConverterSequenceString.write(value.v1, buf)
-                Unit
-            }
-            is ListItems.Homeworld -> {
-                buf.putInt(7)
-                FfiConverterString.write(value.v1, buf)
-                Unit
-            }
-        }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalTypeStarship: FfiConverterRustBuffer<Starship?> {
+    override fun read(buf: ByteBuffer): Starship? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterTypeStarship.read(buf)
     }
-}
-
 
+    override fun allocationSize(value: Starship?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypeStarship.allocationSize(value)
+        }
+    }
 
+    override fun write(value: Starship?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypeStarship.write(value, buf)
+        }
+    }
+}
 
 
 
 
-sealed class NetworkException: kotlin.Exception() {
-    
-    class RequestFailed(
-        
-        val `reason`: kotlin.String
-        ) : NetworkException() {
-        override val message
-            get() = "reason=${ `reason` }"
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalTypeVehicle: FfiConverterRustBuffer<Vehicle?> {
+    override fun read(buf: ByteBuffer): Vehicle? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterTypeVehicle.read(buf)
     }
-    
-    class Timeout(
-        ) : NetworkException() {
-        override val message
-            get() = ""
+
+    override fun allocationSize(value: Vehicle?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypeVehicle.allocationSize(value)
+        }
     }
-    
 
-    
+    override fun write(value: Vehicle?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypeVehicle.write(value, buf)
+        }
+    }
+}
 
 
-    companion object ErrorHandler : UniffiRustCallStatusErrorHandler<NetworkException> {
-        override fun lift(error_buf: RustBuffer.ByValue): NetworkException = FfiConverterTypeNetworkError.lift(error_buf)
-    }
 
-    
-}
 
 /**
  * @suppress
  */
-public object FfiConverterTypeNetworkError : FfiConverterRustBuffer<NetworkException> {
-    override fun read(buf: ByteBuffer): NetworkException {
-        
-
-        return when(buf.getInt()) {
-            1 -> NetworkException.RequestFailed(
-                FfiConverterString.read(buf),
-                )
-            2 -> NetworkException.Timeout()
-            else -> throw RuntimeException("invalid error enum value, something is very wrong!!")
+public object FfiConverterOptionalTypeResponseFormat: FfiConverterRustBuffer<ResponseFormat?> {
+    override fun read(buf: ByteBuffer): ResponseFormat? {
+        if (buf.get().toInt() == 0) {
+            return null
         }
+        return FfiConverterTypeResponseFormat.read(buf)
     }
 
-    override fun allocationSize(value: NetworkException): ULong {
-        return when(value) {
-            is NetworkException.RequestFailed -> (
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                4UL
-                + FfiConverterString.allocationSize(value.`reason`)
-            )
-            is NetworkException.Timeout -> (
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                4UL
-            )
+    override fun allocationSize(value: ResponseFormat?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypeResponseFormat.allocationSize(value)
         }
     }
 
-    override fun write(value: NetworkException, buf: ByteBuffer) {
-        when(value) {
-            is NetworkException.RequestFailed -> {
-                buf.putInt(1)
-                FfiConverterString.write(value.`reason`, buf)
-                Unit
-            }
-            is NetworkException.Timeout -> {
-                buf.putInt(2)
-                Unit
-            }
-        }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
+    override fun write(value: ResponseFormat?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypeResponseFormat.write(value, buf)
+        }
     }
-
 }
 
 
 
-sealed class Selected {
-    
-    data class Film(
-        val v1: uniffi.starwars.Film) : Selected()
-        
-    {
-        
 
-        companion object
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalSequenceTypeFilm: FfiConverterRustBuffer<List<Film>?> {
+    override fun read(buf: ByteBuffer): List<Film>? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterSequenceTypeFilm.read(buf)
     }
-    
-    data class Person(
-        val v1: uniffi.starwars.Person) : Selected()
-        
-    {
-        
 
-        companion object
+    override fun allocationSize(value: List<Film>?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterSequenceTypeFilm.allocationSize(value)
+        }
     }
-    
-    data class Planet(
-        val v1: uniffi.starwars.Planet) : Selected()
-        
-    {
-        
 
-        companion object
+    override fun write(value: List<Film>?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterSequenceTypeFilm.write(value, buf)
+        }
     }
-    
-    data class Species(
-        val v1: uniffi.starwars.Species) : Selected()
-        
-    {
-        
+}
 
-        companion object
-    }
-    
-    data class Starship(
-        val v1: uniffi.starwars.Starship) : Selected()
-        
-    {
-        
 
-        companion object
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalSequenceTypePerson: FfiConverterRustBuffer<List<Person>?> {
+    override fun read(buf: ByteBuffer): List<Person>? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterSequenceTypePerson.read(buf)
     }
-    
-    data class Vehicle(
-        val v1: uniffi.starwars.Vehicle) : Selected()
-        
-    {
-        
 
-        companion object
+    override fun allocationSize(value: List<Person>?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterSequenceTypePerson.allocationSize(value)
+        }
     }
-    
 
-    
+    override fun write(value: List<Person>?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterSequenceTypePerson.write(value, buf)
+        }
+    }
+}
 
-    
-    
 
 
-    companion object
-}
 
 /**
  * @suppress
  */
-public object FfiConverterTypeSelected : FfiConverterRustBuffer<Selected>{
-    override fun read(buf: ByteBuffer): Selected {
-        return when(buf.getInt()) {
-            1 -> Selected.Film(
-                FfiConverterTypeFilm.read(buf),
-                )
-            2 -> Selected.Person(
-                FfiConverterTypePerson.read(buf),
-                )
-            3 -> Selected.Planet(
-                FfiConverterTypePlanet.read(buf),
-                )
-            4 -> Selected.Species(
-                FfiConverterTypeSpecies.read(buf),
-                )
-            5 -> Selected.Starship(
-                FfiConverterTypeStarship.read(buf),
-                )
-            6 -> Selected.Vehicle(
-                FfiConverterTypeVehicle.read(buf),
-                )
-            else -> throw RuntimeException("invalid enum value, something is very wrong!!")
+public object FfiConverterOptionalSequenceTypePlanet: FfiConverterRustBuffer<List<Planet>?> {
+    override fun read(buf: ByteBuffer): List<Planet>? {
+        if (buf.get().toInt() == 0) {
+            return null
         }
+        return FfiConverterSequenceTypePlanet.read(buf)
     }
 
-    override fun allocationSize(value: Selected) = when(value) {
-        is Selected.Film -> {
-            // Add the size for the Int that specifies the variant plus the size needed for all fields
-            (
-                4UL
-                + FfiConverterTypeFilm.allocationSize(value.v1)
-            )
-        }
-        is Selected.Person -> {
-            // Add the size for the Int that specifies the variant plus the size needed for all fields
-            (
-                4UL
-                + FfiConverterTypePerson.allocationSize(value.v1)
-            )
-        }
-        is Selected.Planet -> {
-            // Add the size for the Int that specifies the variant plus the size needed for all fields
-            (
-                4UL
-                + FfiConverterTypePlanet.allocationSize(value.v1)
-            )
-        }
-        is Selected.Species -> {
-            // Add the size for the Int that specifies the variant plus the size needed for all fields
-            (
-                4UL
-                + FfiConverterTypeSpecies.allocationSize(value.v1)
-            )
-        }
-        is Selected.Starship -> {
-            // Add the size for the Int that specifies the variant plus the size needed for all fields
-            (
-                4UL
-                + FfiConverterTypeStarship.allocationSize(value.v1)
-            )
+    override fun allocationSize(value: List<Planet>?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterSequenceTypePlanet.allocationSize(value)
         }
-        is Selected.Vehicle -> {
-            // Add the size for the Int that specifies the variant plus the size needed for all fields
-            (
-                4UL
-                + FfiConverterTypeVehicle.allocationSize(value.v1)
-            )
+    }
+
+    override fun write(value: List<Planet>?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterSequenceTypePlanet.write(value, buf)
         }
     }
+}
 
-    override fun write(value: Selected, buf: ByteBuffer) {
-        when(value) {
-            is Selected.Film -> {
-                buf.putInt(1)
-                FfiConverterTypeFilm.write(value.v1, buf)
-                Unit
-            }
-            is Selected.Person -> {
-                buf.putInt(2)
-                FfiConverterTypePerson.write(value.v1, buf)
-                Unit
-            }
-            is Selected.Planet -> {
-                buf.putInt(3)
-                FfiConverterTypePlanet.write(value.v1, buf)
-                Unit
-            }
-            is Selected.Species -> {
-                buf.putInt(4)
-                FfiConverterTypeSpecies.write(value.v1, buf)
-                Unit
-            }
-            is Selected.Starship -> {
-                buf.putInt(5)
-                FfiConverterTypeStarship.write(value.v1, buf)
-                Unit
-            }
-            is Selected.Vehicle -> {
-                buf.putInt(6)
-                FfiConverterTypeVehicle.write(value.v1, buf)
-                Unit
-            }
-        }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalSequenceTypeSpecies: FfiConverterRustBuffer<List<Species>?> {
+    override fun read(buf: ByteBuffer): List<Species>? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterSequenceTypeSpecies.read(buf)
     }
-}
 
+    override fun allocationSize(value: List<Species>?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterSequenceTypeSpecies.allocationSize(value)
+        }
+    }
 
+    override fun write(value: List<Species>?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterSequenceTypeSpecies.write(value, buf)
+        }
+    }
+}
 
 
 
@@ -3784,28 +10358,28 @@ public object FfiConverterTypeSelected : FfiConverterRustBuffer<Selected>{
 /**
  * @suppress
  */
-public object FfiConverterOptionalString: FfiConverterRustBuffer<kotlin.String?> {
-    override fun read(buf: ByteBuffer): kotlin.String? {
+public object FfiConverterOptionalSequenceTypeStarship: FfiConverterRustBuffer<List<Starship>?> {
+    override fun read(buf: ByteBuffer): List<Starship>? {
         if (buf.get().toInt() == 0) {
             return null
         }
-        return FfiConverterString.read(buf)
+        return FfiConverterSequenceTypeStarship.read(buf)
     }
 
-    override fun allocationSize(value: kotlin.String?): ULong {
+    override fun allocationSize(value: List<Starship>?): ULong {
         if (value == null) {
             return 1UL
         } else {
-            return 1UL + FfiConverterString.allocationSize(value)
+            return 1UL + FfiConverterSequenceTypeStarship.allocationSize(value)
         }
     }
 
-    override fun write(value: kotlin.String?, buf: ByteBuffer) {
+    override fun write(value: List<Starship>?, buf: ByteBuffer) {
         if (value == null) {
             buf.put(0)
         } else {
             buf.put(1)
-            FfiConverterString.write(value, buf)
+            FfiConverterSequenceTypeStarship.write(value, buf)
         }
     }
 }
@@ -3816,28 +10390,28 @@ public object FfiConverterOptionalString: FfiConverterRustBuffer<kotlin.String?>
 /**
  * @suppress
  */
-public object FfiConverterOptionalTypePlanet: FfiConverterRustBuffer<Planet?> {
-    override fun read(buf: ByteBuffer): Planet? {
+public object FfiConverterOptionalSequenceTypeVehicle: FfiConverterRustBuffer<List<Vehicle>?> {
+    override fun read(buf: ByteBuffer): List<Vehicle>? {
         if (buf.get().toInt() == 0) {
             return null
         }
-        return FfiConverterTypePlanet.read(buf)
+        return FfiConverterSequenceTypeVehicle.read(buf)
     }
 
-    override fun allocationSize(value: Planet?): ULong {
+    override fun allocationSize(value: List<Vehicle>?): ULong {
         if (value == null) {
             return 1UL
         } else {
-            return 1UL + FfiConverterTypePlanet.allocationSize(value)
+            return 1UL + FfiConverterSequenceTypeVehicle.allocationSize(value)
         }
     }
 
-    override fun write(value: Planet?, buf: ByteBuffer) {
+    override fun write(value: List<Vehicle>?, buf: ByteBuffer) {
         if (value == null) {
             buf.put(0)
         } else {
             buf.put(1)
-            FfiConverterTypePlanet.write(value, buf)
+            FfiConverterSequenceTypeVehicle.write(value, buf)
         }
     }
 }
@@ -3848,28 +10422,56 @@ public object FfiConverterOptionalTypePlanet: FfiConverterRustBuffer<Planet?> {
 /**
  * @suppress
  */
-public object FfiConverterOptionalSequenceTypePlanet: FfiConverterRustBuffer<List<Planet>?> {
-    override fun read(buf: ByteBuffer): List<Planet>? {
+public object FfiConverterOptionalTypeTimestamp: FfiConverterRustBuffer<Timestamp?> {
+    override fun read(buf: ByteBuffer): Timestamp? {
         if (buf.get().toInt() == 0) {
             return null
         }
-        return FfiConverterSequenceTypePlanet.read(buf)
+        return FfiConverterTypeTimestamp.read(buf)
     }
 
-    override fun allocationSize(value: List<Planet>?): ULong {
+    override fun allocationSize(value: Timestamp?): ULong {
         if (value == null) {
             return 1UL
         } else {
-            return 1UL + FfiConverterSequenceTypePlanet.allocationSize(value)
+            return 1UL + FfiConverterTypeTimestamp.allocationSize(value)
         }
     }
 
-    override fun write(value: List<Planet>?, buf: ByteBuffer) {
+    override fun write(value: Timestamp?, buf: ByteBuffer) {
         if (value == null) {
             buf.put(0)
         } else {
             buf.put(1)
-            FfiConverterSequenceTypePlanet.write(value, buf)
+            FfiConverterTypeTimestamp.write(value, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceUInt: FfiConverterRustBuffer<List<kotlin.UInt>> {
+    override fun read(buf: ByteBuffer): List<kotlin.UInt> {
+        val len = buf.getInt()
+        return List<kotlin.UInt>(len) {
+            FfiConverterUInt.read(buf)
+        }
+    }
+
+    override fun allocationSize(value: List<kotlin.UInt>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterUInt.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<kotlin.UInt>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterUInt.write(it, buf)
         }
     }
 }
@@ -3905,6 +10507,34 @@ public object FfiConverterSequenceString: FfiConverterRustBuffer<List<kotlin.Str
 
 
 
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceTypeAttributeTag: FfiConverterRustBuffer<List<AttributeTag>> {
+    override fun read(buf: ByteBuffer): List<AttributeTag> {
+        val len = buf.getInt()
+        return List<AttributeTag>(len) {
+            FfiConverterTypeAttributeTag.read(buf)
+        }
+    }
+
+    override fun allocationSize(value: List<AttributeTag>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeAttributeTag.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<AttributeTag>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeAttributeTag.write(it, buf)
+        }
+    }
+}
+
+
+
+
 /**
  * @suppress
  */
@@ -3989,6 +10619,34 @@ public object FfiConverterSequenceTypePlanet: FfiConverterRustBuffer<List<Planet
 
 
 
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceTypeRelatedGroup: FfiConverterRustBuffer<List<RelatedGroup>> {
+    override fun read(buf: ByteBuffer): List<RelatedGroup> {
+        val len = buf.getInt()
+        return List<RelatedGroup>(len) {
+            FfiConverterTypeRelatedGroup.read(buf)
+        }
+    }
+
+    override fun allocationSize(value: List<RelatedGroup>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeRelatedGroup.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<RelatedGroup>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeRelatedGroup.write(it, buf)
+        }
+    }
+}
+
+
+
+
 /**
  * @suppress
  */
@@ -4129,6 +10787,83 @@ public object FfiConverterSequenceTypeListItems: FfiConverterRustBuffer<List<Lis
 
 
 
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceTypeSelected: FfiConverterRustBuffer<List<Selected>> {
+    override fun read(buf: ByteBuffer): List<Selected> {
+        val len = buf.getInt()
+        return List<Selected>(len) {
+            FfiConverterTypeSelected.read(buf)
+        }
+    }
+
+    override fun allocationSize(value: List<Selected>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeSelected.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<Selected>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeSelected.write(it, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterMapStringString: FfiConverterRustBuffer<Map<kotlin.String, kotlin.String>> {
+    override fun read(buf: ByteBuffer): Map<kotlin.String, kotlin.String> {
+        val len = buf.getInt()
+        return buildMap<kotlin.String, kotlin.String>(len) {
+            repeat(len) {
+                val k = FfiConverterString.read(buf)
+                val v = FfiConverterString.read(buf)
+                this[k] = v
+            }
+        }
+    }
+
+    override fun allocationSize(value: Map<kotlin.String, kotlin.String>): ULong {
+        val spaceForMapSize = 4UL
+        val spaceForChildren = value.map { (k, v) ->
+            FfiConverterString.allocationSize(k) +
+            FfiConverterString.allocationSize(v)
+        }.sum()
+        return spaceForMapSize + spaceForChildren
+    }
+
+    override fun write(value: Map<kotlin.String, kotlin.String>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        // The parens on `(k, v)` here ensure we're calling the right method,
+        // which is important for compatibility with older android devices.
+        // Ref https://blog.danlew.net/2017/03/16/kotlin-puzzler-whose-line-is-it-anyways/
+        value.forEach { (k, v) ->
+            FfiConverterString.write(k, buf)
+            FfiConverterString.write(v, buf)
+        }
+    }
+}
+
+
+
+/**
+ * Typealias from the type name used in the UDL file to the builtin type.  This
+ * is needed because the UDL type name is used in function/method signatures.
+ * It's also what we have an external type that references a custom type.
+ */
+public typealias Timestamp = java.time.Instant
+public typealias FfiConverterTypeTimestamp = FfiConverterTimestamp
+
+
+
+
 
 
 
@@ -4142,6 +10877,16 @@ public object FfiConverterSequenceTypeListItems: FfiConverterRustBuffer<List<Lis
     )
     }
     
+ fun `resourceId`(`url`: kotlin.String): kotlin.UInt? {
+            return FfiConverterOptionalUInt.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_func_resource_id(
+    
+        FfiConverterString.lower(`url`),_status)
+}
+    )
+    }
+    
  fun `personRelatedItems`(`person`: Person): List<ListItems> {
             return FfiConverterSequenceTypeListItems.lift(
     uniffiRustCall() { _status ->
@@ -4152,5 +10897,105 @@ public object FfiConverterSequenceTypeListItems: FfiConverterRustBuffer<List<Lis
     )
     }
     
+ fun `relatedItems`(`selected`: Selected): List<ListItems> {
+            return FfiConverterSequenceTypeListItems.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_func_related_items(
+    
+        FfiConverterTypeSelected.lower(`selected`),_status)
+}
+    )
+    }
+    
+ fun `splitAttributes`(`value`: kotlin.String): List<AttributeTag> {
+            return FfiConverterSequenceTypeAttributeTag.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_func_split_attributes(
+    
+        FfiConverterString.lower(`value`),_status)
+}
+    )
+    }
+    
+ fun `formatConsumables`(`consumables`: Consumables): kotlin.String {
+            return FfiConverterString.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_func_format_consumables(
+    
+        FfiConverterTypeConsumables.lower(`consumables`),_status)
+}
+    )
+    }
+    
+ fun `formatGalacticYear`(`year`: GalacticYear): kotlin.String {
+            return FfiConverterString.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_func_format_galactic_year(
+    
+        FfiConverterTypeGalacticYear.lower(`year`),_status)
+}
+    )
+    }
+    
+ fun `parseConsumables`(`value`: kotlin.String): Consumables? {
+            return FfiConverterOptionalTypeConsumables.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_func_parse_consumables(
+    
+        FfiConverterString.lower(`value`),_status)
+}
+    )
+    }
+    
+ fun `parseGalacticYear`(`value`: kotlin.String): GalacticYear? {
+            return FfiConverterOptionalTypeGalacticYear.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_func_parse_galactic_year(
+    
+        FfiConverterString.lower(`value`),_status)
+}
+    )
+    }
+    
+ fun `parseCount`(`value`: kotlin.String): kotlin.ULong? {
+            return FfiConverterOptionalULong.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_func_parse_count(
+    
+        FfiConverterString.lower(`value`),_status)
+}
+    )
+    }
+    
+ fun `parseMeasurement`(`value`: kotlin.String): kotlin.Double? {
+            return FfiConverterOptionalDouble.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_func_parse_measurement(
+    
+        FfiConverterString.lower(`value`),_status)
+}
+    )
+    }
+    
+ fun `parseMeasurementRange`(`value`: kotlin.String): NumericRange? {
+            return FfiConverterOptionalTypeNumericRange.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_func_parse_measurement_range(
+    
+        FfiConverterString.lower(`value`),_status)
+}
+    )
+    }
+    
+ fun `parseTimestamp`(`value`: kotlin.String): Timestamp? {
+            return FfiConverterOptionalTypeTimestamp.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_starwars_fn_func_parse_timestamp(
+    
+        FfiConverterString.lower(`value`),_status)
+}
+    )
+    }
+    
 
 
@@ -0,0 +1,43 @@
+#!/bin/sh
+set -e
+
+# ============================================================
+# bindings.sh
+# Regenerates the committed Swift and Kotlin bindings from a
+# host build, without the iOS/Android toolchains build.sh needs.
+# Run it in every change that touches the exported surface;
+# `./bindings.sh --check` fails if the committed files are stale.
+# ============================================================
+
+SCRIPT_DIR="$(cd "$(dirname "$0")" && pwd)"
+LIB_NAME="starwars"
+RUST_DIR="$SCRIPT_DIR/libstarwars"
+SWIFT_BINDINGS="$SCRIPT_DIR/iOS/StarWarsLibrary/Sources/StarWarsLibrary/$LIB_NAME.swift"
+KOTLIN_BINDINGS="$SCRIPT_DIR/Android/app/src/main/java/uniffi/$LIB_NAME/$LIB_NAME.kt"
+
+case "$(uname -s)" in
+  Darwin) LIBRARY="$RUST_DIR/target/debug/lib${LIB_NAME}.dylib" ;;
+  *)      LIBRARY="$RUST_DIR/target/debug/lib${LIB_NAME}.so" ;;
+esac
+
+GENERATED_DIR="$(mktemp -d)"
+trap 'rm -rf "$GENERATED_DIR"' EXIT
+
+(cd "$RUST_DIR" && cargo build)
+for language in swift kotlin; do
+  (cd "$RUST_DIR" && cargo run --bin uniffi-bindgen-main -- generate \
+    --library "$LIBRARY" \
+    --language "$language" \
+    --no-format \
+    --out-dir "$GENERATED_DIR/$language")
+done
+
+if [ "$1" = "--check" ]; then
+  diff -q "$GENERATED_DIR/swift/$LIB_NAME.swift" "$SWIFT_BINDINGS"
+  diff -q "$GENERATED_DIR/kotlin/uniffi/$LIB_NAME/$LIB_NAME.kt" "$KOTLIN_BINDINGS"
+  echo "✅ Bindings are up to date"
+else
+  cp "$GENERATED_DIR/swift/$LIB_NAME.swift" "$SWIFT_BINDINGS"
+  cp "$GENERATED_DIR/kotlin/uniffi/$LIB_NAME/$LIB_NAME.kt" "$KOTLIN_BINDINGS"
+  echo "✅ Bindings regenerated"
+fi
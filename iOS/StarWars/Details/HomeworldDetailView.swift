@@ -25,7 +25,17 @@ struct HomeworldDetailView: View {
             }
         }
         .task {
-            planet = try? await repo.apiClient.fetchPlanet(url: url.absoluteString)
+            // SwiftUI cancels the task when the view disappears; pass that on so the fetch stops
+            let cancellation = CancellationToken()
+            planet = await withTaskCancellationHandler {
+                try? await repo.apiClient.fetchPlanet(
+                    url: url.absoluteString,
+                    policy: .cacheFirst,
+                    cancellation: cancellation
+                )
+            } onCancel: {
+                cancellation.cancel()
+            }
         }
     }
 }
@@ -39,8 +39,8 @@ extension InitialView {
                         }
                         viewState = .loaded(items)
                     } else {
-                        let ignoreCache = !repo.fetched.contains(fetcher.cacheKey)
-                        let items = try await fetcher.fetchAll(repo, ignoreCache)
+                        let policy: CachePolicy = repo.fetched.contains(fetcher.cacheKey) ? .cacheFirst : .networkFirst
+                        let items = try await fetcher.fetchAll(repo, policy)
                         viewState = .loaded(items)
                         repo.fetched.insert(fetcher.cacheKey)
                     }
@@ -66,7 +66,7 @@ extension InitialView {
         let label: (T) -> String
         let selection: (T) -> Selected
         let fetchOne: (Repository, String) async throws -> T
-        let fetchAll: (Repository, Bool) async throws -> [T]
+        let fetchAll: (Repository, CachePolicy) async throws -> [T]
         
         static func == (lhs: InitialView.ResourceFetcher<T>, rhs: InitialView.ResourceFetcher<T>) -> Bool {
             lhs.title == lhs.title
@@ -87,8 +87,8 @@ extension InitialView.ResourceFetcher {
             cacheKey: .planets,
             label: \.name,
             selection: { .planet($0) },
-            fetchOne: { try await $0.apiClient.fetchPlanet(url: $1) },
-            fetchAll: { try await $0.apiClient.fetchPlanets(ignoreCache: $1) }
+            fetchOne: { try await $0.apiClient.fetchPlanet(url: $1, policy: .cacheFirst, cancellation: nil) },
+            fetchAll: { try await $0.apiClient.fetchPlanets(policy: $1, cancellation: nil) }
         )
     }
 
@@ -98,8 +98,8 @@ extension InitialView.ResourceFetcher {
             cacheKey: .people,
             label: \.name,
             selection: { .person($0) },
-            fetchOne: { try await $0.apiClient.fetchPerson(url: $1) },
-            fetchAll: { try await $0.apiClient.fetchPeople(ignoreCache: $1) }
+            fetchOne: { try await $0.apiClient.fetchPerson(url: $1, policy: .cacheFirst, cancellation: nil) },
+            fetchAll: { try await $0.apiClient.fetchPeople(policy: $1, cancellation: nil) }
         )
     }
 
@@ -109,8 +109,8 @@ extension InitialView.ResourceFetcher {
             cacheKey: .films,
             label: \.title,
             selection: { .film($0) },
-            fetchOne: { try await $0.apiClient.fetchFilm(url: $1) },
-            fetchAll: { try await $0.apiClient.fetchFilms(ignoreCache: $1) }
+            fetchOne: { try await $0.apiClient.fetchFilm(url: $1, policy: .cacheFirst, cancellation: nil) },
+            fetchAll: { try await $0.apiClient.fetchFilms(policy: $1, cancellation: nil) }
         )
     }
 
@@ -120,8 +120,8 @@ extension InitialView.ResourceFetcher {
             cacheKey: .species,
             label: \.name,
             selection: { .species($0) },
-            fetchOne: { try await $0.apiClient.fetchSpecies(url: $1) },
-            fetchAll: { try await $0.apiClient.fetchSpeciesList(ignoreCache: $1) }
+            fetchOne: { try await $0.apiClient.fetchSpecies(url: $1, policy: .cacheFirst, cancellation: nil) },
+            fetchAll: { try await $0.apiClient.fetchSpeciesList(policy: $1, cancellation: nil) }
         )
     }
 
@@ -131,8 +131,8 @@ extension InitialView.ResourceFetcher {
             cacheKey: .starships,
             label: \.name,
             selection: { .starship($0) },
-            fetchOne: { try await $0.apiClient.fetchStarship(url: $1) },
-            fetchAll: { try await $0.apiClient.fetchStarships(ignoreCache: $1) }
+            fetchOne: { try await $0.apiClient.fetchStarship(url: $1, policy: .cacheFirst, cancellation: nil) },
+            fetchAll: { try await $0.apiClient.fetchStarships(policy: $1, cancellation: nil) }
         )
     }
 
@@ -142,8 +142,8 @@ extension InitialView.ResourceFetcher {
             cacheKey: .vehicles,
             label: \.name,
             selection: { .vehicle($0) },
-            fetchOne: { try await $0.apiClient.fetchVehicle(url: $1) },
-            fetchAll: { try await $0.apiClient.fetchVehicles(ignoreCache: $1) }
+            fetchOne: { try await $0.apiClient.fetchVehicle(url: $1, policy: .cacheFirst, cancellation: nil) },
+            fetchAll: { try await $0.apiClient.fetchVehicles(policy: $1, cancellation: nil) }
         )
     }
 }
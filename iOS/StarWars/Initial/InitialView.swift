@@ -47,6 +47,9 @@ struct InitialView: View {
                 case .species(let urls): DataListView(urls: urls.compactMap(URL.init), fetcher: .species)
                 case .starships(let urls): DataListView(urls: urls.compactMap(URL.init), fetcher: .starships)
                 case .vehicles(let urls): DataListView(urls: urls.compactMap(URL.init), fetcher: .vehicles)
+                case .characters(let urls): DataListView(urls: urls.compactMap(URL.init), fetcher: .people)
+                case .residents(let urls): DataListView(urls: urls.compactMap(URL.init), fetcher: .people)
+                case .pilots(let urls): DataListView(urls: urls.compactMap(URL.init), fetcher: .people)
                 case .homeworld(let urlStr):
                     if let url = URL(string: urlStr) {
                         HomeworldDetailView(url: url)
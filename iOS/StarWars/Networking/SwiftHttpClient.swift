@@ -9,11 +9,47 @@ import Foundation
 import StarWarsLibrary
 
 final class SwiftHttpClient: HttpClient {
-    func fetch(url: String) async throws -> Data {
-        guard let requestUrl = URL(string: url) else {
+    func fetch(request: HttpRequest) async throws -> HttpResponse {
+        guard let requestUrl = URL(string: request.url) else {
             throw NetworkError.RequestFailed(reason: "Invalid URL")
         }
-        let (data, _) = try await URLSession.shared.data(from: requestUrl)
-        return data
+        // The library handles revalidation itself, so keep URLSession's cache from answering for it
+        var urlRequest = URLRequest(url: requestUrl, cachePolicy: .reloadIgnoringLocalCacheData)
+        for (name, value) in request.headers {
+            urlRequest.setValue(value, forHTTPHeaderField: name)
+        }
+        // Run the transfer in its own task so the library can cancel it once nobody needs the response
+        let transfer = Task { try await URLSession.shared.data(for: urlRequest) }
+        request.cancellation.onCancel(listener: TaskCanceller(task: transfer))
+        let data: Data
+        let response: URLResponse
+        do {
+            (data, response) = try await transfer.value
+        } catch {
+            if request.cancellation.isCancelled() {
+                throw NetworkError.Cancelled
+            }
+            throw error
+        }
+        guard let httpResponse = response as? HTTPURLResponse else {
+            throw NetworkError.RequestFailed(reason: "Invalid response")
+        }
+        var headers: [String: String] = [:]
+        for (key, value) in httpResponse.allHeaderFields {
+            headers["\(key)"] = "\(value)"
+        }
+        return HttpResponse(status: UInt16(httpResponse.statusCode), headers: headers, body: data)
+    }
+}
+
+private final class TaskCanceller: CancellationListener {
+    private let task: Task<(Data, URLResponse), Error>
+
+    init(task: Task<(Data, URLResponse), Error>) {
+        self.task = task
+    }
+
+    func onCancel() {
+        task.cancel()
     }
 }
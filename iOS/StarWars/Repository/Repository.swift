@@ -5,6 +5,7 @@
 //  Created by Will Dale on 21/02/2026.
 //
 
+import Foundation
 import Observation
 import StarWarsLibrary
 
@@ -14,7 +15,17 @@ final class Repository {
     var fetched: Set<Fetchable> = []
     
     init() {
-        self.apiClient = ApiClient(httpClient: SwiftHttpClient(), repository: DataRepository())
+        self.apiClient = ApiClient(httpClient: SwiftHttpClient(), repository: Self.makeRepository())
+    }
+    
+    // Cached records survive relaunches; if the caches directory can't be used, fall back to memory
+    private static func makeRepository() -> DataRepository {
+        let directory = URL.cachesDirectory.appending(path: "repository", directoryHint: .isDirectory)
+        guard let store = try? FileStore(directory: directory.path()),
+              let repository = try? DataRepository.withStore(store: store) else {
+            return DataRepository()
+        }
+        return repository
     }
 }
 
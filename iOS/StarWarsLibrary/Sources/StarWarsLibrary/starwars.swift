@@ -422,6 +422,38 @@ private let UNIFFI_CALLBACK_SUCCESS: Int32 = 0
 private let UNIFFI_CALLBACK_ERROR: Int32 = 1
 private let UNIFFI_CALLBACK_UNEXPECTED_ERROR: Int32 = 2
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterUInt16: FfiConverterPrimitive {
+    typealias FfiType = UInt16
+    typealias SwiftType = UInt16
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UInt16 {
+        return try lift(readInt(&buf))
+    }
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterUInt32: FfiConverterPrimitive {
+    typealias FfiType = UInt32
+    typealias SwiftType = UInt32
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UInt32 {
+        return try lift(readInt(&buf))
+    }
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -438,6 +470,38 @@ fileprivate struct FfiConverterInt32: FfiConverterPrimitive {
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterUInt64: FfiConverterPrimitive {
+    typealias FfiType = UInt64
+    typealias SwiftType = UInt64
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UInt64 {
+        return try lift(readInt(&buf))
+    }
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterDouble: FfiConverterPrimitive {
+    typealias FfiType = Double
+    typealias SwiftType = Double
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Double {
+        return try lift(readDouble(&buf))
+    }
+
+    public static func write(_ value: Double, into buf: inout [UInt8]) {
+        writeDouble(&buf, lower(value))
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -477,7 +541,11 @@ fileprivate struct FfiConverterString: FfiConverter {
             return String()
         }
         let bytes = UnsafeBufferPointer<UInt8>(start: value.data!, count: Int(value.len))
-        return String(bytes: bytes, encoding: String.Encoding.utf8)!
+        // Use Swift's native UTF-8 decoder; `String(bytes:encoding:.utf8)` goes
+        // through Foundation's NSString and silently strips a leading U+FEFF BOM.
+        // Invalid UTF-8 substitutes U+FFFD instead of trapping (unreachable
+        // given Rust's `String` invariant).
+        return String(decoding: bytes, as: UTF8.self)
     }
 
     public static func lower(_ value: String) -> RustBuffer {
@@ -493,7 +561,8 @@ fileprivate struct FfiConverterString: FfiConverter {
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> String {
         let len: Int32 = try readInt(&buf)
-        return String(bytes: try readBytes(&buf, count: Int(len)), encoding: String.Encoding.utf8)!
+        // See `lift` above for why we avoid Foundation's NSString-backed decoder here.
+        return String(decoding: try readBytes(&buf, count: Int(len)), as: UTF8.self)
     }
 
     public static func write(_ value: String, into buf: inout [UInt8]) {
@@ -521,34 +590,80 @@ fileprivate struct FfiConverterData: FfiConverterRustBuffer {
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterTimestamp: FfiConverterRustBuffer {
+    typealias SwiftType = Date
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Date {
+        let seconds: Int64 = try readInt(&buf)
+        let nanoseconds: UInt32 = try readInt(&buf)
+        if seconds >= 0 {
+            let delta = Double(seconds) + (Double(nanoseconds) / 1.0e9)
+            return Date.init(timeIntervalSince1970: delta)
+        } else {
+            let delta = Double(seconds) - (Double(nanoseconds) / 1.0e9)
+            return Date.init(timeIntervalSince1970: delta)
+        }
+    }
+
+    public static func write(_ value: Date, into buf: inout [UInt8]) {
+        var delta = value.timeIntervalSince1970
+        var sign: Int64 = 1
+        if delta < 0 {
+            // The nanoseconds portion of the epoch offset must always be
+            // positive, to simplify the calculation we will use the absolute
+            // value of the offset.
+            sign = -1
+            delta = -delta
+        }
+        if delta.rounded(.down) > Double(Int64.max) {
+            fatalError("Timestamp overflow, exceeds max bounds supported by Uniffi")
+        }
+        let seconds = Int64(delta)
+        let nanoseconds = UInt32((delta - Double(seconds)) * 1.0e9)
+        writeInt(&buf, sign * seconds)
+        writeInt(&buf, nanoseconds)
+    }
+}
+
 
 
 
 public protocol ApiClientProtocol: AnyObject, Sendable {
     
-    func fetchFilm(url: String) async throws  -> Film
+    func searchIndex()  -> SearchIndex
+    
+    func fetchFilm(url: String, policy: CachePolicy, cancellation: CancellationToken?) async throws  -> Film
+    
+    func fetchFilms(policy: CachePolicy, cancellation: CancellationToken?) async throws  -> [Film]
     
-    func fetchFilms(ignoreCache: Bool) async throws  -> [Film]
+    func fetchPeople(policy: CachePolicy, cancellation: CancellationToken?) async throws  -> [Person]
     
-    func fetchPeople(ignoreCache: Bool) async throws  -> [Person]
+    func fetchPerson(url: String, policy: CachePolicy, cancellation: CancellationToken?) async throws  -> Person
     
-    func fetchPerson(url: String) async throws  -> Person
+    func fetchPlanet(url: String, policy: CachePolicy, cancellation: CancellationToken?) async throws  -> Planet
     
-    func fetchPlanet(url: String) async throws  -> Planet
+    func fetchPlanets(policy: CachePolicy, cancellation: CancellationToken?) async throws  -> [Planet]
     
-    func fetchPlanets(ignoreCache: Bool) async throws  -> [Planet]
+    func fetchSpecies(url: String, policy: CachePolicy, cancellation: CancellationToken?) async throws  -> Species
     
-    func fetchSpecies(url: String) async throws  -> Species
+    func fetchSpeciesList(policy: CachePolicy, cancellation: CancellationToken?) async throws  -> [Species]
     
-    func fetchSpeciesList(ignoreCache: Bool) async throws  -> [Species]
+    func fetchStarship(url: String, policy: CachePolicy, cancellation: CancellationToken?) async throws  -> Starship
     
-    func fetchStarship(url: String) async throws  -> Starship
+    func fetchStarships(policy: CachePolicy, cancellation: CancellationToken?) async throws  -> [Starship]
     
-    func fetchStarships(ignoreCache: Bool) async throws  -> [Starship]
+    func fetchVehicle(url: String, policy: CachePolicy, cancellation: CancellationToken?) async throws  -> Vehicle
     
-    func fetchVehicle(url: String) async throws  -> Vehicle
+    func fetchVehicles(policy: CachePolicy, cancellation: CancellationToken?) async throws  -> [Vehicle]
     
-    func fetchVehicles(ignoreCache: Bool) async throws  -> [Vehicle]
+    func pageCursor(fetchable: Fetchable)  -> PageCursor
+    
+    func resolveRelated(items: [ListItems], cancellation: CancellationToken?) async throws  -> [RelatedGroup]
+    
+    func search(fetchable: Fetchable, query: String, cancellation: CancellationToken?) async throws  -> [Selected]
     
 }
 open class ApiClient: ApiClientProtocol, @unchecked Sendable {
@@ -611,15 +726,33 @@ public convenience init(httpClient: HttpClient, repository: DataRepository) {
     }
 
     
+public static func withConfig(httpClient: HttpClient, repository: DataRepository, config: ClientConfig) -> ApiClient  {
+    return try!  FfiConverterTypeApiClient_lift(try! rustCall() {
+    uniffi_starwars_fn_constructor_apiclient_with_config(
+        FfiConverterTypeHttpClient_lower(httpClient),
+        FfiConverterTypeDataRepository_lower(repository),
+        FfiConverterTypeClientConfig_lower(config),$0
+    )
+})
+}
+    
 
     
-open func fetchFilm(url: String)async throws  -> Film  {
+open func searchIndex() -> SearchIndex  {
+    return try!  FfiConverterTypeSearchIndex_lift(try! rustCall() {
+    uniffi_starwars_fn_method_apiclient_search_index(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func fetchFilm(url: String, policy: CachePolicy, cancellation: CancellationToken?)async throws  -> Film  {
     return
         try  await uniffiRustCallAsync(
             rustFutureFunc: {
                 uniffi_starwars_fn_method_apiclient_fetch_film(
                     self.uniffiCloneHandle(),
-                    FfiConverterString.lower(url)
+                    FfiConverterString.lower(url),FfiConverterTypeCachePolicy_lower(policy),FfiConverterOptionTypeCancellationToken.lower(cancellation)
                 )
             },
             pollFunc: ffi_starwars_rust_future_poll_rust_buffer,
@@ -630,13 +763,13 @@ open func fetchFilm(url: String)async throws  -> Film  {
         )
 }
     
-open func fetchFilms(ignoreCache: Bool)async throws  -> [Film]  {
+open func fetchFilms(policy: CachePolicy, cancellation: CancellationToken?)async throws  -> [Film]  {
     return
         try  await uniffiRustCallAsync(
             rustFutureFunc: {
                 uniffi_starwars_fn_method_apiclient_fetch_films(
                     self.uniffiCloneHandle(),
-                    FfiConverterBool.lower(ignoreCache)
+                    FfiConverterTypeCachePolicy_lower(policy),FfiConverterOptionTypeCancellationToken.lower(cancellation)
                 )
             },
             pollFunc: ffi_starwars_rust_future_poll_rust_buffer,
@@ -647,13 +780,13 @@ open func fetchFilms(ignoreCache: Bool)async throws  -> [Film]  {
         )
 }
     
-open func fetchPeople(ignoreCache: Bool)async throws  -> [Person]  {
+open func fetchPeople(policy: CachePolicy, cancellation: CancellationToken?)async throws  -> [Person]  {
     return
         try  await uniffiRustCallAsync(
             rustFutureFunc: {
                 uniffi_starwars_fn_method_apiclient_fetch_people(
                     self.uniffiCloneHandle(),
-                    FfiConverterBool.lower(ignoreCache)
+                    FfiConverterTypeCachePolicy_lower(policy),FfiConverterOptionTypeCancellationToken.lower(cancellation)
                 )
             },
             pollFunc: ffi_starwars_rust_future_poll_rust_buffer,
@@ -664,13 +797,13 @@ open func fetchPeople(ignoreCache: Bool)async throws  -> [Person]  {
         )
 }
     
-open func fetchPerson(url: String)async throws  -> Person  {
+open func fetchPerson(url: String, policy: CachePolicy, cancellation: CancellationToken?)async throws  -> Person  {
     return
         try  await uniffiRustCallAsync(
             rustFutureFunc: {
                 uniffi_starwars_fn_method_apiclient_fetch_person(
                     self.uniffiCloneHandle(),
-                    FfiConverterString.lower(url)
+                    FfiConverterString.lower(url),FfiConverterTypeCachePolicy_lower(policy),FfiConverterOptionTypeCancellationToken.lower(cancellation)
                 )
             },
             pollFunc: ffi_starwars_rust_future_poll_rust_buffer,
@@ -681,13 +814,13 @@ open func fetchPerson(url: String)async throws  -> Person  {
         )
 }
     
-open func fetchPlanet(url: String)async throws  -> Planet  {
+open func fetchPlanet(url: String, policy: CachePolicy, cancellation: CancellationToken?)async throws  -> Planet  {
     return
         try  await uniffiRustCallAsync(
             rustFutureFunc: {
                 uniffi_starwars_fn_method_apiclient_fetch_planet(
                     self.uniffiCloneHandle(),
-                    FfiConverterString.lower(url)
+                    FfiConverterString.lower(url),FfiConverterTypeCachePolicy_lower(policy),FfiConverterOptionTypeCancellationToken.lower(cancellation)
                 )
             },
             pollFunc: ffi_starwars_rust_future_poll_rust_buffer,
@@ -698,13 +831,13 @@ open func fetchPlanet(url: String)async throws  -> Planet  {
         )
 }
     
-open func fetchPlanets(ignoreCache: Bool)async throws  -> [Planet]  {
+open func fetchPlanets(policy: CachePolicy, cancellation: CancellationToken?)async throws  -> [Planet]  {
     return
         try  await uniffiRustCallAsync(
             rustFutureFunc: {
                 uniffi_starwars_fn_method_apiclient_fetch_planets(
                     self.uniffiCloneHandle(),
-                    FfiConverterBool.lower(ignoreCache)
+                    FfiConverterTypeCachePolicy_lower(policy),FfiConverterOptionTypeCancellationToken.lower(cancellation)
                 )
             },
             pollFunc: ffi_starwars_rust_future_poll_rust_buffer,
@@ -715,13 +848,13 @@ open func fetchPlanets(ignoreCache: Bool)async throws  -> [Planet]  {
         )
 }
     
-open func fetchSpecies(url: String)async throws  -> Species  {
+open func fetchSpecies(url: String, policy: CachePolicy, cancellation: CancellationToken?)async throws  -> Species  {
     return
         try  await uniffiRustCallAsync(
             rustFutureFunc: {
                 uniffi_starwars_fn_method_apiclient_fetch_species(
                     self.uniffiCloneHandle(),
-                    FfiConverterString.lower(url)
+                    FfiConverterString.lower(url),FfiConverterTypeCachePolicy_lower(policy),FfiConverterOptionTypeCancellationToken.lower(cancellation)
                 )
             },
             pollFunc: ffi_starwars_rust_future_poll_rust_buffer,
@@ -732,13 +865,13 @@ open func fetchSpecies(url: String)async throws  -> Species  {
         )
 }
     
-open func fetchSpeciesList(ignoreCache: Bool)async throws  -> [Species]  {
+open func fetchSpeciesList(policy: CachePolicy, cancellation: CancellationToken?)async throws  -> [Species]  {
     return
         try  await uniffiRustCallAsync(
             rustFutureFunc: {
                 uniffi_starwars_fn_method_apiclient_fetch_species_list(
                     self.uniffiCloneHandle(),
-                    FfiConverterBool.lower(ignoreCache)
+                    FfiConverterTypeCachePolicy_lower(policy),FfiConverterOptionTypeCancellationToken.lower(cancellation)
                 )
             },
             pollFunc: ffi_starwars_rust_future_poll_rust_buffer,
@@ -749,13 +882,13 @@ open func fetchSpeciesList(ignoreCache: Bool)async throws  -> [Species]  {
         )
 }
     
-open func fetchStarship(url: String)async throws  -> Starship  {
+open func fetchStarship(url: String, policy: CachePolicy, cancellation: CancellationToken?)async throws  -> Starship  {
     return
         try  await uniffiRustCallAsync(
             rustFutureFunc: {
                 uniffi_starwars_fn_method_apiclient_fetch_starship(
                     self.uniffiCloneHandle(),
-                    FfiConverterString.lower(url)
+                    FfiConverterString.lower(url),FfiConverterTypeCachePolicy_lower(policy),FfiConverterOptionTypeCancellationToken.lower(cancellation)
                 )
             },
             pollFunc: ffi_starwars_rust_future_poll_rust_buffer,
@@ -766,13 +899,13 @@ open func fetchStarship(url: String)async throws  -> Starship  {
         )
 }
     
-open func fetchStarships(ignoreCache: Bool)async throws  -> [Starship]  {
+open func fetchStarships(policy: CachePolicy, cancellation: CancellationToken?)async throws  -> [Starship]  {
     return
         try  await uniffiRustCallAsync(
             rustFutureFunc: {
                 uniffi_starwars_fn_method_apiclient_fetch_starships(
                     self.uniffiCloneHandle(),
-                    FfiConverterBool.lower(ignoreCache)
+                    FfiConverterTypeCachePolicy_lower(policy),FfiConverterOptionTypeCancellationToken.lower(cancellation)
                 )
             },
             pollFunc: ffi_starwars_rust_future_poll_rust_buffer,
@@ -783,13 +916,13 @@ open func fetchStarships(ignoreCache: Bool)async throws  -> [Starship]  {
         )
 }
     
-open func fetchVehicle(url: String)async throws  -> Vehicle  {
+open func fetchVehicle(url: String, policy: CachePolicy, cancellation: CancellationToken?)async throws  -> Vehicle  {
     return
         try  await uniffiRustCallAsync(
             rustFutureFunc: {
                 uniffi_starwars_fn_method_apiclient_fetch_vehicle(
                     self.uniffiCloneHandle(),
-                    FfiConverterString.lower(url)
+                    FfiConverterString.lower(url),FfiConverterTypeCachePolicy_lower(policy),FfiConverterOptionTypeCancellationToken.lower(cancellation)
                 )
             },
             pollFunc: ffi_starwars_rust_future_poll_rust_buffer,
@@ -800,13 +933,13 @@ open func fetchVehicle(url: String)async throws  -> Vehicle  {
         )
 }
     
-open func fetchVehicles(ignoreCache: Bool)async throws  -> [Vehicle]  {
+open func fetchVehicles(policy: CachePolicy, cancellation: CancellationToken?)async throws  -> [Vehicle]  {
     return
         try  await uniffiRustCallAsync(
             rustFutureFunc: {
                 uniffi_starwars_fn_method_apiclient_fetch_vehicles(
                     self.uniffiCloneHandle(),
-                    FfiConverterBool.lower(ignoreCache)
+                    FfiConverterTypeCachePolicy_lower(policy),FfiConverterOptionTypeCancellationToken.lower(cancellation)
                 )
             },
             pollFunc: ffi_starwars_rust_future_poll_rust_buffer,
@@ -817,6 +950,49 @@ open func fetchVehicles(ignoreCache: Bool)async throws  -> [Vehicle]  {
         )
 }
     
+open func pageCursor(fetchable: Fetchable) -> PageCursor  {
+    return try!  FfiConverterTypePageCursor_lift(try! rustCall() {
+    uniffi_starwars_fn_method_apiclient_page_cursor(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeFetchable_lower(fetchable),$0
+    )
+})
+}
+    
+open func resolveRelated(items: [ListItems], cancellation: CancellationToken?)async throws  -> [RelatedGroup]  {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_starwars_fn_method_apiclient_resolve_related(
+                    self.uniffiCloneHandle(),
+                    FfiConverterSequenceTypeListItems.lower(items),FfiConverterOptionTypeCancellationToken.lower(cancellation)
+                )
+            },
+            pollFunc: ffi_starwars_rust_future_poll_rust_buffer,
+            completeFunc: ffi_starwars_rust_future_complete_rust_buffer,
+            freeFunc: ffi_starwars_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceTypeRelatedGroup.lift,
+            errorHandler: FfiConverterTypeApiError_lift
+        )
+}
+    
+open func search(fetchable: Fetchable, query: String, cancellation: CancellationToken?)async throws  -> [Selected]  {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_starwars_fn_method_apiclient_search(
+                    self.uniffiCloneHandle(),
+                    FfiConverterTypeFetchable_lower(fetchable),FfiConverterString.lower(query),FfiConverterOptionTypeCancellationToken.lower(cancellation)
+                )
+            },
+            pollFunc: ffi_starwars_rust_future_poll_rust_buffer,
+            completeFunc: ffi_starwars_rust_future_complete_rust_buffer,
+            freeFunc: ffi_starwars_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceTypeSelected.lift,
+            errorHandler: FfiConverterTypeApiError_lift
+        )
+}
+    
 
     
 }
@@ -867,16 +1043,12 @@ public func FfiConverterTypeApiClient_lower(_ value: ApiClient) -> UInt64 {
 
 
 
-public protocol DataRepositoryProtocol: AnyObject, Sendable {
+public protocol CancellationListener: AnyObject, Sendable {
     
-    func getPlanet(url: String)  -> Planet?
-    
-    func getPlanets()  -> [Planet]?
-    
-    func insertPlanet(planet: Planet) 
+    func onCancel() 
     
 }
-open class DataRepository: DataRepositoryProtocol, @unchecked Sendable {
+open class CancellationListenerImpl: CancellationListener, @unchecked Sendable {
     fileprivate let handle: UInt64
 
     /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
@@ -913,16 +1085,9 @@ open class DataRepository: DataRepositoryProtocol, @unchecked Sendable {
     @_documentation(visibility: private)
 #endif
     public func uniffiCloneHandle() -> UInt64 {
-        return try! rustCall { uniffi_starwars_fn_clone_datarepository(self.handle, $0) }
+        return try! rustCall { uniffi_starwars_fn_clone_cancellationlistener(self.handle, $0) }
     }
-public convenience init() {
-    let handle =
-        try! rustCall() {
-    uniffi_starwars_fn_constructor_datarepository_new($0
-    )
-}
-    self.init(unsafeFromHandle: handle)
-}
+    // No primary constructor declared for this class.
 
     deinit {
         if handle == 0 {
@@ -930,34 +1095,16 @@ public convenience init() {
             return
         }
 
-        try! rustCall { uniffi_starwars_fn_free_datarepository(handle, $0) }
+        try! rustCall { uniffi_starwars_fn_free_cancellationlistener(handle, $0) }
     }
 
     
 
     
-open func getPlanet(url: String) -> Planet?  {
-    return try!  FfiConverterOptionTypePlanet.lift(try! rustCall() {
-    uniffi_starwars_fn_method_datarepository_get_planet(
-            self.uniffiCloneHandle(),
-        FfiConverterString.lower(url),$0
-    )
-})
-}
-    
-open func getPlanets() -> [Planet]?  {
-    return try!  FfiConverterOptionSequenceTypePlanet.lift(try! rustCall() {
-    uniffi_starwars_fn_method_datarepository_get_planets(
+open func onCancel()  {try! rustCall() {
+    uniffi_starwars_fn_method_cancellationlistener_on_cancel(
             self.uniffiCloneHandle(),$0
     )
-})
-}
-    
-open func insertPlanet(planet: Planet)  {try! rustCall() {
-    uniffi_starwars_fn_method_datarepository_insert_planet(
-            self.uniffiCloneHandle(),
-        FfiConverterTypePlanet_lower(planet),$0
-    )
 }
 }
     
@@ -966,27 +1113,106 @@ open func insertPlanet(planet: Planet)  {try! rustCall() {
 }
 
 
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiCallbackInterfaceCancellationListener {
+
+    // Create the VTable using a series of closures.
+    // Swift automatically converts these into C callback functions.
+    //
+    // Store the vtable directly.
+    static let vtable: UniffiVTableCallbackInterfaceCancellationListener = UniffiVTableCallbackInterfaceCancellationListener(
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            do {
+                try FfiConverterTypeCancellationListener.handleMap.remove(handle: uniffiHandle)
+            } catch {
+                print("Uniffi callback interface CancellationListener: handle missing in uniffiFree")
+            }
+        },
+        uniffiClone: { (uniffiHandle: UInt64) -> UInt64 in
+            do {
+                return try FfiConverterTypeCancellationListener.handleMap.clone(handle: uniffiHandle)
+            } catch {
+                fatalError("Uniffi callback interface CancellationListener: handle missing in uniffiClone")
+            }
+        },
+        onCancel: { (
+            uniffiHandle: UInt64,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterTypeCancellationListener.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.onCancel(
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        }
+    )
+
+    // Rust stores this pointer for future callback invocations, so it must live
+    // for the process lifetime (not just for the init function call).
+    //
+    // `nonisolated(unsafe)` is needed under Swift 6 strict concurrency.
+    // This is safe because the pointee is initialized once during static init
+    // and never mutated by either side of the FFI.  Its fields are C function pointers.
+    nonisolated(unsafe) static let vtablePtr: UnsafePointer<UniffiVTableCallbackInterfaceCancellationListener> = {
+        let ptr = UnsafeMutablePointer<UniffiVTableCallbackInterfaceCancellationListener>.allocate(capacity: 1)
+        ptr.initialize(to: vtable)
+        return UnsafePointer(ptr)
+    }()
+}
+
+private func uniffiCallbackInitCancellationListener() {
+    uniffi_starwars_fn_init_callback_vtable_cancellationlistener(UniffiCallbackInterfaceCancellationListener.vtablePtr)
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeDataRepository: FfiConverter {
+public struct FfiConverterTypeCancellationListener: FfiConverter {
+    fileprivate static let handleMap = UniffiHandleMap<CancellationListener>()
+
     typealias FfiType = UInt64
-    typealias SwiftType = DataRepository
+    typealias SwiftType = CancellationListener
 
-    public static func lift(_ handle: UInt64) throws -> DataRepository {
-        return DataRepository(unsafeFromHandle: handle)
+    public static func lift(_ handle: UInt64) throws -> CancellationListener {
+        if ((handle & 1) == 0) {
+            // Rust-generated handle, construct a new class that uses the handle to implement the
+            // interface
+            return CancellationListenerImpl(unsafeFromHandle: handle)
+        } else {
+            // Swift-generated handle, get the object from the handle map
+            return try handleMap.remove(handle: handle)
+        }
     }
 
-    public static func lower(_ value: DataRepository) -> UInt64 {
-        return value.uniffiCloneHandle()
+    public static func lower(_ value: CancellationListener) -> UInt64 {
+         if let rustImpl = value as? CancellationListenerImpl {
+             // Rust-implemented object.  Clone the handle and return it
+            return rustImpl.uniffiCloneHandle()
+         } else {
+            // Swift object, generate a new vtable handle and return that.
+            return handleMap.insert(obj: value)
+         }
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DataRepository {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CancellationListener {
         let handle: UInt64 = try readInt(&buf)
         return try lift(handle)
     }
 
-    public static func write(_ value: DataRepository, into buf: inout [UInt8]) {
+    public static func write(_ value: CancellationListener, into buf: inout [UInt8]) {
         writeInt(&buf, lower(value))
     }
 }
@@ -995,15 +1221,15 @@ public struct FfiConverterTypeDataRepository: FfiConverter {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeDataRepository_lift(_ handle: UInt64) throws -> DataRepository {
-    return try FfiConverterTypeDataRepository.lift(handle)
+public func FfiConverterTypeCancellationListener_lift(_ handle: UInt64) throws -> CancellationListener {
+    return try FfiConverterTypeCancellationListener.lift(handle)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeDataRepository_lower(_ value: DataRepository) -> UInt64 {
-    return FfiConverterTypeDataRepository.lower(value)
+public func FfiConverterTypeCancellationListener_lower(_ value: CancellationListener) -> UInt64 {
+    return FfiConverterTypeCancellationListener.lower(value)
 }
 
 
@@ -1011,12 +1237,16 @@ public func FfiConverterTypeDataRepository_lower(_ value: DataRepository) -> UIn
 
 
 
-public protocol HttpClient: AnyObject, Sendable {
+public protocol CancellationTokenProtocol: AnyObject, Sendable {
+    
+    func cancel() 
     
-    func fetch(url: String) async throws  -> Data
+    func isCancelled()  -> Bool
+    
+    func onCancel(listener: CancellationListener) 
     
 }
-open class HttpClientImpl: HttpClient, @unchecked Sendable {
+open class CancellationToken: CancellationTokenProtocol, @unchecked Sendable {
     fileprivate let handle: UInt64
 
     /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
@@ -1053,9 +1283,16 @@ open class HttpClientImpl: HttpClient, @unchecked Sendable {
     @_documentation(visibility: private)
 #endif
     public func uniffiCloneHandle() -> UInt64 {
-        return try! rustCall { uniffi_starwars_fn_clone_httpclient(self.handle, $0) }
+        return try! rustCall { uniffi_starwars_fn_clone_cancellationtoken(self.handle, $0) }
     }
-    // No primary constructor declared for this class.
+public convenience init() {
+    let handle =
+        try! rustCall() {
+    uniffi_starwars_fn_constructor_cancellationtoken_new($0
+    )
+}
+    self.init(unsafeFromHandle: handle)
+}
 
     deinit {
         if handle == 0 {
@@ -1063,27 +1300,33 @@ open class HttpClientImpl: HttpClient, @unchecked Sendable {
             return
         }
 
-        try! rustCall { uniffi_starwars_fn_free_httpclient(handle, $0) }
+        try! rustCall { uniffi_starwars_fn_free_cancellationtoken(handle, $0) }
     }
 
     
 
     
-open func fetch(url: String)async throws  -> Data  {
-    return
-        try  await uniffiRustCallAsync(
-            rustFutureFunc: {
-                uniffi_starwars_fn_method_httpclient_fetch(
-                    self.uniffiCloneHandle(),
-                    FfiConverterString.lower(url)
-                )
-            },
-            pollFunc: ffi_starwars_rust_future_poll_rust_buffer,
-            completeFunc: ffi_starwars_rust_future_complete_rust_buffer,
-            freeFunc: ffi_starwars_rust_future_free_rust_buffer,
-            liftFunc: FfiConverterData.lift,
-            errorHandler: FfiConverterTypeNetworkError_lift
-        )
+open func cancel()  {try! rustCall() {
+    uniffi_starwars_fn_method_cancellationtoken_cancel(
+            self.uniffiCloneHandle(),$0
+    )
+}
+}
+    
+open func isCancelled() -> Bool  {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_starwars_fn_method_cancellationtoken_is_cancelled(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func onCancel(listener: CancellationListener)  {try! rustCall() {
+    uniffi_starwars_fn_method_cancellationtoken_on_cancel(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeCancellationListener_lower(listener),$0
+    )
+}
 }
     
 
@@ -1091,117 +1334,4333 @@ open func fetch(url: String)async throws  -> Data  {
 }
 
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeCancellationToken: FfiConverter {
+    typealias FfiType = UInt64
+    typealias SwiftType = CancellationToken
 
-// Put the implementation in a struct so we don't pollute the top-level namespace
-fileprivate struct UniffiCallbackInterfaceHttpClient {
+    public static func lift(_ handle: UInt64) throws -> CancellationToken {
+        return CancellationToken(unsafeFromHandle: handle)
+    }
 
-    // Create the VTable using a series of closures.
-    // Swift automatically converts these into C callback functions.
-    //
-    // This creates 1-element array, since this seems to be the only way to construct a const
-    // pointer that we can pass to the Rust code.
-    static let vtable: [UniffiVTableCallbackInterfaceHttpClient] = [UniffiVTableCallbackInterfaceHttpClient(
-        uniffiFree: { (uniffiHandle: UInt64) -> () in
-            do {
-                try FfiConverterTypeHttpClient.handleMap.remove(handle: uniffiHandle)
-            } catch {
-                print("Uniffi callback interface HttpClient: handle missing in uniffiFree")
-            }
-        },
+    public static func lower(_ value: CancellationToken) -> UInt64 {
+        return value.uniffiCloneHandle()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CancellationToken {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+    public static func write(_ value: CancellationToken, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCancellationToken_lift(_ handle: UInt64) throws -> CancellationToken {
+    return try FfiConverterTypeCancellationToken.lift(handle)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCancellationToken_lower(_ value: CancellationToken) -> UInt64 {
+    return FfiConverterTypeCancellationToken.lower(value)
+}
+
+
+
+
+
+
+public protocol DataRepositoryProtocol: AnyObject, Sendable {
+    
+    func queryFilms(query: Query) throws  -> [Film]
+    
+    func queryPeople(query: Query) throws  -> [Person]
+    
+    func queryPlanets(query: Query) throws  -> [Planet]
+    
+    func querySpecies(query: Query) throws  -> [Species]
+    
+    func queryStarships(query: Query) throws  -> [Starship]
+    
+    func queryVehicles(query: Query) throws  -> [Vehicle]
+    
+    func addObserver(observer: RepositoryObserver)  -> UInt64
+    
+    func clear(fetchable: Fetchable) throws 
+    
+    func fetchedAt(fetchable: Fetchable, url: String)  -> UInt64?
+    
+    func getFilm(url: String)  -> Film?
+    
+    func getFilmById(id: UInt32)  -> Film?
+    
+    func getFilms()  -> [Film]?
+    
+    func getPeople()  -> [Person]?
+    
+    func getPerson(url: String)  -> Person?
+    
+    func getPersonById(id: UInt32)  -> Person?
+    
+    func getPlanet(url: String)  -> Planet?
+    
+    func getPlanetById(id: UInt32)  -> Planet?
+    
+    func getPlanets()  -> [Planet]?
+    
+    func getSpecies(url: String)  -> Species?
+    
+    func getSpeciesById(id: UInt32)  -> Species?
+    
+    func getSpeciesList()  -> [Species]?
+    
+    func getStarship(url: String)  -> Starship?
+    
+    func getStarshipById(id: UInt32)  -> Starship?
+    
+    func getStarships()  -> [Starship]?
+    
+    func getVehicle(url: String)  -> Vehicle?
+    
+    func getVehicleById(id: UInt32)  -> Vehicle?
+    
+    func getVehicles()  -> [Vehicle]?
+    
+    func insertFilm(film: Film) throws 
+    
+    func insertPerson(person: Person) throws 
+    
+    func insertPlanet(planet: Planet) throws 
+    
+    func insertSpecies(species: Species) throws 
+    
+    func insertStarship(starship: Starship) throws 
+    
+    func insertVehicle(vehicle: Vehicle) throws 
+    
+    func remove(fetchable: Fetchable, id: UInt32) throws 
+    
+    func removeObserver(token: UInt64) 
+    
+    func setTtl(fetchable: Fetchable, ttlSeconds: UInt64) 
+    
+    func ttl(fetchable: Fetchable)  -> UInt64
+    
+}
+open class DataRepository: DataRepositoryProtocol, @unchecked Sendable {
+    fileprivate let handle: UInt64
+
+    /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoHandle {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromHandle handle: UInt64) {
+        self.handle = handle
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noHandle: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing handle the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noHandle: NoHandle) {
+        self.handle = 0
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiCloneHandle() -> UInt64 {
+        return try! rustCall { uniffi_starwars_fn_clone_datarepository(self.handle, $0) }
+    }
+public convenience init() {
+    let handle =
+        try! rustCall() {
+    uniffi_starwars_fn_constructor_datarepository_new($0
+    )
+}
+    self.init(unsafeFromHandle: handle)
+}
+
+    deinit {
+        if handle == 0 {
+            // Mock objects have handle=0 don't try to free them
+            return
+        }
+
+        try! rustCall { uniffi_starwars_fn_free_datarepository(handle, $0) }
+    }
+
+    
+public static func withStore(store: RepositoryStore)throws  -> DataRepository  {
+    return try  FfiConverterTypeDataRepository_lift(try rustCallWithError(FfiConverterTypeStoreError_lift) {
+    uniffi_starwars_fn_constructor_datarepository_with_store(
+        FfiConverterTypeRepositoryStore_lower(store),$0
+    )
+})
+}
+    
+
+    
+open func queryFilms(query: Query)throws  -> [Film]  {
+    return try  FfiConverterSequenceTypeFilm.lift(try rustCallWithError(FfiConverterTypeQueryError_lift) {
+    uniffi_starwars_fn_method_datarepository_query_films(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeQuery_lower(query),$0
+    )
+})
+}
+    
+open func queryPeople(query: Query)throws  -> [Person]  {
+    return try  FfiConverterSequenceTypePerson.lift(try rustCallWithError(FfiConverterTypeQueryError_lift) {
+    uniffi_starwars_fn_method_datarepository_query_people(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeQuery_lower(query),$0
+    )
+})
+}
+    
+open func queryPlanets(query: Query)throws  -> [Planet]  {
+    return try  FfiConverterSequenceTypePlanet.lift(try rustCallWithError(FfiConverterTypeQueryError_lift) {
+    uniffi_starwars_fn_method_datarepository_query_planets(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeQuery_lower(query),$0
+    )
+})
+}
+    
+open func querySpecies(query: Query)throws  -> [Species]  {
+    return try  FfiConverterSequenceTypeSpecies.lift(try rustCallWithError(FfiConverterTypeQueryError_lift) {
+    uniffi_starwars_fn_method_datarepository_query_species(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeQuery_lower(query),$0
+    )
+})
+}
+    
+open func queryStarships(query: Query)throws  -> [Starship]  {
+    return try  FfiConverterSequenceTypeStarship.lift(try rustCallWithError(FfiConverterTypeQueryError_lift) {
+    uniffi_starwars_fn_method_datarepository_query_starships(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeQuery_lower(query),$0
+    )
+})
+}
+    
+open func queryVehicles(query: Query)throws  -> [Vehicle]  {
+    return try  FfiConverterSequenceTypeVehicle.lift(try rustCallWithError(FfiConverterTypeQueryError_lift) {
+    uniffi_starwars_fn_method_datarepository_query_vehicles(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeQuery_lower(query),$0
+    )
+})
+}
+    
+open func addObserver(observer: RepositoryObserver) -> UInt64  {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_starwars_fn_method_datarepository_add_observer(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeRepositoryObserver_lower(observer),$0
+    )
+})
+}
+    
+open func clear(fetchable: Fetchable)throws   {try rustCallWithError(FfiConverterTypeStoreError_lift) {
+    uniffi_starwars_fn_method_datarepository_clear(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeFetchable_lower(fetchable),$0
+    )
+}
+}
+    
+open func fetchedAt(fetchable: Fetchable, url: String) -> UInt64?  {
+    return try!  FfiConverterOptionUInt64.lift(try! rustCall() {
+    uniffi_starwars_fn_method_datarepository_fetched_at(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeFetchable_lower(fetchable),
+        FfiConverterString.lower(url),$0
+    )
+})
+}
+    
+open func getFilm(url: String) -> Film?  {
+    return try!  FfiConverterOptionTypeFilm.lift(try! rustCall() {
+    uniffi_starwars_fn_method_datarepository_get_film(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(url),$0
+    )
+})
+}
+    
+open func getFilmById(id: UInt32) -> Film?  {
+    return try!  FfiConverterOptionTypeFilm.lift(try! rustCall() {
+    uniffi_starwars_fn_method_datarepository_get_film_by_id(
+            self.uniffiCloneHandle(),
+        FfiConverterUInt32.lower(id),$0
+    )
+})
+}
+    
+open func getFilms() -> [Film]?  {
+    return try!  FfiConverterOptionSequenceTypeFilm.lift(try! rustCall() {
+    uniffi_starwars_fn_method_datarepository_get_films(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func getPeople() -> [Person]?  {
+    return try!  FfiConverterOptionSequenceTypePerson.lift(try! rustCall() {
+    uniffi_starwars_fn_method_datarepository_get_people(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func getPerson(url: String) -> Person?  {
+    return try!  FfiConverterOptionTypePerson.lift(try! rustCall() {
+    uniffi_starwars_fn_method_datarepository_get_person(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(url),$0
+    )
+})
+}
+    
+open func getPersonById(id: UInt32) -> Person?  {
+    return try!  FfiConverterOptionTypePerson.lift(try! rustCall() {
+    uniffi_starwars_fn_method_datarepository_get_person_by_id(
+            self.uniffiCloneHandle(),
+        FfiConverterUInt32.lower(id),$0
+    )
+})
+}
+    
+open func getPlanet(url: String) -> Planet?  {
+    return try!  FfiConverterOptionTypePlanet.lift(try! rustCall() {
+    uniffi_starwars_fn_method_datarepository_get_planet(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(url),$0
+    )
+})
+}
+    
+open func getPlanetById(id: UInt32) -> Planet?  {
+    return try!  FfiConverterOptionTypePlanet.lift(try! rustCall() {
+    uniffi_starwars_fn_method_datarepository_get_planet_by_id(
+            self.uniffiCloneHandle(),
+        FfiConverterUInt32.lower(id),$0
+    )
+})
+}
+    
+open func getPlanets() -> [Planet]?  {
+    return try!  FfiConverterOptionSequenceTypePlanet.lift(try! rustCall() {
+    uniffi_starwars_fn_method_datarepository_get_planets(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func getSpecies(url: String) -> Species?  {
+    return try!  FfiConverterOptionTypeSpecies.lift(try! rustCall() {
+    uniffi_starwars_fn_method_datarepository_get_species(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(url),$0
+    )
+})
+}
+    
+open func getSpeciesById(id: UInt32) -> Species?  {
+    return try!  FfiConverterOptionTypeSpecies.lift(try! rustCall() {
+    uniffi_starwars_fn_method_datarepository_get_species_by_id(
+            self.uniffiCloneHandle(),
+        FfiConverterUInt32.lower(id),$0
+    )
+})
+}
+    
+open func getSpeciesList() -> [Species]?  {
+    return try!  FfiConverterOptionSequenceTypeSpecies.lift(try! rustCall() {
+    uniffi_starwars_fn_method_datarepository_get_species_list(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func getStarship(url: String) -> Starship?  {
+    return try!  FfiConverterOptionTypeStarship.lift(try! rustCall() {
+    uniffi_starwars_fn_method_datarepository_get_starship(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(url),$0
+    )
+})
+}
+    
+open func getStarshipById(id: UInt32) -> Starship?  {
+    return try!  FfiConverterOptionTypeStarship.lift(try! rustCall() {
+    uniffi_starwars_fn_method_datarepository_get_starship_by_id(
+            self.uniffiCloneHandle(),
+        FfiConverterUInt32.lower(id),$0
+    )
+})
+}
+    
+open func getStarships() -> [Starship]?  {
+    return try!  FfiConverterOptionSequenceTypeStarship.lift(try! rustCall() {
+    uniffi_starwars_fn_method_datarepository_get_starships(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func getVehicle(url: String) -> Vehicle?  {
+    return try!  FfiConverterOptionTypeVehicle.lift(try! rustCall() {
+    uniffi_starwars_fn_method_datarepository_get_vehicle(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(url),$0
+    )
+})
+}
+    
+open func getVehicleById(id: UInt32) -> Vehicle?  {
+    return try!  FfiConverterOptionTypeVehicle.lift(try! rustCall() {
+    uniffi_starwars_fn_method_datarepository_get_vehicle_by_id(
+            self.uniffiCloneHandle(),
+        FfiConverterUInt32.lower(id),$0
+    )
+})
+}
+    
+open func getVehicles() -> [Vehicle]?  {
+    return try!  FfiConverterOptionSequenceTypeVehicle.lift(try! rustCall() {
+    uniffi_starwars_fn_method_datarepository_get_vehicles(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func insertFilm(film: Film)throws   {try rustCallWithError(FfiConverterTypeStoreError_lift) {
+    uniffi_starwars_fn_method_datarepository_insert_film(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeFilm_lower(film),$0
+    )
+}
+}
+    
+open func insertPerson(person: Person)throws   {try rustCallWithError(FfiConverterTypeStoreError_lift) {
+    uniffi_starwars_fn_method_datarepository_insert_person(
+            self.uniffiCloneHandle(),
+        FfiConverterTypePerson_lower(person),$0
+    )
+}
+}
+    
+open func insertPlanet(planet: Planet)throws   {try rustCallWithError(FfiConverterTypeStoreError_lift) {
+    uniffi_starwars_fn_method_datarepository_insert_planet(
+            self.uniffiCloneHandle(),
+        FfiConverterTypePlanet_lower(planet),$0
+    )
+}
+}
+    
+open func insertSpecies(species: Species)throws   {try rustCallWithError(FfiConverterTypeStoreError_lift) {
+    uniffi_starwars_fn_method_datarepository_insert_species(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeSpecies_lower(species),$0
+    )
+}
+}
+    
+open func insertStarship(starship: Starship)throws   {try rustCallWithError(FfiConverterTypeStoreError_lift) {
+    uniffi_starwars_fn_method_datarepository_insert_starship(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeStarship_lower(starship),$0
+    )
+}
+}
+    
+open func insertVehicle(vehicle: Vehicle)throws   {try rustCallWithError(FfiConverterTypeStoreError_lift) {
+    uniffi_starwars_fn_method_datarepository_insert_vehicle(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeVehicle_lower(vehicle),$0
+    )
+}
+}
+    
+open func remove(fetchable: Fetchable, id: UInt32)throws   {try rustCallWithError(FfiConverterTypeStoreError_lift) {
+    uniffi_starwars_fn_method_datarepository_remove(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeFetchable_lower(fetchable),
+        FfiConverterUInt32.lower(id),$0
+    )
+}
+}
+    
+open func removeObserver(token: UInt64)  {try! rustCall() {
+    uniffi_starwars_fn_method_datarepository_remove_observer(
+            self.uniffiCloneHandle(),
+        FfiConverterUInt64.lower(token),$0
+    )
+}
+}
+    
+open func setTtl(fetchable: Fetchable, ttlSeconds: UInt64)  {try! rustCall() {
+    uniffi_starwars_fn_method_datarepository_set_ttl(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeFetchable_lower(fetchable),
+        FfiConverterUInt64.lower(ttlSeconds),$0
+    )
+}
+}
+    
+open func ttl(fetchable: Fetchable) -> UInt64  {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_starwars_fn_method_datarepository_ttl(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeFetchable_lower(fetchable),$0
+    )
+})
+}
+    
+
+    
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeDataRepository: FfiConverter {
+    typealias FfiType = UInt64
+    typealias SwiftType = DataRepository
+
+    public static func lift(_ handle: UInt64) throws -> DataRepository {
+        return DataRepository(unsafeFromHandle: handle)
+    }
+
+    public static func lower(_ value: DataRepository) -> UInt64 {
+        return value.uniffiCloneHandle()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DataRepository {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+    public static func write(_ value: DataRepository, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDataRepository_lift(_ handle: UInt64) throws -> DataRepository {
+    return try FfiConverterTypeDataRepository.lift(handle)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDataRepository_lower(_ value: DataRepository) -> UInt64 {
+    return FfiConverterTypeDataRepository.lower(value)
+}
+
+
+
+
+
+
+public protocol FileStoreProtocol: AnyObject, Sendable {
+    
+    func keys() throws  -> [String]
+    
+    func read(key: String) throws  -> Data?
+    
+    func remove(key: String) throws 
+    
+    func write(key: String, contents: Data) throws 
+    
+}
+open class FileStore: FileStoreProtocol, @unchecked Sendable {
+    fileprivate let handle: UInt64
+
+    /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoHandle {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromHandle handle: UInt64) {
+        self.handle = handle
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noHandle: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing handle the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noHandle: NoHandle) {
+        self.handle = 0
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiCloneHandle() -> UInt64 {
+        return try! rustCall { uniffi_starwars_fn_clone_filestore(self.handle, $0) }
+    }
+public convenience init(directory: String)throws  {
+    let handle =
+        try rustCallWithError(FfiConverterTypeStoreError_lift) {
+    uniffi_starwars_fn_constructor_filestore_new(
+        FfiConverterString.lower(directory),$0
+    )
+}
+    self.init(unsafeFromHandle: handle)
+}
+
+    deinit {
+        if handle == 0 {
+            // Mock objects have handle=0 don't try to free them
+            return
+        }
+
+        try! rustCall { uniffi_starwars_fn_free_filestore(handle, $0) }
+    }
+
+    
+
+    
+open func keys()throws  -> [String]  {
+    return try  FfiConverterSequenceString.lift(try rustCallWithError(FfiConverterTypeStoreError_lift) {
+    uniffi_starwars_fn_method_filestore_keys(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func read(key: String)throws  -> Data?  {
+    return try  FfiConverterOptionData.lift(try rustCallWithError(FfiConverterTypeStoreError_lift) {
+    uniffi_starwars_fn_method_filestore_read(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(key),$0
+    )
+})
+}
+    
+open func remove(key: String)throws   {try rustCallWithError(FfiConverterTypeStoreError_lift) {
+    uniffi_starwars_fn_method_filestore_remove(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(key),$0
+    )
+}
+}
+    
+open func write(key: String, contents: Data)throws   {try rustCallWithError(FfiConverterTypeStoreError_lift) {
+    uniffi_starwars_fn_method_filestore_write(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(key),
+        FfiConverterData.lower(contents),$0
+    )
+}
+}
+    
+
+    
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFileStore: FfiConverter {
+    typealias FfiType = UInt64
+    typealias SwiftType = FileStore
+
+    public static func lift(_ handle: UInt64) throws -> FileStore {
+        return FileStore(unsafeFromHandle: handle)
+    }
+
+    public static func lower(_ value: FileStore) -> UInt64 {
+        return value.uniffiCloneHandle()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FileStore {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+    public static func write(_ value: FileStore, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+extension FileStore: RepositoryStore {}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFileStore_lift(_ handle: UInt64) throws -> FileStore {
+    return try FfiConverterTypeFileStore.lift(handle)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFileStore_lower(_ value: FileStore) -> UInt64 {
+    return FfiConverterTypeFileStore.lower(value)
+}
+
+
+
+
+
+
+public protocol HttpClient: AnyObject, Sendable {
+    
+    func fetch(request: HttpRequest) async throws  -> HttpResponse
+    
+}
+open class HttpClientImpl: HttpClient, @unchecked Sendable {
+    fileprivate let handle: UInt64
+
+    /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoHandle {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromHandle handle: UInt64) {
+        self.handle = handle
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noHandle: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing handle the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noHandle: NoHandle) {
+        self.handle = 0
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiCloneHandle() -> UInt64 {
+        return try! rustCall { uniffi_starwars_fn_clone_httpclient(self.handle, $0) }
+    }
+    // No primary constructor declared for this class.
+
+    deinit {
+        if handle == 0 {
+            // Mock objects have handle=0 don't try to free them
+            return
+        }
+
+        try! rustCall { uniffi_starwars_fn_free_httpclient(handle, $0) }
+    }
+
+    
+
+    
+open func fetch(request: HttpRequest)async throws  -> HttpResponse  {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_starwars_fn_method_httpclient_fetch(
+                    self.uniffiCloneHandle(),
+                    FfiConverterTypeHttpRequest_lower(request)
+                )
+            },
+            pollFunc: ffi_starwars_rust_future_poll_rust_buffer,
+            completeFunc: ffi_starwars_rust_future_complete_rust_buffer,
+            freeFunc: ffi_starwars_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeHttpResponse_lift,
+            errorHandler: FfiConverterTypeNetworkError_lift
+        )
+}
+    
+
+    
+}
+
+
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiCallbackInterfaceHttpClient {
+
+    // Create the VTable using a series of closures.
+    // Swift automatically converts these into C callback functions.
+    //
+    // Store the vtable directly.
+    static let vtable: UniffiVTableCallbackInterfaceHttpClient = UniffiVTableCallbackInterfaceHttpClient(
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            do {
+                try FfiConverterTypeHttpClient.handleMap.remove(handle: uniffiHandle)
+            } catch {
+                print("Uniffi callback interface HttpClient: handle missing in uniffiFree")
+            }
+        },
+        uniffiClone: { (uniffiHandle: UInt64) -> UInt64 in
+            do {
+                return try FfiConverterTypeHttpClient.handleMap.clone(handle: uniffiHandle)
+            } catch {
+                fatalError("Uniffi callback interface HttpClient: handle missing in uniffiClone")
+            }
+        },
+        fetch: { (
+            uniffiHandle: UInt64,
+            request: RustBuffer,
+            uniffiFutureCallback: @escaping UniffiForeignFutureCompleteRustBuffer,
+            uniffiCallbackData: UInt64,
+            uniffiOutDroppedCallback: UnsafeMutablePointer<UniffiForeignFutureDroppedCallbackStruct>
+        ) in
+            let makeCall = {
+                () async throws -> HttpResponse in
+                guard let uniffiObj = try? FfiConverterTypeHttpClient.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return try await uniffiObj.fetch(
+                     request: try FfiConverterTypeHttpRequest_lift(request)
+                )
+            }
+
+            let uniffiHandleSuccess = { (returnValue: HttpResponse) in
+                uniffiFutureCallback(
+                    uniffiCallbackData,
+                    UniffiForeignFutureResultRustBuffer(
+                        returnValue: FfiConverterTypeHttpResponse_lower(returnValue),
+                        callStatus: RustCallStatus()
+                    )
+                )
+            }
+            let uniffiHandleError = { (statusCode, errorBuf) in
+                uniffiFutureCallback(
+                    uniffiCallbackData,
+                    UniffiForeignFutureResultRustBuffer(
+                        returnValue: RustBuffer.empty(),
+                        callStatus: RustCallStatus(code: statusCode, errorBuf: errorBuf)
+                    )
+                )
+            }
+            uniffiTraitInterfaceCallAsyncWithError(
+                makeCall: makeCall,
+                handleSuccess: uniffiHandleSuccess,
+                handleError: uniffiHandleError,
+                lowerError: FfiConverterTypeNetworkError_lower,
+                droppedCallback: uniffiOutDroppedCallback
+            )
+        }
+    )
+
+    // Rust stores this pointer for future callback invocations, so it must live
+    // for the process lifetime (not just for the init function call).
+    //
+    // `nonisolated(unsafe)` is needed under Swift 6 strict concurrency.
+    // This is safe because the pointee is initialized once during static init
+    // and never mutated by either side of the FFI.  Its fields are C function pointers.
+    nonisolated(unsafe) static let vtablePtr: UnsafePointer<UniffiVTableCallbackInterfaceHttpClient> = {
+        let ptr = UnsafeMutablePointer<UniffiVTableCallbackInterfaceHttpClient>.allocate(capacity: 1)
+        ptr.initialize(to: vtable)
+        return UnsafePointer(ptr)
+    }()
+}
+
+private func uniffiCallbackInitHttpClient() {
+    uniffi_starwars_fn_init_callback_vtable_httpclient(UniffiCallbackInterfaceHttpClient.vtablePtr)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeHttpClient: FfiConverter {
+    fileprivate static let handleMap = UniffiHandleMap<HttpClient>()
+
+    typealias FfiType = UInt64
+    typealias SwiftType = HttpClient
+
+    public static func lift(_ handle: UInt64) throws -> HttpClient {
+        if ((handle & 1) == 0) {
+            // Rust-generated handle, construct a new class that uses the handle to implement the
+            // interface
+            return HttpClientImpl(unsafeFromHandle: handle)
+        } else {
+            // Swift-generated handle, get the object from the handle map
+            return try handleMap.remove(handle: handle)
+        }
+    }
+
+    public static func lower(_ value: HttpClient) -> UInt64 {
+         if let rustImpl = value as? HttpClientImpl {
+             // Rust-implemented object.  Clone the handle and return it
+            return rustImpl.uniffiCloneHandle()
+         } else {
+            // Swift object, generate a new vtable handle and return that.
+            return handleMap.insert(obj: value)
+         }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HttpClient {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+    public static func write(_ value: HttpClient, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHttpClient_lift(_ handle: UInt64) throws -> HttpClient {
+    return try FfiConverterTypeHttpClient.lift(handle)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHttpClient_lower(_ value: HttpClient) -> UInt64 {
+    return FfiConverterTypeHttpClient.lower(value)
+}
+
+
+
+
+
+
+public protocol InMemoryStoreProtocol: AnyObject, Sendable {
+    
+    func keys() throws  -> [String]
+    
+    func read(key: String) throws  -> Data?
+    
+    func remove(key: String) throws 
+    
+    func write(key: String, contents: Data) throws 
+    
+}
+open class InMemoryStore: InMemoryStoreProtocol, @unchecked Sendable {
+    fileprivate let handle: UInt64
+
+    /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoHandle {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromHandle handle: UInt64) {
+        self.handle = handle
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noHandle: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing handle the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noHandle: NoHandle) {
+        self.handle = 0
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiCloneHandle() -> UInt64 {
+        return try! rustCall { uniffi_starwars_fn_clone_inmemorystore(self.handle, $0) }
+    }
+public convenience init() {
+    let handle =
+        try! rustCall() {
+    uniffi_starwars_fn_constructor_inmemorystore_new($0
+    )
+}
+    self.init(unsafeFromHandle: handle)
+}
+
+    deinit {
+        if handle == 0 {
+            // Mock objects have handle=0 don't try to free them
+            return
+        }
+
+        try! rustCall { uniffi_starwars_fn_free_inmemorystore(handle, $0) }
+    }
+
+    
+
+    
+open func keys()throws  -> [String]  {
+    return try  FfiConverterSequenceString.lift(try rustCallWithError(FfiConverterTypeStoreError_lift) {
+    uniffi_starwars_fn_method_inmemorystore_keys(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func read(key: String)throws  -> Data?  {
+    return try  FfiConverterOptionData.lift(try rustCallWithError(FfiConverterTypeStoreError_lift) {
+    uniffi_starwars_fn_method_inmemorystore_read(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(key),$0
+    )
+})
+}
+    
+open func remove(key: String)throws   {try rustCallWithError(FfiConverterTypeStoreError_lift) {
+    uniffi_starwars_fn_method_inmemorystore_remove(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(key),$0
+    )
+}
+}
+    
+open func write(key: String, contents: Data)throws   {try rustCallWithError(FfiConverterTypeStoreError_lift) {
+    uniffi_starwars_fn_method_inmemorystore_write(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(key),
+        FfiConverterData.lower(contents),$0
+    )
+}
+}
+    
+
+    
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeInMemoryStore: FfiConverter {
+    typealias FfiType = UInt64
+    typealias SwiftType = InMemoryStore
+
+    public static func lift(_ handle: UInt64) throws -> InMemoryStore {
+        return InMemoryStore(unsafeFromHandle: handle)
+    }
+
+    public static func lower(_ value: InMemoryStore) -> UInt64 {
+        return value.uniffiCloneHandle()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> InMemoryStore {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+    public static func write(_ value: InMemoryStore, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+extension InMemoryStore: RepositoryStore {}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeInMemoryStore_lift(_ handle: UInt64) throws -> InMemoryStore {
+    return try FfiConverterTypeInMemoryStore.lift(handle)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeInMemoryStore_lower(_ value: InMemoryStore) -> UInt64 {
+    return FfiConverterTypeInMemoryStore.lower(value)
+}
+
+
+
+
+
+
+public protocol PageCursorProtocol: AnyObject, Sendable {
+    
+    func fetchable()  -> Fetchable
+    
+    func hasMore()  -> Bool
+    
+    func isLoading()  -> Bool
+    
+    func items()  -> [Selected]
+    
+    func loadNext(cancellation: CancellationToken?) async throws  -> [Selected]
+    
+    func nextUrl()  -> String?
+    
+    func previousUrl()  -> String?
+    
+    func totalCount()  -> UInt32?
+    
+}
+open class PageCursor: PageCursorProtocol, @unchecked Sendable {
+    fileprivate let handle: UInt64
+
+    /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoHandle {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromHandle handle: UInt64) {
+        self.handle = handle
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noHandle: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing handle the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noHandle: NoHandle) {
+        self.handle = 0
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiCloneHandle() -> UInt64 {
+        return try! rustCall { uniffi_starwars_fn_clone_pagecursor(self.handle, $0) }
+    }
+    // No primary constructor declared for this class.
+
+    deinit {
+        if handle == 0 {
+            // Mock objects have handle=0 don't try to free them
+            return
+        }
+
+        try! rustCall { uniffi_starwars_fn_free_pagecursor(handle, $0) }
+    }
+
+    
+
+    
+open func fetchable() -> Fetchable  {
+    return try!  FfiConverterTypeFetchable_lift(try! rustCall() {
+    uniffi_starwars_fn_method_pagecursor_fetchable(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func hasMore() -> Bool  {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_starwars_fn_method_pagecursor_has_more(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func isLoading() -> Bool  {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_starwars_fn_method_pagecursor_is_loading(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func items() -> [Selected]  {
+    return try!  FfiConverterSequenceTypeSelected.lift(try! rustCall() {
+    uniffi_starwars_fn_method_pagecursor_items(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func loadNext(cancellation: CancellationToken?)async throws  -> [Selected]  {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_starwars_fn_method_pagecursor_load_next(
+                    self.uniffiCloneHandle(),
+                    FfiConverterOptionTypeCancellationToken.lower(cancellation)
+                )
+            },
+            pollFunc: ffi_starwars_rust_future_poll_rust_buffer,
+            completeFunc: ffi_starwars_rust_future_complete_rust_buffer,
+            freeFunc: ffi_starwars_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceTypeSelected.lift,
+            errorHandler: FfiConverterTypeApiError_lift
+        )
+}
+    
+open func nextUrl() -> String?  {
+    return try!  FfiConverterOptionString.lift(try! rustCall() {
+    uniffi_starwars_fn_method_pagecursor_next_url(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func previousUrl() -> String?  {
+    return try!  FfiConverterOptionString.lift(try! rustCall() {
+    uniffi_starwars_fn_method_pagecursor_previous_url(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func totalCount() -> UInt32?  {
+    return try!  FfiConverterOptionUInt32.lift(try! rustCall() {
+    uniffi_starwars_fn_method_pagecursor_total_count(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+
+    
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypePageCursor: FfiConverter {
+    typealias FfiType = UInt64
+    typealias SwiftType = PageCursor
+
+    public static func lift(_ handle: UInt64) throws -> PageCursor {
+        return PageCursor(unsafeFromHandle: handle)
+    }
+
+    public static func lower(_ value: PageCursor) -> UInt64 {
+        return value.uniffiCloneHandle()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PageCursor {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+    public static func write(_ value: PageCursor, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePageCursor_lift(_ handle: UInt64) throws -> PageCursor {
+    return try FfiConverterTypePageCursor.lift(handle)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePageCursor_lower(_ value: PageCursor) -> UInt64 {
+    return FfiConverterTypePageCursor.lower(value)
+}
+
+
+
+
+
+
+public protocol QueryProtocol: AnyObject, Sendable {
+    
+    func filter(field: String, `operator`: FilterOperator, value: String)  -> Query
+    
+    func limit(limit: UInt32)  -> Query
+    
+    func offset(offset: UInt32)  -> Query
+    
+    func sortBy(field: String, direction: SortDirection)  -> Query
+    
+}
+open class Query: QueryProtocol, @unchecked Sendable {
+    fileprivate let handle: UInt64
+
+    /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoHandle {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromHandle handle: UInt64) {
+        self.handle = handle
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noHandle: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing handle the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noHandle: NoHandle) {
+        self.handle = 0
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiCloneHandle() -> UInt64 {
+        return try! rustCall { uniffi_starwars_fn_clone_query(self.handle, $0) }
+    }
+public convenience init() {
+    let handle =
+        try! rustCall() {
+    uniffi_starwars_fn_constructor_query_new($0
+    )
+}
+    self.init(unsafeFromHandle: handle)
+}
+
+    deinit {
+        if handle == 0 {
+            // Mock objects have handle=0 don't try to free them
+            return
+        }
+
+        try! rustCall { uniffi_starwars_fn_free_query(handle, $0) }
+    }
+
+    
+
+    
+open func filter(field: String, `operator`: FilterOperator, value: String) -> Query  {
+    return try!  FfiConverterTypeQuery_lift(try! rustCall() {
+    uniffi_starwars_fn_method_query_filter(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(field),
+        FfiConverterTypeFilterOperator_lower(`operator`),
+        FfiConverterString.lower(value),$0
+    )
+})
+}
+    
+open func limit(limit: UInt32) -> Query  {
+    return try!  FfiConverterTypeQuery_lift(try! rustCall() {
+    uniffi_starwars_fn_method_query_limit(
+            self.uniffiCloneHandle(),
+        FfiConverterUInt32.lower(limit),$0
+    )
+})
+}
+    
+open func offset(offset: UInt32) -> Query  {
+    return try!  FfiConverterTypeQuery_lift(try! rustCall() {
+    uniffi_starwars_fn_method_query_offset(
+            self.uniffiCloneHandle(),
+        FfiConverterUInt32.lower(offset),$0
+    )
+})
+}
+    
+open func sortBy(field: String, direction: SortDirection) -> Query  {
+    return try!  FfiConverterTypeQuery_lift(try! rustCall() {
+    uniffi_starwars_fn_method_query_sort_by(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(field),
+        FfiConverterTypeSortDirection_lower(direction),$0
+    )
+})
+}
+    
+
+    
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeQuery: FfiConverter {
+    typealias FfiType = UInt64
+    typealias SwiftType = Query
+
+    public static func lift(_ handle: UInt64) throws -> Query {
+        return Query(unsafeFromHandle: handle)
+    }
+
+    public static func lower(_ value: Query) -> UInt64 {
+        return value.uniffiCloneHandle()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Query {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+    public static func write(_ value: Query, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeQuery_lift(_ handle: UInt64) throws -> Query {
+    return try FfiConverterTypeQuery.lift(handle)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeQuery_lower(_ value: Query) -> UInt64 {
+    return FfiConverterTypeQuery.lower(value)
+}
+
+
+
+
+
+
+public protocol RepositoryObserver: AnyObject, Sendable {
+    
+    func onChange(change: RepositoryChange) 
+    
+}
+open class RepositoryObserverImpl: RepositoryObserver, @unchecked Sendable {
+    fileprivate let handle: UInt64
+
+    /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoHandle {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromHandle handle: UInt64) {
+        self.handle = handle
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noHandle: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing handle the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noHandle: NoHandle) {
+        self.handle = 0
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiCloneHandle() -> UInt64 {
+        return try! rustCall { uniffi_starwars_fn_clone_repositoryobserver(self.handle, $0) }
+    }
+    // No primary constructor declared for this class.
+
+    deinit {
+        if handle == 0 {
+            // Mock objects have handle=0 don't try to free them
+            return
+        }
+
+        try! rustCall { uniffi_starwars_fn_free_repositoryobserver(handle, $0) }
+    }
+
+    
+
+    
+open func onChange(change: RepositoryChange)  {try! rustCall() {
+    uniffi_starwars_fn_method_repositoryobserver_on_change(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeRepositoryChange_lower(change),$0
+    )
+}
+}
+    
+
+    
+}
+
+
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiCallbackInterfaceRepositoryObserver {
+
+    // Create the VTable using a series of closures.
+    // Swift automatically converts these into C callback functions.
+    //
+    // Store the vtable directly.
+    static let vtable: UniffiVTableCallbackInterfaceRepositoryObserver = UniffiVTableCallbackInterfaceRepositoryObserver(
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            do {
+                try FfiConverterTypeRepositoryObserver.handleMap.remove(handle: uniffiHandle)
+            } catch {
+                print("Uniffi callback interface RepositoryObserver: handle missing in uniffiFree")
+            }
+        },
         uniffiClone: { (uniffiHandle: UInt64) -> UInt64 in
             do {
-                return try FfiConverterTypeHttpClient.handleMap.clone(handle: uniffiHandle)
+                return try FfiConverterTypeRepositoryObserver.handleMap.clone(handle: uniffiHandle)
             } catch {
-                fatalError("Uniffi callback interface HttpClient: handle missing in uniffiClone")
+                fatalError("Uniffi callback interface RepositoryObserver: handle missing in uniffiClone")
             }
         },
-        fetch: { (
+        onChange: { (
             uniffiHandle: UInt64,
-            url: RustBuffer,
-            uniffiFutureCallback: @escaping UniffiForeignFutureCompleteRustBuffer,
-            uniffiCallbackData: UInt64,
-            uniffiOutDroppedCallback: UnsafeMutablePointer<UniffiForeignFutureDroppedCallbackStruct>
+            change: RustBuffer,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
         ) in
             let makeCall = {
-                () async throws -> Data in
-                guard let uniffiObj = try? FfiConverterTypeHttpClient.handleMap.get(handle: uniffiHandle) else {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterTypeRepositoryObserver.handleMap.get(handle: uniffiHandle) else {
                     throw UniffiInternalError.unexpectedStaleHandle
                 }
-                return try await uniffiObj.fetch(
-                     url: try FfiConverterString.lift(url)
+                return uniffiObj.onChange(
+                     change: try FfiConverterTypeRepositoryChange_lift(change)
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        }
+    )
+
+    // Rust stores this pointer for future callback invocations, so it must live
+    // for the process lifetime (not just for the init function call).
+    //
+    // `nonisolated(unsafe)` is needed under Swift 6 strict concurrency.
+    // This is safe because the pointee is initialized once during static init
+    // and never mutated by either side of the FFI.  Its fields are C function pointers.
+    nonisolated(unsafe) static let vtablePtr: UnsafePointer<UniffiVTableCallbackInterfaceRepositoryObserver> = {
+        let ptr = UnsafeMutablePointer<UniffiVTableCallbackInterfaceRepositoryObserver>.allocate(capacity: 1)
+        ptr.initialize(to: vtable)
+        return UnsafePointer(ptr)
+    }()
+}
+
+private func uniffiCallbackInitRepositoryObserver() {
+    uniffi_starwars_fn_init_callback_vtable_repositoryobserver(UniffiCallbackInterfaceRepositoryObserver.vtablePtr)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRepositoryObserver: FfiConverter {
+    fileprivate static let handleMap = UniffiHandleMap<RepositoryObserver>()
+
+    typealias FfiType = UInt64
+    typealias SwiftType = RepositoryObserver
+
+    public static func lift(_ handle: UInt64) throws -> RepositoryObserver {
+        if ((handle & 1) == 0) {
+            // Rust-generated handle, construct a new class that uses the handle to implement the
+            // interface
+            return RepositoryObserverImpl(unsafeFromHandle: handle)
+        } else {
+            // Swift-generated handle, get the object from the handle map
+            return try handleMap.remove(handle: handle)
+        }
+    }
+
+    public static func lower(_ value: RepositoryObserver) -> UInt64 {
+         if let rustImpl = value as? RepositoryObserverImpl {
+             // Rust-implemented object.  Clone the handle and return it
+            return rustImpl.uniffiCloneHandle()
+         } else {
+            // Swift object, generate a new vtable handle and return that.
+            return handleMap.insert(obj: value)
+         }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RepositoryObserver {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+    public static func write(_ value: RepositoryObserver, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRepositoryObserver_lift(_ handle: UInt64) throws -> RepositoryObserver {
+    return try FfiConverterTypeRepositoryObserver.lift(handle)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRepositoryObserver_lower(_ value: RepositoryObserver) -> UInt64 {
+    return FfiConverterTypeRepositoryObserver.lower(value)
+}
+
+
+
+
+
+
+public protocol RepositoryStore: AnyObject, Sendable {
+    
+    func read(key: String) throws  -> Data?
+    
+    func write(key: String, contents: Data) throws 
+    
+    func remove(key: String) throws 
+    
+    func keys() throws  -> [String]
+    
+}
+open class RepositoryStoreImpl: RepositoryStore, @unchecked Sendable {
+    fileprivate let handle: UInt64
+
+    /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoHandle {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromHandle handle: UInt64) {
+        self.handle = handle
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noHandle: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing handle the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noHandle: NoHandle) {
+        self.handle = 0
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiCloneHandle() -> UInt64 {
+        return try! rustCall { uniffi_starwars_fn_clone_repositorystore(self.handle, $0) }
+    }
+    // No primary constructor declared for this class.
+
+    deinit {
+        if handle == 0 {
+            // Mock objects have handle=0 don't try to free them
+            return
+        }
+
+        try! rustCall { uniffi_starwars_fn_free_repositorystore(handle, $0) }
+    }
+
+    
+
+    
+open func read(key: String)throws  -> Data?  {
+    return try  FfiConverterOptionData.lift(try rustCallWithError(FfiConverterTypeStoreError_lift) {
+    uniffi_starwars_fn_method_repositorystore_read(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(key),$0
+    )
+})
+}
+    
+open func write(key: String, contents: Data)throws   {try rustCallWithError(FfiConverterTypeStoreError_lift) {
+    uniffi_starwars_fn_method_repositorystore_write(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(key),
+        FfiConverterData.lower(contents),$0
+    )
+}
+}
+    
+open func remove(key: String)throws   {try rustCallWithError(FfiConverterTypeStoreError_lift) {
+    uniffi_starwars_fn_method_repositorystore_remove(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(key),$0
+    )
+}
+}
+    
+open func keys()throws  -> [String]  {
+    return try  FfiConverterSequenceString.lift(try rustCallWithError(FfiConverterTypeStoreError_lift) {
+    uniffi_starwars_fn_method_repositorystore_keys(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+
+    
+}
+
+
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiCallbackInterfaceRepositoryStore {
+
+    // Create the VTable using a series of closures.
+    // Swift automatically converts these into C callback functions.
+    //
+    // Store the vtable directly.
+    static let vtable: UniffiVTableCallbackInterfaceRepositoryStore = UniffiVTableCallbackInterfaceRepositoryStore(
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            do {
+                try FfiConverterTypeRepositoryStore.handleMap.remove(handle: uniffiHandle)
+            } catch {
+                print("Uniffi callback interface RepositoryStore: handle missing in uniffiFree")
+            }
+        },
+        uniffiClone: { (uniffiHandle: UInt64) -> UInt64 in
+            do {
+                return try FfiConverterTypeRepositoryStore.handleMap.clone(handle: uniffiHandle)
+            } catch {
+                fatalError("Uniffi callback interface RepositoryStore: handle missing in uniffiClone")
+            }
+        },
+        read: { (
+            uniffiHandle: UInt64,
+            key: RustBuffer,
+            uniffiOutReturn: UnsafeMutablePointer<RustBuffer>,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> Data? in
+                guard let uniffiObj = try? FfiConverterTypeRepositoryStore.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return try uniffiObj.read(
+                     key: try FfiConverterString.lift(key)
+                )
+            }
+
+            
+            let writeReturn = { uniffiOutReturn.pointee = FfiConverterOptionData.lower($0) }
+            uniffiTraitInterfaceCallWithError(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn,
+                lowerError: FfiConverterTypeStoreError_lower
+            )
+        },
+        write: { (
+            uniffiHandle: UInt64,
+            key: RustBuffer,
+            contents: RustBuffer,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterTypeRepositoryStore.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return try uniffiObj.write(
+                     key: try FfiConverterString.lift(key),
+                     contents: try FfiConverterData.lift(contents)
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCallWithError(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn,
+                lowerError: FfiConverterTypeStoreError_lower
+            )
+        },
+        remove: { (
+            uniffiHandle: UInt64,
+            key: RustBuffer,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterTypeRepositoryStore.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return try uniffiObj.remove(
+                     key: try FfiConverterString.lift(key)
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCallWithError(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn,
+                lowerError: FfiConverterTypeStoreError_lower
+            )
+        },
+        keys: { (
+            uniffiHandle: UInt64,
+            uniffiOutReturn: UnsafeMutablePointer<RustBuffer>,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> [String] in
+                guard let uniffiObj = try? FfiConverterTypeRepositoryStore.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return try uniffiObj.keys(
                 )
             }
 
-            let uniffiHandleSuccess = { (returnValue: Data) in
-                uniffiFutureCallback(
-                    uniffiCallbackData,
-                    UniffiForeignFutureResultRustBuffer(
-                        returnValue: FfiConverterData.lower(returnValue),
-                        callStatus: RustCallStatus()
-                    )
-                )
-            }
-            let uniffiHandleError = { (statusCode, errorBuf) in
-                uniffiFutureCallback(
-                    uniffiCallbackData,
-                    UniffiForeignFutureResultRustBuffer(
-                        returnValue: RustBuffer.empty(),
-                        callStatus: RustCallStatus(code: statusCode, errorBuf: errorBuf)
-                    )
-                )
-            }
-            uniffiTraitInterfaceCallAsyncWithError(
-                makeCall: makeCall,
-                handleSuccess: uniffiHandleSuccess,
-                handleError: uniffiHandleError,
-                lowerError: FfiConverterTypeNetworkError_lower,
-                droppedCallback: uniffiOutDroppedCallback
-            )
+            
+            let writeReturn = { uniffiOutReturn.pointee = FfiConverterSequenceString.lower($0) }
+            uniffiTraitInterfaceCallWithError(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn,
+                lowerError: FfiConverterTypeStoreError_lower
+            )
+        }
+    )
+
+    // Rust stores this pointer for future callback invocations, so it must live
+    // for the process lifetime (not just for the init function call).
+    //
+    // `nonisolated(unsafe)` is needed under Swift 6 strict concurrency.
+    // This is safe because the pointee is initialized once during static init
+    // and never mutated by either side of the FFI.  Its fields are C function pointers.
+    nonisolated(unsafe) static let vtablePtr: UnsafePointer<UniffiVTableCallbackInterfaceRepositoryStore> = {
+        let ptr = UnsafeMutablePointer<UniffiVTableCallbackInterfaceRepositoryStore>.allocate(capacity: 1)
+        ptr.initialize(to: vtable)
+        return UnsafePointer(ptr)
+    }()
+}
+
+private func uniffiCallbackInitRepositoryStore() {
+    uniffi_starwars_fn_init_callback_vtable_repositorystore(UniffiCallbackInterfaceRepositoryStore.vtablePtr)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRepositoryStore: FfiConverter {
+    fileprivate static let handleMap = UniffiHandleMap<RepositoryStore>()
+
+    typealias FfiType = UInt64
+    typealias SwiftType = RepositoryStore
+
+    public static func lift(_ handle: UInt64) throws -> RepositoryStore {
+        if ((handle & 1) == 0) {
+            // Rust-generated handle, construct a new class that uses the handle to implement the
+            // interface
+            return RepositoryStoreImpl(unsafeFromHandle: handle)
+        } else {
+            // Swift-generated handle, get the object from the handle map
+            return try handleMap.remove(handle: handle)
+        }
+    }
+
+    public static func lower(_ value: RepositoryStore) -> UInt64 {
+         if let rustImpl = value as? RepositoryStoreImpl {
+             // Rust-implemented object.  Clone the handle and return it
+            return rustImpl.uniffiCloneHandle()
+         } else {
+            // Swift object, generate a new vtable handle and return that.
+            return handleMap.insert(obj: value)
+         }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RepositoryStore {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+    public static func write(_ value: RepositoryStore, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRepositoryStore_lift(_ handle: UInt64) throws -> RepositoryStore {
+    return try FfiConverterTypeRepositoryStore.lift(handle)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRepositoryStore_lower(_ value: RepositoryStore) -> UInt64 {
+    return FfiConverterTypeRepositoryStore.lower(value)
+}
+
+
+
+
+
+
+public protocol SearchIndexProtocol: AnyObject, Sendable {
+    
+    func rebuild() 
+    
+    func search(query: String, limit: UInt32)  -> [Selected]
+    
+    func searchIn(fetchable: Fetchable, query: String, limit: UInt32)  -> [Selected]
+    
+}
+open class SearchIndex: SearchIndexProtocol, @unchecked Sendable {
+    fileprivate let handle: UInt64
+
+    /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoHandle {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromHandle handle: UInt64) {
+        self.handle = handle
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noHandle: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing handle the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noHandle: NoHandle) {
+        self.handle = 0
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiCloneHandle() -> UInt64 {
+        return try! rustCall { uniffi_starwars_fn_clone_searchindex(self.handle, $0) }
+    }
+public convenience init(repository: DataRepository) {
+    let handle =
+        try! rustCall() {
+    uniffi_starwars_fn_constructor_searchindex_new(
+        FfiConverterTypeDataRepository_lower(repository),$0
+    )
+}
+    self.init(unsafeFromHandle: handle)
+}
+
+    deinit {
+        if handle == 0 {
+            // Mock objects have handle=0 don't try to free them
+            return
+        }
+
+        try! rustCall { uniffi_starwars_fn_free_searchindex(handle, $0) }
+    }
+
+    
+
+    
+open func rebuild()  {try! rustCall() {
+    uniffi_starwars_fn_method_searchindex_rebuild(
+            self.uniffiCloneHandle(),$0
+    )
+}
+}
+    
+open func search(query: String, limit: UInt32) -> [Selected]  {
+    return try!  FfiConverterSequenceTypeSelected.lift(try! rustCall() {
+    uniffi_starwars_fn_method_searchindex_search(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(query),
+        FfiConverterUInt32.lower(limit),$0
+    )
+})
+}
+    
+open func searchIn(fetchable: Fetchable, query: String, limit: UInt32) -> [Selected]  {
+    return try!  FfiConverterSequenceTypeSelected.lift(try! rustCall() {
+    uniffi_starwars_fn_method_searchindex_search_in(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeFetchable_lower(fetchable),
+        FfiConverterString.lower(query),
+        FfiConverterUInt32.lower(limit),$0
+    )
+})
+}
+    
+
+    
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSearchIndex: FfiConverter {
+    typealias FfiType = UInt64
+    typealias SwiftType = SearchIndex
+
+    public static func lift(_ handle: UInt64) throws -> SearchIndex {
+        return SearchIndex(unsafeFromHandle: handle)
+    }
+
+    public static func lower(_ value: SearchIndex) -> UInt64 {
+        return value.uniffiCloneHandle()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SearchIndex {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+    public static func write(_ value: SearchIndex, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSearchIndex_lift(_ handle: UInt64) throws -> SearchIndex {
+    return try FfiConverterTypeSearchIndex.lift(handle)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSearchIndex_lower(_ value: SearchIndex) -> UInt64 {
+    return FfiConverterTypeSearchIndex.lower(value)
+}
+
+
+
+
+public struct AttributeTag: Equatable, Hashable {
+    public let key: String
+    public let label: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(key: String, label: String) {
+        self.key = key
+        self.label = label
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension AttributeTag: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeAttributeTag: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> AttributeTag {
+        return
+            try AttributeTag(
+                key: FfiConverterString.read(from: &buf), 
+                label: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: AttributeTag, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.key, into: &buf)
+        FfiConverterString.write(value.label, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeAttributeTag_lift(_ buf: RustBuffer) throws -> AttributeTag {
+    return try FfiConverterTypeAttributeTag.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeAttributeTag_lower(_ value: AttributeTag) -> RustBuffer {
+    return FfiConverterTypeAttributeTag.lower(value)
+}
+
+
+public struct ClientConfig: Equatable, Hashable {
+    public let baseUrl: String
+    public let maxPages: UInt32
+    public let maxConcurrentRequests: UInt32
+    public let retry: RetryPolicy
+    public let format: ResponseFormat?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(baseUrl: String = "https://swapi.dev/api/", maxPages: UInt32 = UInt32(50), maxConcurrentRequests: UInt32 = UInt32(6), retry: RetryPolicy = RetryPolicy(), format: ResponseFormat? = nil) {
+        self.baseUrl = baseUrl
+        self.maxPages = maxPages
+        self.maxConcurrentRequests = maxConcurrentRequests
+        self.retry = retry
+        self.format = format
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension ClientConfig: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeClientConfig: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ClientConfig {
+        return
+            try ClientConfig(
+                baseUrl: FfiConverterString.read(from: &buf), 
+                maxPages: FfiConverterUInt32.read(from: &buf), 
+                maxConcurrentRequests: FfiConverterUInt32.read(from: &buf), 
+                retry: FfiConverterTypeRetryPolicy.read(from: &buf), 
+                format: FfiConverterOptionTypeResponseFormat.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ClientConfig, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.baseUrl, into: &buf)
+        FfiConverterUInt32.write(value.maxPages, into: &buf)
+        FfiConverterUInt32.write(value.maxConcurrentRequests, into: &buf)
+        FfiConverterTypeRetryPolicy.write(value.retry, into: &buf)
+        FfiConverterOptionTypeResponseFormat.write(value.format, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeClientConfig_lift(_ buf: RustBuffer) throws -> ClientConfig {
+    return try FfiConverterTypeClientConfig.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeClientConfig_lower(_ value: ClientConfig) -> RustBuffer {
+    return FfiConverterTypeClientConfig.lower(value)
+}
+
+
+public struct Consumables: Equatable, Hashable {
+    public let amount: Double
+    public let unit: TimeUnit
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(amount: Double, unit: TimeUnit) {
+        self.amount = amount
+        self.unit = unit
+    }
+
+    
+public func days() -> Double  {
+    return try!  FfiConverterDouble.lift(try! rustCall() {
+    uniffi_starwars_fn_method_consumables_days(
+            FfiConverterTypeConsumables_lower(self),$0
+    )
+})
+}
+    
+
+    
+}
+
+#if compiler(>=6)
+extension Consumables: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeConsumables: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Consumables {
+        return
+            try Consumables(
+                amount: FfiConverterDouble.read(from: &buf), 
+                unit: FfiConverterTypeTimeUnit.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Consumables, into buf: inout [UInt8]) {
+        FfiConverterDouble.write(value.amount, into: &buf)
+        FfiConverterTypeTimeUnit.write(value.unit, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeConsumables_lift(_ buf: RustBuffer) throws -> Consumables {
+    return try FfiConverterTypeConsumables.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeConsumables_lower(_ value: Consumables) -> RustBuffer {
+    return FfiConverterTypeConsumables.lower(value)
+}
+
+
+public struct FieldFilter: Equatable, Hashable {
+    public let field: String
+    public let `operator`: FilterOperator
+    public let value: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(field: String, `operator`: FilterOperator, value: String) {
+        self.field = field
+        self.`operator` = `operator`
+        self.value = value
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension FieldFilter: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFieldFilter: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FieldFilter {
+        return
+            try FieldFilter(
+                field: FfiConverterString.read(from: &buf), 
+                operator: FfiConverterTypeFilterOperator.read(from: &buf), 
+                value: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FieldFilter, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.field, into: &buf)
+        FfiConverterTypeFilterOperator.write(value.`operator`, into: &buf)
+        FfiConverterString.write(value.value, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFieldFilter_lift(_ buf: RustBuffer) throws -> FieldFilter {
+    return try FfiConverterTypeFieldFilter.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFieldFilter_lower(_ value: FieldFilter) -> RustBuffer {
+    return FfiConverterTypeFieldFilter.lower(value)
+}
+
+
+public struct Film: Equatable, Hashable {
+    public let title: String
+    public let episodeId: Int32
+    public let openingCrawl: String
+    public let director: String
+    public let producer: String
+    public let releaseDate: String
+    public let characters: [String]
+    public let planets: [String]
+    public let starships: [String]
+    public let vehicles: [String]
+    public let species: [String]
+    public let created: String
+    public let edited: String
+    public let url: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(title: String, episodeId: Int32, openingCrawl: String, director: String, producer: String, releaseDate: String, characters: [String], planets: [String], starships: [String], vehicles: [String], species: [String], created: String, edited: String, url: String) {
+        self.title = title
+        self.episodeId = episodeId
+        self.openingCrawl = openingCrawl
+        self.director = director
+        self.producer = producer
+        self.releaseDate = releaseDate
+        self.characters = characters
+        self.planets = planets
+        self.starships = starships
+        self.vehicles = vehicles
+        self.species = species
+        self.created = created
+        self.edited = edited
+        self.url = url
+    }
+
+    
+public func createdAt() -> Timestamp?  {
+    return try!  FfiConverterOptionTypeTimestamp.lift(try! rustCall() {
+    uniffi_starwars_fn_method_film_created_at(
+            FfiConverterTypeFilm_lower(self),$0
+    )
+})
+}
+    
+public func editedAt() -> Timestamp?  {
+    return try!  FfiConverterOptionTypeTimestamp.lift(try! rustCall() {
+    uniffi_starwars_fn_method_film_edited_at(
+            FfiConverterTypeFilm_lower(self),$0
+    )
+})
+}
+    
+public func releasedAt() -> Timestamp?  {
+    return try!  FfiConverterOptionTypeTimestamp.lift(try! rustCall() {
+    uniffi_starwars_fn_method_film_released_at(
+            FfiConverterTypeFilm_lower(self),$0
+    )
+})
+}
+    
+
+    
+}
+
+#if compiler(>=6)
+extension Film: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFilm: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Film {
+        return
+            try Film(
+                title: FfiConverterString.read(from: &buf), 
+                episodeId: FfiConverterInt32.read(from: &buf), 
+                openingCrawl: FfiConverterString.read(from: &buf), 
+                director: FfiConverterString.read(from: &buf), 
+                producer: FfiConverterString.read(from: &buf), 
+                releaseDate: FfiConverterString.read(from: &buf), 
+                characters: FfiConverterSequenceString.read(from: &buf), 
+                planets: FfiConverterSequenceString.read(from: &buf), 
+                starships: FfiConverterSequenceString.read(from: &buf), 
+                vehicles: FfiConverterSequenceString.read(from: &buf), 
+                species: FfiConverterSequenceString.read(from: &buf), 
+                created: FfiConverterString.read(from: &buf), 
+                edited: FfiConverterString.read(from: &buf), 
+                url: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Film, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.title, into: &buf)
+        FfiConverterInt32.write(value.episodeId, into: &buf)
+        FfiConverterString.write(value.openingCrawl, into: &buf)
+        FfiConverterString.write(value.director, into: &buf)
+        FfiConverterString.write(value.producer, into: &buf)
+        FfiConverterString.write(value.releaseDate, into: &buf)
+        FfiConverterSequenceString.write(value.characters, into: &buf)
+        FfiConverterSequenceString.write(value.planets, into: &buf)
+        FfiConverterSequenceString.write(value.starships, into: &buf)
+        FfiConverterSequenceString.write(value.vehicles, into: &buf)
+        FfiConverterSequenceString.write(value.species, into: &buf)
+        FfiConverterString.write(value.created, into: &buf)
+        FfiConverterString.write(value.edited, into: &buf)
+        FfiConverterString.write(value.url, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFilm_lift(_ buf: RustBuffer) throws -> Film {
+    return try FfiConverterTypeFilm.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFilm_lower(_ value: Film) -> RustBuffer {
+    return FfiConverterTypeFilm.lower(value)
+}
+
+
+public struct GalacticYear: Equatable, Hashable {
+    public let years: Double
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(years: Double) {
+        self.years = years
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension GalacticYear: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeGalacticYear: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> GalacticYear {
+        return
+            try GalacticYear(
+                years: FfiConverterDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: GalacticYear, into buf: inout [UInt8]) {
+        FfiConverterDouble.write(value.years, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeGalacticYear_lift(_ buf: RustBuffer) throws -> GalacticYear {
+    return try FfiConverterTypeGalacticYear.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeGalacticYear_lower(_ value: GalacticYear) -> RustBuffer {
+    return FfiConverterTypeGalacticYear.lower(value)
+}
+
+
+public struct HttpRequest {
+    public let url: String
+    public let headers: [String: String]
+    public let cancellation: CancellationToken
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(url: String, headers: [String: String], cancellation: CancellationToken) {
+        self.url = url
+        self.headers = headers
+        self.cancellation = cancellation
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension HttpRequest: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeHttpRequest: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HttpRequest {
+        return
+            try HttpRequest(
+                url: FfiConverterString.read(from: &buf), 
+                headers: FfiConverterDictionaryStringString.read(from: &buf), 
+                cancellation: FfiConverterTypeCancellationToken.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: HttpRequest, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.url, into: &buf)
+        FfiConverterDictionaryStringString.write(value.headers, into: &buf)
+        FfiConverterTypeCancellationToken.write(value.cancellation, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHttpRequest_lift(_ buf: RustBuffer) throws -> HttpRequest {
+    return try FfiConverterTypeHttpRequest.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHttpRequest_lower(_ value: HttpRequest) -> RustBuffer {
+    return FfiConverterTypeHttpRequest.lower(value)
+}
+
+
+public struct HttpResponse: Equatable, Hashable {
+    public let status: UInt16
+    public let headers: [String: String]
+    public let body: Data
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(status: UInt16, headers: [String: String], body: Data) {
+        self.status = status
+        self.headers = headers
+        self.body = body
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension HttpResponse: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeHttpResponse: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HttpResponse {
+        return
+            try HttpResponse(
+                status: FfiConverterUInt16.read(from: &buf), 
+                headers: FfiConverterDictionaryStringString.read(from: &buf), 
+                body: FfiConverterData.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: HttpResponse, into buf: inout [UInt8]) {
+        FfiConverterUInt16.write(value.status, into: &buf)
+        FfiConverterDictionaryStringString.write(value.headers, into: &buf)
+        FfiConverterData.write(value.body, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHttpResponse_lift(_ buf: RustBuffer) throws -> HttpResponse {
+    return try FfiConverterTypeHttpResponse.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHttpResponse_lower(_ value: HttpResponse) -> RustBuffer {
+    return FfiConverterTypeHttpResponse.lower(value)
+}
+
+
+public struct NumericRange: Equatable, Hashable {
+    public let min: Double
+    public let max: Double
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(min: Double, max: Double) {
+        self.min = min
+        self.max = max
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension NumericRange: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeNumericRange: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> NumericRange {
+        return
+            try NumericRange(
+                min: FfiConverterDouble.read(from: &buf), 
+                max: FfiConverterDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: NumericRange, into buf: inout [UInt8]) {
+        FfiConverterDouble.write(value.min, into: &buf)
+        FfiConverterDouble.write(value.max, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeNumericRange_lift(_ buf: RustBuffer) throws -> NumericRange {
+    return try FfiConverterTypeNumericRange.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeNumericRange_lower(_ value: NumericRange) -> RustBuffer {
+    return FfiConverterTypeNumericRange.lower(value)
+}
+
+
+public struct Person: Equatable, Hashable {
+    public let birthYear: String
+    public let eyeColor: String
+    public let films: [String]
+    public let gender: String
+    public let hairColor: String
+    public let height: String
+    public let homeworld: String?
+    public let mass: String
+    public let name: String
+    public let skinColor: String
+    public let created: String
+    public let edited: String
+    public let species: [String]
+    public let starships: [String]
+    public let url: String
+    public let vehicles: [String]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(birthYear: String, eyeColor: String, films: [String], gender: String, hairColor: String, height: String, homeworld: String?, mass: String, name: String, skinColor: String, created: String, edited: String, species: [String], starships: [String], url: String, vehicles: [String]) {
+        self.birthYear = birthYear
+        self.eyeColor = eyeColor
+        self.films = films
+        self.gender = gender
+        self.hairColor = hairColor
+        self.height = height
+        self.homeworld = homeworld
+        self.mass = mass
+        self.name = name
+        self.skinColor = skinColor
+        self.created = created
+        self.edited = edited
+        self.species = species
+        self.starships = starships
+        self.url = url
+        self.vehicles = vehicles
+    }
+
+    
+public func birthYearValue() -> GalacticYear?  {
+    return try!  FfiConverterOptionTypeGalacticYear.lift(try! rustCall() {
+    uniffi_starwars_fn_method_person_birth_year_value(
+            FfiConverterTypePerson_lower(self),$0
+    )
+})
+}
+    
+public func createdAt() -> Timestamp?  {
+    return try!  FfiConverterOptionTypeTimestamp.lift(try! rustCall() {
+    uniffi_starwars_fn_method_person_created_at(
+            FfiConverterTypePerson_lower(self),$0
+    )
+})
+}
+    
+public func editedAt() -> Timestamp?  {
+    return try!  FfiConverterOptionTypeTimestamp.lift(try! rustCall() {
+    uniffi_starwars_fn_method_person_edited_at(
+            FfiConverterTypePerson_lower(self),$0
+    )
+})
+}
+    
+public func heightValue() -> Double?  {
+    return try!  FfiConverterOptionDouble.lift(try! rustCall() {
+    uniffi_starwars_fn_method_person_height_value(
+            FfiConverterTypePerson_lower(self),$0
+    )
+})
+}
+    
+public func massValue() -> Double?  {
+    return try!  FfiConverterOptionDouble.lift(try! rustCall() {
+    uniffi_starwars_fn_method_person_mass_value(
+            FfiConverterTypePerson_lower(self),$0
+    )
+})
+}
+    
+
+    
+}
+
+#if compiler(>=6)
+extension Person: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypePerson: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Person {
+        return
+            try Person(
+                birthYear: FfiConverterString.read(from: &buf), 
+                eyeColor: FfiConverterString.read(from: &buf), 
+                films: FfiConverterSequenceString.read(from: &buf), 
+                gender: FfiConverterString.read(from: &buf), 
+                hairColor: FfiConverterString.read(from: &buf), 
+                height: FfiConverterString.read(from: &buf), 
+                homeworld: FfiConverterOptionString.read(from: &buf), 
+                mass: FfiConverterString.read(from: &buf), 
+                name: FfiConverterString.read(from: &buf), 
+                skinColor: FfiConverterString.read(from: &buf), 
+                created: FfiConverterString.read(from: &buf), 
+                edited: FfiConverterString.read(from: &buf), 
+                species: FfiConverterSequenceString.read(from: &buf), 
+                starships: FfiConverterSequenceString.read(from: &buf), 
+                url: FfiConverterString.read(from: &buf), 
+                vehicles: FfiConverterSequenceString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Person, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.birthYear, into: &buf)
+        FfiConverterString.write(value.eyeColor, into: &buf)
+        FfiConverterSequenceString.write(value.films, into: &buf)
+        FfiConverterString.write(value.gender, into: &buf)
+        FfiConverterString.write(value.hairColor, into: &buf)
+        FfiConverterString.write(value.height, into: &buf)
+        FfiConverterOptionString.write(value.homeworld, into: &buf)
+        FfiConverterString.write(value.mass, into: &buf)
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterString.write(value.skinColor, into: &buf)
+        FfiConverterString.write(value.created, into: &buf)
+        FfiConverterString.write(value.edited, into: &buf)
+        FfiConverterSequenceString.write(value.species, into: &buf)
+        FfiConverterSequenceString.write(value.starships, into: &buf)
+        FfiConverterString.write(value.url, into: &buf)
+        FfiConverterSequenceString.write(value.vehicles, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePerson_lift(_ buf: RustBuffer) throws -> Person {
+    return try FfiConverterTypePerson.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePerson_lower(_ value: Person) -> RustBuffer {
+    return FfiConverterTypePerson.lower(value)
+}
+
+
+public struct Planet: Equatable, Hashable {
+    public let name: String
+    public let rotationPeriod: String
+    public let orbitalPeriod: String
+    public let diameter: String
+    public let climate: String
+    public let gravity: String
+    public let terrain: String
+    public let surfaceWater: String
+    public let population: String
+    public let residents: [String]
+    public let films: [String]
+    public let created: String
+    public let edited: String
+    public let url: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(name: String, rotationPeriod: String, orbitalPeriod: String, diameter: String, climate: String, gravity: String, terrain: String, surfaceWater: String, population: String, residents: [String], films: [String], created: String, edited: String, url: String) {
+        self.name = name
+        self.rotationPeriod = rotationPeriod
+        self.orbitalPeriod = orbitalPeriod
+        self.diameter = diameter
+        self.climate = climate
+        self.gravity = gravity
+        self.terrain = terrain
+        self.surfaceWater = surfaceWater
+        self.population = population
+        self.residents = residents
+        self.films = films
+        self.created = created
+        self.edited = edited
+        self.url = url
+    }
+
+    
+public func climateTags() -> [AttributeTag]  {
+    return try!  FfiConverterSequenceTypeAttributeTag.lift(try! rustCall() {
+    uniffi_starwars_fn_method_planet_climate_tags(
+            FfiConverterTypePlanet_lower(self),$0
+    )
+})
+}
+    
+public func createdAt() -> Timestamp?  {
+    return try!  FfiConverterOptionTypeTimestamp.lift(try! rustCall() {
+    uniffi_starwars_fn_method_planet_created_at(
+            FfiConverterTypePlanet_lower(self),$0
+    )
+})
+}
+    
+public func diameterValue() -> Double?  {
+    return try!  FfiConverterOptionDouble.lift(try! rustCall() {
+    uniffi_starwars_fn_method_planet_diameter_value(
+            FfiConverterTypePlanet_lower(self),$0
+    )
+})
+}
+    
+public func editedAt() -> Timestamp?  {
+    return try!  FfiConverterOptionTypeTimestamp.lift(try! rustCall() {
+    uniffi_starwars_fn_method_planet_edited_at(
+            FfiConverterTypePlanet_lower(self),$0
+    )
+})
+}
+    
+public func orbitalPeriodValue() -> Double?  {
+    return try!  FfiConverterOptionDouble.lift(try! rustCall() {
+    uniffi_starwars_fn_method_planet_orbital_period_value(
+            FfiConverterTypePlanet_lower(self),$0
+    )
+})
+}
+    
+public func populationValue() -> UInt64?  {
+    return try!  FfiConverterOptionUInt64.lift(try! rustCall() {
+    uniffi_starwars_fn_method_planet_population_value(
+            FfiConverterTypePlanet_lower(self),$0
+    )
+})
+}
+    
+public func rotationPeriodValue() -> Double?  {
+    return try!  FfiConverterOptionDouble.lift(try! rustCall() {
+    uniffi_starwars_fn_method_planet_rotation_period_value(
+            FfiConverterTypePlanet_lower(self),$0
+    )
+})
+}
+    
+public func surfaceWaterValue() -> Double?  {
+    return try!  FfiConverterOptionDouble.lift(try! rustCall() {
+    uniffi_starwars_fn_method_planet_surface_water_value(
+            FfiConverterTypePlanet_lower(self),$0
+    )
+})
+}
+    
+public func terrainTags() -> [AttributeTag]  {
+    return try!  FfiConverterSequenceTypeAttributeTag.lift(try! rustCall() {
+    uniffi_starwars_fn_method_planet_terrain_tags(
+            FfiConverterTypePlanet_lower(self),$0
+    )
+})
+}
+    
+
+    
+}
+
+#if compiler(>=6)
+extension Planet: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypePlanet: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Planet {
+        return
+            try Planet(
+                name: FfiConverterString.read(from: &buf), 
+                rotationPeriod: FfiConverterString.read(from: &buf), 
+                orbitalPeriod: FfiConverterString.read(from: &buf), 
+                diameter: FfiConverterString.read(from: &buf), 
+                climate: FfiConverterString.read(from: &buf), 
+                gravity: FfiConverterString.read(from: &buf), 
+                terrain: FfiConverterString.read(from: &buf), 
+                surfaceWater: FfiConverterString.read(from: &buf), 
+                population: FfiConverterString.read(from: &buf), 
+                residents: FfiConverterSequenceString.read(from: &buf), 
+                films: FfiConverterSequenceString.read(from: &buf), 
+                created: FfiConverterString.read(from: &buf), 
+                edited: FfiConverterString.read(from: &buf), 
+                url: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Planet, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterString.write(value.rotationPeriod, into: &buf)
+        FfiConverterString.write(value.orbitalPeriod, into: &buf)
+        FfiConverterString.write(value.diameter, into: &buf)
+        FfiConverterString.write(value.climate, into: &buf)
+        FfiConverterString.write(value.gravity, into: &buf)
+        FfiConverterString.write(value.terrain, into: &buf)
+        FfiConverterString.write(value.surfaceWater, into: &buf)
+        FfiConverterString.write(value.population, into: &buf)
+        FfiConverterSequenceString.write(value.residents, into: &buf)
+        FfiConverterSequenceString.write(value.films, into: &buf)
+        FfiConverterString.write(value.created, into: &buf)
+        FfiConverterString.write(value.edited, into: &buf)
+        FfiConverterString.write(value.url, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePlanet_lift(_ buf: RustBuffer) throws -> Planet {
+    return try FfiConverterTypePlanet.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePlanet_lower(_ value: Planet) -> RustBuffer {
+    return FfiConverterTypePlanet.lower(value)
+}
+
+
+public struct RelatedGroup: Equatable, Hashable {
+    public let relationship: Relationship
+    public let items: [Selected]
+    public let failedUrls: [String]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(relationship: Relationship, items: [Selected], failedUrls: [String]) {
+        self.relationship = relationship
+        self.items = items
+        self.failedUrls = failedUrls
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension RelatedGroup: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRelatedGroup: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RelatedGroup {
+        return
+            try RelatedGroup(
+                relationship: FfiConverterTypeRelationship.read(from: &buf), 
+                items: FfiConverterSequenceTypeSelected.read(from: &buf), 
+                failedUrls: FfiConverterSequenceString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: RelatedGroup, into buf: inout [UInt8]) {
+        FfiConverterTypeRelationship.write(value.relationship, into: &buf)
+        FfiConverterSequenceTypeSelected.write(value.items, into: &buf)
+        FfiConverterSequenceString.write(value.failedUrls, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRelatedGroup_lift(_ buf: RustBuffer) throws -> RelatedGroup {
+    return try FfiConverterTypeRelatedGroup.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRelatedGroup_lower(_ value: RelatedGroup) -> RustBuffer {
+    return FfiConverterTypeRelatedGroup.lower(value)
+}
+
+
+public struct RepositoryChange: Equatable, Hashable {
+    public let kind: ChangeKind
+    public let fetchable: Fetchable
+    public let ids: [UInt32]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(kind: ChangeKind, fetchable: Fetchable, ids: [UInt32]) {
+        self.kind = kind
+        self.fetchable = fetchable
+        self.ids = ids
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension RepositoryChange: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRepositoryChange: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RepositoryChange {
+        return
+            try RepositoryChange(
+                kind: FfiConverterTypeChangeKind.read(from: &buf), 
+                fetchable: FfiConverterTypeFetchable.read(from: &buf), 
+                ids: FfiConverterSequenceUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: RepositoryChange, into buf: inout [UInt8]) {
+        FfiConverterTypeChangeKind.write(value.kind, into: &buf)
+        FfiConverterTypeFetchable.write(value.fetchable, into: &buf)
+        FfiConverterSequenceUInt32.write(value.ids, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRepositoryChange_lift(_ buf: RustBuffer) throws -> RepositoryChange {
+    return try FfiConverterTypeRepositoryChange.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRepositoryChange_lower(_ value: RepositoryChange) -> RustBuffer {
+    return FfiConverterTypeRepositoryChange.lower(value)
+}
+
+
+public struct RetryPolicy: Equatable, Hashable {
+    public let maxAttempts: UInt32
+    public let baseDelayMs: UInt64
+    public let maxDelayMs: UInt64
+    public let jitter: Double
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(maxAttempts: UInt32 = UInt32(3), baseDelayMs: UInt64 = UInt64(500), maxDelayMs: UInt64 = UInt64(10000), jitter: Double = Double(0.2)) {
+        self.maxAttempts = maxAttempts
+        self.baseDelayMs = baseDelayMs
+        self.maxDelayMs = maxDelayMs
+        self.jitter = jitter
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension RetryPolicy: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRetryPolicy: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RetryPolicy {
+        return
+            try RetryPolicy(
+                maxAttempts: FfiConverterUInt32.read(from: &buf), 
+                baseDelayMs: FfiConverterUInt64.read(from: &buf), 
+                maxDelayMs: FfiConverterUInt64.read(from: &buf), 
+                jitter: FfiConverterDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: RetryPolicy, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.maxAttempts, into: &buf)
+        FfiConverterUInt64.write(value.baseDelayMs, into: &buf)
+        FfiConverterUInt64.write(value.maxDelayMs, into: &buf)
+        FfiConverterDouble.write(value.jitter, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRetryPolicy_lift(_ buf: RustBuffer) throws -> RetryPolicy {
+    return try FfiConverterTypeRetryPolicy.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRetryPolicy_lower(_ value: RetryPolicy) -> RustBuffer {
+    return FfiConverterTypeRetryPolicy.lower(value)
+}
+
+
+public struct SortKey: Equatable, Hashable {
+    public let field: String
+    public let direction: SortDirection
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(field: String, direction: SortDirection) {
+        self.field = field
+        self.direction = direction
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension SortKey: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSortKey: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SortKey {
+        return
+            try SortKey(
+                field: FfiConverterString.read(from: &buf), 
+                direction: FfiConverterTypeSortDirection.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SortKey, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.field, into: &buf)
+        FfiConverterTypeSortDirection.write(value.direction, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSortKey_lift(_ buf: RustBuffer) throws -> SortKey {
+    return try FfiConverterTypeSortKey.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSortKey_lower(_ value: SortKey) -> RustBuffer {
+    return FfiConverterTypeSortKey.lower(value)
+}
+
+
+public struct Species: Equatable, Hashable {
+    public let name: String
+    public let classification: String
+    public let designation: String
+    public let averageHeight: String
+    public let skinColors: String
+    public let hairColors: String
+    public let eyeColors: String
+    public let averageLifespan: String
+    public let homeworld: String?
+    public let language: String
+    public let people: [String]
+    public let films: [String]
+    public let created: String
+    public let edited: String
+    public let url: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(name: String, classification: String, designation: String, averageHeight: String, skinColors: String, hairColors: String, eyeColors: String, averageLifespan: String, homeworld: String?, language: String, people: [String], films: [String], created: String, edited: String, url: String) {
+        self.name = name
+        self.classification = classification
+        self.designation = designation
+        self.averageHeight = averageHeight
+        self.skinColors = skinColors
+        self.hairColors = hairColors
+        self.eyeColors = eyeColors
+        self.averageLifespan = averageLifespan
+        self.homeworld = homeworld
+        self.language = language
+        self.people = people
+        self.films = films
+        self.created = created
+        self.edited = edited
+        self.url = url
+    }
+
+    
+public func averageHeightValue() -> Double?  {
+    return try!  FfiConverterOptionDouble.lift(try! rustCall() {
+    uniffi_starwars_fn_method_species_average_height_value(
+            FfiConverterTypeSpecies_lower(self),$0
+    )
+})
+}
+    
+public func averageLifespanValue() -> Double?  {
+    return try!  FfiConverterOptionDouble.lift(try! rustCall() {
+    uniffi_starwars_fn_method_species_average_lifespan_value(
+            FfiConverterTypeSpecies_lower(self),$0
+    )
+})
+}
+    
+public func createdAt() -> Timestamp?  {
+    return try!  FfiConverterOptionTypeTimestamp.lift(try! rustCall() {
+    uniffi_starwars_fn_method_species_created_at(
+            FfiConverterTypeSpecies_lower(self),$0
+    )
+})
+}
+    
+public func editedAt() -> Timestamp?  {
+    return try!  FfiConverterOptionTypeTimestamp.lift(try! rustCall() {
+    uniffi_starwars_fn_method_species_edited_at(
+            FfiConverterTypeSpecies_lower(self),$0
+    )
+})
+}
+    
+public func eyeColorTags() -> [AttributeTag]  {
+    return try!  FfiConverterSequenceTypeAttributeTag.lift(try! rustCall() {
+    uniffi_starwars_fn_method_species_eye_color_tags(
+            FfiConverterTypeSpecies_lower(self),$0
+    )
+})
+}
+    
+public func hairColorTags() -> [AttributeTag]  {
+    return try!  FfiConverterSequenceTypeAttributeTag.lift(try! rustCall() {
+    uniffi_starwars_fn_method_species_hair_color_tags(
+            FfiConverterTypeSpecies_lower(self),$0
+    )
+})
+}
+    
+public func skinColorTags() -> [AttributeTag]  {
+    return try!  FfiConverterSequenceTypeAttributeTag.lift(try! rustCall() {
+    uniffi_starwars_fn_method_species_skin_color_tags(
+            FfiConverterTypeSpecies_lower(self),$0
+    )
+})
+}
+    
+
+    
+}
+
+#if compiler(>=6)
+extension Species: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSpecies: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Species {
+        return
+            try Species(
+                name: FfiConverterString.read(from: &buf), 
+                classification: FfiConverterString.read(from: &buf), 
+                designation: FfiConverterString.read(from: &buf), 
+                averageHeight: FfiConverterString.read(from: &buf), 
+                skinColors: FfiConverterString.read(from: &buf), 
+                hairColors: FfiConverterString.read(from: &buf), 
+                eyeColors: FfiConverterString.read(from: &buf), 
+                averageLifespan: FfiConverterString.read(from: &buf), 
+                homeworld: FfiConverterOptionString.read(from: &buf), 
+                language: FfiConverterString.read(from: &buf), 
+                people: FfiConverterSequenceString.read(from: &buf), 
+                films: FfiConverterSequenceString.read(from: &buf), 
+                created: FfiConverterString.read(from: &buf), 
+                edited: FfiConverterString.read(from: &buf), 
+                url: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Species, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterString.write(value.classification, into: &buf)
+        FfiConverterString.write(value.designation, into: &buf)
+        FfiConverterString.write(value.averageHeight, into: &buf)
+        FfiConverterString.write(value.skinColors, into: &buf)
+        FfiConverterString.write(value.hairColors, into: &buf)
+        FfiConverterString.write(value.eyeColors, into: &buf)
+        FfiConverterString.write(value.averageLifespan, into: &buf)
+        FfiConverterOptionString.write(value.homeworld, into: &buf)
+        FfiConverterString.write(value.language, into: &buf)
+        FfiConverterSequenceString.write(value.people, into: &buf)
+        FfiConverterSequenceString.write(value.films, into: &buf)
+        FfiConverterString.write(value.created, into: &buf)
+        FfiConverterString.write(value.edited, into: &buf)
+        FfiConverterString.write(value.url, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSpecies_lift(_ buf: RustBuffer) throws -> Species {
+    return try FfiConverterTypeSpecies.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSpecies_lower(_ value: Species) -> RustBuffer {
+    return FfiConverterTypeSpecies.lower(value)
+}
+
+
+public struct Starship: Equatable, Hashable {
+    public let name: String
+    public let model: String
+    public let manufacturer: String
+    public let costInCredits: String
+    public let length: String
+    public let maxAtmospheringSpeed: String
+    public let crew: String
+    public let passengers: String
+    public let cargoCapacity: String
+    public let consumables: String
+    public let hyperdriveRating: String
+    public let mglt: String
+    public let starshipClass: String
+    public let pilots: [String]
+    public let films: [String]
+    public let created: String
+    public let edited: String
+    public let url: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(name: String, model: String, manufacturer: String, costInCredits: String, length: String, maxAtmospheringSpeed: String, crew: String, passengers: String, cargoCapacity: String, consumables: String, hyperdriveRating: String, mglt: String, starshipClass: String, pilots: [String], films: [String], created: String, edited: String, url: String) {
+        self.name = name
+        self.model = model
+        self.manufacturer = manufacturer
+        self.costInCredits = costInCredits
+        self.length = length
+        self.maxAtmospheringSpeed = maxAtmospheringSpeed
+        self.crew = crew
+        self.passengers = passengers
+        self.cargoCapacity = cargoCapacity
+        self.consumables = consumables
+        self.hyperdriveRating = hyperdriveRating
+        self.mglt = mglt
+        self.starshipClass = starshipClass
+        self.pilots = pilots
+        self.films = films
+        self.created = created
+        self.edited = edited
+        self.url = url
+    }
+
+    
+public func cargoCapacityValue() -> UInt64?  {
+    return try!  FfiConverterOptionUInt64.lift(try! rustCall() {
+    uniffi_starwars_fn_method_starship_cargo_capacity_value(
+            FfiConverterTypeStarship_lower(self),$0
+    )
+})
+}
+    
+public func consumablesValue() -> Consumables?  {
+    return try!  FfiConverterOptionTypeConsumables.lift(try! rustCall() {
+    uniffi_starwars_fn_method_starship_consumables_value(
+            FfiConverterTypeStarship_lower(self),$0
+    )
+})
+}
+    
+public func costInCreditsValue() -> UInt64?  {
+    return try!  FfiConverterOptionUInt64.lift(try! rustCall() {
+    uniffi_starwars_fn_method_starship_cost_in_credits_value(
+            FfiConverterTypeStarship_lower(self),$0
+    )
+})
+}
+    
+public func createdAt() -> Timestamp?  {
+    return try!  FfiConverterOptionTypeTimestamp.lift(try! rustCall() {
+    uniffi_starwars_fn_method_starship_created_at(
+            FfiConverterTypeStarship_lower(self),$0
+    )
+})
+}
+    
+public func crewRange() -> NumericRange?  {
+    return try!  FfiConverterOptionTypeNumericRange.lift(try! rustCall() {
+    uniffi_starwars_fn_method_starship_crew_range(
+            FfiConverterTypeStarship_lower(self),$0
+    )
+})
+}
+    
+public func editedAt() -> Timestamp?  {
+    return try!  FfiConverterOptionTypeTimestamp.lift(try! rustCall() {
+    uniffi_starwars_fn_method_starship_edited_at(
+            FfiConverterTypeStarship_lower(self),$0
+    )
+})
+}
+    
+public func hyperdriveRatingValue() -> Double?  {
+    return try!  FfiConverterOptionDouble.lift(try! rustCall() {
+    uniffi_starwars_fn_method_starship_hyperdrive_rating_value(
+            FfiConverterTypeStarship_lower(self),$0
+    )
+})
+}
+    
+public func lengthValue() -> Double?  {
+    return try!  FfiConverterOptionDouble.lift(try! rustCall() {
+    uniffi_starwars_fn_method_starship_length_value(
+            FfiConverterTypeStarship_lower(self),$0
+    )
+})
+}
+    
+public func manufacturerTags() -> [AttributeTag]  {
+    return try!  FfiConverterSequenceTypeAttributeTag.lift(try! rustCall() {
+    uniffi_starwars_fn_method_starship_manufacturer_tags(
+            FfiConverterTypeStarship_lower(self),$0
+    )
+})
+}
+    
+public func maxAtmospheringSpeedValue() -> Double?  {
+    return try!  FfiConverterOptionDouble.lift(try! rustCall() {
+    uniffi_starwars_fn_method_starship_max_atmosphering_speed_value(
+            FfiConverterTypeStarship_lower(self),$0
+    )
+})
+}
+    
+public func mgltValue() -> Double?  {
+    return try!  FfiConverterOptionDouble.lift(try! rustCall() {
+    uniffi_starwars_fn_method_starship_mglt_value(
+            FfiConverterTypeStarship_lower(self),$0
+    )
+})
+}
+    
+public func passengersRange() -> NumericRange?  {
+    return try!  FfiConverterOptionTypeNumericRange.lift(try! rustCall() {
+    uniffi_starwars_fn_method_starship_passengers_range(
+            FfiConverterTypeStarship_lower(self),$0
+    )
+})
+}
+    
+
+    
+}
+
+#if compiler(>=6)
+extension Starship: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeStarship: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Starship {
+        return
+            try Starship(
+                name: FfiConverterString.read(from: &buf), 
+                model: FfiConverterString.read(from: &buf), 
+                manufacturer: FfiConverterString.read(from: &buf), 
+                costInCredits: FfiConverterString.read(from: &buf), 
+                length: FfiConverterString.read(from: &buf), 
+                maxAtmospheringSpeed: FfiConverterString.read(from: &buf), 
+                crew: FfiConverterString.read(from: &buf), 
+                passengers: FfiConverterString.read(from: &buf), 
+                cargoCapacity: FfiConverterString.read(from: &buf), 
+                consumables: FfiConverterString.read(from: &buf), 
+                hyperdriveRating: FfiConverterString.read(from: &buf), 
+                mglt: FfiConverterString.read(from: &buf), 
+                starshipClass: FfiConverterString.read(from: &buf), 
+                pilots: FfiConverterSequenceString.read(from: &buf), 
+                films: FfiConverterSequenceString.read(from: &buf), 
+                created: FfiConverterString.read(from: &buf), 
+                edited: FfiConverterString.read(from: &buf), 
+                url: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Starship, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterString.write(value.model, into: &buf)
+        FfiConverterString.write(value.manufacturer, into: &buf)
+        FfiConverterString.write(value.costInCredits, into: &buf)
+        FfiConverterString.write(value.length, into: &buf)
+        FfiConverterString.write(value.maxAtmospheringSpeed, into: &buf)
+        FfiConverterString.write(value.crew, into: &buf)
+        FfiConverterString.write(value.passengers, into: &buf)
+        FfiConverterString.write(value.cargoCapacity, into: &buf)
+        FfiConverterString.write(value.consumables, into: &buf)
+        FfiConverterString.write(value.hyperdriveRating, into: &buf)
+        FfiConverterString.write(value.mglt, into: &buf)
+        FfiConverterString.write(value.starshipClass, into: &buf)
+        FfiConverterSequenceString.write(value.pilots, into: &buf)
+        FfiConverterSequenceString.write(value.films, into: &buf)
+        FfiConverterString.write(value.created, into: &buf)
+        FfiConverterString.write(value.edited, into: &buf)
+        FfiConverterString.write(value.url, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeStarship_lift(_ buf: RustBuffer) throws -> Starship {
+    return try FfiConverterTypeStarship.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeStarship_lower(_ value: Starship) -> RustBuffer {
+    return FfiConverterTypeStarship.lower(value)
+}
+
+
+public struct Vehicle: Equatable, Hashable {
+    public let name: String
+    public let model: String
+    public let manufacturer: String
+    public let costInCredits: String
+    public let length: String
+    public let maxAtmospheringSpeed: String
+    public let crew: String
+    public let passengers: String
+    public let cargoCapacity: String
+    public let consumables: String
+    public let vehicleClass: String
+    public let pilots: [String]
+    public let films: [String]
+    public let created: String
+    public let edited: String
+    public let url: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(name: String, model: String, manufacturer: String, costInCredits: String, length: String, maxAtmospheringSpeed: String, crew: String, passengers: String, cargoCapacity: String, consumables: String, vehicleClass: String, pilots: [String], films: [String], created: String, edited: String, url: String) {
+        self.name = name
+        self.model = model
+        self.manufacturer = manufacturer
+        self.costInCredits = costInCredits
+        self.length = length
+        self.maxAtmospheringSpeed = maxAtmospheringSpeed
+        self.crew = crew
+        self.passengers = passengers
+        self.cargoCapacity = cargoCapacity
+        self.consumables = consumables
+        self.vehicleClass = vehicleClass
+        self.pilots = pilots
+        self.films = films
+        self.created = created
+        self.edited = edited
+        self.url = url
+    }
+
+    
+public func cargoCapacityValue() -> UInt64?  {
+    return try!  FfiConverterOptionUInt64.lift(try! rustCall() {
+    uniffi_starwars_fn_method_vehicle_cargo_capacity_value(
+            FfiConverterTypeVehicle_lower(self),$0
+    )
+})
+}
+    
+public func consumablesValue() -> Consumables?  {
+    return try!  FfiConverterOptionTypeConsumables.lift(try! rustCall() {
+    uniffi_starwars_fn_method_vehicle_consumables_value(
+            FfiConverterTypeVehicle_lower(self),$0
+    )
+})
+}
+    
+public func costInCreditsValue() -> UInt64?  {
+    return try!  FfiConverterOptionUInt64.lift(try! rustCall() {
+    uniffi_starwars_fn_method_vehicle_cost_in_credits_value(
+            FfiConverterTypeVehicle_lower(self),$0
+    )
+})
+}
+    
+public func createdAt() -> Timestamp?  {
+    return try!  FfiConverterOptionTypeTimestamp.lift(try! rustCall() {
+    uniffi_starwars_fn_method_vehicle_created_at(
+            FfiConverterTypeVehicle_lower(self),$0
+    )
+})
+}
+    
+public func crewRange() -> NumericRange?  {
+    return try!  FfiConverterOptionTypeNumericRange.lift(try! rustCall() {
+    uniffi_starwars_fn_method_vehicle_crew_range(
+            FfiConverterTypeVehicle_lower(self),$0
+    )
+})
+}
+    
+public func editedAt() -> Timestamp?  {
+    return try!  FfiConverterOptionTypeTimestamp.lift(try! rustCall() {
+    uniffi_starwars_fn_method_vehicle_edited_at(
+            FfiConverterTypeVehicle_lower(self),$0
+    )
+})
+}
+    
+public func lengthValue() -> Double?  {
+    return try!  FfiConverterOptionDouble.lift(try! rustCall() {
+    uniffi_starwars_fn_method_vehicle_length_value(
+            FfiConverterTypeVehicle_lower(self),$0
+    )
+})
+}
+    
+public func manufacturerTags() -> [AttributeTag]  {
+    return try!  FfiConverterSequenceTypeAttributeTag.lift(try! rustCall() {
+    uniffi_starwars_fn_method_vehicle_manufacturer_tags(
+            FfiConverterTypeVehicle_lower(self),$0
+    )
+})
+}
+    
+public func maxAtmospheringSpeedValue() -> Double?  {
+    return try!  FfiConverterOptionDouble.lift(try! rustCall() {
+    uniffi_starwars_fn_method_vehicle_max_atmosphering_speed_value(
+            FfiConverterTypeVehicle_lower(self),$0
+    )
+})
+}
+    
+public func passengersRange() -> NumericRange?  {
+    return try!  FfiConverterOptionTypeNumericRange.lift(try! rustCall() {
+    uniffi_starwars_fn_method_vehicle_passengers_range(
+            FfiConverterTypeVehicle_lower(self),$0
+    )
+})
+}
+    
+
+    
+}
+
+#if compiler(>=6)
+extension Vehicle: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeVehicle: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Vehicle {
+        return
+            try Vehicle(
+                name: FfiConverterString.read(from: &buf), 
+                model: FfiConverterString.read(from: &buf), 
+                manufacturer: FfiConverterString.read(from: &buf), 
+                costInCredits: FfiConverterString.read(from: &buf), 
+                length: FfiConverterString.read(from: &buf), 
+                maxAtmospheringSpeed: FfiConverterString.read(from: &buf), 
+                crew: FfiConverterString.read(from: &buf), 
+                passengers: FfiConverterString.read(from: &buf), 
+                cargoCapacity: FfiConverterString.read(from: &buf), 
+                consumables: FfiConverterString.read(from: &buf), 
+                vehicleClass: FfiConverterString.read(from: &buf), 
+                pilots: FfiConverterSequenceString.read(from: &buf), 
+                films: FfiConverterSequenceString.read(from: &buf), 
+                created: FfiConverterString.read(from: &buf), 
+                edited: FfiConverterString.read(from: &buf), 
+                url: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Vehicle, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterString.write(value.model, into: &buf)
+        FfiConverterString.write(value.manufacturer, into: &buf)
+        FfiConverterString.write(value.costInCredits, into: &buf)
+        FfiConverterString.write(value.length, into: &buf)
+        FfiConverterString.write(value.maxAtmospheringSpeed, into: &buf)
+        FfiConverterString.write(value.crew, into: &buf)
+        FfiConverterString.write(value.passengers, into: &buf)
+        FfiConverterString.write(value.cargoCapacity, into: &buf)
+        FfiConverterString.write(value.consumables, into: &buf)
+        FfiConverterString.write(value.vehicleClass, into: &buf)
+        FfiConverterSequenceString.write(value.pilots, into: &buf)
+        FfiConverterSequenceString.write(value.films, into: &buf)
+        FfiConverterString.write(value.created, into: &buf)
+        FfiConverterString.write(value.edited, into: &buf)
+        FfiConverterString.write(value.url, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeVehicle_lift(_ buf: RustBuffer) throws -> Vehicle {
+    return try FfiConverterTypeVehicle.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeVehicle_lower(_ value: Vehicle) -> RustBuffer {
+    return FfiConverterTypeVehicle.lower(value)
+}
+
+
+public enum ApiError: Swift.Error, Equatable, Hashable, Foundation.LocalizedError {
+
+    
+    
+    case HttpError(message: String)
+    
+    case NetworkError(message: String)
+    
+    case SerializationError(message: String)
+    
+    case DeserializationError(message: String)
+    
+    case InvalidResponse(message: String)
+    
+    case NotFound(message: String)
+    
+    case Unauthorized(message: String)
+    
+    case RateLimited(message: String)
+    
+    case CacheMiss(message: String)
+    
+    case ServerError(message: String)
+    
+    case StorageError(message: String)
+    
+    case Cancelled(message: String)
+    
+
+    
+
+    
+
+    
+    public var errorDescription: String? {
+        String(reflecting: self)
+    }
+    
+}
+
+#if compiler(>=6)
+extension ApiError: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeApiError: FfiConverterRustBuffer {
+    typealias SwiftType = ApiError
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ApiError {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+
+        
+
+        
+        case 1: return .HttpError(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 2: return .NetworkError(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 3: return .SerializationError(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 4: return .DeserializationError(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 5: return .InvalidResponse(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 6: return .NotFound(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 7: return .Unauthorized(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 8: return .RateLimited(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 9: return .CacheMiss(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 10: return .ServerError(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 11: return .StorageError(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 12: return .Cancelled(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: ApiError, into buf: inout [UInt8]) {
+        switch value {
+
+        
+
+        
+        case .HttpError(_ /* message is ignored*/):
+            writeInt(&buf, Int32(1))
+        case .NetworkError(_ /* message is ignored*/):
+            writeInt(&buf, Int32(2))
+        case .SerializationError(_ /* message is ignored*/):
+            writeInt(&buf, Int32(3))
+        case .DeserializationError(_ /* message is ignored*/):
+            writeInt(&buf, Int32(4))
+        case .InvalidResponse(_ /* message is ignored*/):
+            writeInt(&buf, Int32(5))
+        case .NotFound(_ /* message is ignored*/):
+            writeInt(&buf, Int32(6))
+        case .Unauthorized(_ /* message is ignored*/):
+            writeInt(&buf, Int32(7))
+        case .RateLimited(_ /* message is ignored*/):
+            writeInt(&buf, Int32(8))
+        case .CacheMiss(_ /* message is ignored*/):
+            writeInt(&buf, Int32(9))
+        case .ServerError(_ /* message is ignored*/):
+            writeInt(&buf, Int32(10))
+        case .StorageError(_ /* message is ignored*/):
+            writeInt(&buf, Int32(11))
+        case .Cancelled(_ /* message is ignored*/):
+            writeInt(&buf, Int32(12))
+
+        
         }
-    )]
+    }
 }
 
-private func uniffiCallbackInitHttpClient() {
-    uniffi_starwars_fn_init_callback_vtable_httpclient(UniffiCallbackInterfaceHttpClient.vtable)
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeApiError_lift(_ buf: RustBuffer) throws -> ApiError {
+    return try FfiConverterTypeApiError.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeApiError_lower(_ value: ApiError) -> RustBuffer {
+    return FfiConverterTypeApiError.lower(value)
+}
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum CachePolicy: Equatable, Hashable {
+    
+    case cacheFirst
+    case networkFirst
+    case cacheOnly
+    case networkOnly
+    case staleWhileRevalidate
+
+
+
+
+
+}
+
+#if compiler(>=6)
+extension CachePolicy: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeCachePolicy: FfiConverterRustBuffer {
+    typealias SwiftType = CachePolicy
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CachePolicy {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .cacheFirst
+        
+        case 2: return .networkFirst
+        
+        case 3: return .cacheOnly
+        
+        case 4: return .networkOnly
+        
+        case 5: return .staleWhileRevalidate
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: CachePolicy, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .cacheFirst:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .networkFirst:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .cacheOnly:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .networkOnly:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .staleWhileRevalidate:
+            writeInt(&buf, Int32(5))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCachePolicy_lift(_ buf: RustBuffer) throws -> CachePolicy {
+    return try FfiConverterTypeCachePolicy.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCachePolicy_lower(_ value: CachePolicy) -> RustBuffer {
+    return FfiConverterTypeCachePolicy.lower(value)
+}
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum ChangeKind: Equatable, Hashable {
+    
+    case inserted
+    case updated
+    case removed
+
+
+
+
+
+}
+
+#if compiler(>=6)
+extension ChangeKind: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeChangeKind: FfiConverterRustBuffer {
+    typealias SwiftType = ChangeKind
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ChangeKind {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .inserted
+        
+        case 2: return .updated
+        
+        case 3: return .removed
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: ChangeKind, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .inserted:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .updated:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .removed:
+            writeInt(&buf, Int32(3))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeChangeKind_lift(_ buf: RustBuffer) throws -> ChangeKind {
+    return try FfiConverterTypeChangeKind.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeChangeKind_lower(_ value: ChangeKind) -> RustBuffer {
+    return FfiConverterTypeChangeKind.lower(value)
+}
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum Fetchable: Equatable, Hashable {
+    
+    case planets
+    case people
+    case films
+    case species
+    case starships
+    case vehicles
+
+
+
+public func displayName() -> String  {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_starwars_fn_method_fetchable_display_name(
+            FfiConverterTypeFetchable_lower(self),$0
+    )
+})
+}
+
+
+
+}
+
+#if compiler(>=6)
+extension Fetchable: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFetchable: FfiConverterRustBuffer {
+    typealias SwiftType = Fetchable
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Fetchable {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .planets
+        
+        case 2: return .people
+        
+        case 3: return .films
+        
+        case 4: return .species
+        
+        case 5: return .starships
+        
+        case 6: return .vehicles
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: Fetchable, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .planets:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .people:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .films:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .species:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .starships:
+            writeInt(&buf, Int32(5))
+        
+        
+        case .vehicles:
+            writeInt(&buf, Int32(6))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFetchable_lift(_ buf: RustBuffer) throws -> Fetchable {
+    return try FfiConverterTypeFetchable.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeHttpClient: FfiConverter {
-    fileprivate static let handleMap = UniffiHandleMap<HttpClient>()
+public func FfiConverterTypeFetchable_lower(_ value: Fetchable) -> RustBuffer {
+    return FfiConverterTypeFetchable.lower(value)
+}
 
-    typealias FfiType = UInt64
-    typealias SwiftType = HttpClient
 
-    public static func lift(_ handle: UInt64) throws -> HttpClient {
-        if ((handle & 1) == 0) {
-            // Rust-generated handle, construct a new class that uses the handle to implement the
-            // interface
-            return HttpClientImpl(unsafeFromHandle: handle)
-        } else {
-            // Swift-generated handle, get the object from the handle map
-            return try handleMap.remove(handle: handle)
-        }
-    }
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 
-    public static func lower(_ value: HttpClient) -> UInt64 {
-         if let rustImpl = value as? HttpClientImpl {
-             // Rust-implemented object.  Clone the handle and return it
-            return rustImpl.uniffiCloneHandle()
-         } else {
-            // Swift object, generate a new vtable handle and return that.
-            return handleMap.insert(obj: value)
-         }
-    }
+public enum FilterOperator: Equatable, Hashable {
+    
+    case equals
+    case notEquals
+    case contains
+    case startsWith
+    case greaterThan
+    case greaterThanOrEqual
+    case lessThan
+    case lessThanOrEqual
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HttpClient {
-        let handle: UInt64 = try readInt(&buf)
-        return try lift(handle)
+
+
+
+
+}
+
+#if compiler(>=6)
+extension FilterOperator: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFilterOperator: FfiConverterRustBuffer {
+    typealias SwiftType = FilterOperator
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FilterOperator {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .equals
+        
+        case 2: return .notEquals
+        
+        case 3: return .contains
+        
+        case 4: return .startsWith
+        
+        case 5: return .greaterThan
+        
+        case 6: return .greaterThanOrEqual
+        
+        case 7: return .lessThan
+        
+        case 8: return .lessThanOrEqual
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
     }
 
-    public static func write(_ value: HttpClient, into buf: inout [UInt8]) {
-        writeInt(&buf, lower(value))
+    public static func write(_ value: FilterOperator, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .equals:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .notEquals:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .contains:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .startsWith:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .greaterThan:
+            writeInt(&buf, Int32(5))
+        
+        
+        case .greaterThanOrEqual:
+            writeInt(&buf, Int32(6))
+        
+        
+        case .lessThan:
+            writeInt(&buf, Int32(7))
+        
+        
+        case .lessThanOrEqual:
+            writeInt(&buf, Int32(8))
+        
+        }
     }
 }
 
@@ -1209,103 +5668,152 @@ public struct FfiConverterTypeHttpClient: FfiConverter {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeHttpClient_lift(_ handle: UInt64) throws -> HttpClient {
-    return try FfiConverterTypeHttpClient.lift(handle)
+public func FfiConverterTypeFilterOperator_lift(_ buf: RustBuffer) throws -> FilterOperator {
+    return try FfiConverterTypeFilterOperator.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeHttpClient_lower(_ value: HttpClient) -> UInt64 {
-    return FfiConverterTypeHttpClient.lower(value)
+public func FfiConverterTypeFilterOperator_lower(_ value: FilterOperator) -> RustBuffer {
+    return FfiConverterTypeFilterOperator.lower(value)
 }
 
 
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum ListItems: Equatable, Hashable {
+    
+    case planets([String]
+    )
+    case people([String]
+    )
+    case films([String]
+    )
+    case species([String]
+    )
+    case starships([String]
+    )
+    case vehicles([String]
+    )
+    case homeworld(String
+    )
+    case characters([String]
+    )
+    case residents([String]
+    )
+    case pilots([String]
+    )
 
 
-public struct Film: Equatable, Hashable {
-    public let title: String
-    public let episodeId: Int32
-    public let openingCrawl: String
-    public let director: String
-    public let producer: String
-    public let releaseDate: String
-    public let characters: [String]
-    public let planets: [String]
-    public let starships: [String]
-    public let vehicles: [String]
-    public let species: [String]
-    public let created: String
-    public let edited: String
-    public let url: String
 
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(title: String, episodeId: Int32, openingCrawl: String, director: String, producer: String, releaseDate: String, characters: [String], planets: [String], starships: [String], vehicles: [String], species: [String], created: String, edited: String, url: String) {
-        self.title = title
-        self.episodeId = episodeId
-        self.openingCrawl = openingCrawl
-        self.director = director
-        self.producer = producer
-        self.releaseDate = releaseDate
-        self.characters = characters
-        self.planets = planets
-        self.starships = starships
-        self.vehicles = vehicles
-        self.species = species
-        self.created = created
-        self.edited = edited
-        self.url = url
-    }
 
-    
 
-    
 }
 
 #if compiler(>=6)
-extension Film: Sendable {}
+extension ListItems: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeFilm: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Film {
-        return
-            try Film(
-                title: FfiConverterString.read(from: &buf), 
-                episodeId: FfiConverterInt32.read(from: &buf), 
-                openingCrawl: FfiConverterString.read(from: &buf), 
-                director: FfiConverterString.read(from: &buf), 
-                producer: FfiConverterString.read(from: &buf), 
-                releaseDate: FfiConverterString.read(from: &buf), 
-                characters: FfiConverterSequenceString.read(from: &buf), 
-                planets: FfiConverterSequenceString.read(from: &buf), 
-                starships: FfiConverterSequenceString.read(from: &buf), 
-                vehicles: FfiConverterSequenceString.read(from: &buf), 
-                species: FfiConverterSequenceString.read(from: &buf), 
-                created: FfiConverterString.read(from: &buf), 
-                edited: FfiConverterString.read(from: &buf), 
-                url: FfiConverterString.read(from: &buf)
+public struct FfiConverterTypeListItems: FfiConverterRustBuffer {
+    typealias SwiftType = ListItems
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ListItems {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .planets(try FfiConverterSequenceString.read(from: &buf)
+        )
+        
+        case 2: return .people(try FfiConverterSequenceString.read(from: &buf)
+        )
+        
+        case 3: return .films(try FfiConverterSequenceString.read(from: &buf)
+        )
+        
+        case 4: return .species(try FfiConverterSequenceString.read(from: &buf)
+        )
+        
+        case 5: return .starships(try FfiConverterSequenceString.read(from: &buf)
+        )
+        
+        case 6: return .vehicles(try FfiConverterSequenceString.read(from: &buf)
+        )
+        
+        case 7: return .homeworld(try FfiConverterString.read(from: &buf)
+        )
+        
+        case 8: return .characters(try FfiConverterSequenceString.read(from: &buf)
+        )
+        
+        case 9: return .residents(try FfiConverterSequenceString.read(from: &buf)
         )
+        
+        case 10: return .pilots(try FfiConverterSequenceString.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
     }
 
-    public static func write(_ value: Film, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.title, into: &buf)
-        FfiConverterInt32.write(value.episodeId, into: &buf)
-        FfiConverterString.write(value.openingCrawl, into: &buf)
-        FfiConverterString.write(value.director, into: &buf)
-        FfiConverterString.write(value.producer, into: &buf)
-        FfiConverterString.write(value.releaseDate, into: &buf)
-        FfiConverterSequenceString.write(value.characters, into: &buf)
-        FfiConverterSequenceString.write(value.planets, into: &buf)
-        FfiConverterSequenceString.write(value.starships, into: &buf)
-        FfiConverterSequenceString.write(value.vehicles, into: &buf)
-        FfiConverterSequenceString.write(value.species, into: &buf)
-        FfiConverterString.write(value.created, into: &buf)
-        FfiConverterString.write(value.edited, into: &buf)
-        FfiConverterString.write(value.url, into: &buf)
+    public static func write(_ value: ListItems, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case let .planets(v1):
+            writeInt(&buf, Int32(1))
+            FfiConverterSequenceString.write(v1, into: &buf)
+            
+        
+        case let .people(v1):
+            writeInt(&buf, Int32(2))
+            FfiConverterSequenceString.write(v1, into: &buf)
+            
+        
+        case let .films(v1):
+            writeInt(&buf, Int32(3))
+            FfiConverterSequenceString.write(v1, into: &buf)
+            
+        
+        case let .species(v1):
+            writeInt(&buf, Int32(4))
+            FfiConverterSequenceString.write(v1, into: &buf)
+            
+        
+        case let .starships(v1):
+            writeInt(&buf, Int32(5))
+            FfiConverterSequenceString.write(v1, into: &buf)
+            
+        
+        case let .vehicles(v1):
+            writeInt(&buf, Int32(6))
+            FfiConverterSequenceString.write(v1, into: &buf)
+            
+        
+        case let .homeworld(v1):
+            writeInt(&buf, Int32(7))
+            FfiConverterString.write(v1, into: &buf)
+            
+        
+        case let .characters(v1):
+            writeInt(&buf, Int32(8))
+            FfiConverterSequenceString.write(v1, into: &buf)
+            
+        
+        case let .residents(v1):
+            writeInt(&buf, Int32(9))
+            FfiConverterSequenceString.write(v1, into: &buf)
+            
+        
+        case let .pilots(v1):
+            writeInt(&buf, Int32(10))
+            FfiConverterSequenceString.write(v1, into: &buf)
+            
+        }
     }
 }
 
@@ -1313,109 +5821,86 @@ public struct FfiConverterTypeFilm: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFilm_lift(_ buf: RustBuffer) throws -> Film {
-    return try FfiConverterTypeFilm.lift(buf)
+public func FfiConverterTypeListItems_lift(_ buf: RustBuffer) throws -> ListItems {
+    return try FfiConverterTypeListItems.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFilm_lower(_ value: Film) -> RustBuffer {
-    return FfiConverterTypeFilm.lower(value)
+public func FfiConverterTypeListItems_lower(_ value: ListItems) -> RustBuffer {
+    return FfiConverterTypeListItems.lower(value)
 }
 
 
-public struct Person: Equatable, Hashable {
-    public let birthYear: String
-    public let eyeColor: String
-    public let films: [String]
-    public let gender: String
-    public let hairColor: String
-    public let height: String
-    public let homeworld: String?
-    public let mass: String
-    public let name: String
-    public let skinColor: String
-    public let created: String
-    public let edited: String
-    public let species: [String]
-    public let starships: [String]
-    public let url: String
-    public let vehicles: [String]
 
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(birthYear: String, eyeColor: String, films: [String], gender: String, hairColor: String, height: String, homeworld: String?, mass: String, name: String, skinColor: String, created: String, edited: String, species: [String], starships: [String], url: String, vehicles: [String]) {
-        self.birthYear = birthYear
-        self.eyeColor = eyeColor
-        self.films = films
-        self.gender = gender
-        self.hairColor = hairColor
-        self.height = height
-        self.homeworld = homeworld
-        self.mass = mass
-        self.name = name
-        self.skinColor = skinColor
-        self.created = created
-        self.edited = edited
-        self.species = species
-        self.starships = starships
-        self.url = url
-        self.vehicles = vehicles
-    }
+public enum NetworkError: Swift.Error, Equatable, Hashable, Foundation.LocalizedError {
+
+    
+    
+    case RequestFailed(reason: String
+    )
+    case Timeout
+    case Cancelled
+
+    
 
     
 
     
+    public var errorDescription: String? {
+        String(reflecting: self)
+    }
+    
 }
 
 #if compiler(>=6)
-extension Person: Sendable {}
+extension NetworkError: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypePerson: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Person {
-        return
-            try Person(
-                birthYear: FfiConverterString.read(from: &buf), 
-                eyeColor: FfiConverterString.read(from: &buf), 
-                films: FfiConverterSequenceString.read(from: &buf), 
-                gender: FfiConverterString.read(from: &buf), 
-                hairColor: FfiConverterString.read(from: &buf), 
-                height: FfiConverterString.read(from: &buf), 
-                homeworld: FfiConverterOptionString.read(from: &buf), 
-                mass: FfiConverterString.read(from: &buf), 
-                name: FfiConverterString.read(from: &buf), 
-                skinColor: FfiConverterString.read(from: &buf), 
-                created: FfiConverterString.read(from: &buf), 
-                edited: FfiConverterString.read(from: &buf), 
-                species: FfiConverterSequenceString.read(from: &buf), 
-                starships: FfiConverterSequenceString.read(from: &buf), 
-                url: FfiConverterString.read(from: &buf), 
-                vehicles: FfiConverterSequenceString.read(from: &buf)
-        )
+public struct FfiConverterTypeNetworkError: FfiConverterRustBuffer {
+    typealias SwiftType = NetworkError
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> NetworkError {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+
+        
+
+        
+        case 1: return .RequestFailed(
+            reason: try FfiConverterString.read(from: &buf)
+            )
+        case 2: return .Timeout
+        case 3: return .Cancelled
+
+         default: throw UniffiInternalError.unexpectedEnumCase
+        }
     }
 
-    public static func write(_ value: Person, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.birthYear, into: &buf)
-        FfiConverterString.write(value.eyeColor, into: &buf)
-        FfiConverterSequenceString.write(value.films, into: &buf)
-        FfiConverterString.write(value.gender, into: &buf)
-        FfiConverterString.write(value.hairColor, into: &buf)
-        FfiConverterString.write(value.height, into: &buf)
-        FfiConverterOptionString.write(value.homeworld, into: &buf)
-        FfiConverterString.write(value.mass, into: &buf)
-        FfiConverterString.write(value.name, into: &buf)
-        FfiConverterString.write(value.skinColor, into: &buf)
-        FfiConverterString.write(value.created, into: &buf)
-        FfiConverterString.write(value.edited, into: &buf)
-        FfiConverterSequenceString.write(value.species, into: &buf)
-        FfiConverterSequenceString.write(value.starships, into: &buf)
-        FfiConverterString.write(value.url, into: &buf)
-        FfiConverterSequenceString.write(value.vehicles, into: &buf)
+    public static func write(_ value: NetworkError, into buf: inout [UInt8]) {
+        switch value {
+
+        
+
+        
+        
+        case let .RequestFailed(reason):
+            writeInt(&buf, Int32(1))
+            FfiConverterString.write(reason, into: &buf)
+            
+        
+        case .Timeout:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .Cancelled:
+            writeInt(&buf, Int32(3))
+        
+        }
     }
 }
 
@@ -1423,207 +5908,195 @@ public struct FfiConverterTypePerson: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypePerson_lift(_ buf: RustBuffer) throws -> Person {
-    return try FfiConverterTypePerson.lift(buf)
+public func FfiConverterTypeNetworkError_lift(_ buf: RustBuffer) throws -> NetworkError {
+    return try FfiConverterTypeNetworkError.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypePerson_lower(_ value: Person) -> RustBuffer {
-    return FfiConverterTypePerson.lower(value)
+public func FfiConverterTypeNetworkError_lower(_ value: NetworkError) -> RustBuffer {
+    return FfiConverterTypeNetworkError.lower(value)
 }
 
 
-public struct Planet: Equatable, Hashable {
-    public let name: String
-    public let rotationPeriod: String
-    public let orbitalPeriod: String
-    public let diameter: String
-    public let climate: String
-    public let gravity: String
-    public let terrain: String
-    public let surfaceWater: String
-    public let population: String
-    public let residents: [String]
-    public let films: [String]
-    public let created: String
-    public let edited: String
-    public let url: String
+public enum QueryError: Swift.Error, Equatable, Hashable, Foundation.LocalizedError {
 
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(name: String, rotationPeriod: String, orbitalPeriod: String, diameter: String, climate: String, gravity: String, terrain: String, surfaceWater: String, population: String, residents: [String], films: [String], created: String, edited: String, url: String) {
-        self.name = name
-        self.rotationPeriod = rotationPeriod
-        self.orbitalPeriod = orbitalPeriod
-        self.diameter = diameter
-        self.climate = climate
-        self.gravity = gravity
-        self.terrain = terrain
-        self.surfaceWater = surfaceWater
-        self.population = population
-        self.residents = residents
-        self.films = films
-        self.created = created
-        self.edited = edited
-        self.url = url
-    }
+    
+    
+    case UnknownField(field: String
+    )
 
     
 
     
+
+    
+    public var errorDescription: String? {
+        String(reflecting: self)
+    }
+    
 }
 
 #if compiler(>=6)
-extension Planet: Sendable {}
+extension QueryError: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypePlanet: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Planet {
-        return
-            try Planet(
-                name: FfiConverterString.read(from: &buf), 
-                rotationPeriod: FfiConverterString.read(from: &buf), 
-                orbitalPeriod: FfiConverterString.read(from: &buf), 
-                diameter: FfiConverterString.read(from: &buf), 
-                climate: FfiConverterString.read(from: &buf), 
-                gravity: FfiConverterString.read(from: &buf), 
-                terrain: FfiConverterString.read(from: &buf), 
-                surfaceWater: FfiConverterString.read(from: &buf), 
-                population: FfiConverterString.read(from: &buf), 
-                residents: FfiConverterSequenceString.read(from: &buf), 
-                films: FfiConverterSequenceString.read(from: &buf), 
-                created: FfiConverterString.read(from: &buf), 
-                edited: FfiConverterString.read(from: &buf), 
-                url: FfiConverterString.read(from: &buf)
-        )
+public struct FfiConverterTypeQueryError: FfiConverterRustBuffer {
+    typealias SwiftType = QueryError
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> QueryError {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+
+        
+
+        
+        case 1: return .UnknownField(
+            field: try FfiConverterString.read(from: &buf)
+            )
+
+         default: throw UniffiInternalError.unexpectedEnumCase
+        }
     }
 
-    public static func write(_ value: Planet, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.name, into: &buf)
-        FfiConverterString.write(value.rotationPeriod, into: &buf)
-        FfiConverterString.write(value.orbitalPeriod, into: &buf)
-        FfiConverterString.write(value.diameter, into: &buf)
-        FfiConverterString.write(value.climate, into: &buf)
-        FfiConverterString.write(value.gravity, into: &buf)
-        FfiConverterString.write(value.terrain, into: &buf)
-        FfiConverterString.write(value.surfaceWater, into: &buf)
-        FfiConverterString.write(value.population, into: &buf)
-        FfiConverterSequenceString.write(value.residents, into: &buf)
-        FfiConverterSequenceString.write(value.films, into: &buf)
-        FfiConverterString.write(value.created, into: &buf)
-        FfiConverterString.write(value.edited, into: &buf)
-        FfiConverterString.write(value.url, into: &buf)
+    public static func write(_ value: QueryError, into buf: inout [UInt8]) {
+        switch value {
+
+        
+
+        
+        
+        case let .UnknownField(field):
+            writeInt(&buf, Int32(1))
+            FfiConverterString.write(field, into: &buf)
+            
+        }
     }
 }
 
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
-#endif
-public func FfiConverterTypePlanet_lift(_ buf: RustBuffer) throws -> Planet {
-    return try FfiConverterTypePlanet.lift(buf)
+#endif
+public func FfiConverterTypeQueryError_lift(_ buf: RustBuffer) throws -> QueryError {
+    return try FfiConverterTypeQueryError.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypePlanet_lower(_ value: Planet) -> RustBuffer {
-    return FfiConverterTypePlanet.lower(value)
+public func FfiConverterTypeQueryError_lower(_ value: QueryError) -> RustBuffer {
+    return FfiConverterTypeQueryError.lower(value)
 }
 
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum Relationship: Equatable, Hashable {
+    
+    case planets
+    case people
+    case films
+    case species
+    case starships
+    case vehicles
+    case homeworld
+    case characters
+    case residents
+    case pilots
+
 
-public struct Species: Equatable, Hashable {
-    public let name: String
-    public let classification: String
-    public let designation: String
-    public let averageHeight: String
-    public let skinColors: String
-    public let hairColors: String
-    public let eyeColors: String
-    public let averageLifespan: String
-    public let homeworld: String?
-    public let language: String
-    public let people: [String]
-    public let films: [String]
-    public let created: String
-    public let edited: String
-    public let url: String
 
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(name: String, classification: String, designation: String, averageHeight: String, skinColors: String, hairColors: String, eyeColors: String, averageLifespan: String, homeworld: String?, language: String, people: [String], films: [String], created: String, edited: String, url: String) {
-        self.name = name
-        self.classification = classification
-        self.designation = designation
-        self.averageHeight = averageHeight
-        self.skinColors = skinColors
-        self.hairColors = hairColors
-        self.eyeColors = eyeColors
-        self.averageLifespan = averageLifespan
-        self.homeworld = homeworld
-        self.language = language
-        self.people = people
-        self.films = films
-        self.created = created
-        self.edited = edited
-        self.url = url
-    }
 
-    
 
-    
 }
 
 #if compiler(>=6)
-extension Species: Sendable {}
+extension Relationship: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeSpecies: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Species {
-        return
-            try Species(
-                name: FfiConverterString.read(from: &buf), 
-                classification: FfiConverterString.read(from: &buf), 
-                designation: FfiConverterString.read(from: &buf), 
-                averageHeight: FfiConverterString.read(from: &buf), 
-                skinColors: FfiConverterString.read(from: &buf), 
-                hairColors: FfiConverterString.read(from: &buf), 
-                eyeColors: FfiConverterString.read(from: &buf), 
-                averageLifespan: FfiConverterString.read(from: &buf), 
-                homeworld: FfiConverterOptionString.read(from: &buf), 
-                language: FfiConverterString.read(from: &buf), 
-                people: FfiConverterSequenceString.read(from: &buf), 
-                films: FfiConverterSequenceString.read(from: &buf), 
-                created: FfiConverterString.read(from: &buf), 
-                edited: FfiConverterString.read(from: &buf), 
-                url: FfiConverterString.read(from: &buf)
-        )
+public struct FfiConverterTypeRelationship: FfiConverterRustBuffer {
+    typealias SwiftType = Relationship
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Relationship {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .planets
+        
+        case 2: return .people
+        
+        case 3: return .films
+        
+        case 4: return .species
+        
+        case 5: return .starships
+        
+        case 6: return .vehicles
+        
+        case 7: return .homeworld
+        
+        case 8: return .characters
+        
+        case 9: return .residents
+        
+        case 10: return .pilots
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
     }
 
-    public static func write(_ value: Species, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.name, into: &buf)
-        FfiConverterString.write(value.classification, into: &buf)
-        FfiConverterString.write(value.designation, into: &buf)
-        FfiConverterString.write(value.averageHeight, into: &buf)
-        FfiConverterString.write(value.skinColors, into: &buf)
-        FfiConverterString.write(value.hairColors, into: &buf)
-        FfiConverterString.write(value.eyeColors, into: &buf)
-        FfiConverterString.write(value.averageLifespan, into: &buf)
-        FfiConverterOptionString.write(value.homeworld, into: &buf)
-        FfiConverterString.write(value.language, into: &buf)
-        FfiConverterSequenceString.write(value.people, into: &buf)
-        FfiConverterSequenceString.write(value.films, into: &buf)
-        FfiConverterString.write(value.created, into: &buf)
-        FfiConverterString.write(value.edited, into: &buf)
-        FfiConverterString.write(value.url, into: &buf)
+    public static func write(_ value: Relationship, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .planets:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .people:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .films:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .species:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .starships:
+            writeInt(&buf, Int32(5))
+        
+        
+        case .vehicles:
+            writeInt(&buf, Int32(6))
+        
+        
+        case .homeworld:
+            writeInt(&buf, Int32(7))
+        
+        
+        case .characters:
+            writeInt(&buf, Int32(8))
+        
+        
+        case .residents:
+            writeInt(&buf, Int32(9))
+        
+        
+        case .pilots:
+            writeInt(&buf, Int32(10))
+        
+        }
     }
 }
 
@@ -1631,117 +6104,66 @@ public struct FfiConverterTypeSpecies: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeSpecies_lift(_ buf: RustBuffer) throws -> Species {
-    return try FfiConverterTypeSpecies.lift(buf)
+public func FfiConverterTypeRelationship_lift(_ buf: RustBuffer) throws -> Relationship {
+    return try FfiConverterTypeRelationship.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeSpecies_lower(_ value: Species) -> RustBuffer {
-    return FfiConverterTypeSpecies.lower(value)
+public func FfiConverterTypeRelationship_lower(_ value: Relationship) -> RustBuffer {
+    return FfiConverterTypeRelationship.lower(value)
 }
 
 
-public struct Starship: Equatable, Hashable {
-    public let name: String
-    public let model: String
-    public let manufacturer: String
-    public let costInCredits: String
-    public let length: String
-    public let maxAtmospheringSpeed: String
-    public let crew: String
-    public let passengers: String
-    public let cargoCapacity: String
-    public let consumables: String
-    public let hyperdriveRating: String
-    public let mglt: String
-    public let starshipClass: String
-    public let pilots: [String]
-    public let films: [String]
-    public let created: String
-    public let edited: String
-    public let url: String
-
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(name: String, model: String, manufacturer: String, costInCredits: String, length: String, maxAtmospheringSpeed: String, crew: String, passengers: String, cargoCapacity: String, consumables: String, hyperdriveRating: String, mglt: String, starshipClass: String, pilots: [String], films: [String], created: String, edited: String, url: String) {
-        self.name = name
-        self.model = model
-        self.manufacturer = manufacturer
-        self.costInCredits = costInCredits
-        self.length = length
-        self.maxAtmospheringSpeed = maxAtmospheringSpeed
-        self.crew = crew
-        self.passengers = passengers
-        self.cargoCapacity = cargoCapacity
-        self.consumables = consumables
-        self.hyperdriveRating = hyperdriveRating
-        self.mglt = mglt
-        self.starshipClass = starshipClass
-        self.pilots = pilots
-        self.films = films
-        self.created = created
-        self.edited = edited
-        self.url = url
-    }
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 
+public enum ResponseFormat: Equatable, Hashable {
     
+    case json
+    case wookiee
+
+
+
+
 
-    
 }
 
 #if compiler(>=6)
-extension Starship: Sendable {}
+extension ResponseFormat: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeStarship: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Starship {
-        return
-            try Starship(
-                name: FfiConverterString.read(from: &buf), 
-                model: FfiConverterString.read(from: &buf), 
-                manufacturer: FfiConverterString.read(from: &buf), 
-                costInCredits: FfiConverterString.read(from: &buf), 
-                length: FfiConverterString.read(from: &buf), 
-                maxAtmospheringSpeed: FfiConverterString.read(from: &buf), 
-                crew: FfiConverterString.read(from: &buf), 
-                passengers: FfiConverterString.read(from: &buf), 
-                cargoCapacity: FfiConverterString.read(from: &buf), 
-                consumables: FfiConverterString.read(from: &buf), 
-                hyperdriveRating: FfiConverterString.read(from: &buf), 
-                mglt: FfiConverterString.read(from: &buf), 
-                starshipClass: FfiConverterString.read(from: &buf), 
-                pilots: FfiConverterSequenceString.read(from: &buf), 
-                films: FfiConverterSequenceString.read(from: &buf), 
-                created: FfiConverterString.read(from: &buf), 
-                edited: FfiConverterString.read(from: &buf), 
-                url: FfiConverterString.read(from: &buf)
-        )
+public struct FfiConverterTypeResponseFormat: FfiConverterRustBuffer {
+    typealias SwiftType = ResponseFormat
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ResponseFormat {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .json
+        
+        case 2: return .wookiee
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
     }
 
-    public static func write(_ value: Starship, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.name, into: &buf)
-        FfiConverterString.write(value.model, into: &buf)
-        FfiConverterString.write(value.manufacturer, into: &buf)
-        FfiConverterString.write(value.costInCredits, into: &buf)
-        FfiConverterString.write(value.length, into: &buf)
-        FfiConverterString.write(value.maxAtmospheringSpeed, into: &buf)
-        FfiConverterString.write(value.crew, into: &buf)
-        FfiConverterString.write(value.passengers, into: &buf)
-        FfiConverterString.write(value.cargoCapacity, into: &buf)
-        FfiConverterString.write(value.consumables, into: &buf)
-        FfiConverterString.write(value.hyperdriveRating, into: &buf)
-        FfiConverterString.write(value.mglt, into: &buf)
-        FfiConverterString.write(value.starshipClass, into: &buf)
-        FfiConverterSequenceString.write(value.pilots, into: &buf)
-        FfiConverterSequenceString.write(value.films, into: &buf)
-        FfiConverterString.write(value.created, into: &buf)
-        FfiConverterString.write(value.edited, into: &buf)
-        FfiConverterString.write(value.url, into: &buf)
+    public static func write(_ value: ResponseFormat, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .json:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .wookiee:
+            writeInt(&buf, Int32(2))
+        
+        }
     }
 }
 
@@ -1749,109 +6171,112 @@ public struct FfiConverterTypeStarship: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeStarship_lift(_ buf: RustBuffer) throws -> Starship {
-    return try FfiConverterTypeStarship.lift(buf)
+public func FfiConverterTypeResponseFormat_lift(_ buf: RustBuffer) throws -> ResponseFormat {
+    return try FfiConverterTypeResponseFormat.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeStarship_lower(_ value: Starship) -> RustBuffer {
-    return FfiConverterTypeStarship.lower(value)
+public func FfiConverterTypeResponseFormat_lower(_ value: ResponseFormat) -> RustBuffer {
+    return FfiConverterTypeResponseFormat.lower(value)
 }
 
 
-public struct Vehicle: Equatable, Hashable {
-    public let name: String
-    public let model: String
-    public let manufacturer: String
-    public let costInCredits: String
-    public let length: String
-    public let maxAtmospheringSpeed: String
-    public let crew: String
-    public let passengers: String
-    public let cargoCapacity: String
-    public let consumables: String
-    public let vehicleClass: String
-    public let pilots: [String]
-    public let films: [String]
-    public let created: String
-    public let edited: String
-    public let url: String
-
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(name: String, model: String, manufacturer: String, costInCredits: String, length: String, maxAtmospheringSpeed: String, crew: String, passengers: String, cargoCapacity: String, consumables: String, vehicleClass: String, pilots: [String], films: [String], created: String, edited: String, url: String) {
-        self.name = name
-        self.model = model
-        self.manufacturer = manufacturer
-        self.costInCredits = costInCredits
-        self.length = length
-        self.maxAtmospheringSpeed = maxAtmospheringSpeed
-        self.crew = crew
-        self.passengers = passengers
-        self.cargoCapacity = cargoCapacity
-        self.consumables = consumables
-        self.vehicleClass = vehicleClass
-        self.pilots = pilots
-        self.films = films
-        self.created = created
-        self.edited = edited
-        self.url = url
-    }
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 
+public enum Selected: Equatable, Hashable {
     
+    case film(Film
+    )
+    case person(Person
+    )
+    case planet(Planet
+    )
+    case species(Species
+    )
+    case starship(Starship
+    )
+    case vehicle(Vehicle
+    )
+
+
+
+
 
-    
 }
 
 #if compiler(>=6)
-extension Vehicle: Sendable {}
+extension Selected: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeVehicle: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Vehicle {
-        return
-            try Vehicle(
-                name: FfiConverterString.read(from: &buf), 
-                model: FfiConverterString.read(from: &buf), 
-                manufacturer: FfiConverterString.read(from: &buf), 
-                costInCredits: FfiConverterString.read(from: &buf), 
-                length: FfiConverterString.read(from: &buf), 
-                maxAtmospheringSpeed: FfiConverterString.read(from: &buf), 
-                crew: FfiConverterString.read(from: &buf), 
-                passengers: FfiConverterString.read(from: &buf), 
-                cargoCapacity: FfiConverterString.read(from: &buf), 
-                consumables: FfiConverterString.read(from: &buf), 
-                vehicleClass: FfiConverterString.read(from: &buf), 
-                pilots: FfiConverterSequenceString.read(from: &buf), 
-                films: FfiConverterSequenceString.read(from: &buf), 
-                created: FfiConverterString.read(from: &buf), 
-                edited: FfiConverterString.read(from: &buf), 
-                url: FfiConverterString.read(from: &buf)
+public struct FfiConverterTypeSelected: FfiConverterRustBuffer {
+    typealias SwiftType = Selected
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Selected {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .film(try FfiConverterTypeFilm.read(from: &buf)
+        )
+        
+        case 2: return .person(try FfiConverterTypePerson.read(from: &buf)
+        )
+        
+        case 3: return .planet(try FfiConverterTypePlanet.read(from: &buf)
+        )
+        
+        case 4: return .species(try FfiConverterTypeSpecies.read(from: &buf)
+        )
+        
+        case 5: return .starship(try FfiConverterTypeStarship.read(from: &buf)
+        )
+        
+        case 6: return .vehicle(try FfiConverterTypeVehicle.read(from: &buf)
         )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
     }
 
-    public static func write(_ value: Vehicle, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.name, into: &buf)
-        FfiConverterString.write(value.model, into: &buf)
-        FfiConverterString.write(value.manufacturer, into: &buf)
-        FfiConverterString.write(value.costInCredits, into: &buf)
-        FfiConverterString.write(value.length, into: &buf)
-        FfiConverterString.write(value.maxAtmospheringSpeed, into: &buf)
-        FfiConverterString.write(value.crew, into: &buf)
-        FfiConverterString.write(value.passengers, into: &buf)
-        FfiConverterString.write(value.cargoCapacity, into: &buf)
-        FfiConverterString.write(value.consumables, into: &buf)
-        FfiConverterString.write(value.vehicleClass, into: &buf)
-        FfiConverterSequenceString.write(value.pilots, into: &buf)
-        FfiConverterSequenceString.write(value.films, into: &buf)
-        FfiConverterString.write(value.created, into: &buf)
-        FfiConverterString.write(value.edited, into: &buf)
-        FfiConverterString.write(value.url, into: &buf)
+    public static func write(_ value: Selected, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case let .film(v1):
+            writeInt(&buf, Int32(1))
+            FfiConverterTypeFilm.write(v1, into: &buf)
+            
+        
+        case let .person(v1):
+            writeInt(&buf, Int32(2))
+            FfiConverterTypePerson.write(v1, into: &buf)
+            
+        
+        case let .planet(v1):
+            writeInt(&buf, Int32(3))
+            FfiConverterTypePlanet.write(v1, into: &buf)
+            
+        
+        case let .species(v1):
+            writeInt(&buf, Int32(4))
+            FfiConverterTypeSpecies.write(v1, into: &buf)
+            
+        
+        case let .starship(v1):
+            writeInt(&buf, Int32(5))
+            FfiConverterTypeStarship.write(v1, into: &buf)
+            
+        
+        case let .vehicle(v1):
+            writeInt(&buf, Int32(6))
+            FfiConverterTypeVehicle.write(v1, into: &buf)
+            
+        }
     }
 }
 
@@ -1859,136 +6284,140 @@ public struct FfiConverterTypeVehicle: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeVehicle_lift(_ buf: RustBuffer) throws -> Vehicle {
-    return try FfiConverterTypeVehicle.lift(buf)
+public func FfiConverterTypeSelected_lift(_ buf: RustBuffer) throws -> Selected {
+    return try FfiConverterTypeSelected.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeVehicle_lower(_ value: Vehicle) -> RustBuffer {
-    return FfiConverterTypeVehicle.lower(value)
+public func FfiConverterTypeSelected_lower(_ value: Selected) -> RustBuffer {
+    return FfiConverterTypeSelected.lower(value)
 }
 
 
-public enum ApiError: Swift.Error, Equatable, Hashable, Foundation.LocalizedError {
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 
+public enum SortDirection: Equatable, Hashable {
     
-    
-    case HttpError(message: String)
-    
-    case NetworkError(message: String)
-    
-    case SerializationError(message: String)
-    
-    case DeserializationError(message: String)
-    
-    case InvalidResponse(message: String)
-    
-    case NotFound(message: String)
-    
-    case Unauthorized(message: String)
-    
-    case RateLimited(message: String)
-    
-    case ServerError(message: String)
-    
+    case ascending
+    case descending
+
+
 
-    
 
-    
 
-    
-    public var errorDescription: String? {
-        String(reflecting: self)
-    }
-    
 }
 
 #if compiler(>=6)
-extension ApiError: Sendable {}
+extension SortDirection: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeApiError: FfiConverterRustBuffer {
-    typealias SwiftType = ApiError
+public struct FfiConverterTypeSortDirection: FfiConverterRustBuffer {
+    typealias SwiftType = SortDirection
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ApiError {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SortDirection {
         let variant: Int32 = try readInt(&buf)
         switch variant {
-
-        
-
         
-        case 1: return .HttpError(
-            message: try FfiConverterString.read(from: &buf)
-        )
+        case 1: return .ascending
         
-        case 2: return .NetworkError(
-            message: try FfiConverterString.read(from: &buf)
-        )
+        case 2: return .descending
         
-        case 3: return .SerializationError(
-            message: try FfiConverterString.read(from: &buf)
-        )
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: SortDirection, into buf: inout [UInt8]) {
+        switch value {
         
-        case 4: return .DeserializationError(
-            message: try FfiConverterString.read(from: &buf)
-        )
         
-        case 5: return .InvalidResponse(
-            message: try FfiConverterString.read(from: &buf)
-        )
+        case .ascending:
+            writeInt(&buf, Int32(1))
         
-        case 6: return .NotFound(
-            message: try FfiConverterString.read(from: &buf)
-        )
         
-        case 7: return .Unauthorized(
-            message: try FfiConverterString.read(from: &buf)
-        )
+        case .descending:
+            writeInt(&buf, Int32(2))
         
-        case 8: return .RateLimited(
-            message: try FfiConverterString.read(from: &buf)
-        )
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSortDirection_lift(_ buf: RustBuffer) throws -> SortDirection {
+    return try FfiConverterTypeSortDirection.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSortDirection_lower(_ value: SortDirection) -> RustBuffer {
+    return FfiConverterTypeSortDirection.lower(value)
+}
+
+
+
+public enum StoreError: Swift.Error, Equatable, Hashable, Foundation.LocalizedError {
+
+    
+    
+    case Io(reason: String
+    )
+
+    
+
+    
+
+    
+    public var errorDescription: String? {
+        String(reflecting: self)
+    }
+    
+}
+
+#if compiler(>=6)
+extension StoreError: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeStoreError: FfiConverterRustBuffer {
+    typealias SwiftType = StoreError
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> StoreError {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+
         
-        case 9: return .ServerError(
-            message: try FfiConverterString.read(from: &buf)
-        )
+
         
+        case 1: return .Io(
+            reason: try FfiConverterString.read(from: &buf)
+            )
 
-        default: throw UniffiInternalError.unexpectedEnumCase
+         default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: ApiError, into buf: inout [UInt8]) {
+    public static func write(_ value: StoreError, into buf: inout [UInt8]) {
         switch value {
 
         
 
         
-        case .HttpError(_ /* message is ignored*/):
-            writeInt(&buf, Int32(1))
-        case .NetworkError(_ /* message is ignored*/):
-            writeInt(&buf, Int32(2))
-        case .SerializationError(_ /* message is ignored*/):
-            writeInt(&buf, Int32(3))
-        case .DeserializationError(_ /* message is ignored*/):
-            writeInt(&buf, Int32(4))
-        case .InvalidResponse(_ /* message is ignored*/):
-            writeInt(&buf, Int32(5))
-        case .NotFound(_ /* message is ignored*/):
-            writeInt(&buf, Int32(6))
-        case .Unauthorized(_ /* message is ignored*/):
-            writeInt(&buf, Int32(7))
-        case .RateLimited(_ /* message is ignored*/):
-            writeInt(&buf, Int32(8))
-        case .ServerError(_ /* message is ignored*/):
-            writeInt(&buf, Int32(9))
-
         
+        case let .Io(reason):
+            writeInt(&buf, Int32(1))
+            FfiConverterString.write(reason, into: &buf)
+            
         }
     }
 }
@@ -1997,100 +6426,85 @@ public struct FfiConverterTypeApiError: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeApiError_lift(_ buf: RustBuffer) throws -> ApiError {
-    return try FfiConverterTypeApiError.lift(buf)
+public func FfiConverterTypeStoreError_lift(_ buf: RustBuffer) throws -> StoreError {
+    return try FfiConverterTypeStoreError.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeApiError_lower(_ value: ApiError) -> RustBuffer {
-    return FfiConverterTypeApiError.lower(value)
+public func FfiConverterTypeStoreError_lower(_ value: StoreError) -> RustBuffer {
+    return FfiConverterTypeStoreError.lower(value)
 }
 
 // Note that we don't yet support `indirect` for enums.
 // See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 
-public enum Fetchable: Equatable, Hashable {
+public enum TimeUnit: Equatable, Hashable {
     
-    case planets
-    case people
-    case films
-    case species
-    case starships
-    case vehicles
-
+    case hours
+    case days
+    case weeks
+    case months
+    case years
 
 
-public func displayName() -> String  {
-    return try!  FfiConverterString.lift(try! rustCall() {
-    uniffi_starwars_fn_method_fetchable_display_name(
-            FfiConverterTypeFetchable_lower(self),$0
-    )
-})
-}
 
 
 
 }
 
 #if compiler(>=6)
-extension Fetchable: Sendable {}
+extension TimeUnit: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeFetchable: FfiConverterRustBuffer {
-    typealias SwiftType = Fetchable
+public struct FfiConverterTypeTimeUnit: FfiConverterRustBuffer {
+    typealias SwiftType = TimeUnit
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Fetchable {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TimeUnit {
         let variant: Int32 = try readInt(&buf)
         switch variant {
         
-        case 1: return .planets
-        
-        case 2: return .people
+        case 1: return .hours
         
-        case 3: return .films
+        case 2: return .days
         
-        case 4: return .species
+        case 3: return .weeks
         
-        case 5: return .starships
+        case 4: return .months
         
-        case 6: return .vehicles
+        case 5: return .years
         
         default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: Fetchable, into buf: inout [UInt8]) {
+    public static func write(_ value: TimeUnit, into buf: inout [UInt8]) {
         switch value {
         
         
-        case .planets:
+        case .hours:
             writeInt(&buf, Int32(1))
         
         
-        case .people:
+        case .days:
             writeInt(&buf, Int32(2))
         
         
-        case .films:
+        case .weeks:
             writeInt(&buf, Int32(3))
         
         
-        case .species:
+        case .months:
             writeInt(&buf, Int32(4))
         
         
-        case .starships:
+        case .years:
             writeInt(&buf, Int32(5))
         
-        
-        case .vehicles:
-            writeInt(&buf, Int32(6))
-        
         }
     }
 }
@@ -2099,339 +6513,503 @@ public struct FfiConverterTypeFetchable: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFetchable_lift(_ buf: RustBuffer) throws -> Fetchable {
-    return try FfiConverterTypeFetchable.lift(buf)
+public func FfiConverterTypeTimeUnit_lift(_ buf: RustBuffer) throws -> TimeUnit {
+    return try FfiConverterTypeTimeUnit.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeFetchable_lower(_ value: Fetchable) -> RustBuffer {
-    return FfiConverterTypeFetchable.lower(value)
+public func FfiConverterTypeTimeUnit_lower(_ value: TimeUnit) -> RustBuffer {
+    return FfiConverterTypeTimeUnit.lower(value)
 }
 
 
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionUInt32: FfiConverterRustBuffer {
+    typealias SwiftType = UInt32?
 
-public enum ListItems: Equatable, Hashable {
-    
-    case planets([String]
-    )
-    case people([String]
-    )
-    case films([String]
-    )
-    case species([String]
-    )
-    case starships([String]
-    )
-    case vehicles([String]
-    )
-    case homeworld(String
-    )
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterUInt32.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterUInt32.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionUInt64: FfiConverterRustBuffer {
+    typealias SwiftType = UInt64?
 
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterUInt64.write(value, into: &buf)
+    }
 
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterUInt64.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionDouble: FfiConverterRustBuffer {
+    typealias SwiftType = Double?
 
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterDouble.write(value, into: &buf)
+    }
 
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterDouble.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
-#if compiler(>=6)
-extension ListItems: Sendable {}
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionString: FfiConverterRustBuffer {
+    typealias SwiftType = String?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterString.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterString.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionData: FfiConverterRustBuffer {
+    typealias SwiftType = Data?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterData.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterData.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeCancellationToken: FfiConverterRustBuffer {
+    typealias SwiftType = CancellationToken?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeCancellationToken.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeCancellationToken.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
 #endif
+fileprivate struct FfiConverterOptionTypeConsumables: FfiConverterRustBuffer {
+    typealias SwiftType = Consumables?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeConsumables.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeConsumables.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeListItems: FfiConverterRustBuffer {
-    typealias SwiftType = ListItems
+fileprivate struct FfiConverterOptionTypeFilm: FfiConverterRustBuffer {
+    typealias SwiftType = Film?
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ListItems {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        
-        case 1: return .planets(try FfiConverterSequenceString.read(from: &buf)
-        )
-        
-        case 2: return .people(try FfiConverterSequenceString.read(from: &buf)
-        )
-        
-        case 3: return .films(try FfiConverterSequenceString.read(from: &buf)
-        )
-        
-        case 4: return .species(try FfiConverterSequenceString.read(from: &buf)
-        )
-        
-        case 5: return .starships(try FfiConverterSequenceString.read(from: &buf)
-        )
-        
-        case 6: return .vehicles(try FfiConverterSequenceString.read(from: &buf)
-        )
-        
-        case 7: return .homeworld(try FfiConverterString.read(from: &buf)
-        )
-        
-        default: throw UniffiInternalError.unexpectedEnumCase
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
         }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeFilm.write(value, into: &buf)
     }
 
-    public static func write(_ value: ListItems, into buf: inout [UInt8]) {
-        switch value {
-        
-        
-        case let .planets(v1):
-            writeInt(&buf, Int32(1))
-            FfiConverterSequenceString.write(v1, into: &buf)
-            
-        
-        case let .people(v1):
-            writeInt(&buf, Int32(2))
-            FfiConverterSequenceString.write(v1, into: &buf)
-            
-        
-        case let .films(v1):
-            writeInt(&buf, Int32(3))
-            FfiConverterSequenceString.write(v1, into: &buf)
-            
-        
-        case let .species(v1):
-            writeInt(&buf, Int32(4))
-            FfiConverterSequenceString.write(v1, into: &buf)
-            
-        
-        case let .starships(v1):
-            writeInt(&buf, Int32(5))
-            FfiConverterSequenceString.write(v1, into: &buf)
-            
-        
-        case let .vehicles(v1):
-            writeInt(&buf, Int32(6))
-            FfiConverterSequenceString.write(v1, into: &buf)
-            
-        
-        case let .homeworld(v1):
-            writeInt(&buf, Int32(7))
-            FfiConverterString.write(v1, into: &buf)
-            
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeFilm.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeListItems_lift(_ buf: RustBuffer) throws -> ListItems {
-    return try FfiConverterTypeListItems.lift(buf)
+fileprivate struct FfiConverterOptionTypeGalacticYear: FfiConverterRustBuffer {
+    typealias SwiftType = GalacticYear?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeGalacticYear.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeGalacticYear.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeListItems_lower(_ value: ListItems) -> RustBuffer {
-    return FfiConverterTypeListItems.lower(value)
-}
-
-
+fileprivate struct FfiConverterOptionTypeNumericRange: FfiConverterRustBuffer {
+    typealias SwiftType = NumericRange?
 
-public enum NetworkError: Swift.Error, Equatable, Hashable, Foundation.LocalizedError {
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeNumericRange.write(value, into: &buf)
+    }
 
-    
-    
-    case RequestFailed(reason: String
-    )
-    case Timeout
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeNumericRange.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
 
-    
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypePerson: FfiConverterRustBuffer {
+    typealias SwiftType = Person?
 
-    
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypePerson.write(value, into: &buf)
+    }
 
-    
-    public var errorDescription: String? {
-        String(reflecting: self)
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypePerson.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
     }
-    
 }
 
-#if compiler(>=6)
-extension NetworkError: Sendable {}
-#endif
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeNetworkError: FfiConverterRustBuffer {
-    typealias SwiftType = NetworkError
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> NetworkError {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-
-        
+fileprivate struct FfiConverterOptionTypePlanet: FfiConverterRustBuffer {
+    typealias SwiftType = Planet?
 
-        
-        case 1: return .RequestFailed(
-            reason: try FfiConverterString.read(from: &buf)
-            )
-        case 2: return .Timeout
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypePlanet.write(value, into: &buf)
+    }
 
-         default: throw UniffiInternalError.unexpectedEnumCase
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypePlanet.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
+}
 
-    public static func write(_ value: NetworkError, into buf: inout [UInt8]) {
-        switch value {
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeSpecies: FfiConverterRustBuffer {
+    typealias SwiftType = Species?
 
-        
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeSpecies.write(value, into: &buf)
+    }
 
-        
-        
-        case let .RequestFailed(reason):
-            writeInt(&buf, Int32(1))
-            FfiConverterString.write(reason, into: &buf)
-            
-        
-        case .Timeout:
-            writeInt(&buf, Int32(2))
-        
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeSpecies.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeNetworkError_lift(_ buf: RustBuffer) throws -> NetworkError {
-    return try FfiConverterTypeNetworkError.lift(buf)
+fileprivate struct FfiConverterOptionTypeStarship: FfiConverterRustBuffer {
+    typealias SwiftType = Starship?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeStarship.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeStarship.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeNetworkError_lower(_ value: NetworkError) -> RustBuffer {
-    return FfiConverterTypeNetworkError.lower(value)
-}
+fileprivate struct FfiConverterOptionTypeVehicle: FfiConverterRustBuffer {
+    typealias SwiftType = Vehicle?
 
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeVehicle.write(value, into: &buf)
+    }
 
-public enum Selected: Equatable, Hashable {
-    
-    case film(Film
-    )
-    case person(Person
-    )
-    case planet(Planet
-    )
-    case species(Species
-    )
-    case starship(Starship
-    )
-    case vehicle(Vehicle
-    )
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeVehicle.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeResponseFormat: FfiConverterRustBuffer {
+    typealias SwiftType = ResponseFormat?
 
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeResponseFormat.write(value, into: &buf)
+    }
 
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeResponseFormat.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionSequenceTypeFilm: FfiConverterRustBuffer {
+    typealias SwiftType = [Film]?
 
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterSequenceTypeFilm.write(value, into: &buf)
+    }
 
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterSequenceTypeFilm.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
-#if compiler(>=6)
-extension Selected: Sendable {}
-#endif
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeSelected: FfiConverterRustBuffer {
-    typealias SwiftType = Selected
+fileprivate struct FfiConverterOptionSequenceTypePerson: FfiConverterRustBuffer {
+    typealias SwiftType = [Person]?
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Selected {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        
-        case 1: return .film(try FfiConverterTypeFilm.read(from: &buf)
-        )
-        
-        case 2: return .person(try FfiConverterTypePerson.read(from: &buf)
-        )
-        
-        case 3: return .planet(try FfiConverterTypePlanet.read(from: &buf)
-        )
-        
-        case 4: return .species(try FfiConverterTypeSpecies.read(from: &buf)
-        )
-        
-        case 5: return .starship(try FfiConverterTypeStarship.read(from: &buf)
-        )
-        
-        case 6: return .vehicle(try FfiConverterTypeVehicle.read(from: &buf)
-        )
-        
-        default: throw UniffiInternalError.unexpectedEnumCase
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
         }
+        writeInt(&buf, Int8(1))
+        FfiConverterSequenceTypePerson.write(value, into: &buf)
     }
 
-    public static func write(_ value: Selected, into buf: inout [UInt8]) {
-        switch value {
-        
-        
-        case let .film(v1):
-            writeInt(&buf, Int32(1))
-            FfiConverterTypeFilm.write(v1, into: &buf)
-            
-        
-        case let .person(v1):
-            writeInt(&buf, Int32(2))
-            FfiConverterTypePerson.write(v1, into: &buf)
-            
-        
-        case let .planet(v1):
-            writeInt(&buf, Int32(3))
-            FfiConverterTypePlanet.write(v1, into: &buf)
-            
-        
-        case let .species(v1):
-            writeInt(&buf, Int32(4))
-            FfiConverterTypeSpecies.write(v1, into: &buf)
-            
-        
-        case let .starship(v1):
-            writeInt(&buf, Int32(5))
-            FfiConverterTypeStarship.write(v1, into: &buf)
-            
-        
-        case let .vehicle(v1):
-            writeInt(&buf, Int32(6))
-            FfiConverterTypeVehicle.write(v1, into: &buf)
-            
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterSequenceTypePerson.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeSelected_lift(_ buf: RustBuffer) throws -> Selected {
-    return try FfiConverterTypeSelected.lift(buf)
+fileprivate struct FfiConverterOptionSequenceTypePlanet: FfiConverterRustBuffer {
+    typealias SwiftType = [Planet]?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterSequenceTypePlanet.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterSequenceTypePlanet.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeSelected_lower(_ value: Selected) -> RustBuffer {
-    return FfiConverterTypeSelected.lower(value)
-}
+fileprivate struct FfiConverterOptionSequenceTypeSpecies: FfiConverterRustBuffer {
+    typealias SwiftType = [Species]?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterSequenceTypeSpecies.write(value, into: &buf)
+    }
 
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterSequenceTypeSpecies.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionString: FfiConverterRustBuffer {
-    typealias SwiftType = String?
+fileprivate struct FfiConverterOptionSequenceTypeStarship: FfiConverterRustBuffer {
+    typealias SwiftType = [Starship]?
 
     public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
         guard let value = value else {
@@ -2439,13 +7017,13 @@ fileprivate struct FfiConverterOptionString: FfiConverterRustBuffer {
             return
         }
         writeInt(&buf, Int8(1))
-        FfiConverterString.write(value, into: &buf)
+        FfiConverterSequenceTypeStarship.write(value, into: &buf)
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
         switch try readInt(&buf) as Int8 {
         case 0: return nil
-        case 1: return try FfiConverterString.read(from: &buf)
+        case 1: return try FfiConverterSequenceTypeStarship.read(from: &buf)
         default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
@@ -2454,8 +7032,8 @@ fileprivate struct FfiConverterOptionString: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionTypePlanet: FfiConverterRustBuffer {
-    typealias SwiftType = Planet?
+fileprivate struct FfiConverterOptionSequenceTypeVehicle: FfiConverterRustBuffer {
+    typealias SwiftType = [Vehicle]?
 
     public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
         guard let value = value else {
@@ -2463,13 +7041,13 @@ fileprivate struct FfiConverterOptionTypePlanet: FfiConverterRustBuffer {
             return
         }
         writeInt(&buf, Int8(1))
-        FfiConverterTypePlanet.write(value, into: &buf)
+        FfiConverterSequenceTypeVehicle.write(value, into: &buf)
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
         switch try readInt(&buf) as Int8 {
         case 0: return nil
-        case 1: return try FfiConverterTypePlanet.read(from: &buf)
+        case 1: return try FfiConverterSequenceTypeVehicle.read(from: &buf)
         default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
@@ -2478,8 +7056,8 @@ fileprivate struct FfiConverterOptionTypePlanet: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionSequenceTypePlanet: FfiConverterRustBuffer {
-    typealias SwiftType = [Planet]?
+fileprivate struct FfiConverterOptionTypeTimestamp: FfiConverterRustBuffer {
+    typealias SwiftType = Timestamp?
 
     public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
         guard let value = value else {
@@ -2487,18 +7065,43 @@ fileprivate struct FfiConverterOptionSequenceTypePlanet: FfiConverterRustBuffer
             return
         }
         writeInt(&buf, Int8(1))
-        FfiConverterSequenceTypePlanet.write(value, into: &buf)
+        FfiConverterTypeTimestamp.write(value, into: &buf)
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
         switch try readInt(&buf) as Int8 {
         case 0: return nil
-        case 1: return try FfiConverterSequenceTypePlanet.read(from: &buf)
+        case 1: return try FfiConverterTypeTimestamp.read(from: &buf)
         default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceUInt32: FfiConverterRustBuffer {
+    typealias SwiftType = [UInt32]
+
+    public static func write(_ value: [UInt32], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterUInt32.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [UInt32] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [UInt32]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterUInt32.read(from: &buf))
+        }
+        return seq
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -2524,6 +7127,31 @@ fileprivate struct FfiConverterSequenceString: FfiConverterRustBuffer {
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeAttributeTag: FfiConverterRustBuffer {
+    typealias SwiftType = [AttributeTag]
+
+    public static func write(_ value: [AttributeTag], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeAttributeTag.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [AttributeTag] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [AttributeTag]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeAttributeTag.read(from: &buf))
+        }
+        return seq
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -2599,6 +7227,31 @@ fileprivate struct FfiConverterSequenceTypePlanet: FfiConverterRustBuffer {
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeRelatedGroup: FfiConverterRustBuffer {
+    typealias SwiftType = [RelatedGroup]
+
+    public static func write(_ value: [RelatedGroup], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeRelatedGroup.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [RelatedGroup] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [RelatedGroup]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeRelatedGroup.read(from: &buf))
+        }
+        return seq
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -2680,20 +7333,70 @@ fileprivate struct FfiConverterSequenceTypeVehicle: FfiConverterRustBuffer {
 fileprivate struct FfiConverterSequenceTypeFetchable: FfiConverterRustBuffer {
     typealias SwiftType = [Fetchable]
 
-    public static func write(_ value: [Fetchable], into buf: inout [UInt8]) {
+    public static func write(_ value: [Fetchable], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeFetchable.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Fetchable] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [Fetchable]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeFetchable.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeListItems: FfiConverterRustBuffer {
+    typealias SwiftType = [ListItems]
+
+    public static func write(_ value: [ListItems], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeListItems.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [ListItems] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [ListItems]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeListItems.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeSelected: FfiConverterRustBuffer {
+    typealias SwiftType = [Selected]
+
+    public static func write(_ value: [Selected], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
         for item in value {
-            FfiConverterTypeFetchable.write(item, into: &buf)
+            FfiConverterTypeSelected.write(item, into: &buf)
         }
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Fetchable] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Selected] {
         let len: Int32 = try readInt(&buf)
-        var seq = [Fetchable]()
+        var seq = [Selected]()
         seq.reserveCapacity(Int(len))
         for _ in 0 ..< len {
-            seq.append(try FfiConverterTypeFetchable.read(from: &buf))
+            seq.append(try FfiConverterTypeSelected.read(from: &buf))
         }
         return seq
     }
@@ -2702,27 +7405,72 @@ fileprivate struct FfiConverterSequenceTypeFetchable: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterSequenceTypeListItems: FfiConverterRustBuffer {
-    typealias SwiftType = [ListItems]
-
-    public static func write(_ value: [ListItems], into buf: inout [UInt8]) {
+fileprivate struct FfiConverterDictionaryStringString: FfiConverterRustBuffer {
+    public static func write(_ value: [String: String], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
-        for item in value {
-            FfiConverterTypeListItems.write(item, into: &buf)
+        for (key, value) in value {
+            FfiConverterString.write(key, into: &buf)
+            FfiConverterString.write(value, into: &buf)
         }
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [ListItems] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String: String] {
         let len: Int32 = try readInt(&buf)
-        var seq = [ListItems]()
-        seq.reserveCapacity(Int(len))
-        for _ in 0 ..< len {
-            seq.append(try FfiConverterTypeListItems.read(from: &buf))
+        var dict = [String: String]()
+        dict.reserveCapacity(Int(len))
+        for _ in 0..<len {
+            let key = try FfiConverterString.read(from: &buf)
+            let value = try FfiConverterString.read(from: &buf)
+            dict[key] = value
         }
-        return seq
+        return dict
+    }
+}
+
+
+/**
+ * Typealias from the type name used in the UDL file to the builtin type.  This
+ * is needed because the UDL type name is used in function/method signatures.
+ */
+public typealias Timestamp = Date
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTimestamp: FfiConverter {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Timestamp {
+        return try FfiConverterTimestamp.read(from: &buf)
+    }
+
+    public static func write(_ value: Timestamp, into buf: inout [UInt8]) {
+        return FfiConverterTimestamp.write(value, into: &buf)
+    }
+
+    public static func lift(_ value: RustBuffer) throws -> Timestamp {
+        return try FfiConverterTimestamp.lift(value)
+    }
+
+    public static func lower(_ value: Timestamp) -> RustBuffer {
+        return FfiConverterTimestamp.lower(value)
     }
 }
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTimestamp_lift(_ value: RustBuffer) throws -> Timestamp {
+    return try FfiConverterTypeTimestamp.lift(value)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTimestamp_lower(_ value: Timestamp) -> RustBuffer {
+    return FfiConverterTypeTimestamp.lower(value)
+}
+
 private let UNIFFI_RUST_FUTURE_POLL_READY: Int8 = 0
 private let UNIFFI_RUST_FUTURE_POLL_WAKE: Int8 = 1
 
@@ -2867,6 +7615,13 @@ public func allFetchable() -> [Fetchable]  {
     )
 })
 }
+public func resourceId(url: String) -> UInt32?  {
+    return try!  FfiConverterOptionUInt32.lift(try! rustCall() {
+    uniffi_starwars_fn_func_resource_id(
+        FfiConverterString.lower(url),$0
+    )
+})
+}
 public func personRelatedItems(person: Person) -> [ListItems]  {
     return try!  FfiConverterSequenceTypeListItems.lift(try! rustCall() {
     uniffi_starwars_fn_func_person_related_items(
@@ -2874,6 +7629,76 @@ public func personRelatedItems(person: Person) -> [ListItems]  {
     )
 })
 }
+public func relatedItems(selected: Selected) -> [ListItems]  {
+    return try!  FfiConverterSequenceTypeListItems.lift(try! rustCall() {
+    uniffi_starwars_fn_func_related_items(
+        FfiConverterTypeSelected_lower(selected),$0
+    )
+})
+}
+public func splitAttributes(value: String) -> [AttributeTag]  {
+    return try!  FfiConverterSequenceTypeAttributeTag.lift(try! rustCall() {
+    uniffi_starwars_fn_func_split_attributes(
+        FfiConverterString.lower(value),$0
+    )
+})
+}
+public func formatConsumables(consumables: Consumables) -> String  {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_starwars_fn_func_format_consumables(
+        FfiConverterTypeConsumables_lower(consumables),$0
+    )
+})
+}
+public func formatGalacticYear(year: GalacticYear) -> String  {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_starwars_fn_func_format_galactic_year(
+        FfiConverterTypeGalacticYear_lower(year),$0
+    )
+})
+}
+public func parseConsumables(value: String) -> Consumables?  {
+    return try!  FfiConverterOptionTypeConsumables.lift(try! rustCall() {
+    uniffi_starwars_fn_func_parse_consumables(
+        FfiConverterString.lower(value),$0
+    )
+})
+}
+public func parseGalacticYear(value: String) -> GalacticYear?  {
+    return try!  FfiConverterOptionTypeGalacticYear.lift(try! rustCall() {
+    uniffi_starwars_fn_func_parse_galactic_year(
+        FfiConverterString.lower(value),$0
+    )
+})
+}
+public func parseCount(value: String) -> UInt64?  {
+    return try!  FfiConverterOptionUInt64.lift(try! rustCall() {
+    uniffi_starwars_fn_func_parse_count(
+        FfiConverterString.lower(value),$0
+    )
+})
+}
+public func parseMeasurement(value: String) -> Double?  {
+    return try!  FfiConverterOptionDouble.lift(try! rustCall() {
+    uniffi_starwars_fn_func_parse_measurement(
+        FfiConverterString.lower(value),$0
+    )
+})
+}
+public func parseMeasurementRange(value: String) -> NumericRange?  {
+    return try!  FfiConverterOptionTypeNumericRange.lift(try! rustCall() {
+    uniffi_starwars_fn_func_parse_measurement_range(
+        FfiConverterString.lower(value),$0
+    )
+})
+}
+public func parseTimestamp(value: String) -> Timestamp?  {
+    return try!  FfiConverterOptionTypeTimestamp.lift(try! rustCall() {
+    uniffi_starwars_fn_func_parse_timestamp(
+        FfiConverterString.lower(value),$0
+    )
+})
+}
 
 private enum InitializationResult {
     case ok
@@ -2893,65 +7718,332 @@ private let initializationResult: InitializationResult = {
     if (uniffi_starwars_checksum_func_all_fetchable() != 46703) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_starwars_checksum_func_resource_id() != 25126) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_starwars_checksum_func_person_related_items() != 10831) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_starwars_checksum_method_apiclient_fetch_film() != 29515) {
+    if (uniffi_starwars_checksum_func_related_items() != 31240) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_func_split_attributes() != 15022) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_func_format_consumables() != 33977) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_func_format_galactic_year() != 63486) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_func_parse_consumables() != 35451) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_func_parse_galactic_year() != 26746) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_func_parse_count() != 29497) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_func_parse_measurement() != 42099) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_func_parse_measurement_range() != 27124) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_func_parse_timestamp() != 55381) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_apiclient_search_index() != 4199) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_apiclient_fetch_film() != 60658) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_apiclient_fetch_films() != 11553) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_apiclient_fetch_people() != 5299) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_apiclient_fetch_person() != 49080) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_apiclient_fetch_planet() != 44856) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_apiclient_fetch_planets() != 48194) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_apiclient_fetch_species() != 23546) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_apiclient_fetch_species_list() != 45700) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_apiclient_fetch_starship() != 42129) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_apiclient_fetch_starships() != 55260) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_apiclient_fetch_vehicle() != 4249) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_apiclient_fetch_vehicles() != 54121) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_apiclient_page_cursor() != 60269) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_starwars_checksum_method_apiclient_fetch_films() != 32635) {
+    if (uniffi_starwars_checksum_method_apiclient_resolve_related() != 40878) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_starwars_checksum_method_apiclient_fetch_people() != 51794) {
+    if (uniffi_starwars_checksum_method_apiclient_search() != 46047) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_starwars_checksum_method_apiclient_fetch_person() != 2190) {
+    if (uniffi_starwars_checksum_method_cancellationlistener_on_cancel() != 59954) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_starwars_checksum_method_apiclient_fetch_planet() != 64974) {
+    if (uniffi_starwars_checksum_method_cancellationtoken_cancel() != 56873) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_starwars_checksum_method_apiclient_fetch_planets() != 51131) {
+    if (uniffi_starwars_checksum_method_cancellationtoken_is_cancelled() != 19164) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_starwars_checksum_method_apiclient_fetch_species() != 60394) {
+    if (uniffi_starwars_checksum_method_cancellationtoken_on_cancel() != 51218) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_starwars_checksum_method_apiclient_fetch_species_list() != 53838) {
+    if (uniffi_starwars_checksum_method_httpclient_fetch() != 11135) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_starwars_checksum_method_apiclient_fetch_starship() != 23891) {
+    if (uniffi_starwars_checksum_method_repositoryobserver_on_change() != 51212) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_starwars_checksum_method_apiclient_fetch_starships() != 20326) {
+    if (uniffi_starwars_checksum_method_pagecursor_fetchable() != 3833) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_starwars_checksum_method_apiclient_fetch_vehicle() != 14057) {
+    if (uniffi_starwars_checksum_method_pagecursor_has_more() != 20221) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_starwars_checksum_method_apiclient_fetch_vehicles() != 18633) {
+    if (uniffi_starwars_checksum_method_pagecursor_is_loading() != 54729) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_starwars_checksum_method_httpclient_fetch() != 26818) {
+    if (uniffi_starwars_checksum_method_pagecursor_items() != 40040) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_pagecursor_load_next() != 22944) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_pagecursor_next_url() != 14462) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_pagecursor_previous_url() != 31028) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_pagecursor_total_count() != 20696) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_query_filter() != 47283) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_query_limit() != 61702) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_query_offset() != 56352) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_query_sort_by() != 6917) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_datarepository_query_films() != 65360) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_datarepository_query_people() != 60333) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_datarepository_query_planets() != 19636) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_datarepository_query_species() != 14230) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_datarepository_query_starships() != 57653) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_datarepository_query_vehicles() != 60892) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_datarepository_add_observer() != 25770) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_datarepository_clear() != 39968) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_datarepository_fetched_at() != 50862) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_datarepository_get_film() != 36261) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_datarepository_get_film_by_id() != 50942) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_datarepository_get_films() != 25713) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_datarepository_get_people() != 9680) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_datarepository_get_person() != 3133) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_datarepository_get_person_by_id() != 19029) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_starwars_checksum_method_datarepository_get_planet() != 13100) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_starwars_checksum_method_datarepository_get_planet_by_id() != 21640) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_starwars_checksum_method_datarepository_get_planets() != 37315) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_starwars_checksum_method_datarepository_insert_planet() != 38824) {
+    if (uniffi_starwars_checksum_method_datarepository_get_species() != 35573) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_datarepository_get_species_by_id() != 13785) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_datarepository_get_species_list() != 36863) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_datarepository_get_starship() != 44656) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_datarepository_get_starship_by_id() != 11129) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_datarepository_get_starships() != 62721) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_datarepository_get_vehicle() != 62509) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_datarepository_get_vehicle_by_id() != 30592) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_datarepository_get_vehicles() != 18919) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_datarepository_insert_film() != 57109) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_datarepository_insert_person() != 58312) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_datarepository_insert_planet() != 27787) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_datarepository_insert_species() != 60131) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_datarepository_insert_starship() != 15090) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_datarepository_insert_vehicle() != 36848) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_datarepository_remove() != 28792) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_datarepository_remove_observer() != 16819) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_datarepository_set_ttl() != 61573) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_datarepository_ttl() != 51912) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_searchindex_rebuild() != 26833) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_searchindex_search() != 36139) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_searchindex_search_in() != 32709) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_filestore_keys() != 15743) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_filestore_read() != 48328) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_filestore_remove() != 24318) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_filestore_write() != 48490) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_inmemorystore_keys() != 63189) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_inmemorystore_read() != 39733) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_inmemorystore_remove() != 36573) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_inmemorystore_write() != 65383) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_repositorystore_read() != 13966) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_repositorystore_write() != 55409) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_repositorystore_remove() != 25108) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_method_repositorystore_keys() != 34149) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_starwars_checksum_constructor_apiclient_new() != 50275) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_starwars_checksum_constructor_apiclient_with_config() != 58498) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_constructor_cancellationtoken_new() != 19703) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_constructor_query_new() != 3164) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_starwars_checksum_constructor_datarepository_new() != 26238) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_starwars_checksum_constructor_datarepository_with_store() != 29957) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_constructor_searchindex_new() != 26330) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_constructor_filestore_new() != 61920) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_starwars_checksum_constructor_inmemorystore_new() != 12457) {
+        return InitializationResult.apiChecksumMismatch
+    }
 
+    uniffiCallbackInitCancellationListener()
     uniffiCallbackInitHttpClient()
+    uniffiCallbackInitRepositoryObserver()
+    uniffiCallbackInitRepositoryStore()
     return InitializationResult.ok
 }()
 
@@ -4,5 +4,23 @@ pub mod repository;
 mod interface;
 pub mod http_client;
 pub mod api_client;
+pub mod cache;
+pub mod cancellation;
+pub mod client_config;
+pub mod clock;
+#[cfg(any(test, feature = "fixtures"))]
+pub mod fixtures;
+#[cfg(feature = "native-http")]
+pub mod native_http;
+pub mod observer;
+pub mod page_cursor;
+pub mod query;
+pub mod related;
+pub mod resource;
+pub mod response_format;
+pub mod retry;
+pub mod search;
+pub mod search_index;
+pub mod store;
 mod api_error;
 mod selected;
@@ -1,37 +1,729 @@
-use super::http_client::HttpClient;
-use std::sync::Arc;
+use super::http_client::{HttpClient, HttpRequest, HttpResponse, NetworkError};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use futures::executor::block_on;
+use futures::future::{self, BoxFuture, Either, FutureExt, Shared};
 use serde::de::DeserializeOwned;
+use crate::api::cache::{CachePolicy, Cached, CachedResponse};
+use crate::api::cancellation::CancellationToken;
+use crate::api::client_config::ClientConfig;
+use crate::api::clock::{Clock, SystemClock};
 use crate::api::fetchable::Fetchable;
 use crate::api::repository::DataRepository;
+use crate::api::resource::Resource;
+use crate::api::retry::RetryPolicy;
+use crate::api::search_index::SearchIndex;
+use crate::models::page::Page;
 use super::api_error::ApiError;
 
 #[derive(uniffi::Object)]
 pub struct ApiClient {
     http_client: Arc<dyn HttpClient>,
     pub repository: Arc<DataRepository>,
+    search_index: Arc<SearchIndex>,
+    config: ClientConfig,
+    clock: Arc<dyn Clock>,
+    in_flight: Mutex<HashMap<String, InFlight>>,
+}
+
+struct InFlight {
+    request: Shared<BoxFuture<'static, Result<HttpResponse, ApiError>>>,
+    // Handed to the HttpClient; cancelled only once every waiting caller has given up
+    cancellation: Arc<CancellationToken>,
+    waiters: usize,
+}
+
+// Items from a walk along a `next` chain; `complete` is false if `max_pages` cut it short
+pub struct Pages<T> {
+    pub items: Vec<T>,
+    pub complete: bool,
 }
 
 #[uniffi::export]
 impl ApiClient {
     #[uniffi::constructor]
     pub fn new(http_client: Arc<dyn HttpClient>, repository: Arc<DataRepository>) -> Self {
-        Self { http_client, repository }
+        Self::with_config(http_client, repository, ClientConfig::default())
+    }
+
+    #[uniffi::constructor]
+    pub fn with_config(
+        http_client: Arc<dyn HttpClient>,
+        repository: Arc<DataRepository>,
+        config: ClientConfig,
+    ) -> Self {
+        Self::with_clock(http_client, repository, config, Arc::new(SystemClock))
+    }
+
+    pub fn search_index(&self) -> Arc<SearchIndex> {
+        Arc::clone(&self.search_index)
     }
 }
 
 impl ApiClient {
+    pub fn with_clock(
+        http_client: Arc<dyn HttpClient>,
+        repository: Arc<DataRepository>,
+        config: ClientConfig,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        let search_index = Arc::new(SearchIndex::new(Arc::clone(&repository)));
+        Self {
+            http_client,
+            repository,
+            search_index,
+            config,
+            clock,
+            in_flight: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn config(&self) -> &ClientConfig {
+        &self.config
+    }
+
+    pub fn rebase_all<R: Resource>(&self, items: &mut [R]) {
+        for item in items {
+            let url = self.config.rebase(item.url());
+            *item.url_mut() = url;
+        }
+    }
+
     pub async fn fetch_page<T: DeserializeOwned>(&self, fetch: Fetchable) -> Result<T, ApiError> {
-        let response = self.http_client.fetch(fetch.url()).await?;
-        let object: T = serde_json::from_slice(&response)?;
-        Ok(object)
+        self.fetch_url(self.config.url(fetch), None).await
+    }
+
+    pub async fn fetch_all_pages<P: Page>(
+        &self,
+        fetch: Fetchable,
+        cancellation: Option<&CancellationToken>,
+    ) -> Result<Pages<P::Item>, ApiError> {
+        self.fetch_pages_from::<P>(self.config.url(fetch), cancellation).await
     }
 
-    pub async fn fetch_url<T: DeserializeOwned>(&self, url: String) -> Result<T, ApiError> {
+    pub async fn fetch_pages_from<P: Page>(
+        &self,
+        first_page: String,
+        cancellation: Option<&CancellationToken>,
+    ) -> Result<Pages<P::Item>, ApiError> {
+        let mut items = Vec::new();
+        let mut next = Some(first_page);
+        let mut pages = 0;
+
+        // Walk the `next` chain until SWAPI runs out of pages or we hit the configured cap
+        while let Some(url) = next {
+            if pages >= self.config.max_pages {
+                return Ok(Pages { items, complete: false });
+            }
+            let page = self.fetch_url::<P>(url, cancellation).await?;
+            pages += 1;
+            next = page.next().map(|url| self.config.rebase(url));
+            items.extend(page.into_results());
+        }
+        Ok(Pages { items, complete: true })
+    }
+
+    // Background revalidation outlives the caller, so it never inherits the caller's token
+    pub async fn fetch_resources<R: Resource>(
+        self: &Arc<Self>,
+        policy: CachePolicy,
+        cancellation: Option<&CancellationToken>,
+    ) -> Result<Vec<R>, ApiError> {
+        let cached = self.repository.lookup_all::<R>();
+        let client = Arc::clone(self);
+        let network = self.download_resources::<R>(cancellation);
+        self.apply_policy(policy, cached, network, move || async move {
+            let _ = client.download_resources::<R>(None).await;
+        })
+        .await
+    }
+
+    pub async fn fetch_resource<R: Resource>(
+        self: &Arc<Self>,
+        url: String,
+        policy: CachePolicy,
+        cancellation: Option<&CancellationToken>,
+    ) -> Result<R, ApiError> {
+        let url = self.config.rebase(&url);
+        let cached = self.repository.lookup::<R>(url.as_str());
+        let client = Arc::clone(self);
+        let refresh_url = url.clone();
+        let network = self.download_resource::<R>(url, cancellation);
+        self.apply_policy(policy, cached, network, move || async move {
+            let _ = client.download_resource::<R>(refresh_url, None).await;
+        })
+        .await
+    }
+
+    async fn download_resources<R: Resource>(
+        &self,
+        cancellation: Option<&CancellationToken>,
+    ) -> Result<Vec<R>, ApiError> {
+        let Pages { mut items, complete } =
+            self.fetch_all_pages::<R::Page>(R::FETCHABLE, cancellation).await?;
+        ensure_active(cancellation)?;
+        self.rebase_all(&mut items);
+        // A truncated listing mustn't count as the full collection, or CacheFirst would keep
+        // serving it as if nothing were missing
+        if complete {
+            self.repository.insert_collection(&items)?;
+        } else {
+            self.repository.insert_all(&items)?;
+        }
+        Ok(items)
+    }
+
+    async fn download_resource<R: Resource>(
+        &self,
+        url: String,
+        cancellation: Option<&CancellationToken>,
+    ) -> Result<R, ApiError> {
+        let mut item = self.fetch_url::<R>(url, cancellation).await?;
+        ensure_active(cancellation)?;
+        self.rebase_all(std::slice::from_mut(&mut item));
+        self.repository.insert(&item)?;
+        Ok(item)
+    }
+
+    async fn apply_policy<T, Refresh, Revalidate>(
+        &self,
+        policy: CachePolicy,
+        cached: Option<Cached<T>>,
+        network: impl Future<Output = Result<T, ApiError>>,
+        revalidate: Revalidate,
+    ) -> Result<T, ApiError>
+    where
+        Revalidate: FnOnce() -> Refresh + Send + 'static,
+        Refresh: Future<Output = ()>,
+    {
+        match (policy, cached) {
+            (CachePolicy::NetworkOnly, _) => network.await,
+            (CachePolicy::CacheOnly, Some(cached)) => Ok(cached.value),
+            (CachePolicy::CacheOnly, None) => Err(ApiError::CacheMiss),
+            (CachePolicy::CacheFirst, Some(cached)) if cached.is_fresh => Ok(cached.value),
+            (CachePolicy::CacheFirst, _) => network.await,
+            (CachePolicy::NetworkFirst, cached) => match network.await {
+                Ok(value) => Ok(value),
+                Err(ApiError::Cancelled) => Err(ApiError::Cancelled),
+                Err(error) => cached.map(|cached| cached.value).ok_or(error),
+            },
+            (CachePolicy::StaleWhileRevalidate, Some(cached)) => {
+                if !cached.is_fresh {
+                    // There's no async runtime on the Rust side, so the refresh gets its own
+                    // thread; failures are dropped and the stale value stays in place.
+                    thread::spawn(move || block_on(revalidate()));
+                }
+                Ok(cached.value)
+            }
+            (CachePolicy::StaleWhileRevalidate, None) => network.await,
+        }
+    }
+
+    pub async fn fetch_url<T: DeserializeOwned>(
+        &self,
+        url: String,
+        cancellation: Option<&CancellationToken>,
+    ) -> Result<T, ApiError> {
+        let response = self.send(self.config.format().apply(&url), cancellation).await?;
+        self.config.format().decode(&response.body)
+    }
+
+    // Concurrent callers for the same URL share a single request (retries included) and all
+    // receive its outcome. A caller that cancels stops waiting straight away, but the request
+    // itself is only cancelled once nobody else is waiting on it.
+    async fn send(
+        &self,
+        url: String,
+        cancellation: Option<&CancellationToken>,
+    ) -> Result<HttpResponse, ApiError> {
+        ensure_active(cancellation)?;
+        let request = {
+            let mut in_flight = self.in_flight.lock().unwrap();
+            let entry = in_flight.entry(url.clone()).or_insert_with(|| {
+                let cancellation = Arc::new(CancellationToken::new());
+                let request = send_with_retry(
+                    Arc::clone(&self.http_client),
+                    Arc::clone(&self.clock),
+                    Arc::clone(&self.repository),
+                    self.config.retry.clone(),
+                    HttpRequest {
+                        url: url.clone(),
+                        headers: HashMap::new(),
+                        cancellation: Arc::clone(&cancellation),
+                    },
+                );
+                InFlight { request: request.boxed().shared(), cancellation, waiters: 0 }
+            });
+            entry.waiters += 1;
+            entry.request.clone()
+        };
+        let mut waiter = Waiter { client: self, url, request: request.clone(), finished: false };
+
+        let result = match cancellation {
+            Some(cancellation) => match future::select(request, cancellation.cancelled()).await {
+                Either::Left((result, _)) => result,
+                Either::Right(_) => return Err(ApiError::Cancelled),
+            },
+            None => request.await,
+        };
+        waiter.finished = true;
+        result
+    }
+}
+
+// Keeps the in-flight entry's waiter count honest, including when the caller's future is
+// dropped mid-request (e.g. a Swift task or Kotlin coroutine being cancelled).
+struct Waiter<'a> {
+    client: &'a ApiClient,
+    url: String,
+    request: Shared<BoxFuture<'static, Result<HttpResponse, ApiError>>>,
+    finished: bool,
+}
+
+impl Drop for Waiter<'_> {
+    fn drop(&mut self) {
+        let mut in_flight = self.client.in_flight.lock().unwrap();
+        // A newer request may already have taken the finished one's place
+        let Some(entry) = in_flight.get_mut(&self.url) else {
+            return;
+        };
+        if !entry.request.ptr_eq(&self.request) {
+            return;
+        }
+        entry.waiters -= 1;
+        if self.finished {
+            in_flight.remove(&self.url);
+        } else if entry.waiters == 0 {
+            entry.cancellation.cancel();
+            in_flight.remove(&self.url);
+        }
+    }
+}
+
+pub(crate) fn ensure_active(cancellation: Option<&CancellationToken>) -> Result<(), ApiError> {
+    match cancellation {
+        Some(cancellation) if cancellation.is_cancelled() => Err(ApiError::Cancelled),
+        _ => Ok(()),
+    }
+}
+
+async fn send_with_retry(
+    http_client: Arc<dyn HttpClient>,
+    clock: Arc<dyn Clock>,
+    repository: Arc<DataRepository>,
+    retry: RetryPolicy,
+    mut request: HttpRequest,
+) -> Result<HttpResponse, ApiError> {
+    // With a stored body the request goes out conditionally, and a 304 is answered from the
+    // store; callers just see the cached body again and re-insert it, which only bumps freshness.
+    let mut cached = repository.cached_response(&request.url);
+    if let Some(cached) = &cached {
+        request.headers.extend(cached.conditional_headers());
+    }
+
+    let mut attempt = 1;
+    loop {
         // Call the foreign-implemented HTTP client
-        let response = self.http_client.fetch(url).await?;
+        let (error, retry_after) = match http_client.fetch(request.clone()).await {
+            Ok(response) if response.is_not_modified() => {
+                return match cached.take() {
+                    Some(cached) => Ok(cached.into_response(response)),
+                    None => Err(ApiError::from_response(&response)),
+                };
+            }
+            Ok(response) if response.is_success() => {
+                if let Some(cached) = CachedResponse::from_response(&request.url, &response) {
+                    // Losing a validator only costs a full download next time
+                    let _ = repository.save_response(&cached);
+                }
+                return Ok(response);
+            }
+            Ok(response) if response.is_transient() => {
+                (ApiError::from_response(&response), response.retry_after())
+            }
+            Ok(response) => return Err(ApiError::from_response(&response)),
+            Err(NetworkError::Timeout) => (NetworkError::Timeout.into(), None),
+            Err(NetworkError::Cancelled) => return Err(ApiError::Cancelled),
+            Err(error) => return Err(error.into()),
+        };
 
-        // Deserialize with serde
-        let object: T = serde_json::from_slice(&response)?;
-        Ok(object)
+        let Some(delay) = retry.delay_after(attempt, retry_after) else {
+            return Err(error);
+        };
+        clock.sleep(delay).await;
+        attempt += 1;
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use std::time::Duration;
+    use futures::channel::oneshot;
+    use futures::join;
+    use crate::api::fixtures::replay_client_with_config;
+    use crate::api::store::InMemoryStore;
+    use crate::models::people::Person;
+    use super::*;
+
+    const URL: &str = "https://swapi.dev/api/people/1/";
+
+    // Answers requests from a script, keeping the requests it was handed
+    struct ScriptedHttpClient {
+        responses: Mutex<VecDeque<HttpResponse>>,
+        requests: Mutex<Vec<HttpRequest>>,
+    }
+
+    impl ScriptedHttpClient {
+        fn new(responses: impl IntoIterator<Item = HttpResponse>) -> Arc<Self> {
+            Arc::new(Self {
+                responses: Mutex::new(responses.into_iter().collect()),
+                requests: Mutex::new(Vec::new()),
+            })
+        }
+
+        fn requests(&self) -> usize {
+            self.requests.lock().unwrap().len()
+        }
+
+        fn last_request(&self) -> HttpRequest {
+            self.requests.lock().unwrap().last().cloned().expect("no requests were made")
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl HttpClient for ScriptedHttpClient {
+        async fn fetch(&self, request: HttpRequest) -> Result<HttpResponse, NetworkError> {
+            self.requests.lock().unwrap().push(request);
+            Ok(self.responses.lock().unwrap().pop_front().expect("ran out of scripted responses"))
+        }
+    }
+
+    // Holds every response until `release` is called, keeping the requests it was handed
+    struct GatedHttpClient {
+        response: HttpResponse,
+        gate: Shared<oneshot::Receiver<()>>,
+        release: Mutex<Option<oneshot::Sender<()>>>,
+        requests: Mutex<Vec<HttpRequest>>,
+    }
+
+    impl GatedHttpClient {
+        fn new(response: HttpResponse) -> Arc<Self> {
+            let (release, gate) = oneshot::channel();
+            Arc::new(Self {
+                response,
+                gate: gate.shared(),
+                release: Mutex::new(Some(release)),
+                requests: Mutex::new(Vec::new()),
+            })
+        }
+
+        fn release(&self) {
+            if let Some(release) = self.release.lock().unwrap().take() {
+                let _ = release.send(());
+            }
+        }
+
+        fn requests(&self) -> Vec<HttpRequest> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl HttpClient for GatedHttpClient {
+        async fn fetch(&self, request: HttpRequest) -> Result<HttpResponse, NetworkError> {
+            self.requests.lock().unwrap().push(request);
+            let _ = self.gate.clone().await;
+            Ok(self.response.clone())
+        }
+    }
+
+    fn gated_client(http_client: Arc<GatedHttpClient>) -> Arc<ApiClient> {
+        Arc::new(ApiClient::new(http_client, Arc::new(DataRepository::new())))
+    }
+
+    fn fetch_luke(
+        client: &Arc<ApiClient>,
+        cancellation: Option<&CancellationToken>,
+    ) -> impl Future<Output = Result<Person, ApiError>> {
+        client.fetch_resource::<Person>(URL.to_owned(), CachePolicy::NetworkOnly, cancellation)
+    }
+
+    // Returns immediately, remembering what it was asked to sleep for
+    #[derive(Default)]
+    struct RecordingClock {
+        sleeps: Mutex<Vec<Duration>>,
+    }
+
+    #[async_trait::async_trait]
+    impl Clock for RecordingClock {
+        async fn sleep(&self, duration: Duration) {
+            self.sleeps.lock().unwrap().push(duration);
+        }
+    }
+
+    // A 200 carries Luke's recorded record; anything else has an empty body
+    fn response(status: u16, headers: &[(&str, &str)]) -> HttpResponse {
+        let body = match status {
+            200 => {
+                let fixture =
+                    include_str!("../../tests/fixtures/swapi/swapi_dev_api_people_1-f83e62a1d294e0a9.json");
+                let fixture = serde_json::from_str::<serde_json::Value>(fixture).unwrap();
+                fixture["body"].as_str().unwrap().as_bytes().to_vec()
+            }
+            _ => Vec::new(),
+        };
+        HttpResponse {
+            status,
+            headers: headers.iter().map(|(name, value)| (name.to_string(), value.to_string())).collect(),
+            body,
+        }
+    }
+
+    fn client(
+        http_client: Arc<ScriptedHttpClient>,
+        clock: Arc<RecordingClock>,
+        retry: RetryPolicy,
+    ) -> Arc<ApiClient> {
+        let config = ClientConfig {
+            retry,
+            ..ClientConfig::default()
+        };
+        Arc::new(ApiClient::with_clock(http_client, Arc::new(DataRepository::new()), config, clock))
+    }
+
+    fn policy(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts,
+            base_delay_ms: 500,
+            max_delay_ms: 10_000,
+            jitter: 0.0,
+        }
+    }
+
+    fn fetch_person(client: &Arc<ApiClient>) -> Result<Person, ApiError> {
+        block_on(client.fetch_resource::<Person>(URL.to_owned(), CachePolicy::NetworkOnly, None))
+    }
+
+    #[test]
+    fn concurrent_fetches_of_one_url_share_a_request() {
+        let http_client = GatedHttpClient::new(response(200, &[]));
+        let client = gated_client(http_client.clone());
+
+        let (first, second, ()) = block_on(async {
+            join!(fetch_luke(&client, None), fetch_luke(&client, None), async { http_client.release() })
+        });
+
+        assert_eq!(first.unwrap().name, "Luke Skywalker");
+        assert_eq!(second.unwrap().name, "Luke Skywalker");
+        assert_eq!(http_client.requests().len(), 1);
+        // Once settled, the next fetch goes out afresh rather than reusing the old result
+        assert!(client.in_flight.lock().unwrap().is_empty());
+        assert!(block_on(fetch_luke(&client, None)).is_ok());
+        assert_eq!(http_client.requests().len(), 2);
+    }
+
+    #[test]
+    fn cancelling_mid_request_cancels_the_transfer() {
+        let http_client = GatedHttpClient::new(response(200, &[]));
+        let client = gated_client(http_client.clone());
+        let cancellation = CancellationToken::new();
+
+        let (result, ()) = block_on(async {
+            join!(fetch_luke(&client, Some(&cancellation)), async { cancellation.cancel() })
+        });
+
+        assert!(matches!(result, Err(ApiError::Cancelled)));
+        // Nobody else was waiting, so the HttpClient is told to stop too
+        assert!(http_client.requests()[0].cancellation.is_cancelled());
+        assert!(client.repository.get_person_by_id(1).is_none());
+    }
+
+    #[test]
+    fn a_response_landing_after_cancelling_is_not_stored() {
+        let http_client = GatedHttpClient::new(response(200, &[]));
+        let client = gated_client(http_client.clone());
+        let cancellation = CancellationToken::new();
+
+        let (result, ()) = block_on(async {
+            join!(fetch_luke(&client, Some(&cancellation)), async {
+                http_client.release();
+                cancellation.cancel();
+            })
+        });
+
+        assert!(matches!(result, Err(ApiError::Cancelled)));
+        assert!(client.repository.get_person_by_id(1).is_none());
+    }
+
+    #[test]
+    fn one_caller_cancelling_leaves_the_shared_request_running() {
+        let http_client = GatedHttpClient::new(response(200, &[]));
+        let client = gated_client(http_client.clone());
+        let cancellation = CancellationToken::new();
+
+        let (cancelled, kept, ()) = block_on(async {
+            join!(fetch_luke(&client, Some(&cancellation)), fetch_luke(&client, None), async {
+                cancellation.cancel();
+                http_client.release();
+            })
+        });
+
+        assert!(matches!(cancelled, Err(ApiError::Cancelled)));
+        assert_eq!(kept.unwrap().name, "Luke Skywalker");
+        let requests = http_client.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(!requests[0].cancellation.is_cancelled());
+        assert!(client.repository.get_person_by_id(1).is_some());
+    }
+
+    // A client whose repository already holds Luke under another name, fetched long ago
+    fn client_with_stale_luke(http_client: Arc<ScriptedHttpClient>) -> (Arc<ApiClient>, Person) {
+        let client = Arc::new(ApiClient::new(http_client, Arc::new(DataRepository::new())));
+        let stale = Person {
+            name: String::from("Luke Starkiller"),
+            ..serde_json::from_slice(&response(200, &[]).body).unwrap()
+        };
+        client.repository.insert(&stale).unwrap();
+        client.repository.people.lock().unwrap().entries.get_mut(&1).unwrap().fetched_at = 0;
+        (client, stale)
+    }
+
+    fn fetch_luke_with(client: &Arc<ApiClient>, policy: CachePolicy) -> Result<Person, ApiError> {
+        block_on(client.fetch_resource::<Person>(URL.to_owned(), policy, None))
+    }
+
+    #[test]
+    fn cache_only_misses_without_touching_the_network() {
+        let http_client = ScriptedHttpClient::new([]);
+        let client = Arc::new(ApiClient::new(http_client.clone(), Arc::new(DataRepository::new())));
+
+        assert!(matches!(fetch_luke_with(&client, CachePolicy::CacheOnly), Err(ApiError::CacheMiss)));
+        assert_eq!(http_client.requests(), 0);
+    }
+
+    #[test]
+    fn cache_first_refetches_stale_records() {
+        let (client, _) = client_with_stale_luke(ScriptedHttpClient::new([response(200, &[])]));
+        assert_eq!(fetch_luke_with(&client, CachePolicy::CacheFirst).unwrap().name, "Luke Skywalker");
+        // Now fresh, so answered from the cache (the script has nothing left to send)
+        assert_eq!(fetch_luke_with(&client, CachePolicy::CacheFirst).unwrap().name, "Luke Skywalker");
+    }
+
+    #[test]
+    fn network_first_falls_back_to_the_cache() {
+        let retry = RetryPolicy { max_attempts: 1, ..RetryPolicy::default() };
+        let http_client = ScriptedHttpClient::new([response(503, &[])]);
+        let (client, stale) = client_with_stale_luke(http_client.clone());
+        let client = Arc::new(ApiClient::with_config(
+            http_client,
+            Arc::clone(&client.repository),
+            ClientConfig { retry, ..ClientConfig::default() },
+        ));
+
+        assert_eq!(fetch_luke_with(&client, CachePolicy::NetworkFirst).unwrap(), stale);
+    }
+
+    #[test]
+    fn stale_while_revalidate_answers_at_once_and_refreshes_behind() {
+        let (client, stale) = client_with_stale_luke(ScriptedHttpClient::new([response(200, &[])]));
+        assert_eq!(fetch_luke_with(&client, CachePolicy::StaleWhileRevalidate).unwrap(), stale);
+
+        // The refresh runs on its own thread
+        for _ in 0..200 {
+            if client.repository.get_person_by_id(1).unwrap().name == "Luke Skywalker" {
+                return;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        panic!("the stale record was never refreshed");
+    }
+
+    #[test]
+    fn not_modified_answers_from_the_stored_response() {
+        let http_client =
+            ScriptedHttpClient::new([response(200, &[("ETag", "\"v1\"")]), response(304, &[])]);
+        let repository = Arc::new(DataRepository::with_store(Arc::new(InMemoryStore::new())).unwrap());
+        let client = Arc::new(ApiClient::new(http_client.clone(), repository));
+
+        let first = block_on(fetch_luke(&client, None)).unwrap();
+        assert!(!http_client.last_request().headers.contains_key("If-None-Match"));
+        client.repository.people.lock().unwrap().entries.get_mut(&1).unwrap().fetched_at = 0;
+
+        let second = block_on(fetch_luke(&client, None)).unwrap();
+        let revalidation = http_client.last_request();
+        assert_eq!(revalidation.headers.get("If-None-Match").map(String::as_str), Some("\"v1\""));
+        assert_eq!(second, first);
+        // The 304 still counts as a fresh fetch of the record
+        assert!(client.repository.fetched_at(Fetchable::People, URL).unwrap() > 0);
+    }
+
+    #[test]
+    fn truncated_listing_is_not_cached_as_complete() {
+        let config = ClientConfig {
+            max_pages: 1,
+            ..ClientConfig::default()
+        };
+        let client = replay_client_with_config(config);
+        let people = block_on(client.fetch_resources::<Person>(CachePolicy::NetworkOnly, None)).unwrap();
+
+        assert_eq!(people.len(), 2);
+        let cached = client.repository.lookup_all::<Person>().unwrap();
+        assert_eq!(cached.value.len(), 2);
+        assert!(!cached.is_fresh);
+    }
+
+    #[test]
+    fn backs_off_exponentially_between_attempts() {
+        let http_client = ScriptedHttpClient::new([
+            response(503, &[]),
+            response(503, &[]),
+            response(503, &[]),
+            response(200, &[]),
+        ]);
+        let clock = Arc::new(RecordingClock::default());
+        let client = client(http_client.clone(), clock.clone(), policy(4));
+
+        assert_eq!(fetch_person(&client).unwrap().name, "Luke Skywalker");
+        assert_eq!(
+            *clock.sleeps.lock().unwrap(),
+            [Duration::from_millis(500), Duration::from_millis(1000), Duration::from_millis(2000)]
+        );
+        assert_eq!(http_client.requests(), 4);
+    }
+
+    #[test]
+    fn waits_as_long_as_retry_after_asks() {
+        let http_client =
+            ScriptedHttpClient::new([response(429, &[("Retry-After", "3")]), response(200, &[])]);
+        let clock = Arc::new(RecordingClock::default());
+        let client = client(http_client.clone(), clock.clone(), policy(3));
+
+        assert!(fetch_person(&client).is_ok());
+        assert_eq!(*clock.sleeps.lock().unwrap(), [Duration::from_secs(3)]);
+    }
+
+    #[test]
+    fn gives_up_after_max_attempts() {
+        let http_client =
+            ScriptedHttpClient::new([response(503, &[]), response(503, &[]), response(503, &[])]);
+        let clock = Arc::new(RecordingClock::default());
+        let client = client(http_client.clone(), clock.clone(), policy(3));
+
+        assert!(matches!(fetch_person(&client), Err(ApiError::ServerError { .. })));
+        assert_eq!(http_client.requests(), 3);
+        assert_eq!(clock.sleeps.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn gives_up_when_retry_after_exceeds_max_delay() {
+        let http_client = ScriptedHttpClient::new([response(429, &[("Retry-After", "60")])]);
+        let clock = Arc::new(RecordingClock::default());
+        let client = client(http_client.clone(), clock.clone(), policy(3));
+
+        assert!(matches!(fetch_person(&client), Err(ApiError::RateLimited)));
+        assert_eq!(http_client.requests(), 1);
+        assert!(clock.sleeps.lock().unwrap().is_empty());
+    }
+}
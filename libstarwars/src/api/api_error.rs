@@ -1,7 +1,8 @@
 use thiserror::Error;
-use crate::api::http_client::NetworkError;
+use crate::api::http_client::{HttpResponse, NetworkError};
+use crate::api::store::StoreError;
 
-#[derive(Debug, Error, uniffi::Error)]
+#[derive(Debug, Clone, Error, uniffi::Error)]
 #[uniffi(flat_error)]
 pub enum ApiError {
     #[error("HTTP error: status {status}")]
@@ -28,8 +29,17 @@ pub enum ApiError {
     #[error("Rate limited")]
     RateLimited,
 
+    #[error("Not available in cache")]
+    CacheMiss,
+
     #[error("Server error: {message}")]
     ServerError { message: String },
+
+    #[error("Storage error: {0}")]
+    StorageError(#[from] StoreError),
+
+    #[error("Cancelled")]
+    Cancelled,
 }
 
 // Implement From for serde_json errors
@@ -45,4 +55,18 @@ impl From<serde_json::Error> for ApiError {
             }
         }
     }
-}
\ No newline at end of file
+}
+
+impl ApiError {
+    pub fn from_response(response: &HttpResponse) -> Self {
+        match response.status {
+            401 | 403 => ApiError::Unauthorized,
+            404 => ApiError::NotFound,
+            429 => ApiError::RateLimited,
+            status @ 500..=599 => ApiError::ServerError {
+                message: format!("status {status}"),
+            },
+            status => ApiError::HttpError { status },
+        }
+    }
+}
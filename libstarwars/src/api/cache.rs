@@ -0,0 +1,100 @@
+use std::collections::{BTreeMap, HashMap};
+use std::time::{SystemTime, UNIX_EPOCH};
+use serde::{Deserialize, Serialize};
+use crate::api::http_client::HttpResponse;
+
+pub const DEFAULT_TTL_SECONDS: u64 = 24 * 60 * 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum CachePolicy {
+    CacheFirst,
+    NetworkFirst,
+    CacheOnly,
+    NetworkOnly,
+    StaleWhileRevalidate,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheEntry<R> {
+    pub value: R,
+    pub fetched_at: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CacheTable<R> {
+    // When the whole collection was last downloaded; individual fetches don't count.
+    pub listed_at: Option<u64>,
+    // Keyed by SWAPI id, which also keeps iteration in natural (1, 2, ..., 10) order.
+    pub entries: BTreeMap<u32, CacheEntry<R>>,
+}
+
+impl<R> Default for CacheTable<R> {
+    fn default() -> Self {
+        Self {
+            listed_at: None,
+            entries: BTreeMap::new(),
+        }
+    }
+}
+
+// The last successful body for a URL together with its validators, so a 304 can be answered
+// without downloading the body again.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedResponse {
+    pub url: String,
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+    pub body: String,
+}
+
+impl CachedResponse {
+    // Only responses the server can revalidate are worth keeping
+    pub fn from_response(url: &str, response: &HttpResponse) -> Option<Self> {
+        let etag = response.header("ETag").map(str::to_owned);
+        let last_modified = response.header("Last-Modified").map(str::to_owned);
+        if etag.is_none() && last_modified.is_none() {
+            return None;
+        }
+        Some(Self {
+            url: url.to_owned(),
+            etag,
+            last_modified,
+            body: String::from_utf8(response.body.clone()).ok()?,
+        })
+    }
+
+    pub fn conditional_headers(&self) -> HashMap<String, String> {
+        let mut headers = HashMap::new();
+        if let Some(etag) = &self.etag {
+            headers.insert(String::from("If-None-Match"), etag.clone());
+        }
+        if let Some(last_modified) = &self.last_modified {
+            headers.insert(String::from("If-Modified-Since"), last_modified.clone());
+        }
+        headers
+    }
+
+    // Answers a 304 with the stored body, keeping the fresh response's headers
+    pub fn into_response(self, not_modified: HttpResponse) -> HttpResponse {
+        HttpResponse {
+            status: 200,
+            headers: not_modified.headers,
+            body: self.body.into_bytes(),
+        }
+    }
+}
+
+pub struct Cached<T> {
+    pub value: T,
+    pub is_fresh: bool,
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+pub fn is_fresh(fetched_at: u64, ttl_seconds: u64) -> bool {
+    now().saturating_sub(fetched_at) < ttl_seconds
+}
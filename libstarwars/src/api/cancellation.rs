@@ -0,0 +1,131 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+// Hosts create one per screen (or task) and cancel it when the user navigates away. Fetches
+// given the token stop with `ApiError::Cancelled` and leave the repository untouched.
+// Lets an HttpClient abort its transfer (cancel a URLSession task, disconnect a connection)
+// the moment a request is cancelled, rather than polling `is_cancelled`.
+#[uniffi::export(with_foreign)]
+pub trait CancellationListener: Send + Sync {
+    fn on_cancel(&self);
+}
+
+#[derive(Default, uniffi::Object)]
+pub struct CancellationToken {
+    state: Mutex<State>,
+}
+
+#[derive(Default)]
+struct State {
+    cancelled: bool,
+    wakers: Vec<Waker>,
+    listeners: Vec<Arc<dyn CancellationListener>>,
+}
+
+impl std::fmt::Debug for CancellationToken {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter
+            .debug_struct("CancellationToken")
+            .field("cancelled", &self.is_cancelled())
+            .finish()
+    }
+}
+
+#[uniffi::export]
+impl CancellationToken {
+    #[uniffi::constructor]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        let (wakers, listeners) = {
+            let mut state = self.state.lock().unwrap();
+            if state.cancelled {
+                return;
+            }
+            state.cancelled = true;
+            (std::mem::take(&mut state.wakers), std::mem::take(&mut state.listeners))
+        };
+        for waker in wakers {
+            waker.wake();
+        }
+        for listener in listeners {
+            listener.on_cancel();
+        }
+    }
+
+    // Called at most once; straight away if the token is already cancelled
+    pub fn on_cancel(&self, listener: Arc<dyn CancellationListener>) {
+        {
+            let mut state = self.state.lock().unwrap();
+            if !state.cancelled {
+                state.listeners.push(listener);
+                return;
+            }
+        }
+        listener.on_cancel();
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.state.lock().unwrap().cancelled
+    }
+}
+
+impl CancellationToken {
+    // Resolves once `cancel` has been called
+    pub fn cancelled(&self) -> Cancelled<'_> {
+        Cancelled { token: self }
+    }
+}
+
+pub struct Cancelled<'a> {
+    token: &'a CancellationToken,
+}
+
+impl Future for Cancelled<'_> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<()> {
+        let mut state = self.token.state.lock().unwrap();
+        if state.cancelled {
+            return Poll::Ready(());
+        }
+        if !state.wakers.iter().any(|waker| waker.will_wake(context.waker())) {
+            state.wakers.push(context.waker().clone());
+        }
+        Poll::Pending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use super::*;
+
+    #[derive(Default)]
+    struct CountingListener(AtomicU32);
+
+    impl CancellationListener for CountingListener {
+        fn on_cancel(&self) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    #[test]
+    fn tells_listeners_once_whenever_they_registered() {
+        let token = CancellationToken::new();
+        let early = Arc::new(CountingListener::default());
+        token.on_cancel(early.clone());
+        token.cancel();
+        token.cancel();
+
+        let late = Arc::new(CountingListener::default());
+        token.on_cancel(late.clone());
+
+        assert_eq!(early.0.load(Ordering::Relaxed), 1);
+        assert_eq!(late.0.load(Ordering::Relaxed), 1);
+    }
+}
@@ -0,0 +1,107 @@
+use crate::api::fetchable::Fetchable;
+use crate::api::response_format::ResponseFormat;
+use crate::api::retry::RetryPolicy;
+
+const DEFAULT_BASE_URL: &str = "https://swapi.dev/api/";
+const DEFAULT_MAX_PAGES: u32 = 50;
+const DEFAULT_MAX_CONCURRENT_REQUESTS: u32 = 6;
+const API_SEGMENT: &str = "/api/";
+
+// `base_url` can point at any host serving SWAPI's own response shape (swapi.dev, swapi.py4e.com,
+// a local copy). swapi.tech is not one of them: it wraps each record in `result.properties` and
+// pages differently, so its responses fail to decode.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct ClientConfig {
+    #[uniffi(default = "https://swapi.dev/api/")]
+    pub base_url: String,
+    #[uniffi(default = 50)]
+    pub max_pages: u32,
+    #[uniffi(default = 6)]
+    pub max_concurrent_requests: u32,
+    #[uniffi(default)]
+    pub retry: RetryPolicy,
+    // Records are cached in the format they were fetched in, so switching formats should come
+    // with its own repository.
+    // Optional only so hosts can leave it out (UniFFI can't default an enum field); none is JSON
+    #[uniffi(default)]
+    pub format: Option<ResponseFormat>,
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        Self {
+            base_url: DEFAULT_BASE_URL.to_owned(),
+            max_pages: DEFAULT_MAX_PAGES,
+            max_concurrent_requests: DEFAULT_MAX_CONCURRENT_REQUESTS,
+            retry: RetryPolicy::default(),
+            format: None,
+        }
+    }
+}
+
+impl ClientConfig {
+    pub fn format(&self) -> ResponseFormat {
+        self.format.unwrap_or(ResponseFormat::Json)
+    }
+
+    pub fn url(&self, fetchable: Fetchable) -> String {
+        fetchable.url(&self.base_url)
+    }
+
+    // Points a SWAPI resource URL at the configured host, so links embedded in responses from
+    // one mirror (e.g. `https://swapi.dev/api/people/1/`) resolve and cache against another.
+    pub fn rebase(&self, url: &str) -> String {
+        match url.find(API_SEGMENT) {
+            Some(index) => format!(
+                "{}/{}",
+                self.base_url.trim_end_matches('/'),
+                &url[index + API_SEGMENT.len()..]
+            ),
+            None => url.to_owned(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(base_url: &str) -> ClientConfig {
+        ClientConfig {
+            base_url: base_url.to_owned(),
+            ..ClientConfig::default()
+        }
+    }
+
+    #[test]
+    fn rebases_links_onto_the_configured_host() {
+        let mirror = config("https://swapi.py4e.com/api/");
+        let rebased = mirror.rebase("https://swapi.dev/api/people/1/");
+        assert_eq!(rebased, "https://swapi.py4e.com/api/people/1/");
+        assert_eq!(
+            mirror.rebase("https://swapi.dev/api/planets/?page=2&format=wookiee"),
+            "https://swapi.py4e.com/api/planets/?page=2&format=wookiee",
+        );
+        // With or without a trailing slash on the base
+        let local = config("http://localhost:8000/api");
+        assert_eq!(local.rebase("https://swapi.dev/api/films/1/"), "http://localhost:8000/api/films/1/");
+        assert_eq!(local.url(Fetchable::Films), "http://localhost:8000/api/films/");
+    }
+
+    #[test]
+    fn leaves_other_links_alone() {
+        let mirror = config("https://swapi.py4e.com/api/");
+        assert_eq!(mirror.rebase("https://example.com/people/1/"), "https://example.com/people/1/");
+        assert_eq!(mirror.rebase(""), "");
+    }
+
+    #[test]
+    fn defaults_to_json() {
+        assert_eq!(ClientConfig::default().format(), ResponseFormat::Json);
+        let wookiee = ClientConfig {
+            format: Some(ResponseFormat::Wookiee),
+            ..ClientConfig::default()
+        };
+        assert_eq!(wookiee.format(), ResponseFormat::Wookiee);
+    }
+}
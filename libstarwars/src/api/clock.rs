@@ -0,0 +1,23 @@
+use std::thread;
+use std::time::Duration;
+use futures::channel::oneshot;
+
+#[async_trait::async_trait]
+pub trait Clock: Send + Sync {
+    async fn sleep(&self, duration: Duration);
+}
+
+pub struct SystemClock;
+
+#[async_trait::async_trait]
+impl Clock for SystemClock {
+    async fn sleep(&self, duration: Duration) {
+        // No timer driver is available without an async runtime, so park a helper thread instead
+        let (sender, receiver) = oneshot::channel();
+        thread::spawn(move || {
+            thread::sleep(duration);
+            let _ = sender.send(());
+        });
+        let _ = receiver.await;
+    }
+}
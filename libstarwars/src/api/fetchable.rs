@@ -1,6 +1,4 @@
-const BASE_URL: &str = "https://swapi.dev/api/";
-
-#[derive(Debug, uniffi::Enum)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, uniffi::Enum)]
 pub enum Fetchable {
     Planets,
     People,
@@ -37,14 +35,95 @@ impl Fetchable {
 }
 
 impl Fetchable {
-    pub fn url(&self) -> String {
+    pub fn url(&self, base_url: &str) -> String {
+        format!("{}/{}/", base_url.trim_end_matches('/'), self.path())
+    }
+
+    pub fn search_url(&self, base_url: &str, query: &str) -> String {
+        format!("{}?search={}", self.url(base_url), percent_encode(query.trim()))
+    }
+
+    // Like `resource_id`, but only for urls pointing into this collection
+    pub fn resource_id(&self, url: &str) -> Option<u32> {
+        let mut segments = path_segments(url);
+        let id = segments.next()?.parse().ok()?;
+        (segments.next()? == self.path()).then_some(id)
+    }
+
+    // The collection a SWAPI url points into, whether at a record or a (search) page of them
+    pub fn for_url(url: &str) -> Option<Fetchable> {
+        path_segments(url)
+            .take(2)
+            .find_map(|segment| all_fetchable().into_iter().find(|fetchable| fetchable.path() == segment))
+    }
+
+    pub fn path(&self) -> &'static str {
         match self {
-            Self::Planets => format!("{BASE_URL}planets"),
-            Self::People => format!("{BASE_URL}people"),
-            Self::Films => format!("{BASE_URL}films"),
-            Self::Species => format!("{BASE_URL}species"),
-            Self::Starships => format!("{BASE_URL}starships"),
-            Self::Vehicles => format!("{BASE_URL}vehicles"),
+            Self::Planets => "planets",
+            Self::People => "people",
+            Self::Films => "films",
+            Self::Species => "species",
+            Self::Starships => "starships",
+            Self::Vehicles => "vehicles",
+        }
+    }
+}
+
+// SWAPI urls end in "/{path}/{id}/"; the id stays the same whichever host or scheme served it.
+#[uniffi::export]
+pub fn resource_id(url: &str) -> Option<u32> {
+    path_segments(url).next()?.parse().ok()
+}
+
+fn path_segments(url: &str) -> impl Iterator<Item = &str> {
+    let path = url.split(['?', '#']).next().unwrap_or_default();
+    path.trim_end_matches('/').rsplit('/')
+}
+
+fn percent_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{byte:02X}")),
         }
     }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_the_id_from_any_host_or_scheme() {
+        assert_eq!(resource_id("https://swapi.dev/api/people/1/"), Some(1));
+        assert_eq!(resource_id("http://localhost:8000/api/people/1"), Some(1));
+        assert_eq!(resource_id("https://swapi.dev/api/people/12/?format=wookiee"), Some(12));
+        assert_eq!(resource_id("https://swapi.dev/api/people/"), None);
+    }
+
+    #[test]
+    fn only_reads_ids_in_its_own_collection() {
+        assert_eq!(Fetchable::People.resource_id("https://swapi.dev/api/people/4/"), Some(4));
+        assert_eq!(Fetchable::Planets.resource_id("https://swapi.dev/api/people/4/"), None);
+        assert_eq!(Fetchable::People.resource_id("https://swapi.dev/api/people/?page=2"), None);
+    }
+
+    #[test]
+    fn finds_the_collection_of_records_and_pages() {
+        assert_eq!(Fetchable::for_url("https://swapi.dev/api/people/1/"), Some(Fetchable::People));
+        assert_eq!(Fetchable::for_url("https://swapi.dev/api/films/?page=2"), Some(Fetchable::Films));
+        assert_eq!(Fetchable::for_url("https://swapi.dev/api/"), None);
+    }
+
+    #[test]
+    fn encodes_search_queries() {
+        assert_eq!(
+            Fetchable::Starships.search_url("https://swapi.dev/api", " x-wing & co "),
+            "https://swapi.dev/api/starships/?search=x-wing%20%26%20co"
+        );
+    }
 }
@@ -0,0 +1,217 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use serde::{Deserialize, Serialize};
+use crate::api::http_client::{HttpClient, HttpRequest, HttpResponse, NetworkError};
+use crate::api::store::{StoreError, stable_hash};
+
+// Fixtures let Rust tests drive `ApiClient` deterministically: record once against a real
+// client, commit the directory, then replay offline.
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Fixture {
+    url: String,
+    status: u16,
+    headers: HashMap<String, String>,
+    // Kept as text so recorded SWAPI responses stay readable in diffs
+    body: String,
+}
+
+// One file per URL, named after it so a fixture directory is easy to browse. Sanitising maps
+// e.g. `?page=2` and `/page/2` to the same name, so the hash of the full URL keeps them apart.
+fn fixture_path(directory: &Path, url: &str) -> PathBuf {
+    let name = url
+        .split_once("://")
+        .map_or(url, |(_, rest)| rest)
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect::<String>();
+    directory.join(format!("{}-{:016x}.json", name.trim_matches('_'), stable_hash(url)))
+}
+
+// ========================================================================
+// Recording
+// ========================================================================
+
+pub struct RecordingHttpClient {
+    inner: Arc<dyn HttpClient>,
+    directory: PathBuf,
+}
+
+impl RecordingHttpClient {
+    pub fn new(inner: Arc<dyn HttpClient>, directory: impl Into<PathBuf>) -> Result<Self, StoreError> {
+        let directory = directory.into();
+        fs::create_dir_all(&directory)?;
+        Ok(Self { inner, directory })
+    }
+}
+
+#[async_trait::async_trait]
+impl HttpClient for RecordingHttpClient {
+    async fn fetch(&self, mut request: HttpRequest) -> Result<HttpResponse, NetworkError> {
+        // A recorded 304 would have no body to replay, so always ask for the full response
+        request.headers.retain(|name, _| {
+            !name.eq_ignore_ascii_case("If-None-Match") && !name.eq_ignore_ascii_case("If-Modified-Since")
+        });
+        let url = request.url.clone();
+        let response = self.inner.fetch(request).await?;
+
+        let fixture = Fixture {
+            url: url.clone(),
+            status: response.status,
+            headers: response.headers.clone(),
+            body: String::from_utf8_lossy(&response.body).into_owned(),
+        };
+        let contents = serde_json::to_vec_pretty(&fixture).map_err(|err| NetworkError::RequestFailed {
+            reason: format!("Couldn't encode fixture for {url}: {err}"),
+        })?;
+        fs::write(fixture_path(&self.directory, &url), contents).map_err(|err| {
+            NetworkError::RequestFailed {
+                reason: format!("Couldn't write fixture for {url}: {err}"),
+            }
+        })?;
+        Ok(response)
+    }
+}
+
+// ========================================================================
+// Replay
+// ========================================================================
+
+pub struct ReplayHttpClient {
+    directory: PathBuf,
+}
+
+impl ReplayHttpClient {
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl HttpClient for ReplayHttpClient {
+    // Unknown URLs fail loudly rather than falling through to the network, so a test can't
+    // silently depend on connectivity.
+    async fn fetch(&self, request: HttpRequest) -> Result<HttpResponse, NetworkError> {
+        let missing = || NetworkError::RequestFailed {
+            reason: format!("No fixture recorded for {}", request.url),
+        };
+        let contents = fs::read(fixture_path(&self.directory, &request.url)).map_err(|_| missing())?;
+        let fixture = serde_json::from_slice::<Fixture>(&contents).map_err(|_| missing())?;
+        if fixture.url != request.url {
+            return Err(missing());
+        }
+        Ok(HttpResponse {
+            status: fixture.status,
+            headers: fixture.headers,
+            body: fixture.body.into_bytes(),
+        })
+    }
+}
+
+// A client replaying the synthetic SWAPI responses under tests/fixtures/swapi (see the README there)
+#[cfg(test)]
+pub(crate) fn replay_client() -> Arc<crate::api::api_client::ApiClient> {
+    replay_client_with_config(crate::api::client_config::ClientConfig::default())
+}
+
+#[cfg(test)]
+pub(crate) fn replay_client_with_config(
+    config: crate::api::client_config::ClientConfig,
+) -> Arc<crate::api::api_client::ApiClient> {
+    use crate::api::api_client::ApiClient;
+    use crate::api::repository::DataRepository;
+    let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/swapi");
+    Arc::new(ApiClient::with_config(
+        Arc::new(ReplayHttpClient::new(directory)),
+        Arc::new(DataRepository::new()),
+        config,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::sync::Mutex;
+    use futures::executor::block_on;
+    use crate::api::cancellation::CancellationToken;
+    use super::*;
+
+    // Answers every request with the same response, keeping what it was asked
+    struct CannedHttpClient {
+        response: HttpResponse,
+        requests: Mutex<Vec<HttpRequest>>,
+    }
+
+    #[async_trait::async_trait]
+    impl HttpClient for CannedHttpClient {
+        async fn fetch(&self, request: HttpRequest) -> Result<HttpResponse, NetworkError> {
+            self.requests.lock().unwrap().push(request);
+            Ok(self.response.clone())
+        }
+    }
+
+    fn request(url: &str) -> HttpRequest {
+        HttpRequest {
+            url: url.to_owned(),
+            headers: HashMap::new(),
+            cancellation: Arc::new(CancellationToken::new()),
+        }
+    }
+
+    #[test]
+    fn urls_differing_only_in_punctuation_get_their_own_files() {
+        let directory = Path::new("fixtures");
+        assert_ne!(
+            fixture_path(directory, "https://swapi.dev/api/people/?page=2"),
+            fixture_path(directory, "https://swapi.dev/api/people/page/2"),
+        );
+    }
+
+    fn parts(response: HttpResponse) -> (u16, HashMap<String, String>, Vec<u8>) {
+        (response.status, response.headers, response.body)
+    }
+
+    #[test]
+    fn replays_what_it_recorded() {
+        let directory = env::temp_dir().join(format!("starwars-fixtures-{}", std::process::id()));
+        let response = HttpResponse {
+            status: 200,
+            headers: HashMap::from([(String::from("ETag"), String::from("\"1\""))]),
+            body: br#"{"name":"Luke Skywalker"}"#.to_vec(),
+        };
+        let inner = Arc::new(CannedHttpClient {
+            response: response.clone(),
+            requests: Mutex::new(Vec::new()),
+        });
+        let recorder = RecordingHttpClient::new(inner.clone(), &directory).unwrap();
+
+        let mut revalidation = request("https://swapi.dev/api/people/1/");
+        revalidation.headers.insert(String::from("If-None-Match"), String::from("\"1\""));
+        revalidation.headers.insert(String::from("Accept"), String::from("application/json"));
+        assert_eq!(parts(block_on(recorder.fetch(revalidation)).unwrap()), parts(response.clone()));
+        // Asked for the full response, so there's a body to replay
+        let sent = inner.requests.lock().unwrap()[0].headers.clone();
+        assert_eq!(sent, HashMap::from([(String::from("Accept"), String::from("application/json"))]));
+
+        let replay = ReplayHttpClient::new(&directory);
+        let replayed = block_on(replay.fetch(request("https://swapi.dev/api/people/1/"))).unwrap();
+        assert_eq!(parts(replayed), parts(response));
+        assert!(block_on(replay.fetch(request("https://swapi.dev/api/people/2/"))).is_err());
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn replays_recorded_responses_and_rejects_unknown_urls() {
+        let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/swapi");
+        let client = ReplayHttpClient::new(directory);
+        let response = block_on(client.fetch(request("https://swapi.dev/api/people/999/"))).unwrap();
+        assert_eq!(response.status, 404);
+
+        let missing = block_on(client.fetch(request("https://swapi.dev/api/people/998/")));
+        assert!(matches!(missing, Err(NetworkError::RequestFailed { .. })));
+    }
+}
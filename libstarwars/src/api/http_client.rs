@@ -1,13 +1,65 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use crate::api::cancellation::CancellationToken;
+
 #[uniffi::export(with_foreign)]
 #[async_trait::async_trait]
 pub trait HttpClient: Send + Sync {
-    async fn fetch(&self, url: String) -> Result<Vec<u8>, NetworkError>;
+    async fn fetch(&self, request: HttpRequest) -> Result<HttpResponse, NetworkError>;
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct HttpRequest {
+    pub url: String,
+    pub headers: HashMap<String, String>,
+    // Cancelled once no caller is waiting for the response; clients may abort the transfer
+    pub cancellation: Arc<CancellationToken>,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub headers: HashMap<String, String>,
+    pub body: Vec<u8>,
+}
+
+impl HttpResponse {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    pub fn is_not_modified(&self) -> bool {
+        self.status == 304
+    }
+
+    pub fn is_transient(&self) -> bool {
+        self.status == 429 || (500..600).contains(&self.status)
+    }
+
+    // Only the delta-seconds form is honoured; SWAPI mirrors don't send HTTP dates
+    pub fn retry_after(&self) -> Option<Duration> {
+        self.header("Retry-After")?
+            .trim()
+            .parse::<u64>()
+            .ok()
+            .map(Duration::from_secs)
+    }
 }
 
-#[derive(thiserror::Error, uniffi::Error, Debug)]
+#[derive(thiserror::Error, uniffi::Error, Debug, Clone)]
 pub enum NetworkError {
     #[error("Request failed: {reason}")]
     RequestFailed { reason: String },
     #[error("Timeout")]
     Timeout,
-}
\ No newline at end of file
+    #[error("Cancelled")]
+    Cancelled,
+}
@@ -1,12 +1,14 @@
+use std::sync::Arc;
 use crate::api::api_client::ApiClient;
 use crate::api::api_error::ApiError;
-use crate::api::fetchable::Fetchable;
-use crate::models::films::{Film, Films};
-use crate::models::people::{Person, People};
-use crate::models::planets::{Planet, Planets};
-use crate::models::species::{Species, SpeciesList};
-use crate::models::starships::{Starship, Starships};
-use crate::models::vehicles::{Vehicle, Vehicles};
+use crate::api::cache::CachePolicy;
+use crate::api::cancellation::CancellationToken;
+use crate::models::films::Film;
+use crate::models::people::Person;
+use crate::models::planets::Planet;
+use crate::models::species::Species;
+use crate::models::starships::Starship;
+use crate::models::vehicles::Vehicle;
 
 // ========================================================================
 // Films
@@ -14,25 +16,21 @@ use crate::models::vehicles::{Vehicle, Vehicles};
 
 #[uniffi::export]
 impl ApiClient {
-    pub async fn fetch_films(&self, ignore_cache: bool) -> Result<Vec<Film>, ApiError> {
-        if !ignore_cache
-             && let Some(cached_films) = self.repository.get_films() {
-                return Ok(cached_films);
-            }
-        let films = self.fetch_page::<Films>(Fetchable::Films).await?.results;
-        for film in &films {
-            self.repository.insert_film(film);
-        }
-        Ok(films)
-    }
-
-    pub async fn fetch_film(&self, url: String) -> Result<Film, ApiError> {
-        if let Some(item) = self.repository.get_film(url.as_str()) {
-            return Ok(item);
-        }
-        let film = self.fetch_url::<Film>(url).await?;
-        self.repository.insert_film(&film);
-        Ok(film)
+    pub async fn fetch_films(
+        self: Arc<Self>,
+        policy: CachePolicy,
+        cancellation: Option<Arc<CancellationToken>>,
+    ) -> Result<Vec<Film>, ApiError> {
+        self.fetch_resources(policy, cancellation.as_deref()).await
+    }
+
+    pub async fn fetch_film(
+        self: Arc<Self>,
+        url: String,
+        policy: CachePolicy,
+        cancellation: Option<Arc<CancellationToken>>,
+    ) -> Result<Film, ApiError> {
+        self.fetch_resource(url, policy, cancellation.as_deref()).await
     }
 }
 
@@ -42,25 +40,21 @@ impl ApiClient {
 
 #[uniffi::export]
 impl ApiClient {
-    pub async fn fetch_people(&self, ignore_cache: bool) -> Result<Vec<Person>, ApiError> {
-        if !ignore_cache
-            && let Some(cached_people) = self.repository.get_people() {
-                return Ok(cached_people);
-            }
-        let people = self.fetch_page::<People>(Fetchable::People).await?.results;
-        for person in &people {
-            self.repository.insert_person(person);
-        }
-        Ok(people)
-    }
-
-    pub async fn fetch_person(&self, url: String) -> Result<Person, ApiError> {
-        if let Some(item) = self.repository.get_person(url.as_str()) {
-            return Ok(item);
-        }
-        let person = self.fetch_url::<Person>(url).await?;
-        self.repository.insert_person(&person);
-        Ok(person)
+    pub async fn fetch_people(
+        self: Arc<Self>,
+        policy: CachePolicy,
+        cancellation: Option<Arc<CancellationToken>>,
+    ) -> Result<Vec<Person>, ApiError> {
+        self.fetch_resources(policy, cancellation.as_deref()).await
+    }
+
+    pub async fn fetch_person(
+        self: Arc<Self>,
+        url: String,
+        policy: CachePolicy,
+        cancellation: Option<Arc<CancellationToken>>,
+    ) -> Result<Person, ApiError> {
+        self.fetch_resource(url, policy, cancellation.as_deref()).await
     }
 }
 
@@ -70,25 +64,21 @@ impl ApiClient {
 
 #[uniffi::export]
 impl ApiClient {
-    pub async fn fetch_planets(&self, ignore_cache: bool) -> Result<Vec<Planet>, ApiError> {
-        if !ignore_cache
-            && let Some(cached_planets) = self.repository.get_planets() {
-                return Ok(cached_planets);
-            }
-        let planets = self.fetch_page::<Planets>(Fetchable::Planets).await?.results;
-        for planet in &planets {
-            self.repository.insert_planet(planet);
-        }
-        Ok(planets)
-    }
-
-    pub async fn fetch_planet(&self, url: String) -> Result<Planet, ApiError> {
-        if let Some(item) = self.repository.get_planet(url.as_str()) {
-            return Ok(item);
-        }
-        let planet = self.fetch_url::<Planet>(url).await?;
-        self.repository.insert_planet(&planet);
-        Ok(planet)
+    pub async fn fetch_planets(
+        self: Arc<Self>,
+        policy: CachePolicy,
+        cancellation: Option<Arc<CancellationToken>>,
+    ) -> Result<Vec<Planet>, ApiError> {
+        self.fetch_resources(policy, cancellation.as_deref()).await
+    }
+
+    pub async fn fetch_planet(
+        self: Arc<Self>,
+        url: String,
+        policy: CachePolicy,
+        cancellation: Option<Arc<CancellationToken>>,
+    ) -> Result<Planet, ApiError> {
+        self.fetch_resource(url, policy, cancellation.as_deref()).await
     }
 }
 
@@ -98,25 +88,21 @@ impl ApiClient {
 
 #[uniffi::export]
 impl ApiClient {
-    pub async fn fetch_species_list(&self, ignore_cache: bool) -> Result<Vec<Species>, ApiError> {
-        if !ignore_cache
-            && let Some(cached_species) = self.repository.get_species_list() {
-                return Ok(cached_species);
-            }
-        let species_list = self.fetch_page::<SpeciesList>(Fetchable::Species).await?.results;
-        for species in &species_list {
-            self.repository.insert_species(species);
-        }
-        Ok(species_list)
-    }
-
-    pub async fn fetch_species(&self, url: String) -> Result<Species, ApiError> {
-        if let Some(item) = self.repository.get_species(url.as_str()) {
-            return Ok(item);
-        }
-        let species = self.fetch_url::<Species>(url).await?;
-        self.repository.insert_species(&species);
-        Ok(species)
+    pub async fn fetch_species_list(
+        self: Arc<Self>,
+        policy: CachePolicy,
+        cancellation: Option<Arc<CancellationToken>>,
+    ) -> Result<Vec<Species>, ApiError> {
+        self.fetch_resources(policy, cancellation.as_deref()).await
+    }
+
+    pub async fn fetch_species(
+        self: Arc<Self>,
+        url: String,
+        policy: CachePolicy,
+        cancellation: Option<Arc<CancellationToken>>,
+    ) -> Result<Species, ApiError> {
+        self.fetch_resource(url, policy, cancellation.as_deref()).await
     }
 }
 
@@ -126,25 +112,21 @@ impl ApiClient {
 
 #[uniffi::export]
 impl ApiClient {
-    pub async fn fetch_starships(&self, ignore_cache: bool) -> Result<Vec<Starship>, ApiError> {
-        if !ignore_cache
-            && let Some(cached_starships) = self.repository.get_starships() {
-                return Ok(cached_starships);
-            }
-        let starships = self.fetch_page::<Starships>(Fetchable::Starships).await?.results;
-        for starship in &starships {
-            self.repository.insert_starship(starship);
-        }
-        Ok(starships)
-    }
-
-    pub async fn fetch_starship(&self, url: String) -> Result<Starship, ApiError> {
-        if let Some(item) = self.repository.get_starship(url.as_str()) {
-            return Ok(item);
-        }
-        let starship = self.fetch_url::<Starship>(url).await?;
-        self.repository.insert_starship(&starship);
-        Ok(starship)
+    pub async fn fetch_starships(
+        self: Arc<Self>,
+        policy: CachePolicy,
+        cancellation: Option<Arc<CancellationToken>>,
+    ) -> Result<Vec<Starship>, ApiError> {
+        self.fetch_resources(policy, cancellation.as_deref()).await
+    }
+
+    pub async fn fetch_starship(
+        self: Arc<Self>,
+        url: String,
+        policy: CachePolicy,
+        cancellation: Option<Arc<CancellationToken>>,
+    ) -> Result<Starship, ApiError> {
+        self.fetch_resource(url, policy, cancellation.as_deref()).await
     }
 }
 
@@ -154,24 +136,102 @@ impl ApiClient {
 
 #[uniffi::export]
 impl ApiClient {
-    pub async fn fetch_vehicles(&self, ignore_cache: bool) -> Result<Vec<Vehicle>, ApiError> {
-        if !ignore_cache
-            && let Some(cached_vehicles) = self.repository.get_vehicles() {
-                return Ok(cached_vehicles);
-            }
-        let vehicles = self.fetch_page::<Vehicles>(Fetchable::Vehicles).await?.results;
-        for vehicle in &vehicles {
-            self.repository.insert_vehicle(vehicle);
-        }
-        Ok(vehicles)
-    }
-
-    pub async fn fetch_vehicle(&self, url: String) -> Result<Vehicle, ApiError> {
-        if let Some(item) = self.repository.get_vehicle(url.as_str()) {
-            return Ok(item);
-        }
-        let vehicle = self.fetch_url::<Vehicle>(url).await?;
-        self.repository.insert_vehicle(&vehicle);
-        Ok(vehicle)
+    pub async fn fetch_vehicles(
+        self: Arc<Self>,
+        policy: CachePolicy,
+        cancellation: Option<Arc<CancellationToken>>,
+    ) -> Result<Vec<Vehicle>, ApiError> {
+        self.fetch_resources(policy, cancellation.as_deref()).await
+    }
+
+    pub async fn fetch_vehicle(
+        self: Arc<Self>,
+        url: String,
+        policy: CachePolicy,
+        cancellation: Option<Arc<CancellationToken>>,
+    ) -> Result<Vehicle, ApiError> {
+        self.fetch_resource(url, policy, cancellation.as_deref()).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+    use crate::api::fixtures::replay_client;
+    use super::*;
+
+    const BASE: &str = "https://swapi.dev/api/";
+
+    #[test]
+    fn fetches_every_page_of_films() {
+        let films = block_on(replay_client().fetch_films(CachePolicy::NetworkOnly, None)).unwrap();
+        let titles = films.iter().map(|film| film.title.as_str()).collect::<Vec<_>>();
+        assert_eq!(titles, ["A New Hope", "The Empire Strikes Back", "Return of the Jedi"]);
+
+        let url = format!("{BASE}films/1/");
+        let film = block_on(replay_client().fetch_film(url, CachePolicy::NetworkOnly, None)).unwrap();
+        assert_eq!(film.title, "A New Hope");
+    }
+
+    #[test]
+    fn fetches_every_page_of_people() {
+        let people = block_on(replay_client().fetch_people(CachePolicy::NetworkOnly, None)).unwrap();
+        let names = people.iter().map(|person| person.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, ["Luke Skywalker", "C-3PO", "R2-D2", "Darth Vader"]);
+
+        let url = format!("{BASE}people/1/");
+        let person = block_on(replay_client().fetch_person(url, CachePolicy::NetworkOnly, None)).unwrap();
+        assert_eq!(person.name, "Luke Skywalker");
+    }
+
+    #[test]
+    fn fetches_every_page_of_planets() {
+        let planets = block_on(replay_client().fetch_planets(CachePolicy::NetworkOnly, None)).unwrap();
+        let names = planets.iter().map(|planet| planet.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, ["Tatooine", "Alderaan", "Yavin IV"]);
+
+        let url = format!("{BASE}planets/1/");
+        let planet = block_on(replay_client().fetch_planet(url, CachePolicy::NetworkOnly, None)).unwrap();
+        assert_eq!(planet.name, "Tatooine");
+    }
+
+    #[test]
+    fn fetches_every_page_of_species() {
+        let species = block_on(replay_client().fetch_species_list(CachePolicy::NetworkOnly, None)).unwrap();
+        let names = species.iter().map(|species| species.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, ["Human", "Droid", "Wookie"]);
+
+        let url = format!("{BASE}species/1/");
+        let species = block_on(replay_client().fetch_species(url, CachePolicy::NetworkOnly, None)).unwrap();
+        assert_eq!(species.name, "Human");
+    }
+
+    #[test]
+    fn fetches_every_page_of_starships() {
+        let starships = block_on(replay_client().fetch_starships(CachePolicy::NetworkOnly, None)).unwrap();
+        let names = starships.iter().map(|starship| starship.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, ["CR90 corvette", "Star Destroyer", "Millennium Falcon"]);
+
+        let url = format!("{BASE}starships/2/");
+        let starship = block_on(replay_client().fetch_starship(url, CachePolicy::NetworkOnly, None)).unwrap();
+        assert_eq!(starship.name, "CR90 corvette");
+    }
+
+    #[test]
+    fn fetches_every_page_of_vehicles() {
+        let vehicles = block_on(replay_client().fetch_vehicles(CachePolicy::NetworkOnly, None)).unwrap();
+        let names = vehicles.iter().map(|vehicle| vehicle.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, ["Sand Crawler", "T-16 skyhopper"]);
+
+        let url = format!("{BASE}vehicles/4/");
+        let vehicle = block_on(replay_client().fetch_vehicle(url, CachePolicy::NetworkOnly, None)).unwrap();
+        assert_eq!(vehicle.name, "Sand Crawler");
+    }
+
+    #[test]
+    fn missing_resource_is_not_found() {
+        let url = format!("{BASE}people/999/");
+        let result = block_on(replay_client().fetch_person(url, CachePolicy::NetworkOnly, None));
+        assert!(matches!(result, Err(ApiError::NotFound)));
     }
 }
@@ -1,6 +1,12 @@
+use crate::api::selected::Selected;
+use crate::models::films::Film;
 use crate::models::people::Person;
+use crate::models::planets::Planet;
+use crate::models::species::Species;
+use crate::models::starships::Starship;
+use crate::models::vehicles::Vehicle;
 
-#[derive(Debug, uniffi::Enum)]
+#[derive(Debug, PartialEq, uniffi::Enum)]
 pub enum ListItems {
     Planets(Vec<String>),
     People(Vec<String>),
@@ -9,27 +15,185 @@ pub enum ListItems {
     Starships(Vec<String>),
     Vehicles(Vec<String>),
     Homeworld(String),
+    Characters(Vec<String>),
+    Residents(Vec<String>),
+    Pilots(Vec<String>),
+}
+
+#[uniffi::export]
+pub fn related_items(selected: &Selected) -> Vec<ListItems> {
+    match selected {
+        Selected::Film(film) => film_related_items(film),
+        Selected::Person(person) => person_related_items(person),
+        Selected::Planet(planet) => planet_related_items(planet),
+        Selected::Species(species) => species_related_items(species),
+        Selected::Starship(starship) => starship_related_items(starship),
+        Selected::Vehicle(vehicle) => vehicle_related_items(vehicle),
+    }
 }
 
 #[uniffi::export]
 pub fn person_related_items(person: &Person) -> Vec<ListItems> {
     let mut items: Vec<ListItems> = Vec::new();
 
-    if !person.films.is_empty() {
-        items.push(ListItems::Films(person.films.clone()));
+    push_urls(&mut items, &person.films, ListItems::Films);
+    push_urls(&mut items, &person.species, ListItems::Species);
+    push_urls(&mut items, &person.starships, ListItems::Starships);
+    push_urls(&mut items, &person.vehicles, ListItems::Vehicles);
+    if let Some(hw) = &person.homeworld {
+        items.push(ListItems::Homeworld(hw.clone()));
     }
-    if !person.species.is_empty() {
-        items.push(ListItems::Species(person.species.clone()));
+
+    items
+}
+
+fn film_related_items(film: &Film) -> Vec<ListItems> {
+    let mut items: Vec<ListItems> = Vec::new();
+
+    push_urls(&mut items, &film.characters, ListItems::Characters);
+    push_urls(&mut items, &film.planets, ListItems::Planets);
+    push_urls(&mut items, &film.species, ListItems::Species);
+    push_urls(&mut items, &film.starships, ListItems::Starships);
+    push_urls(&mut items, &film.vehicles, ListItems::Vehicles);
+
+    items
+}
+
+fn planet_related_items(planet: &Planet) -> Vec<ListItems> {
+    let mut items: Vec<ListItems> = Vec::new();
+
+    push_urls(&mut items, &planet.residents, ListItems::Residents);
+    push_urls(&mut items, &planet.films, ListItems::Films);
+
+    items
+}
+
+fn species_related_items(species: &Species) -> Vec<ListItems> {
+    let mut items: Vec<ListItems> = Vec::new();
+
+    push_urls(&mut items, &species.people, ListItems::People);
+    push_urls(&mut items, &species.films, ListItems::Films);
+    if let Some(hw) = &species.homeworld {
+        items.push(ListItems::Homeworld(hw.clone()));
     }
-    if !person.starships.is_empty() {
-        items.push(ListItems::Starships(person.starships.clone()));
+
+    items
+}
+
+fn starship_related_items(starship: &Starship) -> Vec<ListItems> {
+    let mut items: Vec<ListItems> = Vec::new();
+
+    push_urls(&mut items, &starship.pilots, ListItems::Pilots);
+    push_urls(&mut items, &starship.films, ListItems::Films);
+
+    items
+}
+
+fn vehicle_related_items(vehicle: &Vehicle) -> Vec<ListItems> {
+    let mut items: Vec<ListItems> = Vec::new();
+
+    push_urls(&mut items, &vehicle.pilots, ListItems::Pilots);
+    push_urls(&mut items, &vehicle.films, ListItems::Films);
+
+    items
+}
+
+fn push_urls(items: &mut Vec<ListItems>, urls: &[String], variant: fn(Vec<String>) -> ListItems) {
+    if !urls.is_empty() {
+        items.push(variant(urls.to_vec()));
     }
-    if !person.vehicles.is_empty() {
-        items.push(ListItems::Vehicles(person.vehicles.clone()));
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::de::DeserializeOwned;
+    use super::*;
+
+    fn fixture<R: DeserializeOwned>(contents: &str) -> R {
+        let fixture = serde_json::from_str::<serde_json::Value>(contents).unwrap();
+        serde_json::from_str(fixture["body"].as_str().unwrap()).unwrap()
     }
-    if let Some(hw) = &person.homeworld {
-        items.push(ListItems::Homeworld(hw.clone()));
+
+    macro_rules! fixture {
+        ($file:literal) => {
+            fixture(include_str!(concat!("../../tests/fixtures/swapi/", $file)))
+        };
     }
 
-    items
+    #[test]
+    fn films_list_their_characters_planets_species_starships_and_vehicles() {
+        let film: Film = fixture!("swapi_dev_api_films_1-b14ec24c3395b797.json");
+        assert_eq!(
+            related_items(&Selected::Film(film.clone())),
+            [
+                ListItems::Characters(film.characters),
+                ListItems::Planets(film.planets),
+                ListItems::Species(film.species),
+                ListItems::Starships(film.starships),
+                ListItems::Vehicles(film.vehicles),
+            ],
+        );
+    }
+
+    #[test]
+    fn people_list_their_films_craft_and_homeworld_skipping_empty_lists() {
+        let person: Person = fixture!("swapi_dev_api_people_1-f83e62a1d294e0a9.json");
+        assert!(person.species.is_empty());
+        assert_eq!(
+            related_items(&Selected::Person(person.clone())),
+            [
+                ListItems::Films(person.films),
+                ListItems::Starships(person.starships),
+                ListItems::Vehicles(person.vehicles),
+                ListItems::Homeworld(person.homeworld.unwrap()),
+            ],
+        );
+    }
+
+    #[test]
+    fn planets_list_their_residents_and_films() {
+        let planet: Planet = fixture!("swapi_dev_api_planets_1-3942c3373ac3246f.json");
+        assert_eq!(
+            related_items(&Selected::Planet(planet.clone())),
+            [ListItems::Residents(planet.residents), ListItems::Films(planet.films)],
+        );
+    }
+
+    #[test]
+    fn species_list_their_people_films_and_homeworld() {
+        let species: Species = fixture!("swapi_dev_api_species_1-d9c1143194033434.json");
+        assert_eq!(
+            related_items(&Selected::Species(species.clone())),
+            [
+                ListItems::People(species.people.clone()),
+                ListItems::Films(species.films.clone()),
+                ListItems::Homeworld(species.homeworld.clone().unwrap()),
+            ],
+        );
+
+        let films = species.films.clone();
+        let homeless = Species { homeworld: None, people: Vec::new(), ..species };
+        assert_eq!(related_items(&Selected::Species(homeless)), [ListItems::Films(films)]);
+    }
+
+    #[test]
+    fn starships_and_vehicles_list_their_pilots_and_films() {
+        let pilots = vec![String::from("https://swapi.dev/api/people/13/")];
+        let starship: Starship = fixture!("swapi_dev_api_starships_2-04f868eedccf58a0.json");
+        let starship = Starship { pilots: pilots.clone(), ..starship };
+        assert_eq!(
+            related_items(&Selected::Starship(starship.clone())),
+            [ListItems::Pilots(pilots.clone()), ListItems::Films(starship.films)],
+        );
+
+        // Unpiloted, as recorded
+        let vehicle: Vehicle = fixture!("swapi_dev_api_vehicles_4-7f80694ad1c16a76.json");
+        let films = vehicle.films.clone();
+        assert_eq!(related_items(&Selected::Vehicle(vehicle.clone())), [ListItems::Films(films)]);
+        let vehicle = Vehicle { pilots: pilots.clone(), ..vehicle };
+        assert_eq!(
+            related_items(&Selected::Vehicle(vehicle.clone())),
+            [ListItems::Pilots(pilots), ListItems::Films(vehicle.films)],
+        );
+    }
 }
@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+use std::thread;
+use std::time::Duration;
+use futures::channel::oneshot;
+use crate::api::http_client::{HttpClient, HttpRequest, HttpResponse, NetworkError};
+
+const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+// A Rust-side HttpClient for consumers without a host app (desktop tools, CLIs, tests). TLS
+// comes from ureq's bundled rustls.
+pub struct NativeHttpClient {
+    agent: ureq::Agent,
+}
+
+impl NativeHttpClient {
+    pub fn new() -> Self {
+        Self::with_timeouts(DEFAULT_CONNECT_TIMEOUT, DEFAULT_TIMEOUT)
+    }
+
+    // `timeout` covers the whole request, body included
+    pub fn with_timeouts(connect_timeout: Duration, timeout: Duration) -> Self {
+        let config = ureq::Agent::config_builder()
+            .timeout_connect(Some(connect_timeout))
+            .timeout_global(Some(timeout))
+            // Statuses are ApiClient's to interpret (retries, 304s), not transport errors
+            .http_status_as_error(false)
+            .build();
+        Self {
+            agent: ureq::Agent::new_with_config(config),
+        }
+    }
+}
+
+impl Default for NativeHttpClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait::async_trait]
+impl HttpClient for NativeHttpClient {
+    async fn fetch(&self, request: HttpRequest) -> Result<HttpResponse, NetworkError> {
+        if request.cancellation.is_cancelled() {
+            return Err(NetworkError::Cancelled);
+        }
+        // ureq blocks, and there's no async runtime to hand it to, so it gets its own thread
+        // (the same approach as SystemClock). A dropped fetch leaves the thread to finish alone.
+        let agent = self.agent.clone();
+        let cancellation = request.cancellation.clone();
+        let (sender, receiver) = oneshot::channel();
+        thread::spawn(move || {
+            let _ = sender.send(send(&agent, &request));
+        });
+        let response = receiver.await.unwrap_or_else(|_| {
+            Err(NetworkError::RequestFailed {
+                reason: String::from("Request thread exited"),
+            })
+        });
+        // ureq can't abort a call mid-flight, so a response that lands after cancelling is dropped
+        if cancellation.is_cancelled() {
+            return Err(NetworkError::Cancelled);
+        }
+        response
+    }
+}
+
+fn send(agent: &ureq::Agent, request: &HttpRequest) -> Result<HttpResponse, NetworkError> {
+    let mut builder = agent.get(&request.url);
+    for (name, value) in &request.headers {
+        builder = builder.header(name, value);
+    }
+    let mut response = builder.call().map_err(network_error)?;
+
+    let status = response.status().as_u16();
+    let headers = response
+        .headers()
+        .iter()
+        .filter_map(|(name, value)| Some((name.as_str().to_owned(), value.to_str().ok()?.to_owned())))
+        .collect::<HashMap<_, _>>();
+    let body = response.body_mut().read_to_vec().map_err(network_error)?;
+    Ok(HttpResponse { status, headers, body })
+}
+
+fn network_error(error: ureq::Error) -> NetworkError {
+    match error {
+        ureq::Error::Timeout(_) => NetworkError::Timeout,
+        ureq::Error::Io(error) if error.kind() == std::io::ErrorKind::TimedOut => NetworkError::Timeout,
+        error => NetworkError::RequestFailed {
+            reason: error.to_string(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use futures::executor::block_on;
+    use crate::api::cancellation::CancellationToken;
+    use super::*;
+
+    #[test]
+    fn cancelled_request_is_never_sent() {
+        let cancellation = Arc::new(CancellationToken::new());
+        cancellation.cancel();
+        // Unroutable, so the test would time out rather than pass if the request went out
+        let request = HttpRequest {
+            url: String::from("http://10.255.255.1/api/people/"),
+            headers: HashMap::new(),
+            cancellation,
+        };
+        let result = block_on(NativeHttpClient::new().fetch(request));
+        assert!(matches!(result, Err(NetworkError::Cancelled)));
+    }
+}
@@ -0,0 +1,48 @@
+use std::sync::Arc;
+use crate::api::fetchable::Fetchable;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum ChangeKind {
+    Inserted,
+    Updated,
+    Removed,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct RepositoryChange {
+    pub kind: ChangeKind,
+    pub fetchable: Fetchable,
+    pub ids: Vec<u32>,
+}
+
+// Called on whichever thread wrote to the repository (often a background refresh), so hosts
+// should hop to their UI thread before touching views.
+#[uniffi::export(with_foreign)]
+pub trait RepositoryObserver: Send + Sync {
+    fn on_change(&self, change: RepositoryChange);
+}
+
+#[derive(Default)]
+pub struct Observers {
+    next_token: u64,
+    observers: Vec<(u64, Arc<dyn RepositoryObserver>)>,
+}
+
+impl Observers {
+    pub fn add(&mut self, observer: Arc<dyn RepositoryObserver>) -> u64 {
+        self.next_token += 1;
+        self.observers.push((self.next_token, observer));
+        self.next_token
+    }
+
+    pub fn remove(&mut self, token: u64) {
+        self.observers.retain(|(id, _)| *id != token);
+    }
+
+    pub fn snapshot(&self) -> Vec<Arc<dyn RepositoryObserver>> {
+        self.observers
+            .iter()
+            .map(|(_, observer)| Arc::clone(observer))
+            .collect()
+    }
+}
@@ -0,0 +1,194 @@
+use std::sync::{Arc, Mutex};
+use crate::api::api_client::{ApiClient, ensure_active};
+use crate::api::api_error::ApiError;
+use crate::api::cancellation::CancellationToken;
+use crate::api::fetchable::Fetchable;
+use crate::api::resource::Resource;
+use crate::api::selected::Selected;
+use crate::models::films::Film;
+use crate::models::page::Page;
+use crate::models::people::Person;
+use crate::models::planets::Planet;
+use crate::models::species::Species;
+use crate::models::starships::Starship;
+use crate::models::vehicles::Vehicle;
+
+#[derive(uniffi::Object)]
+pub struct PageCursor {
+    client: Arc<ApiClient>,
+    fetchable: Fetchable,
+    state: Mutex<CursorState>,
+}
+
+struct CursorState {
+    next: Option<String>,
+    // Set while a page is in flight, so concurrent calls can't fetch the same page twice
+    loading: bool,
+    previous: Option<String>,
+    total_count: Option<u32>,
+    items: Vec<Selected>,
+}
+
+struct LoadedPage {
+    next: Option<String>,
+    previous: Option<String>,
+    count: u32,
+    items: Vec<Selected>,
+}
+
+#[uniffi::export]
+impl ApiClient {
+    pub fn page_cursor(self: Arc<Self>, fetchable: Fetchable) -> Arc<PageCursor> {
+        Arc::new(PageCursor::new(self, fetchable))
+    }
+}
+
+#[uniffi::export]
+impl PageCursor {
+    pub async fn load_next(
+        &self,
+        cancellation: Option<Arc<CancellationToken>>,
+    ) -> Result<Vec<Selected>, ApiError> {
+        let url = {
+            let mut state = self.state.lock().unwrap();
+            let Some(url) = state.next.clone().filter(|_| !state.loading) else {
+                return Ok(Vec::new());
+            };
+            state.loading = true;
+            url
+        };
+
+        // Cleared however the load ends, including the caller dropping this future
+        let _loading = Loading(&self.state);
+        let loaded = self.load(url, cancellation.as_deref()).await?;
+
+        let mut state = self.state.lock().unwrap();
+        state.next = loaded.next;
+        state.previous = loaded.previous;
+        state.total_count = Some(loaded.count);
+        state.items.extend(loaded.items.iter().cloned());
+        Ok(loaded.items)
+    }
+
+    pub fn is_loading(&self) -> bool {
+        self.state.lock().unwrap().loading
+    }
+
+    pub fn has_more(&self) -> bool {
+        self.state.lock().unwrap().next.is_some()
+    }
+
+    pub fn total_count(&self) -> Option<u32> {
+        self.state.lock().unwrap().total_count
+    }
+
+    pub fn items(&self) -> Vec<Selected> {
+        self.state.lock().unwrap().items.clone()
+    }
+
+    pub fn next_url(&self) -> Option<String> {
+        self.state.lock().unwrap().next.clone()
+    }
+
+    pub fn previous_url(&self) -> Option<String> {
+        self.state.lock().unwrap().previous.clone()
+    }
+
+    pub fn fetchable(&self) -> Fetchable {
+        self.fetchable
+    }
+}
+
+impl PageCursor {
+    pub fn new(client: Arc<ApiClient>, fetchable: Fetchable) -> Self {
+        let first_page = client.config().url(fetchable);
+        Self {
+            client,
+            fetchable,
+            state: Mutex::new(CursorState {
+                next: Some(first_page),
+                loading: false,
+                previous: None,
+                total_count: None,
+                items: Vec::new(),
+            }),
+        }
+    }
+
+    async fn load(&self, url: String, cancellation: Option<&CancellationToken>) -> Result<LoadedPage, ApiError> {
+        match self.fetchable {
+            Fetchable::Films => self.load_page::<Film>(url, cancellation).await,
+            Fetchable::People => self.load_page::<Person>(url, cancellation).await,
+            Fetchable::Planets => self.load_page::<Planet>(url, cancellation).await,
+            Fetchable::Species => self.load_page::<Species>(url, cancellation).await,
+            Fetchable::Starships => self.load_page::<Starship>(url, cancellation).await,
+            Fetchable::Vehicles => self.load_page::<Vehicle>(url, cancellation).await,
+        }
+    }
+
+    // A cancelled load leaves both the cursor and the repository as they were
+    async fn load_page<R: Resource>(
+        &self,
+        url: String,
+        cancellation: Option<&CancellationToken>,
+    ) -> Result<LoadedPage, ApiError> {
+        let page = self.client.fetch_url::<R::Page>(url, cancellation).await?;
+        ensure_active(cancellation)?;
+        let config = self.client.config();
+        let next = page.next().map(|url| config.rebase(url));
+        let previous = page.previous().map(|url| config.rebase(url));
+        let count = page.count();
+        let mut results = page.into_results();
+        self.client.rebase_all(&mut results);
+        self.client.repository.insert_all(&results)?;
+        let items = results.into_iter().map(R::into_selected).collect();
+        Ok(LoadedPage { next, previous, count, items })
+    }
+}
+
+struct Loading<'a>(&'a Mutex<CursorState>);
+
+impl Drop for Loading<'_> {
+    fn drop(&mut self) {
+        self.0.lock().unwrap().loading = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+    use crate::api::fixtures::replay_client;
+    use super::*;
+
+    #[test]
+    fn loads_pages_in_order_until_exhausted() {
+        let cursor = replay_client().page_cursor(Fetchable::People);
+        assert_eq!(block_on(cursor.load_next(None)).unwrap().len(), 2);
+        assert_eq!(cursor.next_url().as_deref(), Some("https://swapi.dev/api/people/?page=2"));
+        assert_eq!(block_on(cursor.load_next(None)).unwrap().len(), 2);
+
+        assert!(!cursor.has_more());
+        assert!(!cursor.is_loading());
+        assert_eq!(cursor.items().len(), 4);
+        assert_eq!(cursor.total_count(), Some(4));
+    }
+
+    #[test]
+    fn keeps_the_next_link_when_a_load_fails() {
+        let cursor = PageCursor {
+            client: replay_client(),
+            fetchable: Fetchable::People,
+            state: Mutex::new(CursorState {
+                next: Some(String::from("https://swapi.dev/api/people/?page=9")),
+                loading: false,
+                previous: None,
+                total_count: None,
+                items: Vec::new(),
+            }),
+        };
+
+        assert!(block_on(cursor.load_next(None)).is_err());
+        assert_eq!(cursor.next_url().as_deref(), Some("https://swapi.dev/api/people/?page=9"));
+        assert!(!cursor.is_loading());
+    }
+}
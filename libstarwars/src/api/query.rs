@@ -0,0 +1,368 @@
+use std::cmp::Ordering;
+use std::sync::Arc;
+use serde::de::{self, Deserializer, Visitor};
+use serde::forward_to_deserialize_any;
+use serde_json::Value;
+use crate::api::repository::DataRepository;
+use crate::api::resource::Resource;
+use crate::models::films::Film;
+use crate::models::measurements::parse_measurement;
+use crate::models::people::Person;
+use crate::models::planets::Planet;
+use crate::models::species::Species;
+use crate::models::starships::Starship;
+use crate::models::vehicles::Vehicle;
+
+const MISSING_VALUES: [&str; 3] = ["", "unknown", "n/a"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum FilterOperator {
+    Equals,
+    NotEquals,
+    Contains,
+    StartsWith,
+    GreaterThan,
+    GreaterThanOrEqual,
+    LessThan,
+    LessThanOrEqual,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+// Field names are the SWAPI ones (e.g. "birth_year", "MGLT"), matched ignoring case. A name
+// the queried type doesn't have is an error rather than a filter that matches nothing.
+#[derive(thiserror::Error, uniffi::Error, Debug, Clone, PartialEq)]
+pub enum QueryError {
+    #[error("Unknown field: {field}")]
+    UnknownField { field: String },
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct FieldFilter {
+    pub field: String,
+    pub operator: FilterOperator,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct SortKey {
+    pub field: String,
+    pub direction: SortDirection,
+}
+
+// Immutable builder: every step returns a new query, so partially built queries can be shared.
+#[derive(Debug, Clone, Default, uniffi::Object)]
+pub struct Query {
+    filters: Vec<FieldFilter>,
+    sort: Vec<SortKey>,
+    limit: Option<u32>,
+    offset: u32,
+}
+
+#[uniffi::export]
+impl Query {
+    #[uniffi::constructor]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn filter(&self, field: String, operator: FilterOperator, value: String) -> Arc<Query> {
+        let mut query = self.clone();
+        query.filters.push(FieldFilter { field, operator, value });
+        Arc::new(query)
+    }
+
+    pub fn sort_by(&self, field: String, direction: SortDirection) -> Arc<Query> {
+        let mut query = self.clone();
+        query.sort.push(SortKey { field, direction });
+        Arc::new(query)
+    }
+
+    pub fn limit(&self, limit: u32) -> Arc<Query> {
+        let mut query = self.clone();
+        query.limit = Some(limit);
+        Arc::new(query)
+    }
+
+    pub fn offset(&self, offset: u32) -> Arc<Query> {
+        let mut query = self.clone();
+        query.offset = offset;
+        Arc::new(query)
+    }
+}
+
+impl Query {
+    pub fn run<R: Resource>(&self, items: Vec<R>) -> Result<Vec<R>, QueryError> {
+        self.validate::<R>()?;
+
+        // Fields are read through the serialized form so every model field is queryable by its
+        // SWAPI name without a hand-written accessor per type.
+        let mut rows = items
+            .into_iter()
+            .map(|item| {
+                let fields = serde_json::to_value(&item).unwrap_or(Value::Null);
+                (item, fields)
+            })
+            .filter(|(_, fields)| self.filters.iter().all(|filter| matches(fields, filter)))
+            .collect::<Vec<_>>();
+
+        if !self.sort.is_empty() {
+            rows.sort_by(|(_, a), (_, b)| {
+                self.sort.iter().fold(Ordering::Equal, |ordering, key| {
+                    ordering.then_with(|| compare(a, b, key))
+                })
+            });
+        }
+
+        Ok(rows
+            .into_iter()
+            .skip(self.offset as usize)
+            .take(self.limit.map_or(usize::MAX, |limit| limit as usize))
+            .map(|(item, _)| item)
+            .collect())
+    }
+
+    fn validate<R: Resource>(&self) -> Result<(), QueryError> {
+        let known = field_names::<R>();
+        let fields = self
+            .filters
+            .iter()
+            .map(|filter| &filter.field)
+            .chain(self.sort.iter().map(|key| &key.field));
+        for field in fields {
+            if !known.iter().any(|known| known.eq_ignore_ascii_case(field)) {
+                return Err(QueryError::UnknownField { field: field.clone() });
+            }
+        }
+        Ok(())
+    }
+}
+
+// The names serde uses for R's fields, read from its derived Deserialize impl (which hands them
+// to `deserialize_struct`), so they can't drift from the models and need no record to inspect.
+fn field_names<R: Resource>() -> &'static [&'static str] {
+    struct FieldNames<'a>(&'a mut &'static [&'static str]);
+
+    impl<'de> Deserializer<'de> for FieldNames<'_> {
+        type Error = de::value::Error;
+
+        fn deserialize_any<V: Visitor<'de>>(self, _: V) -> Result<V::Value, Self::Error> {
+            Err(de::Error::custom("not a struct"))
+        }
+
+        fn deserialize_struct<V: Visitor<'de>>(
+            self,
+            _: &'static str,
+            fields: &'static [&'static str],
+            _: V,
+        ) -> Result<V::Value, Self::Error> {
+            *self.0 = fields;
+            Err(de::Error::custom("only the field names are needed"))
+        }
+
+        forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf
+            option unit unit_struct newtype_struct seq tuple tuple_struct map enum identifier
+            ignored_any
+        }
+    }
+
+    let mut fields: &'static [&'static str] = &[];
+    let _ = R::deserialize(FieldNames(&mut fields));
+    fields
+}
+
+enum SortValue {
+    Number(f64),
+    Text(String),
+    Missing,
+}
+
+fn field_text(fields: &Value, name: &str) -> Option<String> {
+    let Value::Object(map) = fields else {
+        return None;
+    };
+    let value = map
+        .get(name)
+        .or_else(|| map.iter().find(|(key, _)| key.eq_ignore_ascii_case(name)).map(|(_, value)| value))?;
+    let text = match value {
+        Value::String(text) => text.clone(),
+        Value::Number(number) => number.to_string(),
+        Value::Bool(flag) => flag.to_string(),
+        Value::Array(values) => values
+            .iter()
+            .filter_map(Value::as_str)
+            .collect::<Vec<_>>()
+            .join(", "),
+        _ => return None,
+    };
+    (!MISSING_VALUES.contains(&text.trim().to_lowercase().as_str())).then_some(text)
+}
+
+fn sort_value(fields: &Value, name: &str) -> SortValue {
+    match field_text(fields, name) {
+        Some(text) => match parse_measurement(&text) {
+            Some(number) => SortValue::Number(number),
+            None => SortValue::Text(text.to_lowercase()),
+        },
+        None => SortValue::Missing,
+    }
+}
+
+fn matches(fields: &Value, filter: &FieldFilter) -> bool {
+    let Some(text) = field_text(fields, &filter.field) else {
+        return filter.operator == FilterOperator::NotEquals;
+    };
+    let target = filter.value.trim();
+    let numbers = parse_measurement(&text).zip(parse_measurement(target));
+    let text = text.to_lowercase();
+    let target = target.to_lowercase();
+
+    match filter.operator {
+        FilterOperator::Equals => numbers.map_or(text == target, |(value, target)| value == target),
+        FilterOperator::NotEquals => numbers.map_or(text != target, |(value, target)| value != target),
+        FilterOperator::Contains => text.contains(&target),
+        FilterOperator::StartsWith => text.starts_with(&target),
+        FilterOperator::GreaterThan => numbers.is_some_and(|(value, target)| value > target),
+        FilterOperator::GreaterThanOrEqual => numbers.is_some_and(|(value, target)| value >= target),
+        FilterOperator::LessThan => numbers.is_some_and(|(value, target)| value < target),
+        FilterOperator::LessThanOrEqual => numbers.is_some_and(|(value, target)| value <= target),
+    }
+}
+
+// Numbers sort numerically ahead of text, and missing values ("unknown", "n/a") always sort last
+// regardless of direction.
+fn compare(a: &Value, b: &Value, key: &SortKey) -> Ordering {
+    let ordering = match (sort_value(a, &key.field), sort_value(b, &key.field)) {
+        (SortValue::Missing, SortValue::Missing) => return Ordering::Equal,
+        (SortValue::Missing, _) => return Ordering::Greater,
+        (_, SortValue::Missing) => return Ordering::Less,
+        (SortValue::Number(a), SortValue::Number(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+        (SortValue::Number(_), SortValue::Text(_)) => Ordering::Less,
+        (SortValue::Text(_), SortValue::Number(_)) => Ordering::Greater,
+        (SortValue::Text(a), SortValue::Text(b)) => a.cmp(&b),
+    };
+    match key.direction {
+        SortDirection::Ascending => ordering,
+        SortDirection::Descending => ordering.reverse(),
+    }
+}
+
+impl DataRepository {
+    pub fn query<R: Resource>(&self, query: &Query) -> Result<Vec<R>, QueryError> {
+        query.run(self.get_all::<R>().unwrap_or_default())
+    }
+}
+
+#[uniffi::export]
+impl DataRepository {
+    pub fn query_films(&self, query: Arc<Query>) -> Result<Vec<Film>, QueryError> {
+        self.query(&query)
+    }
+
+    pub fn query_people(&self, query: Arc<Query>) -> Result<Vec<Person>, QueryError> {
+        self.query(&query)
+    }
+
+    pub fn query_planets(&self, query: Arc<Query>) -> Result<Vec<Planet>, QueryError> {
+        self.query(&query)
+    }
+
+    pub fn query_species(&self, query: Arc<Query>) -> Result<Vec<Species>, QueryError> {
+        self.query(&query)
+    }
+
+    pub fn query_starships(&self, query: Arc<Query>) -> Result<Vec<Starship>, QueryError> {
+        self.query(&query)
+    }
+
+    pub fn query_vehicles(&self, query: Arc<Query>) -> Result<Vec<Vehicle>, QueryError> {
+        self.query(&query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PERSON: &str =
+        include_str!("../../tests/fixtures/swapi/swapi_dev_api_people_1-f83e62a1d294e0a9.json");
+
+    fn person(id: u32, name: &str, height: &str) -> Person {
+        let fixture = serde_json::from_str::<Value>(PERSON).unwrap();
+        Person {
+            name: name.to_owned(),
+            height: height.to_owned(),
+            url: format!("https://swapi.dev/api/people/{id}/"),
+            ..serde_json::from_str(fixture["body"].as_str().unwrap()).unwrap()
+        }
+    }
+
+    fn people() -> Vec<Person> {
+        vec![
+            person(1, "Luke Skywalker", "172"),
+            person(2, "C-3PO", "167"),
+            person(3, "R2-D2", "96"),
+            person(4, "Darth Vader", "202"),
+            person(5, "Arvel Crynyd", "unknown"),
+        ]
+    }
+
+    fn filter(field: &str, operator: FilterOperator, value: &str) -> Arc<Query> {
+        Query::new().filter(field.to_owned(), operator, value.to_owned())
+    }
+
+    fn names(query: &Query) -> Vec<String> {
+        query.run(people()).unwrap().into_iter().map(|person| person.name).collect()
+    }
+
+    #[test]
+    fn knows_each_models_fields_by_their_swapi_names() {
+        assert!(field_names::<Person>().contains(&"birth_year"));
+        assert!(field_names::<Starship>().contains(&"MGLT"));
+        assert!(!field_names::<Planet>().contains(&"birth_year"));
+    }
+
+    #[test]
+    fn rejects_fields_the_type_does_not_have() {
+        let unknown = |field: &str| Err(QueryError::UnknownField { field: field.to_owned() });
+
+        let query = filter("heigth", FilterOperator::NotEquals, "1");
+        assert_eq!(query.run(people()), unknown("heigth"));
+        let query = Query::new().sort_by(String::from("diameter"), SortDirection::Ascending);
+        assert_eq!(query.run(people()), unknown("diameter"));
+        // Checked even when there is nothing to filter
+        assert_eq!(query.run(Vec::<Person>::new()), unknown("diameter"));
+    }
+
+    #[test]
+    fn filters_numbers_numerically_and_text_ignoring_case() {
+        let tall = filter("Height", FilterOperator::GreaterThan, "170");
+        assert_eq!(names(&tall), ["Luke Skywalker", "Darth Vader"]);
+        let darth = filter("name", FilterOperator::StartsWith, "DARTH");
+        assert_eq!(names(&darth), ["Darth Vader"]);
+    }
+
+    #[test]
+    fn missing_values_match_only_not_equals() {
+        let query = filter("height", FilterOperator::NotEquals, "172");
+        assert_eq!(names(&query), ["C-3PO", "R2-D2", "Darth Vader", "Arvel Crynyd"]);
+        let query = filter("height", FilterOperator::LessThan, "1000");
+        assert_eq!(names(&query), ["Luke Skywalker", "C-3PO", "R2-D2", "Darth Vader"]);
+    }
+
+    #[test]
+    fn sorts_missing_values_last_in_either_direction_then_pages() {
+        let query = Query::new().sort_by(String::from("height"), SortDirection::Descending);
+        let expected = ["Darth Vader", "Luke Skywalker", "C-3PO", "R2-D2", "Arvel Crynyd"];
+        assert_eq!(names(&query), expected);
+        let query = Query::new().sort_by(String::from("height"), SortDirection::Ascending);
+        let expected = ["R2-D2", "C-3PO", "Luke Skywalker", "Darth Vader", "Arvel Crynyd"];
+        assert_eq!(names(&query), expected);
+        assert_eq!(names(&query.offset(1).limit(2)), ["C-3PO", "Luke Skywalker"]);
+    }
+}
@@ -0,0 +1,186 @@
+use std::sync::Arc;
+use futures::{StreamExt, stream};
+use crate::api::api_client::{ApiClient, ensure_active};
+use crate::api::api_error::ApiError;
+use crate::api::cache::CachePolicy;
+use crate::api::cancellation::CancellationToken;
+use crate::api::fetchable::Fetchable;
+use crate::api::list_items::ListItems;
+use crate::api::resource::Resource;
+use crate::api::selected::Selected;
+use crate::models::films::Film;
+use crate::models::people::Person;
+use crate::models::planets::Planet;
+use crate::models::species::Species;
+use crate::models::starships::Starship;
+use crate::models::vehicles::Vehicle;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum Relationship {
+    Planets,
+    People,
+    Films,
+    Species,
+    Starships,
+    Vehicles,
+    Homeworld,
+    Characters,
+    Residents,
+    Pilots,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct RelatedGroup {
+    pub relationship: Relationship,
+    pub items: Vec<Selected>,
+    // Links that couldn't be resolved; the rest of the group is still returned
+    pub failed_urls: Vec<String>,
+}
+
+impl ListItems {
+    pub fn relationship(&self) -> Relationship {
+        match self {
+            Self::Planets(_) => Relationship::Planets,
+            Self::People(_) => Relationship::People,
+            Self::Films(_) => Relationship::Films,
+            Self::Species(_) => Relationship::Species,
+            Self::Starships(_) => Relationship::Starships,
+            Self::Vehicles(_) => Relationship::Vehicles,
+            Self::Homeworld(_) => Relationship::Homeworld,
+            Self::Characters(_) => Relationship::Characters,
+            Self::Residents(_) => Relationship::Residents,
+            Self::Pilots(_) => Relationship::Pilots,
+        }
+    }
+
+    pub fn into_urls(self) -> Vec<String> {
+        match self {
+            Self::Homeworld(url) => vec![url],
+            Self::Planets(urls)
+            | Self::People(urls)
+            | Self::Films(urls)
+            | Self::Species(urls)
+            | Self::Starships(urls)
+            | Self::Vehicles(urls)
+            | Self::Characters(urls)
+            | Self::Residents(urls)
+            | Self::Pilots(urls) => urls,
+        }
+    }
+}
+
+impl Relationship {
+    pub fn fetchable(&self) -> Fetchable {
+        match self {
+            Self::Planets | Self::Homeworld => Fetchable::Planets,
+            Self::People | Self::Characters | Self::Residents | Self::Pilots => Fetchable::People,
+            Self::Films => Fetchable::Films,
+            Self::Species => Fetchable::Species,
+            Self::Starships => Fetchable::Starships,
+            Self::Vehicles => Fetchable::Vehicles,
+        }
+    }
+}
+
+#[uniffi::export]
+impl ApiClient {
+    pub async fn resolve_related(
+        self: Arc<Self>,
+        items: Vec<ListItems>,
+        cancellation: Option<Arc<CancellationToken>>,
+    ) -> Result<Vec<RelatedGroup>, ApiError> {
+        let relationships = items.iter().map(ListItems::relationship).collect::<Vec<_>>();
+        let requests = items
+            .into_iter()
+            .enumerate()
+            .flat_map(|(group, item)| {
+                let fetchable = item.relationship().fetchable();
+                item.into_urls().into_iter().map(move |url| (group, fetchable, url))
+            })
+            .collect::<Vec<_>>();
+
+        // `buffered` keeps results in request order, so each group comes back as listed
+        let limit = self.config().max_concurrent_requests.max(1) as usize;
+        let resolved = stream::iter(requests)
+            .map(|(group, fetchable, url)| {
+                let client = Arc::clone(&self);
+                let cancellation = cancellation.clone();
+                async move {
+                    let result = client.fetch_selected(fetchable, url.clone(), cancellation.as_deref()).await;
+                    (group, url, result)
+                }
+            })
+            .buffered(limit)
+            .collect::<Vec<_>>()
+            .await;
+        // Links that failed because of the cancellation aren't worth reporting one by one
+        ensure_active(cancellation.as_deref())?;
+
+        let mut groups = relationships
+            .into_iter()
+            .map(|relationship| RelatedGroup {
+                relationship,
+                items: Vec::new(),
+                failed_urls: Vec::new(),
+            })
+            .collect::<Vec<_>>();
+        for (group, url, result) in resolved {
+            match result {
+                Ok(selected) => groups[group].items.push(selected),
+                Err(_) => groups[group].failed_urls.push(url),
+            }
+        }
+        Ok(groups)
+    }
+}
+
+impl ApiClient {
+    async fn fetch_selected(
+        self: &Arc<Self>,
+        fetchable: Fetchable,
+        url: String,
+        cancellation: Option<&CancellationToken>,
+    ) -> Result<Selected, ApiError> {
+        match fetchable {
+            Fetchable::Films => self.fetch_related::<Film>(url, cancellation).await,
+            Fetchable::People => self.fetch_related::<Person>(url, cancellation).await,
+            Fetchable::Planets => self.fetch_related::<Planet>(url, cancellation).await,
+            Fetchable::Species => self.fetch_related::<Species>(url, cancellation).await,
+            Fetchable::Starships => self.fetch_related::<Starship>(url, cancellation).await,
+            Fetchable::Vehicles => self.fetch_related::<Vehicle>(url, cancellation).await,
+        }
+    }
+
+    async fn fetch_related<R: Resource>(
+        self: &Arc<Self>,
+        url: String,
+        cancellation: Option<&CancellationToken>,
+    ) -> Result<Selected, ApiError> {
+        let item = self.fetch_resource::<R>(url, CachePolicy::CacheFirst, cancellation).await?;
+        Ok(item.into_selected())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+    use crate::api::fixtures::replay_client;
+    use super::*;
+
+    #[test]
+    fn failed_links_are_reported_without_dropping_the_rest() {
+        let items = vec![
+            ListItems::Characters(vec![
+                String::from("https://swapi.dev/api/people/1/"),
+                String::from("https://swapi.dev/api/people/999/"),
+            ]),
+            ListItems::Homeworld(String::from("https://swapi.dev/api/planets/1/")),
+        ];
+        let groups = block_on(replay_client().resolve_related(items, None)).unwrap();
+
+        assert!(matches!(&groups[0].items[..], [Selected::Person(person)] if person.name == "Luke Skywalker"));
+        assert_eq!(groups[0].failed_urls, ["https://swapi.dev/api/people/999/"]);
+        assert!(matches!(&groups[1].items[..], [Selected::Planet(planet)] if planet.name == "Tatooine"));
+        assert!(groups[1].failed_urls.is_empty());
+    }
+}
@@ -1,20 +1,37 @@
+use crate::api::cache::{is_fresh, now, CacheEntry, CacheTable, Cached, CachedResponse, DEFAULT_TTL_SECONDS};
+use crate::api::fetchable::{all_fetchable, Fetchable};
+use crate::api::observer::{ChangeKind, Observers, RepositoryChange, RepositoryObserver};
+use crate::api::resource::{Resource, Table};
+use crate::api::store::{stable_hash, InMemoryStore, RepositoryStore, StoreError};
 use crate::models::films::Film;
 use crate::models::people::Person;
 use crate::models::planets::Planet;
 use crate::models::species::Species;
 use crate::models::starships::Starship;
 use crate::models::vehicles::Vehicle;
+use std::cmp;
 use std::collections::HashMap;
-use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
 
 #[derive(uniffi::Object)]
 pub struct DataRepository {
-    planets: Mutex<HashMap<String, Planet>>,
-    people: Mutex<HashMap<String, Person>>,
-    films: Mutex<HashMap<String, Film>>,
-    species: Mutex<HashMap<String, Species>>,
-    starships: Mutex<HashMap<String, Starship>>,
-    vehicles: Mutex<HashMap<String, Vehicle>>,
+    store: Arc<dyn RepositoryStore>,
+    ttls: Mutex<HashMap<Fetchable, u64>>,
+    // An in-memory repository already holds every record, so it doesn't keep a second copy of
+    // each response body for revalidation
+    keeps_responses: bool,
+    revision: AtomicU64,
+    // Revision of the last snapshot written for each table; one lock per table, so a slow write
+    // of one doesn't hold up the others
+    persisted: HashMap<Fetchable, Mutex<u64>>,
+    observers: Mutex<Observers>,
+    pub(crate) planets: Table<Planet>,
+    pub(crate) people: Table<Person>,
+    pub(crate) films: Table<Film>,
+    pub(crate) species: Table<Species>,
+    pub(crate) starships: Table<Starship>,
+    pub(crate) vehicles: Table<Vehicle>,
 }
 
 #[uniffi::export]
@@ -22,12 +39,75 @@ impl DataRepository {
     #[uniffi::constructor]
     pub fn new() -> Self {
         Self {
-            planets: Mutex::new(HashMap::new()),
-            people: Mutex::new(HashMap::new()),
-            films: Mutex::new(HashMap::new()),
-            species: Mutex::new(HashMap::new()),
-            starships: Mutex::new(HashMap::new()),
-            vehicles: Mutex::new(HashMap::new()),
+            keeps_responses: false,
+            ..Self::empty(Arc::new(InMemoryStore::new()))
+        }
+    }
+
+    #[uniffi::constructor]
+    pub fn with_store(store: Arc<dyn RepositoryStore>) -> Result<Self, StoreError> {
+        let repository = Self::empty(store);
+        repository.load::<Planet>()?;
+        repository.load::<Person>()?;
+        repository.load::<Film>()?;
+        repository.load::<Species>()?;
+        repository.load::<Starship>()?;
+        repository.load::<Vehicle>()?;
+        Ok(repository)
+    }
+
+    pub fn set_ttl(&self, fetchable: Fetchable, ttl_seconds: u64) {
+        self.ttls.lock().unwrap().insert(fetchable, ttl_seconds);
+    }
+
+    pub fn ttl(&self, fetchable: Fetchable) -> u64 {
+        self.ttls
+            .lock()
+            .unwrap()
+            .get(&fetchable)
+            .copied()
+            .unwrap_or(DEFAULT_TTL_SECONDS)
+    }
+
+    pub fn fetched_at(&self, fetchable: Fetchable, url: &str) -> Option<u64> {
+        match fetchable {
+            Fetchable::Planets => self.fetched_at_in::<Planet>(url),
+            Fetchable::People => self.fetched_at_in::<Person>(url),
+            Fetchable::Films => self.fetched_at_in::<Film>(url),
+            Fetchable::Species => self.fetched_at_in::<Species>(url),
+            Fetchable::Starships => self.fetched_at_in::<Starship>(url),
+            Fetchable::Vehicles => self.fetched_at_in::<Vehicle>(url),
+        }
+    }
+
+    // Returns a token for `remove_observer`
+    pub fn add_observer(&self, observer: Arc<dyn RepositoryObserver>) -> u64 {
+        self.observers.lock().unwrap().add(observer)
+    }
+
+    pub fn remove_observer(&self, token: u64) {
+        self.observers.lock().unwrap().remove(token);
+    }
+
+    pub fn remove(&self, fetchable: Fetchable, id: u32) -> Result<(), StoreError> {
+        match fetchable {
+            Fetchable::Planets => self.remove_in::<Planet>(&[id]),
+            Fetchable::People => self.remove_in::<Person>(&[id]),
+            Fetchable::Films => self.remove_in::<Film>(&[id]),
+            Fetchable::Species => self.remove_in::<Species>(&[id]),
+            Fetchable::Starships => self.remove_in::<Starship>(&[id]),
+            Fetchable::Vehicles => self.remove_in::<Vehicle>(&[id]),
+        }
+    }
+
+    pub fn clear(&self, fetchable: Fetchable) -> Result<(), StoreError> {
+        match fetchable {
+            Fetchable::Planets => self.clear_in::<Planet>(),
+            Fetchable::People => self.clear_in::<Person>(),
+            Fetchable::Films => self.clear_in::<Film>(),
+            Fetchable::Species => self.clear_in::<Species>(),
+            Fetchable::Starships => self.clear_in::<Starship>(),
+            Fetchable::Vehicles => self.clear_in::<Vehicle>(),
         }
     }
 }
@@ -38,30 +118,277 @@ impl Default for DataRepository {
     }
 }
 
+impl DataRepository {
+    fn empty(store: Arc<dyn RepositoryStore>) -> Self {
+        Self {
+            store,
+            keeps_responses: true,
+            ttls: Mutex::new(HashMap::new()),
+            revision: AtomicU64::new(0),
+            persisted: all_fetchable().into_iter().map(|fetchable| (fetchable, Mutex::new(0))).collect(),
+            observers: Mutex::new(Observers::default()),
+            planets: Mutex::new(CacheTable::default()),
+            people: Mutex::new(CacheTable::default()),
+            films: Mutex::new(CacheTable::default()),
+            species: Mutex::new(CacheTable::default()),
+            starships: Mutex::new(CacheTable::default()),
+            vehicles: Mutex::new(CacheTable::default()),
+        }
+    }
+
+    fn load<R: Resource>(&self) -> Result<(), StoreError> {
+        let Some(contents) = self.store.read(R::FETCHABLE.path().to_owned())? else {
+            return Ok(());
+        };
+        // A cache we can no longer decode (e.g. written by an older model layout) is dropped
+        // rather than failing the launch; it will be repopulated by the next fetch.
+        let Ok(stored) = serde_json::from_slice::<CacheTable<R>>(&contents) else {
+            return Ok(());
+        };
+        *R::table(self).lock().unwrap() = stored;
+        Ok(())
+    }
+
+    // Taken with the table locked, bumping the revision, so the store write can happen after
+    // the lock is released
+    fn snapshot<R: Resource>(&self, table: &CacheTable<R>) -> Result<Snapshot, StoreError> {
+        let revision = self.revision.fetch_add(1, Ordering::Relaxed) + 1;
+        let contents = serde_json::to_vec(table).map_err(|err| StoreError::Io {
+            reason: err.to_string(),
+        })?;
+        Ok(Snapshot {
+            fetchable: R::FETCHABLE,
+            revision,
+            contents,
+        })
+    }
+
+    // Concurrent writers can reach the store out of order, so a snapshot older than the one
+    // already written is dropped rather than overwriting it
+    fn persist(&self, snapshot: Snapshot) -> Result<(), StoreError> {
+        let mut written = self.persisted[&snapshot.fetchable].lock().unwrap();
+        if *written > snapshot.revision {
+            return Ok(());
+        }
+        self.store.write(snapshot.fetchable.path().to_owned(), snapshot.contents)?;
+        *written = snapshot.revision;
+        Ok(())
+    }
+
+    // Responses are stored one per key, so revalidating a page doesn't rewrite every other one
+    pub fn cached_response(&self, url: &str) -> Option<CachedResponse> {
+        if !self.keeps_responses {
+            return None;
+        }
+        let contents = self.store.read(response_key(url)).ok()??;
+        serde_json::from_slice::<CachedResponse>(&contents)
+            .ok()
+            .filter(|cached| cached.url == url)
+    }
+
+    pub fn save_response(&self, response: &CachedResponse) -> Result<(), StoreError> {
+        if !self.keeps_responses {
+            return Ok(());
+        }
+        let contents = serde_json::to_vec(response).map_err(|err| StoreError::Io {
+            reason: err.to_string(),
+        })?;
+        self.store.write(response_key(&response.url), contents)
+    }
+
+    fn fetched_at_in<R: Resource>(&self, url: &str) -> Option<u64> {
+        let id = R::FETCHABLE.resource_id(url)?;
+        let table = R::table(self).lock().unwrap();
+        table.entries.get(&id).map(|entry| entry.fetched_at)
+    }
+
+    pub fn insert<R: Resource>(&self, item: &R) -> Result<(), StoreError> {
+        self.insert_all(std::slice::from_ref(item))
+    }
+
+    pub fn insert_all<R: Resource>(&self, items: &[R]) -> Result<(), StoreError> {
+        self.insert_entries(items, false)
+    }
+
+    // Marks the table as listed in full, which is what makes `lookup_all` fresh
+    pub fn insert_collection<R: Resource>(&self, items: &[R]) -> Result<(), StoreError> {
+        self.insert_entries(items, true)
+    }
+
+    fn insert_entries<R: Resource>(&self, items: &[R], listed: bool) -> Result<(), StoreError> {
+        let mut inserted = Vec::new();
+        let mut updated = Vec::new();
+        let snapshot = {
+            let mut table = R::table(self).lock().unwrap();
+            let fetched_at = now();
+            if listed {
+                table.listed_at = Some(fetched_at);
+            }
+            // Items without an id in their url can't be addressed later, so they aren't cached
+            for item in items {
+                let Some(id) = item.id() else {
+                    continue;
+                };
+                match table.entries.get(&id) {
+                    None => inserted.push(id),
+                    Some(previous) => match edit_order(&previous.value, item) {
+                        // e.g. a page fetched before a record changed, landing after a fetch of it
+                        Some(cmp::Ordering::Greater) => continue,
+                        Some(cmp::Ordering::Less) => updated.push(id),
+                        // Same or unknown edit time: fall back to comparing the records
+                        _ if previous.value != *item => updated.push(id),
+                        // Re-fetching an unchanged record only refreshes its timestamp
+                        _ => {}
+                    },
+                }
+                let entry = CacheEntry {
+                    value: item.clone(),
+                    fetched_at,
+                };
+                table.entries.insert(id, entry);
+            }
+            // Refreshed fetch times alone aren't worth rewriting the table for; after a relaunch
+            // those records just look a little older than they are
+            let changed = listed || !inserted.is_empty() || !updated.is_empty();
+            changed.then(|| self.snapshot(&table)).transpose()
+        };
+        let result = match snapshot {
+            Ok(Some(snapshot)) => self.persist(snapshot),
+            Ok(None) => Ok(()),
+            Err(error) => Err(error),
+        };
+        self.notify(R::FETCHABLE, ChangeKind::Inserted, inserted);
+        self.notify(R::FETCHABLE, ChangeKind::Updated, updated);
+        result
+    }
+
+    pub fn remove_in<R: Resource>(&self, ids: &[u32]) -> Result<(), StoreError> {
+        let (removed, snapshot) = {
+            let mut table = R::table(self).lock().unwrap();
+            let removed = ids
+                .iter()
+                .filter(|id| table.entries.remove(id).is_some())
+                .copied()
+                .collect::<Vec<_>>();
+            if removed.is_empty() {
+                return Ok(());
+            }
+            (removed, self.snapshot(&table))
+        };
+        let result = snapshot.and_then(|snapshot| self.persist(snapshot));
+        self.notify(R::FETCHABLE, ChangeKind::Removed, removed);
+        result
+    }
+
+    // Also drops the table's stored responses, so nothing cleared can come back through a 304
+    pub fn clear_in<R: Resource>(&self) -> Result<(), StoreError> {
+        let ids = {
+            let mut table = R::table(self).lock().unwrap();
+            table.listed_at = None;
+            table.entries.keys().copied().collect::<Vec<_>>()
+        };
+        let prefix = format!("response-{}-", R::FETCHABLE.path());
+        for key in self.store.keys()? {
+            if key.starts_with(&prefix) {
+                self.store.remove(key)?;
+            }
+        }
+        self.remove_in::<R>(&ids)
+    }
+
+    // Observers are called with no table locked, so they can read the repository straight back
+    fn notify(&self, fetchable: Fetchable, kind: ChangeKind, ids: Vec<u32>) {
+        if ids.is_empty() {
+            return;
+        }
+        let observers = self.observers.lock().unwrap().snapshot();
+        let change = RepositoryChange { kind, fetchable, ids };
+        for observer in observers {
+            observer.on_change(change.clone());
+        }
+    }
+
+    pub fn lookup<R: Resource>(&self, url: &str) -> Option<Cached<R>> {
+        self.lookup_by_id(R::FETCHABLE.resource_id(url)?)
+    }
+
+    pub fn lookup_by_id<R: Resource>(&self, id: u32) -> Option<Cached<R>> {
+        let ttl = self.ttl(R::FETCHABLE);
+        let table = R::table(self).lock().unwrap();
+        table.entries.get(&id).map(|entry| Cached {
+            value: entry.value.clone(),
+            is_fresh: is_fresh(entry.fetched_at, ttl),
+        })
+    }
+
+    pub fn lookup_all<R: Resource>(&self) -> Option<Cached<Vec<R>>> {
+        let ttl = self.ttl(R::FETCHABLE);
+        let table = R::table(self).lock().unwrap();
+        if table.entries.is_empty() {
+            return None;
+        }
+        let values = table
+            .entries
+            .values()
+            .map(|entry| entry.value.clone())
+            .collect::<Vec<R>>();
+        Some(Cached {
+            value: values,
+            is_fresh: table.listed_at.is_some_and(|listed_at| is_fresh(listed_at, ttl)),
+        })
+    }
+
+    pub fn get<R: Resource>(&self, url: &str) -> Option<R> {
+        self.lookup(url).map(|cached| cached.value)
+    }
+
+    pub fn get_by_id<R: Resource>(&self, id: u32) -> Option<R> {
+        self.lookup_by_id(id).map(|cached| cached.value)
+    }
+
+    pub fn get_all<R: Resource>(&self) -> Option<Vec<R>> {
+        self.lookup_all().map(|cached| cached.value)
+    }
+}
+
+// How the cached copy's edit time compares to the incoming one's, if both have one
+fn edit_order<R: Resource>(cached: &R, incoming: &R) -> Option<cmp::Ordering> {
+    Some(cached.edited_at()?.cmp(&incoming.edited_at()?))
+}
+
+struct Snapshot {
+    fetchable: Fetchable,
+    revision: u64,
+    contents: Vec<u8>,
+}
+
+// URLs can't be used as store keys directly (a FileStore turns keys into file names). The
+// collection is kept readable so `clear` can find a table's responses.
+fn response_key(url: &str) -> String {
+    let collection = Fetchable::for_url(url).map_or("other", |fetchable| fetchable.path());
+    format!("response-{collection}-{:016x}", stable_hash(url))
+}
+
 // ========================================================================
 // Planets
 // ========================================================================
 
 #[uniffi::export]
 impl DataRepository {
-    pub fn insert_planet(&self, planet: &Planet) {
-        let mut planets = self.planets.lock().unwrap();
-        planets.insert(planet.url.clone(), planet.clone());
+    pub fn insert_planet(&self, planet: &Planet) -> Result<(), StoreError> {
+        self.insert(planet)
     }
 
     pub fn get_planet(&self, url: &str) -> Option<Planet> {
-        let planets = self.planets.lock().unwrap();
-        planets.get (url).cloned()
+        self.get(url)
+    }
+
+    pub fn get_planet_by_id(&self, id: u32) -> Option<Planet> {
+        self.get_by_id(id)
     }
 
     pub fn get_planets(&self) -> Option<Vec<Planet>> {
-        let planets = self.planets.lock().unwrap();
-        if planets.is_empty() {
-            return None;
-        }
-        let mut sorted_planets = planets.values().cloned().collect::<Vec<Planet>>();
-        sorted_planets.sort_by(|a, b| a.url.cmp(&b.url));
-        Some(sorted_planets)
+        self.get_all()
     }
 }
 
@@ -69,25 +396,22 @@ impl DataRepository {
 // People
 // ========================================================================
 
+#[uniffi::export]
 impl DataRepository {
-    pub fn insert_person(&self, person: &Person) {
-        let mut people = self.people.lock().unwrap();
-        people.insert(person.url.clone(), person.clone());
+    pub fn insert_person(&self, person: &Person) -> Result<(), StoreError> {
+        self.insert(person)
     }
 
     pub fn get_person(&self, url: &str) -> Option<Person> {
-        let people = self.people.lock().unwrap();
-        people.get(url).cloned()
+        self.get(url)
+    }
+
+    pub fn get_person_by_id(&self, id: u32) -> Option<Person> {
+        self.get_by_id(id)
     }
 
     pub fn get_people(&self) -> Option<Vec<Person>> {
-        let people = self.people.lock().unwrap();
-        if people.is_empty() {
-            return None;
-        }
-        let mut sorted_people = people.values().cloned().collect::<Vec<Person>>();
-        sorted_people.sort_by(|a, b| a.url.cmp(&b.url));
-        Some(sorted_people)
+        self.get_all()
     }
 }
 
@@ -95,25 +419,22 @@ impl DataRepository {
 // Films
 // ========================================================================
 
+#[uniffi::export]
 impl DataRepository {
-    pub fn insert_film(&self, film: &Film) {
-        let mut films = self.films.lock().unwrap();
-        films.insert(film.url.clone(), film.clone());
+    pub fn insert_film(&self, film: &Film) -> Result<(), StoreError> {
+        self.insert(film)
     }
 
     pub fn get_film(&self, url: &str) -> Option<Film> {
-        let films = self.films.lock().unwrap();
-        films.get(url).cloned()
+        self.get(url)
+    }
+
+    pub fn get_film_by_id(&self, id: u32) -> Option<Film> {
+        self.get_by_id(id)
     }
 
     pub fn get_films(&self) -> Option<Vec<Film>> {
-        let films = self.films.lock().unwrap();
-        if films.is_empty() {
-            return None;
-        }
-        let mut sorted_films = films.values().cloned().collect::<Vec<Film>>();
-        sorted_films.sort_by(|a, b| a.url.cmp(&b.url));
-        Some(sorted_films)
+        self.get_all()
     }
 }
 
@@ -121,25 +442,22 @@ impl DataRepository {
 // Species
 // ========================================================================
 
+#[uniffi::export]
 impl DataRepository {
-    pub fn insert_species(&self, species: &Species) {
-        let mut species_map = self.species.lock().unwrap();
-        species_map.insert(species.url.clone(), species.clone());
+    pub fn insert_species(&self, species: &Species) -> Result<(), StoreError> {
+        self.insert(species)
     }
 
     pub fn get_species(&self, url: &str) -> Option<Species> {
-        let species = self.species.lock().unwrap();
-        species.get(url).cloned()
+        self.get(url)
+    }
+
+    pub fn get_species_by_id(&self, id: u32) -> Option<Species> {
+        self.get_by_id(id)
     }
 
     pub fn get_species_list(&self) -> Option<Vec<Species>> {
-        let species = self.species.lock().unwrap();
-        if species.is_empty() {
-            return None;
-        }
-        let mut sorted_species = species.values().cloned().collect::<Vec<Species>>();
-        sorted_species.sort_by(|a, b| a.url.cmp(&b.url));
-        Some(sorted_species)
+        self.get_all()
     }
 }
 
@@ -147,25 +465,22 @@ impl DataRepository {
 // Starships
 // ========================================================================
 
+#[uniffi::export]
 impl DataRepository {
-    pub fn insert_starship(&self, starship: &Starship) {
-        let mut starships = self.starships.lock().unwrap();
-        starships.insert(starship.url.clone(), starship.clone());
+    pub fn insert_starship(&self, starship: &Starship) -> Result<(), StoreError> {
+        self.insert(starship)
     }
 
     pub fn get_starship(&self, url: &str) -> Option<Starship> {
-        let starships = self.starships.lock().unwrap();
-        starships.get(url).cloned()
+        self.get(url)
+    }
+
+    pub fn get_starship_by_id(&self, id: u32) -> Option<Starship> {
+        self.get_by_id(id)
     }
 
     pub fn get_starships(&self) -> Option<Vec<Starship>> {
-        let starships = self.starships.lock().unwrap();
-        if starships.is_empty() {
-            return None;
-        }
-        let mut sorted_starships = starships.values().cloned().collect::<Vec<Starship>>();
-        sorted_starships.sort_by(|a, b| a.url.cmp(&b.url));
-        Some(sorted_starships)
+        self.get_all()
     }
 }
 
@@ -173,24 +488,330 @@ impl DataRepository {
 // Vehicles
 // ========================================================================
 
+#[uniffi::export]
 impl DataRepository {
-    pub fn insert_vehicle(&self, vehicle: &Vehicle) {
-        let mut vehicles = self.vehicles.lock().unwrap();
-        vehicles.insert(vehicle.url.clone(), vehicle.clone());
+    pub fn insert_vehicle(&self, vehicle: &Vehicle) -> Result<(), StoreError> {
+        self.insert(vehicle)
     }
 
     pub fn get_vehicle(&self, url: &str) -> Option<Vehicle> {
-        let vehicles = self.vehicles.lock().unwrap();
-        vehicles.get(url).cloned()
+        self.get(url)
+    }
+
+    pub fn get_vehicle_by_id(&self, id: u32) -> Option<Vehicle> {
+        self.get_by_id(id)
     }
 
     pub fn get_vehicles(&self) -> Option<Vec<Vehicle>> {
-        let vehicles = self.vehicles.lock().unwrap();
-        if vehicles.is_empty() {
-            return None;
+        self.get_all()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc::{self, Receiver, Sender};
+    use std::{env, fs, process, thread};
+    use crate::api::store::FileStore;
+    use super::*;
+
+    // Records the key of every write
+    #[derive(Default)]
+    struct CountingStore {
+        writes: Mutex<Vec<String>>,
+    }
+
+    impl RepositoryStore for CountingStore {
+        fn read(&self, _key: String) -> Result<Option<Vec<u8>>, StoreError> {
+            Ok(None)
+        }
+
+        fn write(&self, key: String, _contents: Vec<u8>) -> Result<(), StoreError> {
+            self.writes.lock().unwrap().push(key);
+            Ok(())
+        }
+
+        fn remove(&self, _key: String) -> Result<(), StoreError> {
+            Ok(())
+        }
+
+        fn keys(&self) -> Result<Vec<String>, StoreError> {
+            Ok(Vec::new())
+        }
+    }
+
+    // Holds every write until the test lets it through
+    struct BlockingStore {
+        entered: Mutex<Sender<()>>,
+        release: Mutex<Receiver<()>>,
+    }
+
+    impl RepositoryStore for BlockingStore {
+        fn read(&self, _key: String) -> Result<Option<Vec<u8>>, StoreError> {
+            Ok(None)
+        }
+
+        fn write(&self, _key: String, _contents: Vec<u8>) -> Result<(), StoreError> {
+            self.entered.lock().unwrap().send(()).unwrap();
+            self.release.lock().unwrap().recv().unwrap();
+            Ok(())
+        }
+
+        fn remove(&self, _key: String) -> Result<(), StoreError> {
+            Ok(())
+        }
+
+        fn keys(&self) -> Result<Vec<String>, StoreError> {
+            Ok(Vec::new())
+        }
+    }
+
+    fn tatooine() -> Planet {
+        let fixture =
+            include_str!("../../tests/fixtures/swapi/swapi_dev_api_planets_1-3942c3373ac3246f.json");
+        let fixture = serde_json::from_str::<serde_json::Value>(fixture).unwrap();
+        serde_json::from_str(fixture["body"].as_str().unwrap()).unwrap()
+    }
+
+    fn planet(id: u32, host: &str) -> Planet {
+        Planet {
+            url: format!("{host}/api/planets/{id}/"),
+            ..tatooine()
+        }
+    }
+
+    #[test]
+    fn looks_records_up_by_id_whichever_host_served_them() {
+        let repository = DataRepository::new();
+        repository.insert(&planet(1, "https://swapi.dev")).unwrap();
+
+        assert!(repository.get_planet("http://localhost:8000/api/planets/1/").is_some());
+        assert!(repository.get_planet_by_id(1).is_some());
+        assert!(repository.get_person_by_id(1).is_none());
+    }
+
+    #[test]
+    fn lists_records_in_numeric_id_order() {
+        let repository = DataRepository::new();
+        let planets = [10, 2, 1].map(|id| planet(id, "https://swapi.dev"));
+        repository.insert_all(&planets).unwrap();
+
+        let ids = repository.get_planets().unwrap().iter().map(|planet| planet.id()).collect::<Vec<_>>();
+        assert_eq!(ids, [Some(1), Some(2), Some(10)]);
+    }
+
+    #[test]
+    fn older_edits_do_not_replace_newer_ones() {
+        let repository = DataRepository::new();
+        let current = tatooine();
+        repository.insert(&current).unwrap();
+
+        let stale = Planet {
+            population: String::from("1000"),
+            edited: String::from("2014-12-01T00:00:00.000000Z"),
+            ..current.clone()
+        };
+        repository.insert(&stale).unwrap();
+        assert_eq!(repository.get_by_id::<Planet>(1), Some(current.clone()));
+
+        let newer = Planet {
+            population: String::from("300000"),
+            edited: String::from("2015-01-01T00:00:00.000000Z"),
+            ..current
+        };
+        repository.insert(&newer).unwrap();
+        assert_eq!(repository.get_by_id::<Planet>(1), Some(newer));
+    }
+
+    #[test]
+    fn unparseable_edit_times_fall_back_to_comparing_records() {
+        let repository = DataRepository::new();
+        let garbled = Planet {
+            edited: String::from("99999999999999-01-01"),
+            ..tatooine()
+        };
+        repository.insert(&tatooine()).unwrap();
+        repository.insert(&garbled).unwrap();
+        assert_eq!(repository.get_by_id::<Planet>(1), Some(garbled));
+    }
+
+    #[test]
+    fn reloads_what_was_saved_through_a_file_store() {
+        let directory = env::temp_dir().join(format!("starwars-repository-{}", process::id()));
+        let store = || Arc::new(FileStore::new(directory.to_string_lossy().into_owned()).unwrap());
+
+        let saved = DataRepository::with_store(store()).unwrap();
+        let planets = [1, 2].map(|id| planet(id, "https://swapi.dev"));
+        saved.insert_collection(&planets).unwrap();
+        saved.insert(&planet(3, "https://swapi.dev")).unwrap();
+
+        let reloaded = DataRepository::with_store(store()).unwrap();
+        let _ = fs::remove_dir_all(&directory);
+        assert_eq!(reloaded.get_planets(), saved.get_planets());
+        assert_eq!(reloaded.get_planets().map(|planets| planets.len()), Some(3));
+        assert!(reloaded.lookup_all::<Planet>().unwrap().is_fresh);
+    }
+
+    #[test]
+    fn only_writes_when_something_changed() {
+        let store = Arc::new(CountingStore::default());
+        let repository = DataRepository::with_store(store.clone()).unwrap();
+
+        repository.insert_all::<Planet>(&[]).unwrap();
+        repository.insert(&tatooine()).unwrap();
+        repository.insert(&tatooine()).unwrap();
+        repository.remove_in::<Planet>(&[2]).unwrap();
+        assert_eq!(*store.writes.lock().unwrap(), ["planets"]);
+
+        // Listing the collection is a change in itself
+        repository.insert_collection(&[tatooine()]).unwrap();
+        assert_eq!(store.writes.lock().unwrap().len(), 2);
+    }
+
+    fn cached_response(url: &str) -> CachedResponse {
+        CachedResponse {
+            url: url.to_owned(),
+            etag: Some(String::from("\"abc\"")),
+            last_modified: None,
+            body: String::from("{}"),
+        }
+    }
+
+    #[test]
+    fn clearing_a_table_drops_its_stored_responses() {
+        let repository = DataRepository::with_store(Arc::new(InMemoryStore::new())).unwrap();
+        let planets = "https://swapi.dev/api/planets/";
+        let people = "https://swapi.dev/api/people/";
+        repository.save_response(&cached_response(planets)).unwrap();
+        repository.save_response(&cached_response(people)).unwrap();
+
+        repository.clear(Fetchable::Planets).unwrap();
+        assert!(repository.cached_response(planets).is_none());
+        assert!(repository.cached_response(people).is_some());
+    }
+
+    #[test]
+    fn in_memory_repositories_keep_no_response_bodies() {
+        let repository = DataRepository::new();
+        let url = "https://swapi.dev/api/planets/";
+        repository.save_response(&cached_response(url)).unwrap();
+        assert!(repository.cached_response(url).is_none());
+    }
+
+    #[test]
+    fn reads_are_not_blocked_by_a_slow_store() {
+        let (entered, on_enter) = mpsc::channel();
+        let (release, on_release) = mpsc::channel();
+        let store = BlockingStore {
+            entered: Mutex::new(entered),
+            release: Mutex::new(on_release),
+        };
+        let repository = Arc::new(DataRepository::with_store(Arc::new(store)).unwrap());
+
+        let writer = thread::spawn({
+            let repository = Arc::clone(&repository);
+            move || repository.insert(&tatooine())
+        });
+        on_enter.recv().unwrap();
+        assert_eq!(repository.get_by_id::<Planet>(1).map(|planet| planet.name), Some("Tatooine".into()));
+
+        release.send(()).unwrap();
+        writer.join().unwrap().unwrap();
+    }
+
+    #[derive(Default)]
+    struct RecordingObserver {
+        changes: Mutex<Vec<(ChangeKind, Fetchable, Vec<u32>)>>,
+    }
+
+    impl RecordingObserver {
+        fn take(&self) -> Vec<(ChangeKind, Fetchable, Vec<u32>)> {
+            std::mem::take(&mut *self.changes.lock().unwrap())
+        }
+    }
+
+    impl RepositoryObserver for RecordingObserver {
+        fn on_change(&self, change: RepositoryChange) {
+            self.changes.lock().unwrap().push((change.kind, change.fetchable, change.ids));
         }
-        let mut sorted_vehicles = vehicles.values().cloned().collect::<Vec<Vehicle>>();
-        sorted_vehicles.sort_by(|a, b| a.url.cmp(&b.url));
-        Some(sorted_vehicles)
+    }
+
+    fn observed() -> (DataRepository, Arc<RecordingObserver>) {
+        let repository = DataRepository::new();
+        let observer = Arc::new(RecordingObserver::default());
+        repository.add_observer(observer.clone());
+        (repository, observer)
+    }
+
+    #[test]
+    fn tells_observers_what_was_inserted_updated_and_removed() {
+        let (repository, observer) = observed();
+        let renamed = Planet {
+            name: String::from("Tatooine Prime"),
+            ..planet(1, "https://swapi.dev")
+        };
+
+        repository.insert(&planet(1, "https://swapi.dev")).unwrap();
+        assert_eq!(observer.take(), [(ChangeKind::Inserted, Fetchable::Planets, vec![1])]);
+
+        repository.insert(&renamed).unwrap();
+        assert_eq!(observer.take(), [(ChangeKind::Updated, Fetchable::Planets, vec![1])]);
+
+        repository.remove(Fetchable::Planets, 1).unwrap();
+        assert_eq!(observer.take(), [(ChangeKind::Removed, Fetchable::Planets, vec![1])]);
+    }
+
+    #[test]
+    fn reports_new_and_changed_records_of_one_write_separately() {
+        let (repository, observer) = observed();
+        let planets = [1, 2].map(|id| planet(id, "https://swapi.dev"));
+        repository.insert_all(&planets).unwrap();
+        observer.take();
+
+        let renamed = Planet {
+            name: String::from("Alderaan"),
+            ..planet(2, "https://swapi.dev")
+        };
+        let planets = [planet(1, "https://swapi.dev"), renamed, planet(3, "https://swapi.dev")];
+        repository.insert_collection(&planets).unwrap();
+        assert_eq!(
+            observer.take(),
+            [
+                (ChangeKind::Inserted, Fetchable::Planets, vec![3]),
+                (ChangeKind::Updated, Fetchable::Planets, vec![2]),
+            ],
+        );
+
+        repository.clear(Fetchable::Planets).unwrap();
+        assert_eq!(observer.take(), [(ChangeKind::Removed, Fetchable::Planets, vec![1, 2, 3])]);
+    }
+
+    #[test]
+    fn stays_quiet_when_nothing_changed() {
+        let (repository, observer) = observed();
+        repository.insert(&planet(1, "https://swapi.dev")).unwrap();
+        observer.take();
+
+        // The same record again, an older edit of it, and removing what isn't there
+        repository.insert(&planet(1, "https://swapi.dev")).unwrap();
+        let older = Planet {
+            name: String::from("Old Tatooine"),
+            edited: String::from("2014-12-09T13:50:49.641000Z"),
+            ..planet(1, "https://swapi.dev")
+        };
+        repository.insert(&older).unwrap();
+        repository.remove(Fetchable::Planets, 2).unwrap();
+        repository.clear(Fetchable::People).unwrap();
+        assert!(observer.take().is_empty());
+    }
+
+    #[test]
+    fn removed_observers_hear_nothing_more() {
+        let repository = DataRepository::new();
+        let observer = Arc::new(RecordingObserver::default());
+        let token = repository.add_observer(observer.clone());
+        repository.remove_observer(token);
+
+        repository.insert(&planet(1, "https://swapi.dev")).unwrap();
+        assert!(observer.take().is_empty());
     }
 }
@@ -0,0 +1,214 @@
+use std::sync::Mutex;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use crate::api::cache::CacheTable;
+use crate::api::fetchable::Fetchable;
+use crate::api::repository::DataRepository;
+use crate::api::selected::Selected;
+use crate::models::films::{Film, Films};
+use crate::models::page::Page;
+use crate::models::people::{People, Person};
+use crate::models::planets::{Planet, Planets};
+use crate::models::species::{Species, SpeciesList};
+use crate::models::starships::{Starship, Starships};
+use crate::models::timestamp::{parse_timestamp, Timestamp};
+use crate::models::vehicles::{Vehicle, Vehicles};
+
+pub type Table<R> = Mutex<CacheTable<R>>;
+
+pub trait Resource: DeserializeOwned + Serialize + Clone + PartialEq + Send + Sync + 'static {
+    type Page: Page<Item = Self>;
+
+    const FETCHABLE: Fetchable;
+
+    fn url(&self) -> &str;
+    fn id(&self) -> Option<u32> {
+        Self::FETCHABLE.resource_id(self.url())
+    }
+    fn url_mut(&mut self) -> &mut String;
+    // When SWAPI last changed the record, which is what decides whether a re-fetch is newer
+    fn edited_at(&self) -> Option<Timestamp>;
+    fn search_terms(&self) -> Vec<&str>;
+    fn table(repository: &DataRepository) -> &Table<Self>;
+    fn into_selected(self) -> Selected;
+}
+
+impl Resource for Film {
+    type Page = Films;
+
+    const FETCHABLE: Fetchable = Fetchable::Films;
+
+    fn url(&self) -> &str {
+        &self.url
+    }
+
+    fn edited_at(&self) -> Option<Timestamp> {
+        parse_timestamp(&self.edited)
+    }
+
+    fn url_mut(&mut self) -> &mut String {
+        &mut self.url
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec![&self.title, &self.director]
+    }
+
+    fn table(repository: &DataRepository) -> &Table<Self> {
+        &repository.films
+    }
+
+    fn into_selected(self) -> Selected {
+        Selected::Film(self)
+    }
+}
+
+impl Resource for Person {
+    type Page = People;
+
+    const FETCHABLE: Fetchable = Fetchable::People;
+
+    fn url(&self) -> &str {
+        &self.url
+    }
+
+    fn edited_at(&self) -> Option<Timestamp> {
+        parse_timestamp(&self.edited)
+    }
+
+    fn url_mut(&mut self) -> &mut String {
+        &mut self.url
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec![&self.name]
+    }
+
+    fn table(repository: &DataRepository) -> &Table<Self> {
+        &repository.people
+    }
+
+    fn into_selected(self) -> Selected {
+        Selected::Person(self)
+    }
+}
+
+impl Resource for Planet {
+    type Page = Planets;
+
+    const FETCHABLE: Fetchable = Fetchable::Planets;
+
+    fn url(&self) -> &str {
+        &self.url
+    }
+
+    fn edited_at(&self) -> Option<Timestamp> {
+        parse_timestamp(&self.edited)
+    }
+
+    fn url_mut(&mut self) -> &mut String {
+        &mut self.url
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec![&self.name]
+    }
+
+    fn table(repository: &DataRepository) -> &Table<Self> {
+        &repository.planets
+    }
+
+    fn into_selected(self) -> Selected {
+        Selected::Planet(self)
+    }
+}
+
+impl Resource for Species {
+    type Page = SpeciesList;
+
+    const FETCHABLE: Fetchable = Fetchable::Species;
+
+    fn url(&self) -> &str {
+        &self.url
+    }
+
+    fn edited_at(&self) -> Option<Timestamp> {
+        parse_timestamp(&self.edited)
+    }
+
+    fn url_mut(&mut self) -> &mut String {
+        &mut self.url
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec![&self.name, &self.classification]
+    }
+
+    fn table(repository: &DataRepository) -> &Table<Self> {
+        &repository.species
+    }
+
+    fn into_selected(self) -> Selected {
+        Selected::Species(self)
+    }
+}
+
+impl Resource for Starship {
+    type Page = Starships;
+
+    const FETCHABLE: Fetchable = Fetchable::Starships;
+
+    fn url(&self) -> &str {
+        &self.url
+    }
+
+    fn edited_at(&self) -> Option<Timestamp> {
+        parse_timestamp(&self.edited)
+    }
+
+    fn url_mut(&mut self) -> &mut String {
+        &mut self.url
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec![&self.name, &self.model]
+    }
+
+    fn table(repository: &DataRepository) -> &Table<Self> {
+        &repository.starships
+    }
+
+    fn into_selected(self) -> Selected {
+        Selected::Starship(self)
+    }
+}
+
+impl Resource for Vehicle {
+    type Page = Vehicles;
+
+    const FETCHABLE: Fetchable = Fetchable::Vehicles;
+
+    fn url(&self) -> &str {
+        &self.url
+    }
+
+    fn edited_at(&self) -> Option<Timestamp> {
+        parse_timestamp(&self.edited)
+    }
+
+    fn url_mut(&mut self) -> &mut String {
+        &mut self.url
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec![&self.name, &self.model]
+    }
+
+    fn table(repository: &DataRepository) -> &Table<Self> {
+        &repository.vehicles
+    }
+
+    fn into_selected(self) -> Selected {
+        Selected::Vehicle(self)
+    }
+}
@@ -0,0 +1,234 @@
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use crate::api::api_error::ApiError;
+
+// SWAPI's wookiee transliteration, indexed by letter ('a'..='z'). No code is a prefix of another,
+// so wookiee text decodes greedily left to right.
+const WOOKIEE: [&str; 26] = [
+    "ra", "rh", "oa", "wa", "wo", "ww", "rr", "ac", "ah", "sh", "or", "an", "sc", "wh", "oo", "ak",
+    "rq", "rc", "c", "ao", "hu", "ho", "oh", "k", "ro", "uf",
+];
+// "http" as it comes back in wookiee output
+const WOOKIEE_URL_PREFIX: &str = "acaoaoak";
+// JSON's bare literals, transliterated along with everything else
+const WOOKIEE_LITERALS: [(&str, &str); 3] = [
+    ("whhuanan", "null"),
+    ("aorchuwo", "true"),
+    ("wwraancwo", "false"),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum ResponseFormat {
+    Json,
+    Wookiee,
+}
+
+impl ResponseFormat {
+    pub fn apply(&self, url: &str) -> String {
+        match self {
+            Self::Json => url.to_owned(),
+            // `next`/`previous` links in a wookiee page already carry the parameter
+            Self::Wookiee if url.contains("format=wookiee") => url.to_owned(),
+            Self::Wookiee => {
+                let separator = if url.contains('?') { '&' } else { '?' };
+                format!("{url}{separator}format=wookiee")
+            }
+        }
+    }
+
+    pub fn decode<T: DeserializeOwned>(&self, body: &[u8]) -> Result<T, ApiError> {
+        match self {
+            Self::Json => Ok(serde_json::from_slice(body)?),
+            Self::Wookiee => {
+                let body = restore_json(&String::from_utf8_lossy(body));
+                let value = serde_json::from_str::<Value>(&body)?;
+                Ok(serde_json::from_value(from_wookiee(value))?)
+            }
+        }
+    }
+}
+
+// Keys are mapped back so the response fits the regular models. Values stay transliterated,
+// except links, which the client needs intact to page, cache and resolve relations.
+fn from_wookiee(value: Value) -> Value {
+    match value {
+        Value::Object(fields) => Value::Object(
+            fields
+                .into_iter()
+                .map(|(key, value)| (decode_text(&key), from_wookiee(value)))
+                .collect(),
+        ),
+        Value::Array(values) => Value::Array(values.into_iter().map(from_wookiee).collect()),
+        Value::String(text) if text.starts_with(WOOKIEE_URL_PREFIX) => {
+            Value::String(decode_text(&text))
+        }
+        value => value,
+    }
+}
+
+// SWAPI transliterates the rendered JSON text rather than its values, so the syntax comes back
+// mangled too: bare `null`/`true`/`false` become unquoted words, and escape letters are encoded
+// (`\n` arrives as `\wh`). Both are restored here; everything inside strings is left alone.
+fn restore_json(body: &str) -> String {
+    let mut json = String::with_capacity(body.len());
+    let mut rest = body;
+    let mut in_string = false;
+    while let Some(next) = rest.chars().next() {
+        if !in_string && let Some((wookiee, literal)) = WOOKIEE_LITERALS
+            .iter()
+            .find(|(wookiee, _)| rest.starts_with(wookiee))
+        {
+            json.push_str(literal);
+            rest = &rest[wookiee.len()..];
+            continue;
+        }
+        rest = &rest[next.len_utf8()..];
+        json.push(next);
+        match next {
+            '"' => in_string = !in_string,
+            '\\' if in_string => rest = restore_escape(rest, &mut json),
+            _ => {}
+        }
+    }
+    json
+}
+
+// Restores the escape following a backslash and returns what's left after it
+fn restore_escape<'a>(rest: &'a str, json: &mut String) -> &'a str {
+    let Some((letter, rest)) = decode_letter(rest) else {
+        // `\"`, `\\` and `\/` have no letters to decode; copy the escaped character as is
+        let Some(escaped) = rest.chars().next() else {
+            return rest;
+        };
+        json.push(escaped);
+        return &rest[escaped.len_utf8()..];
+    };
+    json.push(letter);
+    if letter != 'u' {
+        return rest;
+    }
+    // `\uXXXX`: lowercase hex digits a-f were transliterated as well
+    let mut rest = rest;
+    for _ in 0..4 {
+        match decode_letter(rest) {
+            Some((digit, remaining)) => {
+                json.push(digit);
+                rest = remaining;
+            }
+            None => {
+                let Some(digit) = rest.chars().next() else {
+                    break;
+                };
+                json.push(digit);
+                rest = &rest[digit.len_utf8()..];
+            }
+        }
+    }
+    rest
+}
+
+// SWAPI only transliterates lowercase letters, so matching is case-sensitive and anything else
+// (uppercase, digits, punctuation) is already in its original form.
+fn decode_letter(text: &str) -> Option<(char, &str)> {
+    WOOKIEE
+        .iter()
+        .zip('a'..='z')
+        .find_map(|(code, letter)| text.strip_prefix(code).map(|rest| (letter, rest)))
+}
+
+fn decode_text(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(next) = rest.chars().next() {
+        match decode_letter(rest) {
+            Some((letter, remaining)) => {
+                decoded.push(letter);
+                rest = remaining;
+            }
+            None => {
+                decoded.push(next);
+                rest = &rest[next.len_utf8()..];
+            }
+        }
+    }
+    decoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::films::Film;
+    use crate::models::species::SpeciesList;
+
+    // swapi.dev's renderer: every lowercase letter of the rendered JSON text is transliterated
+    fn encode(text: &str) -> String {
+        text.chars()
+            .map(|c| match c {
+                'a'..='z' => WOOKIEE[(c as u8 - b'a') as usize].to_owned(),
+                c => c.to_string(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn decodes_captured_species_page() {
+        let body = include_bytes!("../../tests/fixtures/wookiee/species_page_1.json");
+        let page = ResponseFormat::Wookiee.decode::<SpeciesList>(body).unwrap();
+
+        assert_eq!(page.count, 3);
+        assert_eq!(
+            page.next.as_deref(),
+            Some("https://swapi.dev/api/species/?page=2&format=wookiee")
+        );
+        assert_eq!(page.previous, None);
+        assert_eq!(page.results[0].name, encode("Human"));
+        assert_eq!(page.results[0].url, "https://swapi.dev/api/species/1/");
+        assert_eq!(
+            page.results[0].homeworld.as_deref(),
+            Some("https://swapi.dev/api/planets/9/")
+        );
+        assert_eq!(page.results[1].homeworld, None);
+    }
+
+    #[test]
+    fn restores_escapes_in_captured_film() {
+        let body = include_bytes!("../../tests/fixtures/wookiee/film_1.json");
+        let film = ResponseFormat::Wookiee.decode::<Film>(body).unwrap();
+
+        assert_eq!(film.episode_id, 4);
+        assert!(film.opening_crawl.starts_with(&encode("It is a period of civil war.\r\n")));
+        assert_eq!(film.characters[0], "https://swapi.dev/api/people/1/");
+    }
+
+    #[test]
+    fn restores_literals_outside_strings_only() {
+        let body = encode(r#"{"a":null,"b":true,"c":false,"d":"null","e":"say \"null\"\\","f":"\u00e9"}"#);
+        let value = ResponseFormat::Wookiee.decode::<Value>(body.as_bytes()).unwrap();
+
+        assert_eq!(value["a"], Value::Null);
+        assert_eq!(value["b"], Value::Bool(true));
+        assert_eq!(value["c"], Value::Bool(false));
+        assert_eq!(value["d"], Value::String(encode("null")));
+        assert_eq!(value["e"], Value::String(encode("say \"null\"\\")));
+        assert_eq!(value["f"], Value::String(String::from("é")));
+    }
+
+    #[test]
+    fn keeps_uppercase_when_decoding_links() {
+        let url = "https://swapi.dev/api/people/?search=Skywalker&format=wookiee";
+        assert_eq!(decode_text(&encode(url)), url);
+    }
+
+    #[test]
+    fn only_adds_the_format_parameter_once() {
+        let wookiee = ResponseFormat::Wookiee;
+        assert_eq!(
+            wookiee.apply("https://swapi.dev/api/people/"),
+            "https://swapi.dev/api/people/?format=wookiee"
+        );
+        assert_eq!(
+            wookiee.apply("https://swapi.dev/api/people/?page=2&format=wookiee"),
+            "https://swapi.dev/api/people/?page=2&format=wookiee"
+        );
+    }
+}
@@ -0,0 +1,55 @@
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+use std::time::Duration;
+
+// How ApiClient retries transient failures (429, 5xx and timeouts). Delays grow exponentially
+// from `base_delay_ms`, capped at `max_delay_ms`; a server's `Retry-After` replaces the backoff.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct RetryPolicy {
+    #[uniffi(default = 3)]
+    pub max_attempts: u32,
+    #[uniffi(default = 500)]
+    pub base_delay_ms: u64,
+    // Also the longest `Retry-After` honoured: if the server asks for a longer wait, the request
+    // fails with its error straight away rather than stalling the caller.
+    #[uniffi(default = 10000)]
+    pub max_delay_ms: u64,
+    #[uniffi(default = 0.2)]
+    pub jitter: f64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay_ms: 500,
+            max_delay_ms: 10_000,
+            jitter: 0.2,
+        }
+    }
+}
+
+impl RetryPolicy {
+    // Delay before the attempt following `attempt` (1-based), or `None` once attempts are spent
+    // or the server asks us to wait longer than we are prepared to.
+    pub fn delay_after(&self, attempt: u32, retry_after: Option<Duration>) -> Option<Duration> {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+        let max_delay = Duration::from_millis(self.max_delay_ms);
+        if let Some(retry_after) = retry_after {
+            return (retry_after <= max_delay).then_some(retry_after);
+        }
+
+        let exponent = attempt.saturating_sub(1).min(31);
+        let backoff = self.base_delay_ms.saturating_mul(1 << exponent).min(self.max_delay_ms);
+        let jitter = self.jitter.clamp(0.0, 1.0) * random_unit();
+        Some(Duration::from_millis((backoff as f64 * (1.0 - jitter)) as u64))
+    }
+}
+
+fn random_unit() -> f64 {
+    // Each RandomState is seeded differently, which is plenty of entropy for spreading retries
+    let bits = RandomState::new().hash_one(0u8);
+    (bits >> 11) as f64 / (1u64 << 53) as f64
+}
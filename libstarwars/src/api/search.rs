@@ -0,0 +1,129 @@
+use std::sync::Arc;
+use crate::api::api_client::{ApiClient, ensure_active};
+use crate::api::api_error::ApiError;
+use crate::api::cancellation::CancellationToken;
+use crate::api::fetchable::Fetchable;
+use crate::api::resource::Resource;
+use crate::api::selected::Selected;
+use crate::models::films::Film;
+use crate::models::people::Person;
+use crate::models::planets::Planet;
+use crate::models::species::Species;
+use crate::models::starships::Starship;
+use crate::models::vehicles::Vehicle;
+
+#[uniffi::export]
+impl ApiClient {
+    pub async fn search(
+        &self,
+        fetchable: Fetchable,
+        query: String,
+        cancellation: Option<Arc<CancellationToken>>,
+    ) -> Result<Vec<Selected>, ApiError> {
+        let cancellation = cancellation.as_deref();
+        let remote = match fetchable {
+            Fetchable::Films => self.search_remote::<Film>(&query, cancellation).await,
+            Fetchable::People => self.search_remote::<Person>(&query, cancellation).await,
+            Fetchable::Planets => self.search_remote::<Planet>(&query, cancellation).await,
+            Fetchable::Species => self.search_remote::<Species>(&query, cancellation).await,
+            Fetchable::Starships => self.search_remote::<Starship>(&query, cancellation).await,
+            Fetchable::Vehicles => self.search_remote::<Vehicle>(&query, cancellation).await,
+        };
+        match remote {
+            // Unreachable, failing or throttling server: answer from whatever is already cached
+            Err(ApiError::NetworkError(_) | ApiError::ServerError { .. } | ApiError::RateLimited) => {
+                Ok(self.search_index().search_in(fetchable, query, u32::MAX))
+            }
+            result => result,
+        }
+    }
+}
+
+impl ApiClient {
+    async fn search_remote<R: Resource>(
+        &self,
+        query: &str,
+        cancellation: Option<&CancellationToken>,
+    ) -> Result<Vec<Selected>, ApiError> {
+        let url = R::FETCHABLE.search_url(&self.config().base_url, query);
+        let mut items = self.fetch_pages_from::<R::Page>(url, cancellation).await?.items;
+        ensure_active(cancellation)?;
+        self.rebase_all(&mut items);
+        self.repository.insert_all(&items)?;
+        Ok(items.into_iter().map(R::into_selected).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+    use crate::api::cache::CachePolicy;
+    use crate::api::client_config::ClientConfig;
+    use crate::api::fixtures::{replay_client, replay_client_with_config};
+    use crate::api::retry::RetryPolicy;
+    use super::*;
+
+    fn name(selected: &Selected) -> &str {
+        match selected {
+            Selected::Film(film) => &film.title,
+            Selected::Person(person) => &person.name,
+            Selected::Planet(planet) => &planet.name,
+            Selected::Species(species) => &species.name,
+            Selected::Starship(starship) => &starship.name,
+            Selected::Vehicle(vehicle) => &vehicle.name,
+        }
+    }
+
+    fn cached_client() -> Arc<ApiClient> {
+        // Retrying would only replay the same recorded failure
+        let config = ClientConfig {
+            retry: RetryPolicy {
+                max_attempts: 1,
+                ..RetryPolicy::default()
+            },
+            ..ClientConfig::default()
+        };
+        let client = replay_client_with_config(config);
+        block_on(client.fetch_resources::<Planet>(CachePolicy::NetworkOnly, None)).unwrap();
+        block_on(client.fetch_resources::<Person>(CachePolicy::NetworkOnly, None)).unwrap();
+        client
+    }
+
+    #[test]
+    fn falls_back_to_the_local_index_on_server_errors() {
+        // The search for "alder" is stored as a 503
+        let results = block_on(cached_client().search(Fetchable::Planets, String::from("alder"), None)).unwrap();
+        assert_eq!(results.iter().map(name).collect::<Vec<_>>(), ["Alderaan"]);
+    }
+
+    #[test]
+    fn falls_back_to_the_local_index_when_rate_limited() {
+        // The search for "vader" is stored as a 429
+        let results = block_on(cached_client().search(Fetchable::People, String::from("vader"), None)).unwrap();
+        assert_eq!(results.iter().map(name).collect::<Vec<_>>(), ["Darth Vader"]);
+    }
+
+    #[test]
+    fn falls_back_to_the_local_index_when_unreachable() {
+        // Nothing was recorded for "yavin", so the replay client fails as if offline
+        let results = block_on(cached_client().search(Fetchable::Planets, String::from("yavin"), None)).unwrap();
+        assert_eq!(results.iter().map(name).collect::<Vec<_>>(), ["Yavin IV"]);
+    }
+
+    #[test]
+    fn searches_every_fetchable() {
+        let searches = [
+            (Fetchable::Films, "hope", "A New Hope"),
+            (Fetchable::People, "sky", "Luke Skywalker"),
+            (Fetchable::Planets, "tat", "Tatooine"),
+            (Fetchable::Species, "wook", "Wookie"),
+            (Fetchable::Starships, "falcon", "Millennium Falcon"),
+            (Fetchable::Vehicles, "sand", "Sand Crawler"),
+        ];
+        for (fetchable, query, expected) in searches {
+            let results = block_on(replay_client().search(fetchable, query.to_owned(), None)).unwrap();
+            let names = results.iter().map(name).collect::<Vec<_>>();
+            assert_eq!(names, [expected], "searching {fetchable:?} for {query:?}");
+        }
+    }
+}
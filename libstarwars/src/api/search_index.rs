@@ -0,0 +1,346 @@
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use crate::api::fetchable::Fetchable;
+use crate::api::observer::{RepositoryChange, RepositoryObserver};
+use crate::api::repository::DataRepository;
+use crate::api::resource::Resource;
+use crate::api::selected::Selected;
+use crate::models::films::Film;
+use crate::models::people::Person;
+use crate::models::planets::Planet;
+use crate::models::species::Species;
+use crate::models::starships::Starship;
+use crate::models::vehicles::Vehicle;
+
+const EXACT_SCORE: f64 = 1.0;
+const PREFIX_SCORE: f64 = 0.9;
+const WORD_PREFIX_SCORE: f64 = 0.8;
+const SUBSTRING_SCORE: f64 = 0.6;
+const FUZZY_SCORE: f64 = 0.5;
+const MIN_FUZZY_QUERY_LENGTH: usize = 3;
+
+// Built in full on the first search, then kept up to date one record at a time: the index
+// observes the repository and re-reads only the records that changed since the last search.
+#[derive(uniffi::Object)]
+pub struct SearchIndex {
+    repository: Arc<DataRepository>,
+    state: Mutex<IndexState>,
+    changes: Arc<PendingChanges>,
+    observer_token: u64,
+}
+
+#[derive(Default)]
+struct IndexState {
+    built: bool,
+    entries: HashMap<(Fetchable, u32), IndexEntry>,
+}
+
+struct IndexEntry {
+    label: String,
+    terms: Vec<String>,
+    item: Selected,
+}
+
+// The observer holds no reference back to the repository, so registering it doesn't create a
+// cycle; the index unregisters it when dropped.
+#[derive(Default)]
+struct PendingChanges {
+    records: Mutex<HashSet<(Fetchable, u32)>>,
+}
+
+impl RepositoryObserver for PendingChanges {
+    fn on_change(&self, change: RepositoryChange) {
+        let mut records = self.records.lock().unwrap();
+        records.extend(change.ids.into_iter().map(|id| (change.fetchable, id)));
+    }
+}
+
+#[uniffi::export]
+impl SearchIndex {
+    #[uniffi::constructor]
+    pub fn new(repository: Arc<DataRepository>) -> Self {
+        let changes = Arc::new(PendingChanges::default());
+        let observer_token = repository.add_observer(changes.clone());
+        Self {
+            repository,
+            state: Mutex::new(IndexState::default()),
+            changes,
+            observer_token,
+        }
+    }
+
+    pub fn rebuild(&self) {
+        let mut state = self.state.lock().unwrap();
+        self.rebuild_locked(&mut state);
+    }
+
+    pub fn search(&self, query: String, limit: u32) -> Vec<Selected> {
+        self.ranked(&query, None, limit)
+    }
+
+    pub fn search_in(&self, fetchable: Fetchable, query: String, limit: u32) -> Vec<Selected> {
+        self.ranked(&query, Some(fetchable), limit)
+    }
+}
+
+impl SearchIndex {
+    fn ranked(&self, query: &str, fetchable: Option<Fetchable>, limit: u32) -> Vec<Selected> {
+        let query = normalize(query);
+        if query.is_empty() {
+            return Vec::new();
+        }
+
+        let mut state = self.state.lock().unwrap();
+        if state.built {
+            self.apply_changes(&mut state);
+        } else {
+            self.rebuild_locked(&mut state);
+        }
+
+        let mut matches = state
+            .entries
+            .iter()
+            .filter(|((table, _), _)| fetchable.is_none_or(|fetchable| *table == fetchable))
+            .filter_map(|((_, id), entry)| {
+                let score = entry
+                    .terms
+                    .iter()
+                    .map(|term| score(&query, term))
+                    .fold(0.0, f64::max);
+                (score > 0.0).then_some((score, *id, entry))
+            })
+            .collect::<Vec<_>>();
+        matches.sort_by(|(a_score, a_id, a), (b_score, b_id, b)| {
+            b_score
+                .partial_cmp(a_score)
+                .unwrap_or(Ordering::Equal)
+                .then_with(|| a.label.cmp(&b.label))
+                .then_with(|| a_id.cmp(b_id))
+        });
+        matches
+            .into_iter()
+            .take(limit as usize)
+            .map(|(_, _, entry)| entry.item.clone())
+            .collect()
+    }
+
+    fn rebuild_locked(&self, state: &mut IndexState) {
+        // Taken before reading, so a write racing the rebuild is applied again on the next search
+        self.changes.records.lock().unwrap().clear();
+        state.built = true;
+        state.entries.clear();
+        self.index_all::<Film>(state);
+        self.index_all::<Person>(state);
+        self.index_all::<Planet>(state);
+        self.index_all::<Species>(state);
+        self.index_all::<Starship>(state);
+        self.index_all::<Vehicle>(state);
+    }
+
+    fn apply_changes(&self, state: &mut IndexState) {
+        let changed = std::mem::take(&mut *self.changes.records.lock().unwrap());
+        for (fetchable, id) in changed {
+            match fetchable {
+                Fetchable::Films => self.reindex::<Film>(state, id),
+                Fetchable::People => self.reindex::<Person>(state, id),
+                Fetchable::Planets => self.reindex::<Planet>(state, id),
+                Fetchable::Species => self.reindex::<Species>(state, id),
+                Fetchable::Starships => self.reindex::<Starship>(state, id),
+                Fetchable::Vehicles => self.reindex::<Vehicle>(state, id),
+            }
+        }
+    }
+
+    fn index_all<R: Resource>(&self, state: &mut IndexState) {
+        for item in self.repository.get_all::<R>().unwrap_or_default() {
+            if let Some(id) = item.id() {
+                state.entries.insert((R::FETCHABLE, id), index_entry(item));
+            }
+        }
+    }
+
+    // Re-reads one record, dropping it from the index if it has been removed
+    fn reindex<R: Resource>(&self, state: &mut IndexState, id: u32) {
+        match self.repository.get_by_id::<R>(id) {
+            Some(item) => state.entries.insert((R::FETCHABLE, id), index_entry(item)),
+            None => state.entries.remove(&(R::FETCHABLE, id)),
+        };
+    }
+}
+
+impl Drop for SearchIndex {
+    fn drop(&mut self) {
+        self.repository.remove_observer(self.observer_token);
+    }
+}
+
+fn index_entry<R: Resource>(item: R) -> IndexEntry {
+    let terms = item.search_terms().into_iter().map(normalize).collect::<Vec<_>>();
+    IndexEntry {
+        label: terms.first().cloned().unwrap_or_default(),
+        terms,
+        item: item.into_selected(),
+    }
+}
+
+fn normalize(value: &str) -> String {
+    value.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase()
+}
+
+fn score(query: &str, term: &str) -> f64 {
+    if term == query {
+        return EXACT_SCORE;
+    }
+    if term.starts_with(query) {
+        return PREFIX_SCORE;
+    }
+    let words = term.split([' ', '-', '/']).filter(|word| !word.is_empty());
+    if words.clone().any(|word| word.starts_with(query)) {
+        return WORD_PREFIX_SCORE;
+    }
+    if term.contains(query) {
+        return SUBSTRING_SCORE;
+    }
+
+    // Tolerate typos: compare against each word and against the word's prefix of the query's
+    // length, so "skywlker" finds "skywalker" and "lukw" finds "luke".
+    let query_length = query.chars().count();
+    if query_length < MIN_FUZZY_QUERY_LENGTH {
+        return 0.0;
+    }
+    let allowed = (query_length / 4).max(1);
+    words
+        .chain(std::iter::once(term))
+        .filter_map(|word| {
+            let prefix = word.chars().take(query_length).collect::<String>();
+            let distance = levenshtein(query, word).min(levenshtein(query, &prefix));
+            (distance <= allowed).then(|| FUZZY_SCORE * (1.0 - distance as f64 / (query_length + 1) as f64))
+        })
+        .fold(0.0, f64::max)
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture<R: Resource>(contents: &str) -> R {
+        let fixture = serde_json::from_str::<serde_json::Value>(contents).unwrap();
+        serde_json::from_str(fixture["body"].as_str().unwrap()).unwrap()
+    }
+
+    const PLANET: &str =
+        include_str!("../../tests/fixtures/swapi/swapi_dev_api_planets_1-3942c3373ac3246f.json");
+    const PERSON: &str =
+        include_str!("../../tests/fixtures/swapi/swapi_dev_api_people_1-f83e62a1d294e0a9.json");
+
+    fn planet(id: u32, name: &str) -> Planet {
+        Planet {
+            name: name.to_owned(),
+            url: format!("https://swapi.dev/api/planets/{id}/"),
+            ..fixture(PLANET)
+        }
+    }
+
+    fn person(id: u32, name: &str) -> Person {
+        Person {
+            name: name.to_owned(),
+            url: format!("https://swapi.dev/api/people/{id}/"),
+            ..fixture(PERSON)
+        }
+    }
+
+    fn names(results: &[Selected]) -> Vec<&str> {
+        results
+            .iter()
+            .map(|selected| match selected {
+                Selected::Planet(planet) => planet.name.as_str(),
+                Selected::Person(person) => person.name.as_str(),
+                _ => unreachable!(),
+            })
+            .collect()
+    }
+
+    fn index_of(planets: &[(u32, &str)]) -> (Arc<DataRepository>, SearchIndex) {
+        let repository = Arc::new(DataRepository::new());
+        let planets = planets.iter().map(|(id, name)| planet(*id, name)).collect::<Vec<_>>();
+        repository.insert_all(&planets).unwrap();
+        (Arc::clone(&repository), SearchIndex::new(repository))
+    }
+
+    #[test]
+    fn ranks_exact_then_prefix_then_word_then_substring_then_typos() {
+        let (_, index) = index_of(&[
+            (1, "Shoth"),
+            (2, "New Hoth"),
+            (3, "Hott"),
+            (4, "Hothar"),
+            (5, "Hoth"),
+            (6, "Naboo"),
+        ]);
+        let results = index.search(String::from("hoth"), 10);
+        assert_eq!(names(&results), ["Hoth", "Hothar", "New Hoth", "Shoth", "Hott"]);
+        assert_eq!(names(&index.search(String::from(" HOTH "), 2)), ["Hoth", "Hothar"]);
+        assert!(index.search(String::from("   "), 10).is_empty());
+    }
+
+    #[test]
+    fn tolerates_typos_in_longer_queries() {
+        let (repository, index) = index_of(&[(1, "Tatooine")]);
+        repository.insert(&person(1, "Luke Skywalker")).unwrap();
+
+        assert_eq!(names(&index.search(String::from("skywlker"), 10)), ["Luke Skywalker"]);
+        assert_eq!(names(&index.search(String::from("lukw"), 10)), ["Luke Skywalker"]);
+        assert_eq!(names(&index.search(String::from("tatoine"), 10)), ["Tatooine"]);
+        // Too short to guess at
+        assert!(index.search(String::from("lx"), 10).is_empty());
+        assert!(index.search(String::from("wookiee"), 10).is_empty());
+    }
+
+    #[test]
+    fn searches_within_one_table() {
+        let (repository, index) = index_of(&[(1, "Kamino")]);
+        repository.insert(&person(1, "Kamino Cloner")).unwrap();
+
+        let everything = index.search(String::from("kamino"), 10);
+        assert_eq!(names(&everything), ["Kamino", "Kamino Cloner"]);
+        let people = index.search_in(Fetchable::People, String::from("kamino"), 10);
+        assert_eq!(names(&people), ["Kamino Cloner"]);
+        assert!(index.search_in(Fetchable::Films, String::from("kamino"), 10).is_empty());
+    }
+
+    #[test]
+    fn picks_up_writes_record_by_record() {
+        let (repository, index) = index_of(&[(1, "Tatooine"), (2, "Alderaan")]);
+        assert_eq!(names(&index.search(String::from("tatooine"), 10)), ["Tatooine"]);
+
+        repository.insert(&planet(1, "Tatooine Prime")).unwrap();
+        repository.insert(&planet(3, "Yavin IV")).unwrap();
+        repository.remove(Fetchable::Planets, 2).unwrap();
+        // Only the three changed records are waiting to be re-read
+        assert_eq!(index.changes.records.lock().unwrap().len(), 3);
+
+        assert_eq!(names(&index.search(String::from("tatooine"), 10)), ["Tatooine Prime"]);
+        assert_eq!(names(&index.search(String::from("yavin"), 10)), ["Yavin IV"]);
+        assert!(index.search(String::from("alderaan"), 10).is_empty());
+        assert!(index.changes.records.lock().unwrap().is_empty());
+
+        repository.clear(Fetchable::Planets).unwrap();
+        assert!(index.search(String::from("tatooine"), 10).is_empty());
+        assert!(index.state.lock().unwrap().entries.is_empty());
+    }
+}
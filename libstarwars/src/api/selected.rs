@@ -5,7 +5,7 @@ use crate::models::species::Species;
 use crate::models::starships::Starship;
 use crate::models::vehicles::Vehicle;
 
-#[derive(Debug, uniffi::Enum)]
+#[derive(Debug, Clone, uniffi::Enum)]
 pub enum Selected {
     Film(Film),
     Person(Person),
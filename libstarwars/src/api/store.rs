@@ -0,0 +1,137 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+// FNV-1a, which unlike `DefaultHasher` is stable across Rust releases, so anything named after
+// it on disk can be found again by a later build.
+pub(crate) fn stable_hash(text: &str) -> u64 {
+    text.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+#[derive(thiserror::Error, uniffi::Error, Debug, Clone)]
+pub enum StoreError {
+    #[error("Storage I/O failed: {reason}")]
+    Io { reason: String },
+}
+
+impl From<std::io::Error> for StoreError {
+    fn from(err: std::io::Error) -> Self {
+        StoreError::Io {
+            reason: err.to_string(),
+        }
+    }
+}
+
+#[uniffi::export(with_foreign)]
+pub trait RepositoryStore: Send + Sync {
+    fn read(&self, key: String) -> Result<Option<Vec<u8>>, StoreError>;
+    fn write(&self, key: String, contents: Vec<u8>) -> Result<(), StoreError>;
+    // Removing a key that isn't there is not an error
+    fn remove(&self, key: String) -> Result<(), StoreError>;
+    fn keys(&self) -> Result<Vec<String>, StoreError>;
+}
+
+// ========================================================================
+// In-memory
+// ========================================================================
+
+#[derive(uniffi::Object, Default)]
+pub struct InMemoryStore {
+    entries: Mutex<HashMap<String, Vec<u8>>>,
+}
+
+#[uniffi::export]
+impl InMemoryStore {
+    #[uniffi::constructor]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[uniffi::export]
+impl RepositoryStore for InMemoryStore {
+    fn read(&self, key: String) -> Result<Option<Vec<u8>>, StoreError> {
+        Ok(self.entries.lock().unwrap().get(&key).cloned())
+    }
+
+    fn write(&self, key: String, contents: Vec<u8>) -> Result<(), StoreError> {
+        self.entries.lock().unwrap().insert(key, contents);
+        Ok(())
+    }
+
+    fn remove(&self, key: String) -> Result<(), StoreError> {
+        self.entries.lock().unwrap().remove(&key);
+        Ok(())
+    }
+
+    fn keys(&self) -> Result<Vec<String>, StoreError> {
+        Ok(self.entries.lock().unwrap().keys().cloned().collect())
+    }
+}
+
+// ========================================================================
+// File
+// ========================================================================
+
+#[derive(uniffi::Object)]
+pub struct FileStore {
+    directory: PathBuf,
+}
+
+#[uniffi::export]
+impl FileStore {
+    #[uniffi::constructor]
+    pub fn new(directory: String) -> Result<Self, StoreError> {
+        let directory = PathBuf::from(directory);
+        fs::create_dir_all(&directory)?;
+        Ok(Self { directory })
+    }
+}
+
+impl FileStore {
+    fn path(&self, key: &str) -> PathBuf {
+        self.directory.join(format!("{key}.json"))
+    }
+}
+
+#[uniffi::export]
+impl RepositoryStore for FileStore {
+    fn read(&self, key: String) -> Result<Option<Vec<u8>>, StoreError> {
+        match fs::read(self.path(&key)) {
+            Ok(contents) => Ok(Some(contents)),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn write(&self, key: String, contents: Vec<u8>) -> Result<(), StoreError> {
+        // Write to a sibling file first so a crash mid-write never leaves a truncated cache
+        let path = self.path(&key);
+        let staging = path.with_extension("json.tmp");
+        fs::write(&staging, contents)?;
+        fs::rename(staging, path)?;
+        Ok(())
+    }
+
+    fn remove(&self, key: String) -> Result<(), StoreError> {
+        match fs::remove_file(self.path(&key)) {
+            Err(err) if err.kind() != std::io::ErrorKind::NotFound => Err(err.into()),
+            _ => Ok(()),
+        }
+    }
+
+    fn keys(&self) -> Result<Vec<String>, StoreError> {
+        let mut keys = Vec::new();
+        for entry in fs::read_dir(&self.directory)? {
+            let name = entry?.file_name();
+            // Staging files from an interrupted write end in `.json.tmp` and are skipped
+            if let Some(key) = name.to_str().and_then(|name| name.strip_suffix(".json")) {
+                keys.push(key.to_owned());
+            }
+        }
+        Ok(keys)
+    }
+}
@@ -1,6 +1,11 @@
+pub mod attributes;
 pub mod films;
+pub mod galactic_time;
+pub mod measurements;
+pub mod page;
 pub mod people;
 pub mod planets;
 pub mod species;
 pub mod starships;
+pub mod timestamp;
 pub mod vehicles;
@@ -0,0 +1,120 @@
+// Several SWAPI fields pack a list into one comma-joined string, e.g. climate "arid, temperate"
+// or manufacturer "Kuat Drive Yards, Fondor Shipyards". Each entry becomes a tag with a
+// lowercase key for matching and a label for display.
+
+const MISSING_VALUES: [&str; 2] = ["unknown", "n/a"];
+const COMPANY_SUFFIXES: [&str; 5] = ["inc", "inc.", "ltd", "ltd.", "llc"];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, uniffi::Record)]
+pub struct AttributeTag {
+    pub key: String,
+    pub label: String,
+}
+
+#[uniffi::export]
+pub fn split_attributes(value: &str) -> Vec<AttributeTag> {
+    let mut entries: Vec<String> = Vec::new();
+    for part in value.split(',') {
+        let part = part.split_whitespace().collect::<Vec<_>>().join(" ");
+        if part.is_empty() {
+            continue;
+        }
+        // "Gallofree Yards, Inc." is one company, not two
+        if let Some(previous) = entries.last_mut()
+            && COMPANY_SUFFIXES.contains(&part.to_lowercase().as_str()) {
+                previous.push_str(", ");
+                previous.push_str(&part);
+                continue;
+            }
+        entries.push(part);
+    }
+
+    let mut tags: Vec<AttributeTag> = Vec::new();
+    for entry in entries {
+        let key = entry.to_lowercase();
+        if MISSING_VALUES.contains(&key.as_str()) || tags.iter().any(|tag| tag.key == key) {
+            continue;
+        }
+        let label = if entry.chars().any(char::is_uppercase) {
+            entry
+        } else {
+            title_case(&entry)
+        };
+        tags.push(AttributeTag { key, label });
+    }
+    tags
+}
+
+fn title_case(value: &str) -> String {
+    value
+        .split(' ')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(value: &str) -> Vec<String> {
+        split_attributes(value).into_iter().map(|tag| tag.key).collect()
+    }
+
+    fn labels(value: &str) -> Vec<String> {
+        split_attributes(value).into_iter().map(|tag| tag.label).collect()
+    }
+
+    #[test]
+    fn trims_and_collapses_whitespace() {
+        assert_eq!(keys("  arid ,temperate,  ,tropical  "), ["arid", "temperate", "tropical"]);
+        assert_eq!(keys("grasslands,  mountains\n and  lakes"), ["grasslands", "mountains and lakes"]);
+        assert!(split_attributes("").is_empty());
+        assert!(split_attributes(" , ,").is_empty());
+    }
+
+    #[test]
+    fn drops_duplicates_ignoring_case() {
+        assert_eq!(labels("arid, Arid, temperate, ARID"), ["Arid", "Temperate"]);
+    }
+
+    #[test]
+    fn drops_missing_values() {
+        assert!(split_attributes("unknown").is_empty());
+        assert!(split_attributes("N/A").is_empty());
+        assert_eq!(keys("unknown, murky, n/a"), ["murky"]);
+    }
+
+    #[test]
+    fn keeps_company_suffixes_with_their_company() {
+        assert_eq!(labels("Gallofree Yards, Inc."), ["Gallofree Yards, Inc."]);
+        assert_eq!(
+            labels("Kuat Drive Yards, Fondor Shipyards, Incom Corporation, Ltd"),
+            ["Kuat Drive Yards", "Fondor Shipyards", "Incom Corporation, Ltd"],
+        );
+        // A suffix with nothing before it is kept as it is
+        assert_eq!(labels("Inc., Sienar Fleet Systems"), ["Inc.", "Sienar Fleet Systems"]);
+    }
+
+    #[test]
+    fn title_cases_lowercase_labels_only() {
+        let tags = split_attributes("gas giant, Corellian Engineering Corporation, MandalMotors");
+        assert_eq!(
+            tags,
+            [
+                AttributeTag { key: "gas giant".into(), label: "Gas Giant".into() },
+                AttributeTag {
+                    key: "corellian engineering corporation".into(),
+                    label: "Corellian Engineering Corporation".into(),
+                },
+                AttributeTag { key: "mandalmotors".into(), label: "MandalMotors".into() },
+            ],
+        );
+    }
+}
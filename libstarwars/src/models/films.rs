@@ -1,4 +1,6 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use crate::models::page::Page;
+use crate::models::timestamp::{parse_timestamp, Timestamp};
 
 #[derive(Deserialize, Debug)]
 pub struct Films {
@@ -7,7 +9,28 @@ pub struct Films {
     pub previous: Option<String>,
     pub results: Vec<Film>,
 }
-#[derive(Deserialize, Clone, Debug, uniffi::Record)]
+
+impl Page for Films {
+    type Item = Film;
+
+    fn count(&self) -> u32 {
+        self.count
+    }
+
+    fn next(&self) -> Option<&str> {
+        self.next.as_deref()
+    }
+
+    fn previous(&self) -> Option<&str> {
+        self.previous.as_deref()
+    }
+
+    fn into_results(self) -> Vec<Film> {
+        self.results
+    }
+}
+
+#[derive(Deserialize, Serialize, Clone, PartialEq, Debug, uniffi::Record)]
 pub struct Film {
     pub title: String,
     pub episode_id: i32,
@@ -39,4 +62,19 @@ impl Film {
             self.title, self.episode_id, self.release_date, self.director, self.producer
         )
     }
-}
\ No newline at end of file
+}
+
+#[uniffi::export]
+impl Film {
+    pub fn released_at(&self) -> Option<Timestamp> {
+        parse_timestamp(&self.release_date)
+    }
+
+    pub fn created_at(&self) -> Option<Timestamp> {
+        parse_timestamp(&self.created)
+    }
+
+    pub fn edited_at(&self) -> Option<Timestamp> {
+        parse_timestamp(&self.edited)
+    }
+}
@@ -0,0 +1,161 @@
+// Durations and dates as SWAPI writes them: consumables like "2 months" and birth years
+// counted from the Battle of Yavin, e.g. "19BBY", "41.9BBY" or "4ABY".
+
+const BEFORE_YAVIN: &str = "BBY";
+const AFTER_YAVIN: &str = "ABY";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, uniffi::Enum)]
+pub enum TimeUnit {
+    Hours,
+    Days,
+    Weeks,
+    Months,
+    Years,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, uniffi::Record)]
+pub struct Consumables {
+    pub amount: f64,
+    pub unit: TimeUnit,
+}
+
+// Years relative to the Battle of Yavin: negative values are BBY, positive values ABY.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, uniffi::Record)]
+pub struct GalacticYear {
+    pub years: f64,
+}
+
+impl TimeUnit {
+    pub fn days(&self) -> f64 {
+        match self {
+            Self::Hours => 1.0 / 24.0,
+            Self::Days => 1.0,
+            Self::Weeks => 7.0,
+            Self::Months => 30.0,
+            Self::Years => 365.0,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Hours => "hour",
+            Self::Days => "day",
+            Self::Weeks => "week",
+            Self::Months => "month",
+            Self::Years => "year",
+        }
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        match value.trim_end_matches('s') {
+            "hour" => Some(Self::Hours),
+            "day" => Some(Self::Days),
+            "week" => Some(Self::Weeks),
+            "month" => Some(Self::Months),
+            "year" => Some(Self::Years),
+            _ => None,
+        }
+    }
+}
+
+#[uniffi::export]
+impl Consumables {
+    pub fn days(&self) -> f64 {
+        self.amount * self.unit.days()
+    }
+}
+
+#[uniffi::export]
+pub fn parse_consumables(value: &str) -> Option<Consumables> {
+    let value = value.trim().to_lowercase();
+    let mut parts = value.split_whitespace();
+    let amount = parts
+        .next()?
+        .parse::<f64>()
+        .ok()
+        .filter(|amount| amount.is_finite() && *amount >= 0.0)?;
+    let unit = TimeUnit::parse(parts.next()?)?;
+    if parts.next().is_some() {
+        return None;
+    }
+    Some(Consumables { amount, unit })
+}
+
+#[uniffi::export]
+pub fn format_consumables(consumables: Consumables) -> String {
+    let plural = if consumables.amount == 1.0 { "" } else { "s" };
+    format!("{} {}{plural}", consumables.amount, consumables.unit.name())
+}
+
+#[uniffi::export]
+pub fn parse_galactic_year(value: &str) -> Option<GalacticYear> {
+    let value = value.trim().to_uppercase();
+    let (number, sign) = if let Some(number) = value.strip_suffix(BEFORE_YAVIN) {
+        (number, -1.0)
+    } else if let Some(number) = value.strip_suffix(AFTER_YAVIN) {
+        (number, 1.0)
+    } else {
+        return None;
+    };
+    // The era carries the sign, so "-5BBY" is rejected rather than read as 5ABY
+    let years = number
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|years| years.is_finite() && years.is_sign_positive())?;
+    Some(GalacticYear { years: sign * years })
+}
+
+#[uniffi::export]
+pub fn format_galactic_year(year: GalacticYear) -> String {
+    if year.years > 0.0 {
+        format!("{}{AFTER_YAVIN}", year.years)
+    } else {
+        format!("{}{BEFORE_YAVIN}", year.years.abs())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn galactic_years_round_trip() {
+        for value in ["19BBY", "41.9BBY", "896BBY", "0BBY", "4ABY", "0.5ABY"] {
+            let year = parse_galactic_year(value).unwrap();
+            assert_eq!(format_galactic_year(year), value);
+            assert_eq!(parse_galactic_year(&format_galactic_year(year)), Some(year));
+        }
+    }
+
+    #[test]
+    fn reads_galactic_years_loosely() {
+        assert_eq!(parse_galactic_year(" 19bby "), Some(GalacticYear { years: -19.0 }));
+        assert_eq!(parse_galactic_year("4 ABY"), Some(GalacticYear { years: 4.0 }));
+        assert!(parse_galactic_year("19BBY") < parse_galactic_year("4ABY"));
+        for value in ["unknown", "19", "BBY", "-5BBY", "19XBY", "infBBY"] {
+            assert_eq!(parse_galactic_year(value), None, "{value:?}");
+        }
+    }
+
+    #[test]
+    fn consumables_round_trip() {
+        for value in ["1 year", "2 months", "1 week", "5 days", "0.5 hours", "6 years"] {
+            let consumables = parse_consumables(value).unwrap();
+            assert_eq!(format_consumables(consumables), value);
+            assert_eq!(parse_consumables(&format_consumables(consumables)), Some(consumables));
+        }
+    }
+
+    #[test]
+    fn reads_consumables_loosely() {
+        let two_months = Consumables { amount: 2.0, unit: TimeUnit::Months };
+        assert_eq!(parse_consumables(" 2 Months "), Some(two_months));
+        assert_eq!(parse_consumables("2 month"), Some(two_months));
+        assert_eq!(format_consumables(parse_consumables("1 years").unwrap()), "1 year");
+        assert_eq!(two_months.days(), 60.0);
+        for value in ["unknown", "none", "2", "months", "-1 days", "2 fortnights", "1 week 2 days"] {
+            assert_eq!(parse_consumables(value), None, "{value:?}");
+        }
+    }
+}
@@ -0,0 +1,119 @@
+// SWAPI reports every measurement as free text: "1,358", "unknown", "n/a", "30-165", "1000km".
+// These helpers turn that text into numbers, treating anything unparseable as missing.
+
+#[derive(Debug, Clone, Copy, PartialEq, uniffi::Record)]
+pub struct NumericRange {
+    pub min: f64,
+    pub max: f64,
+}
+
+#[uniffi::export]
+pub fn parse_measurement(value: &str) -> Option<f64> {
+    parse_measurement_range(value).map(|range| range.min)
+}
+
+#[uniffi::export]
+pub fn parse_measurement_range(value: &str) -> Option<NumericRange> {
+    let value = value.trim();
+    // A leading '-' is a sign and one after an exponent marker ("1.0e-5") belongs to the
+    // number, so only split on a dash after the first character that doesn't follow e/E
+    let separator = value
+        .char_indices()
+        .zip(value.chars().skip(1))
+        .find(|((_, previous), c)| *c == '-' && !matches!(previous, 'e' | 'E'))
+        .map(|((index, previous), _)| index + previous.len_utf8());
+    match separator {
+        Some(index) => {
+            let min = parse_number(&value[..index])?;
+            let max = parse_number(&value[index + 1..])?;
+            Some(NumericRange {
+                min: min.min(max),
+                max: min.max(max),
+            })
+        }
+        None => parse_number(value).map(|number| NumericRange {
+            min: number,
+            max: number,
+        }),
+    }
+}
+
+#[uniffi::export]
+pub fn parse_count(value: &str) -> Option<u64> {
+    let digits = strip_number(value);
+    match digits.parse::<u64>() {
+        Ok(count) => Some(count),
+        Err(_) => parse_measurement(value)
+            .filter(|number| *number >= 0.0)
+            .map(|number| number as u64),
+    }
+}
+
+fn parse_number(value: &str) -> Option<f64> {
+    strip_number(value)
+        .parse::<f64>()
+        .ok()
+        .filter(|number| number.is_finite())
+}
+
+fn strip_number(value: &str) -> String {
+    value
+        .trim()
+        .trim_end_matches(|c: char| c.is_ascii_alphabetic())
+        .chars()
+        .filter(|c| *c != ',' && !c.is_whitespace())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(min: f64, max: f64) -> Option<NumericRange> {
+        Some(NumericRange { min, max })
+    }
+
+    #[test]
+    fn parses_plain_and_decorated_numbers() {
+        assert_eq!(parse_measurement("172"), Some(172.0));
+        assert_eq!(parse_measurement("1,358"), Some(1358.0));
+        assert_eq!(parse_measurement(" 1000km "), Some(1000.0));
+        assert_eq!(parse_measurement("0.9"), Some(0.9));
+        assert_eq!(parse_measurement("-5"), Some(-5.0));
+    }
+
+    #[test]
+    fn treats_placeholders_as_missing() {
+        for value in ["unknown", "n/a", "none", "indefinite", "", "-"] {
+            assert_eq!(parse_measurement(value), None, "{value:?}");
+        }
+    }
+
+    #[test]
+    fn parses_ranges_low_to_high() {
+        assert_eq!(parse_measurement_range("30-165"), range(30.0, 165.0));
+        assert_eq!(parse_measurement_range("165 - 30"), range(30.0, 165.0));
+        assert_eq!(parse_measurement_range("1,000-1,500"), range(1000.0, 1500.0));
+        assert_eq!(parse_measurement_range("-10-20"), range(-10.0, 20.0));
+        assert_eq!(parse_measurement_range("30-unknown"), None);
+        assert_eq!(parse_measurement("30-165"), Some(30.0));
+    }
+
+    #[test]
+    fn keeps_negative_exponents_inside_the_number() {
+        assert_eq!(parse_measurement_range("1.0e-5"), range(1.0e-5, 1.0e-5));
+        assert_eq!(parse_measurement_range("2E-3"), range(2.0e-3, 2.0e-3));
+        assert_eq!(parse_measurement_range("1.0e-5-2.0e-5"), range(1.0e-5, 2.0e-5));
+        assert_eq!(parse_measurement_range("1e5-2e5"), range(1.0e5, 2.0e5));
+    }
+
+    #[test]
+    fn counts_are_whole_and_never_negative() {
+        assert_eq!(parse_count("200000"), Some(200_000));
+        assert_eq!(parse_count("1,000,000,000,000"), Some(1_000_000_000_000));
+        assert_eq!(parse_count("1.5e3"), Some(1500));
+        assert_eq!(parse_count("30-165"), Some(30));
+        assert_eq!(parse_count("-3"), None);
+        assert_eq!(parse_count("unknown"), None);
+    }
+}
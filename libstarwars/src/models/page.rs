@@ -0,0 +1,10 @@
+use serde::de::DeserializeOwned;
+
+pub trait Page: DeserializeOwned {
+    type Item;
+
+    fn count(&self) -> u32;
+    fn next(&self) -> Option<&str>;
+    fn previous(&self) -> Option<&str>;
+    fn into_results(self) -> Vec<Self::Item>;
+}
@@ -1,4 +1,8 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use crate::models::galactic_time::{parse_galactic_year, GalacticYear};
+use crate::models::measurements::parse_measurement;
+use crate::models::page::Page;
+use crate::models::timestamp::{parse_timestamp, Timestamp};
 
 #[derive(Deserialize, Debug)]
 pub struct People {
@@ -8,7 +12,27 @@ pub struct People {
     pub results: Vec<Person>,
 }
 
-#[derive(Deserialize, Clone, Debug, uniffi::Record)]
+impl Page for People {
+    type Item = Person;
+
+    fn count(&self) -> u32 {
+        self.count
+    }
+
+    fn next(&self) -> Option<&str> {
+        self.next.as_deref()
+    }
+
+    fn previous(&self) -> Option<&str> {
+        self.previous.as_deref()
+    }
+
+    fn into_results(self) -> Vec<Person> {
+        self.results
+    }
+}
+
+#[derive(Deserialize, Serialize, Clone, PartialEq, Debug, uniffi::Record)]
 pub struct Person {
     pub birth_year: String,
     pub eye_color: String,
@@ -56,4 +80,27 @@ impl Person {
                 .map_or_else(|| "Unknown".to_string(), |s| s.clone()),
         )
     }
-}
\ No newline at end of file
+}
+
+#[uniffi::export]
+impl Person {
+    pub fn height_value(&self) -> Option<f64> {
+        parse_measurement(&self.height)
+    }
+
+    pub fn mass_value(&self) -> Option<f64> {
+        parse_measurement(&self.mass)
+    }
+
+    pub fn birth_year_value(&self) -> Option<GalacticYear> {
+        parse_galactic_year(&self.birth_year)
+    }
+
+    pub fn created_at(&self) -> Option<Timestamp> {
+        parse_timestamp(&self.created)
+    }
+
+    pub fn edited_at(&self) -> Option<Timestamp> {
+        parse_timestamp(&self.edited)
+    }
+}
@@ -1,4 +1,8 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use crate::models::attributes::{split_attributes, AttributeTag};
+use crate::models::measurements::{parse_count, parse_measurement};
+use crate::models::page::Page;
+use crate::models::timestamp::{parse_timestamp, Timestamp};
 
 #[derive(Deserialize, Debug)]
 pub struct Planets {
@@ -8,7 +12,27 @@ pub struct Planets {
     pub results: Vec<Planet>,
 }
 
-#[derive(Deserialize, Clone, Debug, uniffi::Record)]
+impl Page for Planets {
+    type Item = Planet;
+
+    fn count(&self) -> u32 {
+        self.count
+    }
+
+    fn next(&self) -> Option<&str> {
+        self.next.as_deref()
+    }
+
+    fn previous(&self) -> Option<&str> {
+        self.previous.as_deref()
+    }
+
+    fn into_results(self) -> Vec<Planet> {
+        self.results
+    }
+}
+
+#[derive(Deserialize, Serialize, Clone, PartialEq, Debug, uniffi::Record)]
 pub struct Planet {
     pub name: String,
     pub rotation_period: String,
@@ -52,4 +76,43 @@ impl Planet {
             self.population
         )
     }
-}
\ No newline at end of file
+}
+
+#[uniffi::export]
+impl Planet {
+    pub fn rotation_period_value(&self) -> Option<f64> {
+        parse_measurement(&self.rotation_period)
+    }
+
+    pub fn orbital_period_value(&self) -> Option<f64> {
+        parse_measurement(&self.orbital_period)
+    }
+
+    pub fn diameter_value(&self) -> Option<f64> {
+        parse_measurement(&self.diameter)
+    }
+
+    pub fn surface_water_value(&self) -> Option<f64> {
+        parse_measurement(&self.surface_water)
+    }
+
+    pub fn population_value(&self) -> Option<u64> {
+        parse_count(&self.population)
+    }
+
+    pub fn climate_tags(&self) -> Vec<AttributeTag> {
+        split_attributes(&self.climate)
+    }
+
+    pub fn terrain_tags(&self) -> Vec<AttributeTag> {
+        split_attributes(&self.terrain)
+    }
+
+    pub fn created_at(&self) -> Option<Timestamp> {
+        parse_timestamp(&self.created)
+    }
+
+    pub fn edited_at(&self) -> Option<Timestamp> {
+        parse_timestamp(&self.edited)
+    }
+}
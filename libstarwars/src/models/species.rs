@@ -1,4 +1,8 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use crate::models::attributes::{split_attributes, AttributeTag};
+use crate::models::measurements::parse_measurement;
+use crate::models::page::Page;
+use crate::models::timestamp::{parse_timestamp, Timestamp};
 
 #[derive(Debug, Deserialize)]
 pub struct SpeciesList {
@@ -8,7 +12,27 @@ pub struct SpeciesList {
     pub results: Vec<Species>,
 }
 
-#[derive(Debug, Clone, Deserialize, uniffi::Record)]
+impl Page for SpeciesList {
+    type Item = Species;
+
+    fn count(&self) -> u32 {
+        self.count
+    }
+
+    fn next(&self) -> Option<&str> {
+        self.next.as_deref()
+    }
+
+    fn previous(&self) -> Option<&str> {
+        self.previous.as_deref()
+    }
+
+    fn into_results(self) -> Vec<Species> {
+        self.results
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, uniffi::Record)]
 pub struct Species {
     pub name: String,
     pub classification: String,
@@ -57,4 +81,35 @@ impl Species {
             self.language
         )
     }
-}
\ No newline at end of file
+}
+
+#[uniffi::export]
+impl Species {
+    pub fn average_height_value(&self) -> Option<f64> {
+        parse_measurement(&self.average_height)
+    }
+
+    pub fn average_lifespan_value(&self) -> Option<f64> {
+        parse_measurement(&self.average_lifespan)
+    }
+
+    pub fn skin_color_tags(&self) -> Vec<AttributeTag> {
+        split_attributes(&self.skin_colors)
+    }
+
+    pub fn hair_color_tags(&self) -> Vec<AttributeTag> {
+        split_attributes(&self.hair_colors)
+    }
+
+    pub fn eye_color_tags(&self) -> Vec<AttributeTag> {
+        split_attributes(&self.eye_colors)
+    }
+
+    pub fn created_at(&self) -> Option<Timestamp> {
+        parse_timestamp(&self.created)
+    }
+
+    pub fn edited_at(&self) -> Option<Timestamp> {
+        parse_timestamp(&self.edited)
+    }
+}
@@ -1,4 +1,9 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use crate::models::attributes::{split_attributes, AttributeTag};
+use crate::models::galactic_time::{parse_consumables, Consumables};
+use crate::models::measurements::{NumericRange, parse_count, parse_measurement, parse_measurement_range};
+use crate::models::page::Page;
+use crate::models::timestamp::{parse_timestamp, Timestamp};
 
 #[derive(Deserialize, Debug)]
 pub struct Starships {
@@ -8,7 +13,27 @@ pub struct Starships {
     pub results: Vec<Starship>,
 }
 
-#[derive(Deserialize, Clone, Debug, uniffi::Record)]
+impl Page for Starships {
+    type Item = Starship;
+
+    fn count(&self) -> u32 {
+        self.count
+    }
+
+    fn next(&self) -> Option<&str> {
+        self.next.as_deref()
+    }
+
+    fn previous(&self) -> Option<&str> {
+        self.previous.as_deref()
+    }
+
+    fn into_results(self) -> Vec<Starship> {
+        self.results
+    }
+}
+
+#[derive(Deserialize, Serialize, Clone, PartialEq, Debug, uniffi::Record)]
 pub struct Starship {
     pub name: String,
     pub model: String,
@@ -66,3 +91,54 @@ impl Starship {
         )
     }
 }
+
+#[uniffi::export]
+impl Starship {
+    pub fn cost_in_credits_value(&self) -> Option<u64> {
+        parse_count(&self.cost_in_credits)
+    }
+
+    pub fn length_value(&self) -> Option<f64> {
+        parse_measurement(&self.length)
+    }
+
+    pub fn max_atmosphering_speed_value(&self) -> Option<f64> {
+        parse_measurement(&self.max_atmosphering_speed)
+    }
+
+    pub fn crew_range(&self) -> Option<NumericRange> {
+        parse_measurement_range(&self.crew)
+    }
+
+    pub fn passengers_range(&self) -> Option<NumericRange> {
+        parse_measurement_range(&self.passengers)
+    }
+
+    pub fn cargo_capacity_value(&self) -> Option<u64> {
+        parse_count(&self.cargo_capacity)
+    }
+
+    pub fn consumables_value(&self) -> Option<Consumables> {
+        parse_consumables(&self.consumables)
+    }
+
+    pub fn hyperdrive_rating_value(&self) -> Option<f64> {
+        parse_measurement(&self.hyperdrive_rating)
+    }
+
+    pub fn mglt_value(&self) -> Option<f64> {
+        parse_measurement(&self.mglt)
+    }
+
+    pub fn manufacturer_tags(&self) -> Vec<AttributeTag> {
+        split_attributes(&self.manufacturer)
+    }
+
+    pub fn created_at(&self) -> Option<Timestamp> {
+        parse_timestamp(&self.created)
+    }
+
+    pub fn edited_at(&self) -> Option<Timestamp> {
+        parse_timestamp(&self.edited)
+    }
+}
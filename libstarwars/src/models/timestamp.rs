@@ -0,0 +1,142 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// Exposed to Swift as `Date` and to Kotlin as `java.time.Instant` via uniffi's `SystemTime` support.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Timestamp(pub SystemTime);
+
+uniffi::custom_type!(Timestamp, SystemTime, {
+    lower: |timestamp| timestamp.0,
+    try_lift: |time| Ok(Timestamp(time)),
+});
+
+impl Timestamp {
+    pub fn from_unix_seconds(seconds: i64, nanos: u32) -> Self {
+        let time = if seconds >= 0 {
+            UNIX_EPOCH + Duration::new(seconds as u64, nanos)
+        } else {
+            UNIX_EPOCH - Duration::from_secs(seconds.unsigned_abs()) + Duration::from_nanos(nanos.into())
+        };
+        Timestamp(time)
+    }
+}
+
+// Accepts the two shapes SWAPI uses: RFC 3339 timestamps ("2014-12-09T13:50:51.644000Z")
+// for `created`/`edited`, and plain dates ("1977-05-25") for `release_date`.
+#[uniffi::export]
+pub fn parse_timestamp(value: &str) -> Option<Timestamp> {
+    let value = value.trim();
+    let (date, time) = match value.split_once(['T', 't', ' ']) {
+        Some((date, time)) => (date, Some(time)),
+        None => (value, None),
+    };
+
+    let mut date_parts = date.splitn(3, '-');
+    // Four-digit years keep the arithmetic below well clear of overflow
+    let year = date_parts.next()?.parse::<i64>().ok().filter(|year| (0..=9999).contains(year))?;
+    let month = date_parts.next()?.parse::<u32>().ok().filter(|month| (1..=12).contains(month))?;
+    let day = date_parts
+        .next()?
+        .parse::<u32>()
+        .ok()
+        .filter(|day| (1..=days_in_month(year, month)).contains(day))?;
+    let mut seconds = days_from_civil(year, month, day).checked_mul(86_400)?;
+    let mut nanos = 0;
+
+    if let Some(time) = time {
+        let (clock, offset) = split_offset(time)?;
+        let (clock, fraction) = match clock.split_once('.') {
+            Some((clock, fraction)) => (clock, Some(fraction)),
+            None => (clock, None),
+        };
+        let mut clock_parts = clock.splitn(3, ':');
+        let hours = clock_parts.next()?.parse::<i64>().ok().filter(|hours| (0..24).contains(hours))?;
+        let minutes = clock_parts.next()?.parse::<i64>().ok().filter(|minutes| (0..60).contains(minutes))?;
+        let secs = match clock_parts.next() {
+            Some(secs) => secs.parse::<i64>().ok().filter(|secs| (0..=60).contains(secs))?,
+            None => 0,
+        };
+        seconds = seconds.checked_add(hours * 3_600 + minutes * 60 + secs - offset)?;
+        if let Some(fraction) = fraction {
+            if fraction.is_empty() || !fraction.bytes().all(|byte| byte.is_ascii_digit()) {
+                return None;
+            }
+            let digits = &fraction[..fraction.len().min(9)];
+            nanos = digits.parse::<u32>().ok()? * 10u32.pow(9 - digits.len() as u32);
+        }
+    }
+
+    Some(Timestamp::from_unix_seconds(seconds, nanos))
+}
+
+// Splits a trailing `Z` or `±hh:mm` zone designator off a time, returning the offset in seconds.
+// Times without a designator are taken as UTC, which is what SWAPI means.
+fn split_offset(time: &str) -> Option<(&str, i64)> {
+    if let Some(clock) = time.strip_suffix(['Z', 'z']) {
+        return Some((clock, 0));
+    }
+    let Some(index) = time.rfind(['+', '-']) else {
+        return Some((time, 0));
+    };
+    let (clock, zone) = time.split_at(index);
+    let sign = if zone.starts_with('-') { -1 } else { 1 };
+    let (hours, minutes) = zone[1..].split_once(':').unwrap_or((&zone[1..], "0"));
+    let hours = hours.parse::<i64>().ok().filter(|hours| (0..24).contains(hours))?;
+    let minutes = minutes.parse::<i64>().ok().filter(|minutes| (0..60).contains(minutes))?;
+    Some((clock, sign * (hours * 3_600 + minutes * 60)))
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// Days since 1970-01-01 in the proleptic Gregorian calendar (Howard Hinnant's algorithm).
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = i64::from(month);
+    let shifted_month = if month > 2 { month - 3 } else { month + 9 };
+    let day_of_year = (153 * shifted_month + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_days_past_the_end_of_the_month() {
+        assert!(parse_timestamp("2014-02-31").is_none());
+        assert!(parse_timestamp("2014-04-31T10:00:00Z").is_none());
+        assert!(parse_timestamp("2014-12-31").is_some());
+    }
+
+    #[test]
+    fn allows_the_leap_day_only_in_leap_years() {
+        assert!(parse_timestamp("2016-02-29").is_some());
+        assert!(parse_timestamp("2000-02-29").is_some());
+        assert!(parse_timestamp("2015-02-29").is_none());
+        assert!(parse_timestamp("1900-02-29").is_none());
+    }
+
+    #[test]
+    fn rejects_out_of_range_years_and_offsets() {
+        assert!(parse_timestamp("99999999999999-01-01").is_none());
+        assert!(parse_timestamp("10000-01-01").is_none());
+        assert!(parse_timestamp("2014-12-09T13:50:51+99999999999999:00").is_none());
+        assert!(parse_timestamp("2014-12-09T13:50:51+05:75").is_none());
+        assert!(parse_timestamp("2014-12-09T13:50:51+05:30").is_some());
+    }
+
+    #[test]
+    fn parses_swapi_timestamps() {
+        let parsed = parse_timestamp("2014-12-09T13:50:51.644000Z").unwrap();
+        assert_eq!(parsed, Timestamp::from_unix_seconds(1_418_133_051, 644_000_000));
+    }
+}